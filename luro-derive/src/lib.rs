@@ -3,6 +3,26 @@ use proc_macro::TokenStream;
 mod functions;
 mod slash_command;
 
+/// Turn an async function into a `luro_model::command::SlashCommand`.
+///
+/// The first paragraph of the doc comment is the description, and the rest is the help text.
+/// The first parameter is the context, taken as `&mut`. Every other parameter is an option, typed using
+/// `twilight_interactions::command::CreateOption`, so enums deriving `CreateOption` become choices.
+///
+/// The command takes `rename`, `nsfw`, `ephemeral` and the checks `guild_only`, `owner_only`, `user_permissions = "..."`,
+/// `bot_permissions = "..."` and `cooldown = "..."`, which work like they do on `#[command(...)]`.
+///
+/// Parameters take `#[description = "..."]` (or a doc comment), `#[rename = "..."]`, `#[flag]`, `#[channel_types = "..."]`,
+/// `#[min_value = ...]`, `#[max_value = ...]`, `#[min_length = ...]` and `#[max_length = ...]`.
+/// `#[autocomplete]` is refused, as function commands can't handle autocomplete yet.
+///
+/// ```ignore
+/// /// Roll a die
+/// #[luro_derive::slash_command(ephemeral)]
+/// pub async fn roll(framework: &mut InteractionContext, #[min_value = 2] #[description = "How many sides"] sides: i64) -> InteractionResult<()> {
+///     framework.respond(|r| r.content(format!("You rolled {}", fastrand::i64(1..=sides)))).await
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn slash_command(args: TokenStream, function: TokenStream) -> TokenStream {
     let args = match darling::ast::NestedMeta::parse_meta_list(args.into()) {
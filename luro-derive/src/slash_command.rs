@@ -3,8 +3,16 @@ use syn::spanned::Spanned;
 #[derive(Default, Debug, darling::FromMeta)]
 #[darling(default)]
 pub struct SlashCommandArgs {
+    /// Use a different command name than the function name, such as one with a dash in it
+    rename: Option<String>,
     nsfw: bool,
     ephemeral: bool,
+    /// Checks run before the command, with the same syntax as `#[command(...)]` in twilight-interactions
+    guild_only: bool,
+    owner_only: bool,
+    user_permissions: Option<String>,
+    bot_permissions: Option<String>,
+    cooldown: Option<String>,
 }
 
 #[derive(Default, Debug, darling::FromMeta)]
@@ -13,6 +21,21 @@ struct SlashCommandParameterArgs {
     description: Option<String>,
     rename: Option<String>,
     flag: bool,
+    autocomplete: bool,
+    /// Space separated channel types, such as `"guild_text guild_voice"`
+    channel_types: Option<String>,
+    min_value: Option<syn::Lit>,
+    max_value: Option<syn::Lit>,
+    min_length: Option<u16>,
+    max_length: Option<u16>,
+}
+
+/// A function parameter, turned into a field of the generated options struct
+struct CommandParameter {
+    field: proc_macro2::TokenStream,
+    ident: syn::Ident,
+    flag: bool,
+    required: bool,
 }
 
 use darling::Error;
@@ -27,9 +50,21 @@ pub fn slash_command(args: SlashCommandArgs, mut function: syn::ItemFn) -> Resul
     }
 
     // The function should have an output of `Result<(), ...>`
-    if function.sig.output == syn::ReturnType::Default {
-        return Err(syn::Error::new(function.sig.span(), "command function must return Result<(), ...>").into());
-    }
+    let output = match &function.sig.output {
+        syn::ReturnType::Type(_, output) => output.as_ref().clone(),
+        syn::ReturnType::Default => {
+            return Err(syn::Error::new(function.sig.span(), "command function must return Result<(), ...>").into())
+        }
+    };
+
+    // The first parameter is the context the command is run with, such as `&mut InteractionContext`
+    let context = match function.sig.inputs.first() {
+        Some(syn::FnArg::Typed(pattern)) => match pattern.ty.as_ref() {
+            syn::Type::Reference(reference) if reference.mutability.is_some() => reference.elem.as_ref().clone(),
+            ty => return Err(syn::Error::new(ty.span(), "the context must be a mutable reference").into()),
+        },
+        _ => return Err(syn::Error::new(function.sig.span(), "command function must take a context first").into()),
+    };
 
     // Extract the command descriptions from the function doc comments
     let (description, help_text) = match extract_help_from_doc_comments(&function.attrs) {
@@ -37,32 +72,105 @@ pub fn slash_command(args: SlashCommandArgs, mut function: syn::ItemFn) -> Resul
         (Some(description), help_text) => (description, help_text),
     };
 
+    if description.chars().count() > 100 {
+        return Err(syn::Error::new(
+            function.sig.span(),
+            "the first paragraph of the doc comment must be 100 characters or less",
+        )
+        .into());
+    }
+
     // Handle the parameters present
-    let command_parameters = command_parameters(&mut function)?;
+    let mut command_parameters = command_parameters(&mut function)?;
 
     // Details needed for the function itself
-    let command_name = &function.sig.ident.to_string();
+    let command_name = args.rename.unwrap_or_else(|| function.sig.ident.to_string());
     let function_ident = std::mem::replace(&mut function.sig.ident, syn::parse_quote! { inner });
-    let function_generics = &function.sig.generics;
-    let function_visibility = &function.vis;
-    let function = &function;
+    let function_visibility = std::mem::replace(&mut function.vis, syn::Visibility::Inherited);
+    let function_docs = function
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .cloned()
+        .collect::<Vec<_>>();
+    function.attrs.retain(|attr| !attr.path().is_ident("doc"));
+
+    // Arguments are passed in the order of the function, while Discord wants required options before optional ones
+    let arguments = command_parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            match parameter.flag {
+                true => quote::quote! { options.#ident.unwrap_or_default() },
+                false => quote::quote! { options.#ident },
+            }
+        })
+        .collect::<Vec<_>>();
+    command_parameters.sort_by_key(|parameter| !parameter.required);
+    let fields = command_parameters.iter().map(|parameter| &parameter.field);
 
     // Details needed for the slash command
     let nsfw = args.nsfw;
     let ephemeral = args.ephemeral;
+    let mut command_arguments = vec![
+        quote::quote! { name = #command_name },
+        quote::quote! { desc = #description },
+        quote::quote! { nsfw = #nsfw },
+    ];
+    if args.guild_only {
+        command_arguments.push(quote::quote! { guild_only });
+    }
+    if args.owner_only {
+        command_arguments.push(quote::quote! { owner_only });
+    }
+    if let Some(user_permissions) = &args.user_permissions {
+        command_arguments.push(quote::quote! { user_permissions = #user_permissions });
+    }
+    if let Some(bot_permissions) = &args.bot_permissions {
+        command_arguments.push(quote::quote! { bot_permissions = #bot_permissions });
+    }
+    if let Some(cooldown) = &args.cooldown {
+        command_arguments.push(quote::quote! { cooldown = #cooldown });
+    }
+    let long_description = match help_text {
+        Some(help_text) => quote::quote! { Some(#help_text.to_owned()) },
+        None => quote::quote! { None },
+    };
 
     let slash_command = quote::quote! {
-        #function_visibility fn #function_ident -> ::luro_model::command::SlashCommand {
+        #( #function_docs )*
+        #function_visibility fn #function_ident() -> ::luro_model::command::SlashCommand<#context, <#output as ::luro_model::command::SlashOutput>::Error> {
             #function
 
+            #[derive(::twilight_interactions::command::CommandModel, ::twilight_interactions::command::CreateCommand)]
+            #[command( #( #command_arguments ),* )]
+            struct Options {
+                #( #fields ),*
+            }
+
+            fn handler<'a>(
+                context: &'a mut #context,
+                data: ::twilight_interactions::command::CommandInputData<'static>,
+            ) -> ::luro_model::BoxFuture<'a, #output> {
+                Box::pin(async move {
+                    let options = match <Options as ::twilight_interactions::command::CommandModel>::from_interaction(data) {
+                        Ok(options) => options,
+                        Err(why) => return Err(::std::convert::From::from(why)),
+                    };
+
+                    inner(context, #( #arguments ),*).await
+                })
+            }
+
             ::luro_model::command::SlashCommand {
-                command: Box::pin(async move {}),
-                name: #command_name.to_string(),
-                description: #description.to_string(),
-                long_description: Some("WTF".to_owned()),
-                parameters: vec![ #( #command_parameters ),* ],
+                name: #command_name.to_owned(),
+                description: #description.to_owned(),
+                long_description: #long_description,
+                parameters: <Options as ::twilight_interactions::command::CreateCommand>::create_command().options,
                 nsfw: #nsfw,
-                ephemeral: #ephemeral
+                ephemeral: #ephemeral,
+                checks: <Options as ::twilight_interactions::command::CreateCommand>::CHECKS,
+                handler,
             }
         }
     };
@@ -70,9 +178,9 @@ pub fn slash_command(args: SlashCommandArgs, mut function: syn::ItemFn) -> Resul
     Ok(slash_command.into())
 }
 
-fn command_parameters(function: &mut syn::ItemFn) -> Result<Vec<proc_macro2::TokenStream>, Error> {
+fn command_parameters(function: &mut syn::ItemFn) -> Result<Vec<CommandParameter>, Error> {
     let mut command_parameters = Vec::new();
-    for command_parameter in function.sig.inputs.iter_mut().skip(1) {
+    for (index, command_parameter) in function.sig.inputs.iter_mut().skip(1).enumerate() {
         let pattern = match command_parameter {
             syn::FnArg::Typed(x) => x,
             syn::FnArg::Receiver(r) => {
@@ -80,11 +188,9 @@ fn command_parameters(function: &mut syn::ItemFn) -> Result<Vec<proc_macro2::Tok
             }
         };
 
-        let attrs: Vec<_> = pattern
-            .attrs
-            .drain(..)
-            .map(|attr| darling::ast::NestedMeta::Meta(attr.meta))
-            .collect();
+        // Doc comments are read as the description, everything else is an argument for the option
+        let (docs, attrs): (Vec<_>, Vec<_>) = pattern.attrs.drain(..).partition(|attr| attr.path().is_ident("doc"));
+        let attrs: Vec<_> = attrs.into_iter().map(|attr| darling::ast::NestedMeta::Meta(attr.meta)).collect();
         let arguments = <SlashCommandParameterArgs as darling::FromMeta>::from_list(&attrs)?;
 
         let command_option_name = if let Some(rename) = &arguments.rename {
@@ -96,101 +202,62 @@ fn command_parameters(function: &mut syn::ItemFn) -> Result<Vec<proc_macro2::Tok
             return Err(syn::Error::new(pattern.pat.span(), message).into());
         };
 
-        // no #[description] check here even if slash_command set, so users can programatically
-        // supply descriptions later (e.g. via translation framework like fluent)
-        let description = match arguments.description {
-            Some(ref description) => description.clone(),
-            None => "No description for this paramater has been set.".to_owned(),
+        // Use #[description = "..."] first, then the doc comment, so descriptions can also be supplied by a translation framework later
+        let description = match (arguments.description, extract_help_from_doc_comments(&docs).0) {
+            (Some(description), _) | (None, Some(description)) => description,
+            (None, None) => "No description for this parameter has been set.".to_owned(),
         };
 
-        let (mut required, _type_) =
-            match extract_type_parameter("Option", &pattern.ty).or_else(|| extract_type_parameter("Vec", &pattern.ty)) {
-                Some(t) => (false, t),
-                None => (true, pattern.ty.as_ref()),
-            };
-
         // Don't require user to input a value for flags - use false as default value (see below)
-        if arguments.flag {
-            required = false;
+        let required = extract_type_parameter("Option", &pattern.ty).is_none() && !arguments.flag;
+        let ty = match arguments.flag {
+            true => syn::parse_quote! { Option<bool> },
+            false => pattern.ty.as_ref().clone(),
+        };
+
+        // The option types, choices and limits are handled by twilight-interactions, based on the parameter type
+        let mut option_arguments = vec![
+            quote::quote! { rename = #command_option_name },
+            quote::quote! { desc = #description },
+        ];
+        // Function commands have nowhere to handle the autocomplete interactions yet, so the option could never be filled in
+        if arguments.autocomplete {
+            let message = "#[autocomplete] is not supported on function commands, write the command as a struct instead";
+            return Err(syn::Error::new(pattern.pat.span(), message).into());
+        }
+        if let Some(channel_types) = &arguments.channel_types {
+            option_arguments.push(quote::quote! { channel_types = #channel_types });
+        }
+        if let Some(min_value) = &arguments.min_value {
+            option_arguments.push(quote::quote! { min_value = #min_value });
+        }
+        if let Some(max_value) = &arguments.max_value {
+            option_arguments.push(quote::quote! { max_value = #max_value });
+        }
+        if let Some(min_length) = arguments.min_length {
+            let min_length = proc_macro2::Literal::u16_unsuffixed(min_length);
+            option_arguments.push(quote::quote! { min_length = #min_length });
+        }
+        if let Some(max_length) = arguments.max_length {
+            let max_length = proc_macro2::Literal::u16_unsuffixed(max_length);
+            option_arguments.push(quote::quote! { max_length = #max_length });
         }
 
-        // // We can just cast to f64 here because Discord only uses f64 precision anyways
-        // // TODO: move this to poise::CommandParameter::{min, max} fields
-        // let min_value_setter = match &param.args.min {
-        //     Some(x) => quote::quote! { .min_number_value(#x as f64) },
-        //     None => quote::quote! {},
-        // };
-        // let max_value_setter = match &param.args.max {
-        //     Some(x) => quote::quote! { .max_number_value(#x as f64) },
-        //     None => quote::quote! {},
-        // };
-        // // TODO: move this to poise::CommandParameter::{min_length, max_length} fields
-        // let min_length_setter = match &param.args.min_length {
-        //     Some(x) => quote::quote! { .min_length(#x) },
-        //     None => quote::quote! {},
-        // };
-        // let max_length_setter = match &param.args.max_length {
-        //     Some(x) => quote::quote! { .max_length(#x) },
-        //     None => quote::quote! {},
-        // };
-
-        // let type_setter = match inv.args.slash_command {
-        //     true => {
-        //         if let Some(_choices) = &param.args.choices {
-        //             quote::quote! { Some(|o| o.kind(::poise::serenity_prelude::CommandOptionType::Integer)) }
-        //         } else {
-        //             quote::quote! { Some(|o| {
-        //                 poise::create_slash_argument!(#type_, o)
-        //                 #min_value_setter #max_value_setter
-        //                 #min_length_setter #max_length_setter
-        //             }) }
-        //         }
-        //     }
-        //     false => quote::quote! { None },
-        // };
-
-        // // TODO: theoretically a problem that we don't store choices for non slash commands
-        // // TODO: move this to poise::CommandParameter::choices (is there a reason not to?)
-        // let choices = match inv.args.slash_command {
-        //     true => {
-        //         if let Some(choices) = &param.args.choices {
-        //             let choices = &choices.0;
-        //             quote::quote! { vec![#( ::poise::CommandParameterChoice {
-        //                 name: ToString::to_string(&#choices),
-        //                 localizations: Default::default(),
-        //                 __non_exhaustive: (),
-        //             } ),*] }
-        //         } else {
-        //             quote::quote! { poise::slash_argument_choices!(#type_) }
-        //         }
-        //     }
-        //     false => quote::quote! { vec![] },
-        // };
-
-        // let channel_types = match &param.args.channel_types {
-        //     Some(crate::util::List(channel_types)) => quote::quote! { Some(
-        //         vec![ #( poise::serenity_prelude::ChannelType::#channel_types ),* ]
-        //     ) },
-        //     None => quote::quote! { None },
-        // };
-
-        command_parameters.push((
-            quote::quote! {
-                ::luro_model::command::SlashCommandParameter {
-                    name: #command_option_name,
-                    description: #description,
-                    required: #required,
-                }
+        // Fields are named after the parameter where possible, so parse errors name the right option
+        let ident = match &*pattern.pat {
+            syn::Pat::Ident(ident) => ident.ident.clone(),
+            _ => quote::format_ident!("parameter_{index}"),
+        };
+        command_parameters.push(CommandParameter {
+            field: quote::quote_spanned! {pattern.ty.span()=>
+                #[command( #( #option_arguments ),* )]
+                #ident: #ty
             },
+            ident,
+            flag: arguments.flag,
             required,
-        ));
+        });
     }
 
-    // Sort the commands so that optional (non-required) are last. Then drop that information from the vec.
-    command_parameters.sort_by_key(|(_, required)| !required);
-    let command_parameters = command_parameters
-        .into_iter()
-        .map(|(command_parameters, _)| command_parameters)
-        .collect();
     Ok(command_parameters)
 }
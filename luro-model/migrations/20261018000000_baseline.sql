@@ -0,0 +1,290 @@
+-- The tables that existed before migrations were tracked. Every statement is skipped if the object already exists,
+-- so this is safe to run against a database that was created by hand.
+
+DO $$ BEGIN
+    CREATE TYPE gender AS ENUM ('FEMALE', 'ITS_COMPLICATED', 'MALE', 'TRANS_FEMALE', 'TRANS_MALE');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE sexuality AS ENUM ('BISEXUAL', 'GAY', 'LESBIAN', 'PANSEXUAL', 'STRAIGHT');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE user_permissions AS ENUM ('ADMINISTRATOR', 'OWNER', 'USER');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE message_source AS ENUM (
+        'TWILIGHT_MESSAGE',
+        'LURO_MESSAGE',
+        'CACHED_MESSAGE',
+        'MESSAGE_UPDATE',
+        'MESSAGE_DELETE',
+        'MESSAGE_CREATE',
+        'NONE'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE interaction_kind AS ENUM (
+        'APPLICATION_COMMAND',
+        'APPLICATION_COMMAND_AUTOCOMPLETE',
+        'MESSAGE_COMPONENT',
+        'MODAL_SUBMIT',
+        'PING',
+        'UNKNOWN'
+    );
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+    CREATE TYPE user_characters_fetishes_category AS ENUM ('FAV', 'LOVE', 'LIKE', 'NEUTRAL', 'DISLIKE', 'HATE', 'LIMIT');
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS users (
+    user_id BIGINT PRIMARY KEY,
+    accent_colour INTEGER,
+    avatar_decoration TEXT,
+    averagesize BIGINT,
+    bot BOOLEAN NOT NULL DEFAULT FALSE,
+    characters JSONB,
+    discriminator SMALLINT NOT NULL DEFAULT 0,
+    email TEXT,
+    gender gender,
+    global_name TEXT,
+    locale TEXT,
+    message_edits BIGINT,
+    messages JSONB,
+    mfa_enabled BOOLEAN,
+    moderation_actions JSONB,
+    moderation_actions_performed BIGINT,
+    premium_type SMALLINT,
+    public_flags BIGINT,
+    sexuality sexuality,
+    user_avatar TEXT,
+    user_banner TEXT,
+    user_flags BIGINT,
+    user_name TEXT NOT NULL DEFAULT '',
+    user_permissions user_permissions NOT NULL DEFAULT 'USER',
+    user_system BOOLEAN,
+    verified BOOLEAN,
+    warnings JSONB,
+    words_average BIGINT,
+    words_count BIGINT
+);
+
+CREATE TABLE IF NOT EXISTS applications (
+    application_id BIGINT PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS guilds (
+    guild_id BIGINT PRIMARY KEY,
+    afk_channel_id BIGINT,
+    afk_timeout SMALLINT,
+    application_id BIGINT,
+    approximate_presence_count BIGINT,
+    banner TEXT,
+    default_message_notifications SMALLINT,
+    discovery_splash TEXT,
+    explicit_content_filter SMALLINT,
+    icon TEXT,
+    joined_at TIMESTAMPTZ,
+    large BOOLEAN,
+    max_members BIGINT,
+    max_presences BIGINT,
+    max_video_channel_users BIGINT,
+    mfa_level SMALLINT,
+    name TEXT,
+    nsfw_level SMALLINT,
+    owner BOOLEAN,
+    owner_id BIGINT,
+    permissions BIGINT,
+    preferred_locale TEXT,
+    premium_progress_bar_enabled BOOLEAN,
+    premium_subscription_count BIGINT,
+    premium_tier SMALLINT,
+    public_updates_channel_id BIGINT,
+    rules_channel_id BIGINT,
+    safety_alerts_channel_id BIGINT,
+    splash TEXT,
+    system_channel_flags BIGINT,
+    system_channel_id BIGINT,
+    unavailable BOOLEAN,
+    vanity_url_code TEXT,
+    verification_level SMALLINT,
+    widget_channel_id BIGINT,
+    widget_enabled BOOLEAN
+);
+
+CREATE TABLE IF NOT EXISTS channels (
+    channel_id BIGINT PRIMARY KEY,
+    guild_id BIGINT,
+    kind SMALLINT NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS guild_roles (
+    guild_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    colour INTEGER NOT NULL DEFAULT 0,
+    deleted BOOLEAN NOT NULL DEFAULT FALSE,
+    hoist BOOLEAN NOT NULL DEFAULT FALSE,
+    icon TEXT,
+    managed BOOLEAN NOT NULL DEFAULT FALSE,
+    mentionable BOOLEAN NOT NULL DEFAULT FALSE,
+    permissions INTEGER NOT NULL DEFAULT 0,
+    position BIGINT NOT NULL DEFAULT 0,
+    role_flags INTEGER NOT NULL DEFAULT 0,
+    role_name TEXT NOT NULL DEFAULT '',
+    tags JSONB,
+    unicode_emoji TEXT,
+    PRIMARY KEY (guild_id, role_id)
+);
+
+CREATE TABLE IF NOT EXISTS guild_members (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    boosting_since TIMESTAMPTZ,
+    communication_disabled_until TIMESTAMPTZ,
+    deafened BOOLEAN NOT NULL DEFAULT FALSE,
+    guild_owner BOOLEAN NOT NULL DEFAULT FALSE,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    left_at TIMESTAMPTZ,
+    member_avatar TEXT,
+    member_flags BIGINT NOT NULL DEFAULT 0,
+    muted BOOLEAN NOT NULL DEFAULT FALSE,
+    nickname TEXT,
+    pending BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (guild_id, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS guild_member_roles (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, role_id, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS messages (
+    message_id BIGINT PRIMARY KEY,
+    activity JSONB,
+    application JSONB,
+    application_id BIGINT,
+    attachments JSONB,
+    author JSONB NOT NULL,
+    author_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    components JSONB,
+    content TEXT,
+    deleted BOOLEAN NOT NULL DEFAULT FALSE,
+    edited_timestamp TIMESTAMPTZ,
+    embeds JSONB,
+    flags JSONB,
+    guild_id BIGINT,
+    interaction JSONB,
+    kind JSONB NOT NULL,
+    member JSONB,
+    mention_channels JSONB,
+    mention_everyone BOOLEAN,
+    mention_roles BIGINT[],
+    mentions JSONB,
+    message_updates JSONB,
+    pinned BOOLEAN,
+    reactions JSONB,
+    reference JSONB,
+    referenced_message JSONB,
+    role_subscription_data JSONB,
+    source message_source NOT NULL DEFAULT 'NONE',
+    sticker_items JSONB,
+    thread JSONB,
+    timestamp TIMESTAMPTZ NOT NULL,
+    tts BOOLEAN,
+    webhook_id BIGINT
+);
+
+-- The words of each message, used by the message counts
+CREATE OR REPLACE VIEW message_words AS
+SELECT
+    message_id,
+    author_id,
+    channel_id,
+    guild_id,
+    source::TEXT AS source,
+    regexp_split_to_array(lower(content), '\s+') AS words
+FROM messages;
+
+CREATE TABLE IF NOT EXISTS interactions (
+    interaction_id BIGINT PRIMARY KEY,
+    app_permissions BIGINT,
+    application_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    data JSONB,
+    guild_id BIGINT,
+    guild_locale TEXT,
+    kind interaction_kind NOT NULL,
+    locale TEXT,
+    member JSONB,
+    message_id BIGINT,
+    token TEXT NOT NULL,
+    user_id BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS user_marriages (
+    proposer_id BIGINT NOT NULL,
+    proposee_id BIGINT NOT NULL,
+    divorced BOOLEAN NOT NULL DEFAULT FALSE,
+    rejected BOOLEAN NOT NULL DEFAULT FALSE,
+    reason TEXT NOT NULL,
+    PRIMARY KEY (proposer_id, proposee_id)
+);
+
+CREATE TABLE IF NOT EXISTS user_marriage_approvals (
+    proposer_id BIGINT NOT NULL,
+    proposee_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    approve BOOLEAN NOT NULL DEFAULT FALSE,
+    disapprove BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (proposer_id, proposee_id, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS user_characters (
+    user_id BIGINT NOT NULL,
+    character_name TEXT NOT NULL,
+    colour INTEGER,
+    nickname TEXT,
+    prefix TEXT,
+    sfw_description TEXT NOT NULL DEFAULT '',
+    sfw_summary TEXT NOT NULL DEFAULT '',
+    sfw_icon TEXT NOT NULL DEFAULT '',
+    nsfw_description TEXT,
+    nsfw_summary TEXT,
+    nsfw_icon TEXT,
+    PRIMARY KEY (user_id, character_name)
+);
+
+CREATE TABLE IF NOT EXISTS user_character_images (
+    user_id BIGINT NOT NULL,
+    character_name TEXT NOT NULL,
+    img_id BIGINT NOT NULL,
+    favourite BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (user_id, character_name, img_id)
+);
+
+CREATE TABLE IF NOT EXISTS fetishes (
+    fetish_id BIGINT PRIMARY KEY,
+    creator BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS user_characters_fetishes (
+    user_id BIGINT NOT NULL,
+    character_name TEXT NOT NULL,
+    fetish_id BIGINT NOT NULL,
+    category user_characters_fetishes_category NOT NULL DEFAULT 'NEUTRAL',
+    PRIMARY KEY (user_id, character_name, fetish_id)
+);
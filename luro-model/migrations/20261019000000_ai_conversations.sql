@@ -0,0 +1,19 @@
+-- Per channel settings for the AI module
+CREATE TABLE IF NOT EXISTS ai_channels (
+    channel_id BIGINT PRIMARY KEY,
+    persona TEXT,
+    character_owner BIGINT,
+    character_name TEXT
+);
+
+-- Conversation history used as context for the AI module
+CREATE TABLE IF NOT EXISTS ai_history (
+    message_id BIGINT PRIMARY KEY,
+    channel_id BIGINT NOT NULL,
+    author_id BIGINT NOT NULL,
+    assistant BOOLEAN NOT NULL DEFAULT FALSE,
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS ai_history_channel_idx ON ai_history (channel_id, created_at DESC);
@@ -0,0 +1,8 @@
+-- Channels need to opt in to AI responses when `channel_opt_in` is set
+ALTER TABLE ai_channels ADD COLUMN IF NOT EXISTS enabled BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Users that do not want their messages used as context for the AI module
+CREATE TABLE IF NOT EXISTS ai_opt_outs (
+    user_id BIGINT PRIMARY KEY,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
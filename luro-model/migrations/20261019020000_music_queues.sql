@@ -0,0 +1,24 @@
+-- Per guild music player state, so playback can resume after a restart
+CREATE TABLE IF NOT EXISTS music_queues (
+    guild_id BIGINT PRIMARY KEY,
+    voice_channel_id BIGINT,
+    text_channel_id BIGINT,
+    now_playing_message_id BIGINT,
+    loop_mode TEXT NOT NULL DEFAULT 'off',
+    volume BIGINT NOT NULL DEFAULT 100,
+    paused BOOLEAN NOT NULL DEFAULT FALSE,
+    position BIGINT NOT NULL DEFAULT 0
+);
+
+-- The tracks in each guild's queue. Index 0 is the track currently playing
+CREATE TABLE IF NOT EXISTS music_tracks (
+    guild_id BIGINT NOT NULL REFERENCES music_queues (guild_id) ON DELETE CASCADE,
+    track_index INTEGER NOT NULL,
+    encoded TEXT NOT NULL,
+    title TEXT NOT NULL,
+    author TEXT NOT NULL,
+    uri TEXT,
+    length BIGINT NOT NULL,
+    requested_by BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, track_index)
+);
@@ -0,0 +1,40 @@
+CREATE TABLE IF NOT EXISTS quotes (
+    id BIGSERIAL PRIMARY KEY,
+    added_by BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+    nsfw BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+-- Quotes keep their own copy of the message, so they no longer depend on the message being recorded
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS guild_id BIGINT;
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS author_id BIGINT;
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS content TEXT NOT NULL DEFAULT '';
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS starboard_message_id BIGINT;
+
+UPDATE quotes
+SET
+    guild_id = messages.guild_id,
+    author_id = messages.author_id,
+    content = COALESCE(messages.content, '')
+FROM messages
+WHERE messages.message_id = quotes.message_id AND quotes.author_id IS NULL;
+
+-- Quotes whose message was never recorded keep their place, but have no known author
+
+ALTER TABLE quotes ADD COLUMN IF NOT EXISTS content_search TSVECTOR
+    GENERATED ALWAYS AS (to_tsvector('english', content)) STORED;
+
+CREATE INDEX IF NOT EXISTS quotes_content_search ON quotes USING GIN (content_search);
+CREATE INDEX IF NOT EXISTS quotes_guild_author ON quotes (guild_id, author_id);
+
+-- A message can only be quoted once, so concurrent stars can't save it twice. Older duplicates keep the first quote.
+DELETE FROM quotes a USING quotes b WHERE a.message_id = b.message_id AND a.id > b.id;
+CREATE UNIQUE INDEX IF NOT EXISTS quotes_message_id ON quotes (message_id);
+
+-- Messages that reach a guild's star threshold are quoted and reposted to the starboard channel
+CREATE TABLE IF NOT EXISTS starboards (
+    guild_id BIGINT PRIMARY KEY,
+    channel_id BIGINT NOT NULL,
+    threshold INTEGER NOT NULL DEFAULT 3
+);
@@ -0,0 +1,26 @@
+-- Marriages remember where and when they happened, so anniversaries can be celebrated
+ALTER TABLE user_marriages ADD COLUMN IF NOT EXISTS guild_id BIGINT;
+ALTER TABLE user_marriages ADD COLUMN IF NOT EXISTS channel_id BIGINT;
+ALTER TABLE user_marriages ADD COLUMN IF NOT EXISTS married_at TIMESTAMPTZ;
+-- The last year an anniversary was posted, so restarts don't post it twice
+ALTER TABLE user_marriages ADD COLUMN IF NOT EXISTS anniversary_year INTEGER;
+
+-- Proposals were previously treated as marriages straight away, so count them as married from today
+UPDATE user_marriages SET married_at = NOW() WHERE married_at IS NULL AND NOT divorced AND NOT rejected;
+
+-- Parent and child relations. A row without `adopted_at` is waiting for the child to accept
+CREATE TABLE IF NOT EXISTS user_adoptions (
+    parent_id BIGINT NOT NULL,
+    child_id BIGINT NOT NULL,
+    guild_id BIGINT,
+    adopted_at TIMESTAMPTZ,
+    PRIMARY KEY (parent_id, child_id)
+);
+
+CREATE INDEX IF NOT EXISTS user_adoptions_child_idx ON user_adoptions (child_id);
+
+CREATE TABLE IF NOT EXISTS marriage_settings (
+    guild_id BIGINT PRIMARY KEY,
+    partner_limit INTEGER NOT NULL DEFAULT 5,
+    anniversary_channel_id BIGINT
+);
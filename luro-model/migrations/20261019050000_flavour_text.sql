@@ -0,0 +1,25 @@
+-- Flavour text a guild added to its own pools, on top of the pools shipped in config/flavour
+CREATE TABLE IF NOT EXISTS flavour_entries (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    pool TEXT NOT NULL,
+    content TEXT NOT NULL,
+    added_by BIGINT NOT NULL,
+    disabled BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (guild_id, pool, content)
+);
+
+CREATE TABLE IF NOT EXISTS flavour_votes (
+    entry_id BIGINT NOT NULL REFERENCES flavour_entries (id) ON DELETE CASCADE,
+    user_id BIGINT NOT NULL,
+    vote SMALLINT NOT NULL CHECK (vote IN (-1, 1)),
+    PRIMARY KEY (entry_id, user_id)
+);
+
+-- Built in entries a guild does not want to see. These are matched by their text, as they have no ID.
+CREATE TABLE IF NOT EXISTS flavour_disabled (
+    guild_id BIGINT NOT NULL,
+    pool TEXT NOT NULL,
+    content TEXT NOT NULL,
+    PRIMARY KEY (guild_id, pool, content)
+);
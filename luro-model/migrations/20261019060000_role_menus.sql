@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS guild_role_blacklist (
+    guild_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, role_id)
+);
+
+-- Named self-assignable role menus. The message is edited in place when the menu changes.
+CREATE TABLE IF NOT EXISTS role_menus (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    title TEXT,
+    description TEXT,
+    style TEXT NOT NULL DEFAULT 'select',
+    single BOOLEAN NOT NULL DEFAULT FALSE,
+    required_roles BIGINT[] NOT NULL DEFAULT '{}',
+    channel_id BIGINT,
+    message_id BIGINT,
+    UNIQUE (guild_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS role_menus_message_id ON role_menus (message_id);
+
+CREATE TABLE IF NOT EXISTS role_menu_options (
+    menu_id BIGINT NOT NULL REFERENCES role_menus (id) ON DELETE CASCADE,
+    role_id BIGINT NOT NULL,
+    position INTEGER NOT NULL,
+    label TEXT,
+    emoji TEXT,
+    description TEXT,
+    PRIMARY KEY (menu_id, role_id)
+);
@@ -0,0 +1,44 @@
+-- Reaction roles, keyed by the emoji ID for custom emoji or the emoji itself for unicode emoji
+CREATE TABLE IF NOT EXISTS reaction_roles (
+    guild_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+    emoji TEXT NOT NULL,
+    role_id BIGINT NOT NULL,
+    PRIMARY KEY (message_id, emoji)
+);
+
+CREATE INDEX IF NOT EXISTS reaction_roles_guild_id ON reaction_roles (guild_id);
+
+-- Roles given to members when they join
+CREATE TABLE IF NOT EXISTS auto_roles (
+    guild_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    delay_seconds BIGINT NOT NULL DEFAULT 0,
+    after_screening BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (guild_id, role_id)
+);
+
+-- Auto roles waiting to be given. A null apply_at means the member has not passed screening yet.
+CREATE TABLE IF NOT EXISTS auto_roles_pending (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    apply_at TIMESTAMPTZ,
+    PRIMARY KEY (guild_id, user_id, role_id)
+);
+
+CREATE INDEX IF NOT EXISTS auto_roles_pending_apply_at ON auto_roles_pending (apply_at);
+
+-- Guilds that give members back their roles when they rejoin
+CREATE TABLE IF NOT EXISTS sticky_role_guilds (
+    guild_id BIGINT PRIMARY KEY
+);
+
+-- The roles members held when they left, copied from guild_member_roles
+CREATE TABLE IF NOT EXISTS sticky_roles (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, user_id, role_id)
+);
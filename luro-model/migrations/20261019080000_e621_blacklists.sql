@@ -0,0 +1,5 @@
+-- Each user's own e621 blacklist, applied on top of the global blacklist in the configuration
+CREATE TABLE IF NOT EXISTS e621_blacklists (
+    user_id BIGINT PRIMARY KEY,
+    blacklist TEXT NOT NULL
+);
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS images (
+    img_id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    nsfw BOOLEAN NOT NULL,
+    owner_id BIGINT NOT NULL,
+    source TEXT,
+    url TEXT NOT NULL
+);
+
+-- Images added before the review queue existed are treated as approved
+ALTER TABLE images ADD COLUMN IF NOT EXISTS artist TEXT;
+ALTER TABLE images ADD COLUMN IF NOT EXISTS tags TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE images ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'approved';
+ALTER TABLE images ADD COLUMN IF NOT EXISTS reviewed_by BIGINT;
+ALTER TABLE images ADD COLUMN IF NOT EXISTS phash BIGINT;
+ALTER TABLE images ADD COLUMN IF NOT EXISTS submitted_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS images_status ON images (status, submitted_at);
+CREATE INDEX IF NOT EXISTS images_tags ON images USING GIN (tags);
@@ -0,0 +1,4 @@
+-- Word statistics filter messages by guild, channel or author over a time window
+CREATE INDEX IF NOT EXISTS messages_guild_timestamp_idx ON messages (guild_id, timestamp);
+CREATE INDEX IF NOT EXISTS messages_channel_timestamp_idx ON messages (channel_id, timestamp);
+CREATE INDEX IF NOT EXISTS messages_author_timestamp_idx ON messages (author_id, timestamp);
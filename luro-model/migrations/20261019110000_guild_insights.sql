@@ -0,0 +1,21 @@
+-- Members joining and leaving, as guild_members only keeps the latest state of each member
+CREATE TABLE IF NOT EXISTS guild_member_events (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    kind TEXT NOT NULL,
+    happened_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS guild_member_events_guild_idx ON guild_member_events (guild_id, kind, happened_at);
+
+-- Daily totals for each guild, written by a scheduled job
+CREATE TABLE IF NOT EXISTS guild_insights (
+    guild_id BIGINT NOT NULL,
+    day DATE NOT NULL,
+    members BIGINT NOT NULL,
+    joins BIGINT NOT NULL,
+    leaves BIGINT NOT NULL,
+    messages BIGINT NOT NULL,
+    active_members BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, day)
+);
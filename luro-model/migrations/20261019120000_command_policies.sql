@@ -0,0 +1,10 @@
+-- Commands a guild has enabled or disabled, for the whole guild, a channel or a role.
+-- Subcommands are stored with their parents, such as `quote get`. Guild policies have a target of 0.
+CREATE TABLE IF NOT EXISTS command_policies (
+    guild_id BIGINT NOT NULL,
+    command TEXT NOT NULL,
+    scope TEXT NOT NULL CHECK (scope IN ('guild', 'channel', 'role')),
+    target_id BIGINT NOT NULL DEFAULT 0,
+    enabled BOOLEAN NOT NULL,
+    PRIMARY KEY (guild_id, command, scope, target_id)
+);
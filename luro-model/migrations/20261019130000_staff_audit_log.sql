@@ -0,0 +1,10 @@
+-- Every change made to the bot staff with `/owner staff`, so there is a record of who gave or took away access.
+CREATE TABLE IF NOT EXISTS staff_audit_log (
+    actor_id BIGINT NOT NULL,
+    target_id BIGINT NOT NULL,
+    action TEXT NOT NULL CHECK (action IN ('add', 'remove')),
+    permissions user_permissions NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS staff_audit_log_created_at ON staff_audit_log (created_at DESC);
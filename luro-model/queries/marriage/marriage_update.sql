@@ -1,14 +1,18 @@
 INSERT INTO user_marriages (
         divorced,
-        proposee_id,
         proposer_id,
+        proposee_id,
         reason,
-        rejected
+        rejected,
+        guild_id,
+        channel_id,
+        married_at
     )
-VALUES ($1, $2, $3, $4, $5) ON CONFLICT (proposer_id, proposee_id) DO
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (proposer_id, proposee_id) DO
 UPDATE
 SET divorced = $1,
-    proposer_id = $2,
-    proposee_id = $3,
     reason = $4,
-    rejected = $5;
\ No newline at end of file
+    rejected = $5,
+    guild_id = $6,
+    channel_id = $7,
+    married_at = $8;
@@ -87,6 +87,7 @@ mod content;
 mod custom_id;
 mod deferred;
 mod embed;
+mod modal;
 mod response_type;
 mod title;
 
@@ -0,0 +1,16 @@
+use twilight_interactions::modal::ModalData;
+use twilight_model::http::interaction::{InteractionResponseData, InteractionResponseType};
+
+use super::InteractionResponseBuilder;
+
+impl InteractionResponseBuilder {
+    /// Respond with a modal created by [`twilight_interactions::modal::CreateModal`]
+    pub fn modal(&mut self, modal: ModalData) -> &mut Self {
+        let data = InteractionResponseData::from(modal);
+        self.title = data.title;
+        self.custom_id = data.custom_id;
+        self.components = data.components;
+        self.interaction_response_type = InteractionResponseType::Modal;
+        self
+    }
+}
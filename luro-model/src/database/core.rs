@@ -1,5 +1,6 @@
 mod check_staff;
 mod count;
 mod create;
+mod delete;
 mod fetch;
 mod update;
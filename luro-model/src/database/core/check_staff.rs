@@ -5,8 +5,24 @@ use crate::{database::Error, user::UserContext};
 impl crate::database::Database {
     /// Check if a user is staff. Returns the user if matches, otherwise returns none on no match
     pub async fn check_staff(&self, user_id: Id<UserMarker>) -> Result<Option<UserContext>, Error> {
-        let staff = self.fetch_staff().await?;
+        // Configured staff don't need the whole list fetched
+        if let Some(permissions) = self.staff.permissions(user_id) {
+            let mut user = self.fetch_user(user_id).await?;
+            user.user_type = permissions;
+            return Ok(Some(user));
+        }
 
-        Ok(staff.into_iter().find(|staff| staff.twilight_user.id == user_id))
+        // Only staff from the database are left, so skip fetching every configured staff member
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_staff().await {
+            Ok(staff) => Ok(staff.into_iter().find(|staff| staff.twilight_user.id == user_id)),
+            Err(why) => {
+                tracing::error!(?why, "Error raised while trying to find staff");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Ok(None)
     }
 }
@@ -0,0 +1,23 @@
+use crate::{database::Error, flavour::FlavourEntry};
+
+impl crate::database::Database {
+    /// Add an entry to a guild's pool, returning the new entry ID
+    pub async fn create_flavour_entry(&self, entry: &FlavourEntry) -> Result<i64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.create_flavour_entry(entry).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error adding a flavour entry to pool `{}` for guild `{}`",
+                    entry.pool,
+                    entry.guild_id
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, image::Image};
+
+impl crate::database::Database {
+    /// Add an image to the library, returning the new image ID
+    pub async fn create_image(&self, image: &Image) -> Result<i64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.create_image(image).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error adding image `{}`", image.name);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,28 @@
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::{database::Error, insights::MemberEventKind};
+
+impl crate::database::Database {
+    /// Record a member joining or leaving a guild
+    pub async fn create_member_event(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        kind: MemberEventKind,
+    ) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.create_member_event(guild_id, user_id, kind).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error recording a member event");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, quote::Quote};
+
+impl crate::database::Database {
+    /// Add a quote to the database, returning the new quote ID. Returns `None` if the message has already been quoted.
+    pub async fn create_quote(&self, quote: &Quote) -> Result<Option<i64>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.create_quote(quote).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error adding a quote of message `{}`", quote.message_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
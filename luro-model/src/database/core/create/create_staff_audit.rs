@@ -0,0 +1,18 @@
+use crate::{database::Error, staff::StaffAuditEntry};
+
+impl crate::database::Database {
+    /// Record a change to the staff
+    pub async fn create_staff_audit(&self, entry: &StaffAuditEntry) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.create_staff_audit(entry).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error recording a change to the staff");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
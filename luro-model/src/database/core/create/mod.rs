@@ -1 +1,6 @@
 mod create_character_image;
+mod create_flavour_entry;
+mod create_image;
+mod create_member_event;
+mod create_quote;
+mod create_staff_audit;
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Remove an adoption, or a request to adopt
+    pub async fn delete_adoption(&self, parent_id: Id<UserMarker>, child_id: Id<UserMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_adoption(parent_id, child_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error removing the adoption of `{child_id}` by `{parent_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
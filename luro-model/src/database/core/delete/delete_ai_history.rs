@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Forget the conversation in a channel, returning how many messages were removed
+    pub async fn delete_ai_history(&self, channel_id: Id<ChannelMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_ai_history(channel_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error clearing AI history for channel `{channel_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Stop giving a role to members when they join, including to anyone still waiting for it
+    pub async fn delete_auto_role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_auto_role(guild_id, role_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error deleting auto role `{role_id}` in guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
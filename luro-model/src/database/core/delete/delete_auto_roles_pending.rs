@@ -0,0 +1,31 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Remove a member's queued auto roles, or just the one role if passed
+    pub async fn delete_auto_roles_pending(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Option<Id<RoleMarker>>,
+    ) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_auto_roles_pending(guild_id, user_id, role_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error removing the queued auto roles for member `{user_id}` in guild `{guild_id}`"
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
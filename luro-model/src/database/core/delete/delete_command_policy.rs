@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{command_policy::PolicyScope, database::Error};
+
+impl crate::database::Database {
+    /// Remove a command policy, so the command falls back to the next policy that applies
+    pub async fn delete_command_policy(&self, guild_id: Id<GuildMarker>, command: &str, scope: PolicyScope) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_command_policy(guild_id, command, scope).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error deleting the `{command}` command policy of guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    pub async fn delete_e621_blacklist(&self, user_id: Id<UserMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_e621_blacklist(user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error deleting the e621 blacklist of user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
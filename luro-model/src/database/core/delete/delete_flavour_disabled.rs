@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Enable a built in entry of a pool for a guild again
+    pub async fn delete_flavour_disabled(&self, guild_id: Id<GuildMarker>, pool: &str, content: &str) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_flavour_disabled(guild_id, pool, content).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error enabling a flavour entry of pool `{pool}` for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Remove a guild's queue and all of its tracks
+    pub async fn delete_music_queue(&self, guild_id: Id<GuildMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_music_queue(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error removing music queue for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
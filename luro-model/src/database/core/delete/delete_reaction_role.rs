@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    pub async fn delete_reaction_role(&self, message_id: Id<MessageMarker>, emoji: &str) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_reaction_role(message_id, emoji).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error deleting the reaction role for `{emoji}` on message `{message_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Let members give themselves a role again
+    pub async fn delete_role_blacklist(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_role_blacklist(guild_id, role_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error removing role `{role_id}` from the blacklist in guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Delete a menu and the roles it offers. The posted message is left alone.
+    pub async fn delete_role_menu(&self, menu_id: i64) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_role_menu(menu_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error deleting role menu `{menu_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
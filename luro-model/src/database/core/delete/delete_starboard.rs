@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Turn off the starboard for a guild. Existing quotes are kept.
+    pub async fn delete_starboard(&self, guild_id: Id<GuildMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_starboard(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error removing the starboard for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Forget the roles a member held when they left, returning them so they can be given back
+    pub async fn delete_sticky_roles(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<Vec<Id<RoleMarker>>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.delete_sticky_roles(guild_id, user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error taking the sticky roles of member `{user_id}` in guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,13 @@
+mod delete_adoption;
+mod delete_ai_history;
+mod delete_auto_role;
+mod delete_auto_roles_pending;
+mod delete_command_policy;
+mod delete_e621_blacklist;
+mod delete_flavour_disabled;
+mod delete_music_queue;
+mod delete_reaction_role;
+mod delete_role_blacklist;
+mod delete_role_menu;
+mod delete_starboard;
+mod delete_sticky_roles;
@@ -0,0 +1,21 @@
+use crate::{
+    database::Error,
+    word_stats::{ActivityHeatmap, StatsScope},
+};
+
+impl crate::database::Database {
+    /// Messages within the scope, by day of the week and hour of the day
+    pub async fn fetch_activity_heatmap(&self, scope: &StatsScope) -> Result<ActivityHeatmap, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_activity_heatmap(scope).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the activity heatmap");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
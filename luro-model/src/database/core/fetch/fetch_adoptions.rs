@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{database::Error, user::Adoption};
+
+impl crate::database::Database {
+    /// Fetch every adoption a user is part of, as either the parent or the child. Includes adoptions waiting for an answer.
+    pub async fn fetch_adoptions(&self, user_id: Id<UserMarker>) -> Result<Vec<Adoption>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_adoptions(user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching adoptions of user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
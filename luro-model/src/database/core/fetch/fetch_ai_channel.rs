@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::{ai::AiChannel, database::Error};
+
+impl crate::database::Database {
+    /// Fetch the AI settings for a channel, if any have been set.
+    pub async fn fetch_ai_channel(&self, channel_id: Id<ChannelMarker>) -> Result<Option<AiChannel>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_ai_channel(channel_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching AI settings for channel `{channel_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::{ai::AiHistoryMessage, database::Error};
+
+impl crate::database::Database {
+    /// Fetch the most recent messages in a channel's conversation, oldest first.
+    pub async fn fetch_ai_history(&self, channel_id: Id<ChannelMarker>, limit: i64) -> Result<Vec<AiHistoryMessage>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_ai_history(channel_id, limit).await {
+            Ok(mut data) => {
+                data.reverse();
+                Ok(data)
+            }
+            Err(why) => {
+                tracing::error!(?why, "Error fetching AI history for channel `{channel_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
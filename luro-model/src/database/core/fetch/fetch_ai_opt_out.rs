@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Returns true if the user does not want their messages used as context
+    pub async fn fetch_ai_opt_out(&self, user_id: Id<UserMarker>) -> Result<bool, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_ai_opt_out(user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching AI opt out for user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
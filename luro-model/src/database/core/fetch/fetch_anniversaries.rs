@@ -0,0 +1,18 @@
+use crate::{database::Error, user::Marriage};
+
+impl crate::database::Database {
+    /// Fetch the marriages that have an anniversary today which has not been posted yet. Marriages on Feb 29 have theirs on Feb 28 in other years.
+    pub async fn fetch_anniversaries(&self) -> Result<Vec<Marriage>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_anniversaries().await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching today's anniversaries");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
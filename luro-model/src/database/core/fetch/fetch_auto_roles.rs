@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{auto_role::AutoRole, database::Error};
+
+impl crate::database::Database {
+    /// The roles given to members when they join a guild
+    pub async fn fetch_auto_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<AutoRole>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_auto_roles(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the auto roles for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{auto_role::PendingAutoRole, database::Error};
+
+impl crate::database::Database {
+    /// Auto roles across every guild that are ready to be given
+    pub async fn fetch_auto_roles_due(&self) -> Result<Vec<PendingAutoRole>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_auto_roles_due().await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the auto roles that are due");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
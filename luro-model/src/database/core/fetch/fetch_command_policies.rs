@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{command_policy::CommandPolicy, database::Error};
+
+impl crate::database::Database {
+    /// Every command policy of a guild, sorted by command
+    pub async fn fetch_command_policies(&self, guild_id: Id<GuildMarker>) -> Result<Vec<CommandPolicy>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_command_policies(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the command policies of guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
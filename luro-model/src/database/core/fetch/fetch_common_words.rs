@@ -0,0 +1,21 @@
+use crate::{
+    database::Error,
+    word_stats::{StatsScope, WordUsage},
+};
+
+impl crate::database::Database {
+    /// The most used words within the scope, leaving out any in `excluded`
+    pub async fn fetch_common_words(&self, scope: &StatsScope, excluded: &[String], limit: i64) -> Result<Vec<WordUsage>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_common_words(scope, excluded, limit).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching common words");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// A user's own e621 blacklist, one set of tags per line
+    pub async fn fetch_e621_blacklist(&self, user_id: Id<UserMarker>) -> Result<Option<String>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_e621_blacklist(user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the e621 blacklist of user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Names of fetishes containing `search`, ignoring case. Used to autocomplete fetish options.
+    pub async fn fetch_fetish_names(&self, search: &str, limit: i64) -> Result<Vec<String>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_fetish_names(search, limit).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching fetish names");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
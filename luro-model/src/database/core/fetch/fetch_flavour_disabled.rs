@@ -0,0 +1,23 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// The built in entries of a pool that a guild has disabled
+    pub async fn fetch_flavour_disabled(&self, guild_id: Id<GuildMarker>, pool: &str) -> Result<Vec<String>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_flavour_disabled(guild_id, pool).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error fetching the disabled flavour entries of pool `{pool}` for guild `{guild_id}`"
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, flavour::FlavourEntry};
+
+impl crate::database::Database {
+    /// Fetch a guild's entries for a pool, or for all pools if no pool is given. Highest scoring entries come first.
+    pub async fn fetch_flavour_entries(&self, guild_id: Id<GuildMarker>, pool: Option<&str>) -> Result<Vec<FlavourEntry>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_flavour_entries(guild_id, pool).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the flavour entries for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
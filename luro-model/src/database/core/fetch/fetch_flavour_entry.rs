@@ -0,0 +1,17 @@
+use crate::{database::Error, flavour::FlavourEntry};
+
+impl crate::database::Database {
+    pub async fn fetch_flavour_entry(&self, entry_id: i64) -> Result<Option<FlavourEntry>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_flavour_entry(entry_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching flavour entry `{entry_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{database::Error, image::Image};
+
+impl crate::database::Database {
+    pub async fn fetch_image(&self, img_id: i64) -> Result<Option<Image>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_image(img_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching image `{img_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, image::Image};
+
+impl crate::database::Database {
+    /// An approved or pending image that looks the same as the perceptual hash, if there is one
+    pub async fn fetch_image_duplicate(&self, phash: i64) -> Result<Option<Image>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_image_duplicate(phash).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error checking for duplicate images");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, image::Image};
+
+impl crate::database::Database {
+    /// The oldest image waiting for review, and how many are waiting in total
+    pub async fn fetch_image_queue(&self) -> Result<(Option<Image>, i64), Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_image_queue().await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the image review queue");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, image::Image};
+
+impl crate::database::Database {
+    /// Every approved image, either NSFW or not
+    pub async fn fetch_images(&self, nsfw: bool) -> Result<Vec<Image>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_images(nsfw).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching images");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
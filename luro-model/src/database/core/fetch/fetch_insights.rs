@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, insights::InsightsDay};
+
+impl crate::database::Database {
+    /// The daily snapshots of a guild for the last `days` days, oldest first
+    pub async fn fetch_insights(&self, guild_id: Id<GuildMarker>, days: i64) -> Result<Vec<InsightsDay>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_insights(guild_id, days).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching guild insights");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{database::Error, user::Marriage};
+
+impl crate::database::Database {
+    pub async fn fetch_marriage(&self, first_user: Id<UserMarker>, second_user: Id<UserMarker>) -> Result<Option<Marriage>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_marriage(first_user, second_user).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the marriage of `{first_user}` and `{second_user}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
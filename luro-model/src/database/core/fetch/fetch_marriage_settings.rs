@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, user::MarriageSettings};
+
+impl crate::database::Database {
+    /// Fetch a guild's marriage settings, using the defaults if it has not set any
+    pub async fn fetch_marriage_settings(&self, guild_id: Id<GuildMarker>) -> Result<MarriageSettings, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_marriage_settings(guild_id).await {
+            Ok(data) => Ok(data.unwrap_or_else(|| MarriageSettings::new(guild_id))),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching marriage settings for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
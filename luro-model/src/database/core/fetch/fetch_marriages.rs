@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{database::Error, user::Marriage};
+
+impl crate::database::Database {
+    /// Fetch every marriage and proposal a user has been part of, oldest first
+    pub async fn fetch_marriages(&self, user_id: Id<UserMarker>) -> Result<Vec<Marriage>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_marriages(user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching marriages of user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
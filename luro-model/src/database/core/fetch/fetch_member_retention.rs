@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, insights::MemberRetention};
+
+impl crate::database::Database {
+    /// Of the members that joined in the last `lookback` days, how many had not left `days` days later
+    pub async fn fetch_member_retention(&self, guild_id: Id<GuildMarker>, days: i64, lookback: i64) -> Result<MemberRetention, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_member_retention(guild_id, days, lookback).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching member retention");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, music::MusicQueue};
+
+impl crate::database::Database {
+    pub async fn fetch_music_queue(&self, guild_id: Id<GuildMarker>) -> Result<Option<MusicQueue>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_music_queue(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching music queue for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{database::Error, quote::Quote};
+
+impl crate::database::Database {
+    pub async fn fetch_quote(&self, quote_id: i64) -> Result<Option<Quote>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_quote(quote_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching quote `{quote_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::{database::Error, quote::Quote};
+
+impl crate::database::Database {
+    /// Fetch the quote of a message, if it has already been quoted
+    pub async fn fetch_quote_by_message(&self, message_id: Id<MessageMarker>) -> Result<Option<Quote>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_quote_by_message(message_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the quote of message `{message_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    database::Error,
+    quote::{Quote, QuoteSearch},
+};
+
+impl crate::database::Database {
+    /// Search a guild's quotes. Results are ranked by how well they match the query, then newest first.
+    pub async fn fetch_quotes(&self, guild_id: Id<GuildMarker>, search: &QuoteSearch) -> Result<Vec<Quote>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_quotes(guild_id, search).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error searching quotes in guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, image::Image};
+
+impl crate::database::Database {
+    /// A random approved image that has every one of the tags
+    pub async fn fetch_random_image(&self, nsfw: bool, tags: &[String]) -> Result<Option<Image>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_random_image(nsfw, tags).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching a random image");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
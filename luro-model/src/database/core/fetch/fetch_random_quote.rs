@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, quote::Quote};
+
+impl crate::database::Database {
+    /// Fetch a random quote from a guild. NSFW quotes are only included if `nsfw` is set.
+    pub async fn fetch_random_quote(&self, guild_id: Id<GuildMarker>, nsfw: bool) -> Result<Option<Quote>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_random_quote(guild_id, nsfw).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching a random quote in guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
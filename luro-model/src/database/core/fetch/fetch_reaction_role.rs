@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::{auto_role::ReactionRole, database::Error};
+
+impl crate::database::Database {
+    /// The role bound to an emoji on a message, if there is one
+    pub async fn fetch_reaction_role(&self, message_id: Id<MessageMarker>, emoji: &str) -> Result<Option<ReactionRole>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_reaction_role(message_id, emoji).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the reaction role for `{emoji}` on message `{message_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{auto_role::ReactionRole, database::Error};
+
+impl crate::database::Database {
+    /// All of a guild's reaction roles, grouped by message
+    pub async fn fetch_reaction_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<ReactionRole>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_reaction_roles(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the reaction roles for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
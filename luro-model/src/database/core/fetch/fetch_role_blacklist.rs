@@ -0,0 +1,23 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// The roles members can't give themselves in a guild
+    pub async fn fetch_role_blacklist(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Id<RoleMarker>>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_role_blacklist(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the role blacklist for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, role_menu::RoleMenu};
+
+impl crate::database::Database {
+    pub async fn fetch_role_menu(&self, guild_id: Id<GuildMarker>, name: &str) -> Result<Option<RoleMenu>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_role_menu(guild_id, name).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching role menu `{name}` for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::{database::Error, role_menu::RoleMenu};
+
+impl crate::database::Database {
+    /// Fetch the menu posted as a message, used when someone picks from it
+    pub async fn fetch_role_menu_by_message(&self, message_id: Id<MessageMarker>) -> Result<Option<RoleMenu>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_role_menu_by_message(message_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the role menu posted as message `{message_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
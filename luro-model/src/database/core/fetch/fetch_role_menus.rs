@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, role_menu::RoleMenu};
+
+impl crate::database::Database {
+    /// Fetch all of a guild's role menus, sorted by name
+    pub async fn fetch_role_menus(&self, guild_id: Id<GuildMarker>) -> Result<Vec<RoleMenu>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_role_menus(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the role menus for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -1,19 +1,36 @@
 use crate::{database::Error, user::UserContext};
 
 impl crate::database::Database {
+    /// Staff from the configuration, followed by staff from the database.
+    ///
+    /// Configured staff keep the permissions they are given in the configuration, even if the database disagrees.
     pub async fn fetch_staff(&self) -> Result<Vec<UserContext>, Error> {
+        let mut staff: Vec<UserContext> = vec![];
+
+        for staff_id in self.staff.owners.iter().chain(&self.staff.administrators) {
+            if staff.iter().any(|staff| staff.twilight_user.id == *staff_id) {
+                continue;
+            }
+
+            let mut user = self.fetch_user(*staff_id).await?;
+            user.user_type = self.staff.permissions(*staff_id).unwrap_or_default();
+            staff.push(user);
+        }
+
         #[cfg(feature = "database-sqlx")]
         match self.sqlx_driver.fetch_staff().await {
-            Ok(data) => match data.is_empty() {
-                true => tracing::warn!("No staff were returned from the database, falling back to hardcoded."),
-                false => return Ok(data),
-            },
+            Ok(data) => {
+                for user in data {
+                    if !staff.iter().any(|staff| staff.twilight_user.id == user.twilight_user.id) {
+                        staff.push(user)
+                    }
+                }
+            }
             Err(why) => tracing::error!(?why, "Error raised while trying to find staff"),
         };
 
-        let mut staff = vec![];
-        for staff_id in crate::BOT_OWNERS {
-            staff.push(self.fetch_user(staff_id).await?);
+        if staff.is_empty() {
+            tracing::warn!("No staff are configured or in the database, so no one can use the staff commands.")
         }
 
         Ok(staff)
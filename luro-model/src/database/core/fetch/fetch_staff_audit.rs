@@ -0,0 +1,18 @@
+use crate::{database::Error, staff::StaffAuditEntry};
+
+impl crate::database::Database {
+    /// The most recent changes to the staff, newest first
+    pub async fn fetch_staff_audit(&self, limit: i64) -> Result<Vec<StaffAuditEntry>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_staff_audit(limit).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the staff audit log");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
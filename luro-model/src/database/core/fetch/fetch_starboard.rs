@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{database::Error, quote::Starboard};
+
+impl crate::database::Database {
+    pub async fn fetch_starboard(&self, guild_id: Id<GuildMarker>) -> Result<Option<Starboard>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_starboard(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the starboard for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// If a guild gives members back their roles when they rejoin
+    pub async fn fetch_sticky_roles_enabled(&self, guild_id: Id<GuildMarker>) -> Result<bool, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_sticky_roles_enabled(guild_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the sticky role setting for guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    database::Error,
+    word_stats::{LeaderboardEntry, LeaderboardKind, StatsScope},
+};
+
+impl crate::database::Database {
+    /// The users or channels with the most messages within the scope
+    pub async fn fetch_word_leaderboard(
+        &self,
+        scope: &StatsScope,
+        kind: LeaderboardKind,
+        limit: i64,
+    ) -> Result<Vec<LeaderboardEntry>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_word_leaderboard(scope, kind, limit).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the word leaderboard");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
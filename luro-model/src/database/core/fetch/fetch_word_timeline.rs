@@ -0,0 +1,21 @@
+use crate::{
+    database::Error,
+    word_stats::{StatsScope, WordTimelinePoint},
+};
+
+impl crate::database::Database {
+    /// Messages and words within the scope, grouped into periods of `bucket` such as `day`
+    pub async fn fetch_word_timeline(&self, scope: &StatsScope, bucket: &str) -> Result<Vec<WordTimelinePoint>, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_word_timeline(scope, bucket).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching the word timeline");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    database::Error,
+    word_stats::{StatsScope, WordTotals},
+};
+
+impl crate::database::Database {
+    /// Message and word totals for messages within the scope
+    pub async fn fetch_word_totals(&self, scope: &StatsScope) -> Result<WordTotals, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.fetch_word_totals(scope).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error fetching word totals");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
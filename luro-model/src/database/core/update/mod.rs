@@ -1,3 +1,30 @@
+mod update_adoption;
+mod update_ai_channel;
+mod update_ai_history;
+mod update_ai_opt_out;
+mod update_anniversary;
+mod update_auto_role;
+mod update_auto_role_pending;
+mod update_auto_roles_screened;
 mod update_character;
 mod update_character_fetish;
 mod update_character_image;
+mod update_command_policy;
+mod update_e621_blacklist;
+mod update_flavour_disabled;
+mod update_flavour_entry;
+mod update_flavour_vote;
+mod update_image;
+mod update_insights;
+mod update_marriage;
+mod update_marriage_settings;
+mod update_music_position;
+mod update_music_queue;
+mod update_quote_starboard;
+mod update_reaction_role;
+mod update_role_blacklist;
+mod update_role_menu;
+mod update_starboard;
+mod update_sticky_roles;
+mod update_sticky_roles_enabled;
+mod update_user_permissions;
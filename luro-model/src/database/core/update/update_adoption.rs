@@ -0,0 +1,22 @@
+use crate::{database::Error, user::Adoption};
+
+impl crate::database::Database {
+    pub async fn update_adoption(&self, adoption: &Adoption) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_adoption(adoption).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error updating the adoption of `{}` by `{}`",
+                    adoption.child_id,
+                    adoption.parent_id
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
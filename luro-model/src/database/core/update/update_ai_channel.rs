@@ -0,0 +1,17 @@
+use crate::{ai::AiChannel, database::Error};
+
+impl crate::database::Database {
+    pub async fn update_ai_channel(&self, channel: &AiChannel) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_ai_channel(channel).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating AI settings for channel `{}`", channel.channel_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
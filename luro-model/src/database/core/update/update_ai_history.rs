@@ -0,0 +1,18 @@
+use crate::{ai::AiHistoryMessage, database::Error};
+
+impl crate::database::Database {
+    /// Record a message as part of a channel's conversation
+    pub async fn update_ai_history(&self, message: &AiHistoryMessage) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_ai_history(message).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error recording AI history for message `{}`", message.message_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,22 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Opt a user in or out of having their messages used as context.
+    ///
+    /// When opting out, returns how many of their recorded messages were removed.
+    pub async fn update_ai_opt_out(&self, user_id: Id<UserMarker>, opt_out: bool) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_ai_opt_out(user_id, opt_out).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating AI opt out for user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
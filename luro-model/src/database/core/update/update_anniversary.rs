@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Record that a couple's anniversary has been posted for this year
+    pub async fn update_anniversary(&self, proposer_id: Id<UserMarker>, proposee_id: Id<UserMarker>, year: i32) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_anniversary(proposer_id, proposee_id, year).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error recording the anniversary of `{proposer_id}` and `{proposee_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
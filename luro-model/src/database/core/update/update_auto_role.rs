@@ -0,0 +1,23 @@
+use crate::{auto_role::AutoRole, database::Error};
+
+impl crate::database::Database {
+    /// Give a role to members when they join, replacing the settings if it already is
+    pub async fn update_auto_role(&self, auto_role: &AutoRole) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_auto_role(auto_role).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error updating auto role `{}` in guild `{}`",
+                    auto_role.role_id,
+                    auto_role.guild_id
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
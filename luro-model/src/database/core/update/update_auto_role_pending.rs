@@ -0,0 +1,37 @@
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Queue an auto role for a member. Without `apply_at`, the role waits until the member passes screening.
+    pub async fn update_auto_role_pending(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Id<RoleMarker>,
+        apply_at: Option<OffsetDateTime>,
+    ) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self
+            .sqlx_driver
+            .update_auto_role_pending(guild_id, user_id, role_id, apply_at)
+            .await
+        {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error queueing auto role `{role_id}` for member `{user_id}` in guild `{guild_id}`"
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// A member passed screening, so start the delay on any auto roles waiting for it
+    pub async fn update_auto_roles_screened(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_auto_roles_screened(guild_id, user_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error starting the auto roles for member `{user_id}` in guild `{guild_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
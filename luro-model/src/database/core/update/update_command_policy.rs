@@ -0,0 +1,23 @@
+use crate::{command_policy::CommandPolicy, database::Error};
+
+impl crate::database::Database {
+    /// Create or replace a command policy
+    pub async fn update_command_policy(&self, policy: &CommandPolicy) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_command_policy(policy).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error updating the `{}` command policy of guild `{}`",
+                    policy.command,
+                    policy.guild_id
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
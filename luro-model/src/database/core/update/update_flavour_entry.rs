@@ -0,0 +1,17 @@
+use crate::{database::Error, flavour::FlavourEntry};
+
+impl crate::database::Database {
+    pub async fn update_flavour_entry(&self, entry: &FlavourEntry) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_flavour_entry(entry).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating flavour entry `{}`", entry.id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Set a user's vote on an entry, replacing any earlier vote. An upvote is `1` and a downvote is `-1`.
+    pub async fn update_flavour_vote(&self, entry_id: i64, user_id: Id<UserMarker>, vote: i16) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_flavour_vote(entry_id, user_id, vote).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error voting on flavour entry `{entry_id}` for user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Write the daily snapshots of every guild for yesterday and today
+    pub async fn update_insights(&self) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_insights().await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error writing guild insights");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
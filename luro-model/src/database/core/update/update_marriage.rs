@@ -0,0 +1,23 @@
+use crate::{database::Error, user::Marriage};
+
+impl crate::database::Database {
+    /// Save a marriage. Any record of the pair the other way around is replaced, so each couple only has one marriage.
+    pub async fn update_marriage(&self, marriage: &Marriage) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_marriage(marriage).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(
+                    ?why,
+                    "Error updating the marriage of `{}` and `{}`",
+                    marriage.proposer_id,
+                    marriage.proposee_id
+                );
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{database::Error, user::MarriageSettings};
+
+impl crate::database::Database {
+    pub async fn update_marriage_settings(&self, settings: &MarriageSettings) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_marriage_settings(settings).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating marriage settings for guild `{}`", settings.guild_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, music::MusicQueue};
+
+impl crate::database::Database {
+    /// Save a guild's queue, replacing all tracks that were previously saved
+    pub async fn update_music_queue(&self, queue: &MusicQueue) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_music_queue(queue).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error saving music queue for guild `{}`", queue.guild_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
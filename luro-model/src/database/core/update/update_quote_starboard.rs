@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::database::Error;
+
+impl crate::database::Database {
+    /// Record the starboard repost of a quote, unless it already has one. Returns 0 if another repost was recorded first.
+    pub async fn update_quote_starboard(&self, quote_id: i64, starboard_message_id: Id<MessageMarker>) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_quote_starboard(quote_id, starboard_message_id).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating the starboard message of quote `{quote_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{auto_role::ReactionRole, database::Error};
+
+impl crate::database::Database {
+    /// Bind an emoji on a message to a role, replacing any role it was already bound to
+    pub async fn update_reaction_role(&self, reaction_role: &ReactionRole) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_reaction_role(reaction_role).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating the reaction role on message `{}`", reaction_role.message_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{database::Error, role_menu::RoleMenu};
+
+impl crate::database::Database {
+    /// Save a menu and the roles it offers, returning the menu ID. Menus are matched by guild and name.
+    pub async fn update_role_menu(&self, menu: &RoleMenu) -> Result<i64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_role_menu(menu).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error saving role menu `{}` for guild `{}`", menu.name, menu.guild_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{database::Error, quote::Starboard};
+
+impl crate::database::Database {
+    pub async fn update_starboard(&self, starboard: &Starboard) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_starboard(starboard).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating the starboard for guild `{}`", starboard.guild_id);
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{database::Error, user::UserPermissions};
+
+impl crate::database::Database {
+    /// Set the permissions of a user, such as making them staff
+    pub async fn update_user_permissions(&self, user_id: Id<UserMarker>, permissions: UserPermissions) -> Result<u64, Error> {
+        #[cfg(feature = "database-sqlx")]
+        match self.sqlx_driver.update_user_permissions(user_id, permissions).await {
+            Ok(data) => Ok(data),
+            Err(why) => {
+                tracing::error!(?why, "Error updating the permissions of user `{user_id}`");
+                Err(Error::DriverFailure)
+            }
+        }
+
+        #[cfg(not(feature = "database-sqlx"))]
+        Err(Error::RequiresDriver)
+    }
+}
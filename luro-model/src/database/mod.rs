@@ -2,13 +2,19 @@ use std::sync::Arc;
 
 use twilight_http::Client;
 
-use crate::config::Config;
+use crate::{config::Config, staff::StaffConfig};
 
 /// Core module that uses twilight, preferring any configured drivers first
 mod core;
 #[cfg(feature = "database-sqlx")]
 /// A module for fetching data using the SQLx driver.
 pub mod sqlx;
+#[cfg(feature = "database-sync")]
+/// A queue of gateway events, written to the database in the background.
+mod sync_queue;
+
+#[cfg(feature = "database-sync")]
+pub use sync_queue::{SyncMetrics, SyncQueue, SyncStats};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -26,11 +32,15 @@ pub enum Error {
 pub struct Database {
     #[cfg(feature = "database-sqlx")]
     pub sqlx_driver: crate::database::sqlx::Database,
+    #[cfg(feature = "database-sync")]
+    pub sync_queue: SyncQueue,
+    /// Staff from the configuration, who are always staff alongside those in the database
+    pub staff: StaffConfig,
     pub twilight_client: Arc<Client>,
 }
 
 impl Database {
-    pub async fn new(#[cfg(feature = "database-sqlx")] config: &Config, twilight_client: Arc<Client>) -> Result<Self, Error> {
+    pub async fn new(config: &Config, twilight_client: Arc<Client>) -> Result<Self, Error> {
         Ok(Self {
             #[cfg(feature = "database-sqlx")]
             sqlx_driver: match crate::database::sqlx::Database::new(config).await {
@@ -40,15 +50,21 @@ impl Database {
                     return Err(Error::DriverFailure);
                 }
             },
+            #[cfg(feature = "database-sync")]
+            sync_queue: SyncQueue::new(&config.database_sync),
+            staff: config.staff.clone(),
             twilight_client,
         })
     }
 
     #[cfg(feature = "database-sync")]
-    /// Sync data from the gateway to the database driver.
+    /// Sync data from the gateway to the database driver, waiting for the write.
     /// Useful for keeping things like roles, channels, and messages in sync.
-    /// This task should be spawned in the event loop of your bot.
+    /// Prefer [`Self::queue_sync`] in the event loop of your bot, which writes in the background.
     pub async fn sync_gateway(&self, event: &twilight_gateway::Event) {
-        self.sqlx_driver.sync_gateway(event).await
+        match self.sqlx_driver.sync_gateway(event).await {
+            Ok(rows_updated) => tracing::debug!("DATABASE: Updated {rows_updated} rows of data"),
+            Err(why) => tracing::warn!(why = ?why, "DATABASE: Failed to sync incoming data"),
+        }
     }
 }
@@ -21,26 +21,34 @@ pub enum Error {
     TimestampParseError(#[from] TimestampParseError),
     #[error("Time range was outside of the allowed range")]
     TimeParseError(#[from] ComponentRange),
+    #[error("Discord did not send `{0}`, which is needed to sync this data")]
+    MissingField(&'static str),
+    #[error("The database could not be migrated to the latest schema")]
+    MigrateError(#[from] ::sqlx::migrate::MigrateError),
 }
 
+/// The size of the connection pool, shared by commands and the gateway sync
+pub const MAX_CONNECTIONS: u32 = 5;
+
 #[derive(Debug)]
 pub struct Database {
     pub pool: ::sqlx::Pool<::sqlx::Postgres>,
 }
 
 impl Database {
-    /// Create a new database instance
+    /// Create a new database instance, bringing the schema up to date with the migrations in `luro-model/migrations`
     pub async fn new(config: &Config) -> Result<Self, Error> {
         let Some(ref connection_string) = config.connection_string else {
             return Err(Error::NoConnectionString);
         };
 
-        Ok(Self {
-            pool: ::sqlx::postgres::PgPoolOptions::new()
-                .max_connections(5)
-                .connect(connection_string)
-                .await?,
-        })
+        let pool = ::sqlx::postgres::PgPoolOptions::new()
+            .max_connections(MAX_CONNECTIONS)
+            .connect(connection_string)
+            .await?;
+        ::sqlx::migrate!().run(&pool).await?;
+
+        Ok(Self { pool })
     }
 }
 
@@ -52,11 +60,10 @@ mod sync {
     use super::{Database, Error};
 
     impl Database {
-        /// Sync data from the gateway to the database driver.
+        /// Sync data from the gateway to the database driver, returning the number of rows updated.
         /// Useful for keeping things like roles, channels, and messages in sync.
-        /// This task should be spawned in the event loop of your bot.
-        pub async fn sync_gateway(&self, event: &twilight_gateway::Event) {
-            let callback = match event {
+        pub async fn sync_gateway(&self, event: &twilight_gateway::Event) -> Result<u64, Error> {
+            match event {
                 Event::ChannelCreate(event) => self.update_channel(event.as_ref()).await,
                 Event::ChannelDelete(event) => self.update_channel(event.as_ref()).await,
                 Event::ChannelPinsUpdate(event) => self.update_channel(event).await,
@@ -68,10 +75,10 @@ mod sync {
                 Event::MemberChunk(event) => self.update_user(event).await,
                 Event::MemberRemove(event) => self.update_user(event).await,
                 Event::MemberUpdate(event) => self.update_user(event.as_ref()).await,
-                // Event::MessageCreate(event) => message::create(self, event).await,
-                // Event::MessageDelete(event) => message::delete(self, event).await,
-                // Event::MessageDeleteBulk(event) => message::delete_bulk(self, event).await,
-                // Event::MessageUpdate(event) => message::update(self, event).await,
+                Event::MessageCreate(event) => self.update_message(event.as_ref()).await,
+                Event::MessageDelete(event) => self.update_message(event).await,
+                Event::MessageDeleteBulk(event) => self.update_message(event).await,
+                Event::MessageUpdate(event) => self.update_message(event.as_ref()).await,
                 Event::PresenceUpdate(event) => presence(self, event).await,
                 Event::Ready(event) => ready(self, event).await,
                 Event::RoleCreate(event) => self.update_role(event).await,
@@ -79,11 +86,6 @@ mod sync {
                 Event::RoleUpdate(event) => self.update_role(event).await,
                 Event::UserUpdate(event) => self.update_user(event).await,
                 _ => Ok(0),
-            };
-
-            match callback {
-                Ok(rows_updated) => tracing::debug!("DATABASE: Updated {rows_updated} rows of data"),
-                Err(why) => tracing::warn!(why = ?why, "DATABASE: Failed to sync incoming data"),
             }
         }
     }
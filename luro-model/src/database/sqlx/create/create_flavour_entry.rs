@@ -0,0 +1,22 @@
+use crate::flavour::FlavourEntry;
+
+impl crate::database::sqlx::Database {
+    /// Add an entry to a guild's pool, returning the new entry ID
+    pub async fn create_flavour_entry(&self, entry: &FlavourEntry) -> Result<i64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO flavour_entries (guild_id, pool, content, added_by, disabled)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            ",
+            entry.guild_id.get() as i64,
+            entry.pool,
+            entry.content,
+            entry.added_by.get() as i64,
+            entry.disabled
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map(|x| x.id)
+    }
+}
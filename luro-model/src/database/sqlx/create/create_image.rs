@@ -0,0 +1,26 @@
+use crate::image::Image;
+
+impl crate::database::sqlx::Database {
+    /// Add an image to the library, returning the new image ID
+    pub async fn create_image(&self, image: &Image) -> Result<i64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO images (name, nsfw, owner_id, source, url, artist, tags, status, phash)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING img_id
+            ",
+            image.name,
+            image.nsfw,
+            image.owner_id,
+            image.source,
+            image.url,
+            image.artist,
+            &image.tags,
+            image.status.as_str(),
+            image.phash
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map(|x| x.img_id)
+    }
+}
@@ -0,0 +1,26 @@
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::insights::MemberEventKind;
+
+impl crate::database::sqlx::Database {
+    /// Record a member joining or leaving a guild
+    pub async fn create_member_event(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        kind: MemberEventKind,
+    ) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO guild_member_events (guild_id, user_id, kind) VALUES ($1, $2, $3)",
+            guild_id.get() as i64,
+            user_id.get() as i64,
+            kind.as_str()
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
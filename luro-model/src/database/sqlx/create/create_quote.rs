@@ -0,0 +1,26 @@
+use crate::quote::Quote;
+
+impl crate::database::sqlx::Database {
+    /// Add a quote to the database, returning the new quote ID. Returns `None` if the message has already been quoted.
+    pub async fn create_quote(&self, quote: &Quote) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO quotes (guild_id, channel_id, message_id, author_id, added_by, content, nsfw, starboard_message_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (message_id) DO NOTHING
+            RETURNING id
+            ",
+            quote.guild_id.map(|x| x.get() as i64),
+            quote.channel_id.get() as i64,
+            quote.message_id.get() as i64,
+            quote.author_id.map(|x| x.get() as i64),
+            quote.added_by.get() as i64,
+            quote.content,
+            quote.nsfw,
+            quote.starboard_message_id.map(|x| x.get() as i64)
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map(|x| x.map(|x| x.id))
+    }
+}
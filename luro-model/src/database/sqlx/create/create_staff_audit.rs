@@ -0,0 +1,21 @@
+use crate::staff::StaffAuditEntry;
+
+impl crate::database::sqlx::Database {
+    /// Record a change to the staff
+    pub async fn create_staff_audit(&self, entry: &StaffAuditEntry) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO staff_audit_log (actor_id, target_id, action, permissions, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ",
+            entry.actor_id.get() as i64,
+            entry.target_id.get() as i64,
+            entry.action.as_str(),
+            entry.permissions.clone() as _,
+            entry.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
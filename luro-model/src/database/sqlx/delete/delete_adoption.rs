@@ -0,0 +1,15 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Remove an adoption, or a request to adopt
+    pub async fn delete_adoption(&self, parent_id: Id<UserMarker>, child_id: Id<UserMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM user_adoptions WHERE parent_id = $1 AND child_id = $2",
+            parent_id.get() as i64,
+            child_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
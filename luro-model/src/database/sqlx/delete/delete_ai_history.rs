@@ -0,0 +1,11 @@
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Forget the conversation in a channel, returning how many messages were removed
+    pub async fn delete_ai_history(&self, channel_id: Id<ChannelMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!("DELETE FROM ai_history WHERE channel_id = $1", channel_id.get() as i64)
+            .execute(&self.pool)
+            .await
+            .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,30 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Stop giving a role to members when they join, including to anyone still waiting for it
+    pub async fn delete_auto_role(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<u64, sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        let mut rows_modified = sqlx::query!(
+            "DELETE FROM auto_roles_pending WHERE guild_id = $1 AND role_id = $2",
+            guild_id.get() as i64,
+            role_id.get() as i64
+        )
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+        rows_modified += sqlx::query!(
+            "DELETE FROM auto_roles WHERE guild_id = $1 AND role_id = $2",
+            guild_id.get() as i64,
+            role_id.get() as i64
+        )
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+        transaction.commit().await?;
+
+        Ok(rows_modified)
+    }
+}
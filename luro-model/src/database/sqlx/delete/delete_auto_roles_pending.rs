@@ -0,0 +1,24 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Remove a member's queued auto roles, or just the one role if passed
+    pub async fn delete_auto_roles_pending(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Option<Id<RoleMarker>>,
+    ) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM auto_roles_pending WHERE guild_id = $1 AND user_id = $2 AND ($3::BIGINT IS NULL OR role_id = $3)",
+            guild_id.get() as i64,
+            user_id.get() as i64,
+            role_id.map(|x| x.get() as i64)
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,21 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::command_policy::PolicyScope;
+
+impl crate::database::sqlx::Database {
+    /// Remove a command policy, so the command falls back to the next policy that applies
+    pub async fn delete_command_policy(&self, guild_id: Id<GuildMarker>, command: &str, scope: PolicyScope) -> Result<u64, sqlx::Error> {
+        let (scope, target_id) = scope.as_parts();
+
+        sqlx::query!(
+            "DELETE FROM command_policies WHERE guild_id = $1 AND command = $2 AND scope = $3 AND target_id = $4",
+            guild_id.get() as i64,
+            command,
+            scope,
+            target_id
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,10 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    pub async fn delete_e621_blacklist(&self, user_id: Id<UserMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!("DELETE FROM e621_blacklists WHERE user_id = $1", user_id.get() as i64)
+            .execute(&self.pool)
+            .await
+            .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,16 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Enable a built in entry of a pool for a guild again
+    pub async fn delete_flavour_disabled(&self, guild_id: Id<GuildMarker>, pool: &str, content: &str) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM flavour_disabled WHERE guild_id = $1 AND pool = $2 AND content = $3",
+            guild_id.get() as i64,
+            pool,
+            content
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
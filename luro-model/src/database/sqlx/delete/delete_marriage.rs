@@ -1,6 +1,6 @@
 use twilight_model::id::{marker::UserMarker, Id};
 
-use crate::user::Marriage;
+use crate::{database::sqlx::fetch::MarriageRow, user::Marriage};
 
 impl crate::database::sqlx::Database {
     pub async fn delete_marriage(
@@ -8,31 +8,21 @@ impl crate::database::sqlx::Database {
         first_user: Id<UserMarker>,
         second_user: Id<UserMarker>,
     ) -> Result<Option<Marriage>, sqlx::Error> {
-        let proposee_id = first_user.min(second_user);
-        let proposer_id = first_user.max(second_user);
-        sqlx::query!(
+        sqlx::query_as!(
+            MarriageRow,
             "
             DELETE FROM user_marriages
-            WHERE (proposer_id, proposee_id) IN (select proposer_id, proposee_id from user_marriages where
+            WHERE
                 (proposer_id = $1 AND proposee_id = $2)
                     or
                 (proposer_id = $2 AND proposee_id = $1)
-            )
-            RETURNING *
+            RETURNING proposer_id, proposee_id, divorced, rejected, reason, guild_id, channel_id, married_at
             ",
-            proposee_id.get() as i64,
-            proposer_id.get() as i64,
+            first_user.get() as i64,
+            second_user.get() as i64,
         )
         .fetch_optional(&self.pool)
         .await
-        .map(|some| {
-            some.map(|marriage| Marriage {
-                proposer_id,
-                proposee_id,
-                divorced: marriage.divorced,
-                rejected: marriage.rejected,
-                reason: marriage.reason,
-            })
-        })
+        .map(|some| some.map(|x| x.into()))
     }
 }
@@ -0,0 +1,11 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Remove a guild's queue and all of its tracks
+    pub async fn delete_music_queue(&self, guild_id: Id<GuildMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!("DELETE FROM music_queues WHERE guild_id = $1", guild_id.get() as i64)
+            .execute(&self.pool)
+            .await
+            .map(|x| x.rows_affected())
+    }
+}
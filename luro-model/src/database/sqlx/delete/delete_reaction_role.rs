@@ -0,0 +1,14 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+impl crate::database::sqlx::Database {
+    pub async fn delete_reaction_role(&self, message_id: Id<MessageMarker>, emoji: &str) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM reaction_roles WHERE message_id = $1 AND emoji = $2",
+            message_id.get() as i64,
+            emoji
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,18 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Let members give themselves a role again
+    pub async fn delete_role_blacklist(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM guild_role_blacklist WHERE guild_id = $1 AND role_id = $2",
+            guild_id.get() as i64,
+            role_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,9 @@
+impl crate::database::sqlx::Database {
+    /// Delete a menu and the roles it offers. The posted message is left alone.
+    pub async fn delete_role_menu(&self, menu_id: i64) -> Result<u64, sqlx::Error> {
+        sqlx::query!("DELETE FROM role_menus WHERE id = $1", menu_id)
+            .execute(&self.pool)
+            .await
+            .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,11 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Turn off the starboard for a guild. Existing quotes are kept.
+    pub async fn delete_starboard(&self, guild_id: Id<GuildMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!("DELETE FROM starboards WHERE guild_id = $1", guild_id.get() as i64)
+            .execute(&self.pool)
+            .await
+            .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,22 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Forget the roles a member held when they left, returning them so they can be given back
+    pub async fn delete_sticky_roles(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<Id<RoleMarker>>, sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM sticky_roles WHERE guild_id = $1 AND user_id = $2 RETURNING role_id",
+            guild_id.get() as i64,
+            user_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| rows.into_iter().map(|x| Id::new(x.role_id as u64)).collect())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::word_stats::{ActivityHeatmap, StatsScope};
+
+impl crate::database::sqlx::Database {
+    /// Messages within the scope, by day of the week and hour of the day
+    pub async fn fetch_activity_heatmap(&self, scope: &StatsScope) -> Result<ActivityHeatmap, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                EXTRACT(ISODOW FROM timestamp AT TIME ZONE 'UTC')::INT AS "day!",
+                EXTRACT(HOUR FROM timestamp AT TIME ZONE 'UTC')::INT AS "hour!",
+                COUNT(*) AS "messages!"
+            FROM messages
+            WHERE ($1::BIGINT IS NULL OR guild_id = $1)
+                AND ($2::BIGINT IS NULL OR channel_id = $2)
+                AND ($3::BIGINT IS NULL OR author_id = $3)
+                AND ($4::TIMESTAMPTZ IS NULL OR timestamp >= $4)
+            GROUP BY 1, 2
+            "#,
+            scope.guild_id.map(|id| id.get() as i64),
+            scope.channel_id.map(|id| id.get() as i64),
+            scope.user_id.map(|id| id.get() as i64),
+            scope.since,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut heatmap = ActivityHeatmap::default();
+        for row in rows {
+            // ISODOW counts from 1, for Monday
+            let Some(day) = (row.day as usize).checked_sub(1) else {
+                continue;
+            };
+
+            if let Some(hours) = heatmap.get_mut(day) {
+                if let Some(count) = hours.get_mut(row.hour as usize) {
+                    *count = row.messages;
+                }
+            }
+        }
+        Ok(heatmap)
+    }
+}
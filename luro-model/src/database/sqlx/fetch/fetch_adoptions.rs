@@ -0,0 +1,42 @@
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::user::Adoption;
+
+/// A row of the adoptions table, converted into an [Adoption]
+pub(crate) struct AdoptionRow {
+    pub parent_id: i64,
+    pub child_id: i64,
+    pub guild_id: Option<i64>,
+    pub adopted_at: Option<OffsetDateTime>,
+}
+
+impl From<AdoptionRow> for Adoption {
+    fn from(row: AdoptionRow) -> Self {
+        Self {
+            parent_id: Id::new(row.parent_id as u64),
+            child_id: Id::new(row.child_id as u64),
+            guild_id: row.guild_id.map(|x| Id::new(x as u64)),
+            adopted_at: row.adopted_at,
+        }
+    }
+}
+
+impl crate::database::sqlx::Database {
+    /// Fetch every adoption a user is part of, as either the parent or the child. Includes adoptions waiting for an answer.
+    pub async fn fetch_adoptions(&self, user_id: Id<UserMarker>) -> Result<Vec<Adoption>, sqlx::Error> {
+        sqlx::query_as!(
+            AdoptionRow,
+            "
+            SELECT parent_id, child_id, guild_id, adopted_at
+            FROM user_adoptions
+            WHERE parent_id = $1 OR child_id = $1
+            ORDER BY adopted_at NULLS LAST
+            ",
+            user_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|adoptions| adoptions.into_iter().map(|x| x.into()).collect())
+    }
+}
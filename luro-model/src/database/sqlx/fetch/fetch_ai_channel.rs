@@ -0,0 +1,25 @@
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::ai::AiChannel;
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_ai_channel(&self, channel_id: Id<ChannelMarker>) -> Result<Option<AiChannel>, sqlx::Error> {
+        sqlx::query!(
+            "
+            SELECT * FROM ai_channels WHERE channel_id = $1
+            ",
+            channel_id.get() as i64
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map(|some| {
+            some.map(|channel| AiChannel {
+                channel_id,
+                persona: channel.persona,
+                character_owner: channel.character_owner.map(|x| Id::new(x as u64)),
+                character_name: channel.character_name,
+                enabled: channel.enabled,
+            })
+        })
+    }
+}
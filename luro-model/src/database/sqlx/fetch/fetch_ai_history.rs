@@ -0,0 +1,31 @@
+use futures_util::TryStreamExt;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::ai::AiHistoryMessage;
+
+impl crate::database::sqlx::Database {
+    /// Fetch the most recent messages in a channel's conversation, newest first.
+    pub async fn fetch_ai_history(&self, channel_id: Id<ChannelMarker>, limit: i64) -> Result<Vec<AiHistoryMessage>, sqlx::Error> {
+        let mut messages = vec![];
+        let mut query = sqlx::query!(
+            "
+            SELECT * FROM ai_history WHERE channel_id = $1 ORDER BY created_at DESC LIMIT $2
+            ",
+            channel_id.get() as i64,
+            limit
+        )
+        .fetch(&self.pool);
+
+        while let Some(message) = query.try_next().await? {
+            messages.push(AiHistoryMessage {
+                channel_id,
+                message_id: Id::new(message.message_id as u64),
+                author_id: Id::new(message.author_id as u64),
+                assistant: message.assistant,
+                content: message.content,
+            })
+        }
+
+        Ok(messages)
+    }
+}
@@ -0,0 +1,11 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Returns true if the user does not want their messages used as context
+    pub async fn fetch_ai_opt_out(&self, user_id: Id<UserMarker>) -> Result<bool, sqlx::Error> {
+        sqlx::query!("SELECT user_id FROM ai_opt_outs WHERE user_id = $1", user_id.get() as i64)
+            .fetch_optional(&self.pool)
+            .await
+            .map(|some| some.is_some())
+    }
+}
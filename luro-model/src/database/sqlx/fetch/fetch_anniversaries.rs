@@ -0,0 +1,25 @@
+use crate::user::Marriage;
+
+use super::fetch_marriage::MarriageRow;
+
+impl crate::database::sqlx::Database {
+    /// Fetch the marriages that have an anniversary today which has not been posted yet. Marriages on Feb 29 have theirs on Feb 28 in other years.
+    pub async fn fetch_anniversaries(&self) -> Result<Vec<Marriage>, sqlx::Error> {
+        sqlx::query_as!(
+            MarriageRow,
+            "
+            SELECT proposer_id, proposee_id, divorced, rejected, reason, guild_id, channel_id, married_at
+            FROM user_marriages
+            WHERE NOT divorced AND NOT rejected AND guild_id IS NOT NULL
+                AND EXTRACT(YEAR FROM married_at) < EXTRACT(YEAR FROM NOW())
+                -- Adding whole years moves Feb 29 to Feb 28 in other years, so those marriages are still celebrated
+                AND (married_at + make_interval(years => (EXTRACT(YEAR FROM NOW()) - EXTRACT(YEAR FROM married_at))::INTEGER))::DATE
+                    = NOW()::DATE
+                AND (anniversary_year IS NULL OR anniversary_year < EXTRACT(YEAR FROM NOW()))
+            "
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|marriages| marriages.into_iter().map(|x| x.into()).collect())
+    }
+}
@@ -0,0 +1,25 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::auto_role::AutoRole;
+
+impl crate::database::sqlx::Database {
+    /// The roles given to members when they join a guild
+    pub async fn fetch_auto_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<AutoRole>, sqlx::Error> {
+        sqlx::query!(
+            "SELECT guild_id, role_id, delay_seconds, after_screening FROM auto_roles WHERE guild_id = $1",
+            guild_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| AutoRole {
+                    guild_id: Id::new(row.guild_id as u64),
+                    role_id: Id::new(row.role_id as u64),
+                    delay_seconds: row.delay_seconds,
+                    after_screening: row.after_screening,
+                })
+                .collect()
+        })
+    }
+}
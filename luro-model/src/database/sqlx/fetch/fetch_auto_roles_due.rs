@@ -0,0 +1,21 @@
+use twilight_model::id::Id;
+
+use crate::auto_role::PendingAutoRole;
+
+impl crate::database::sqlx::Database {
+    /// Auto roles across every guild that are ready to be given
+    pub async fn fetch_auto_roles_due(&self) -> Result<Vec<PendingAutoRole>, sqlx::Error> {
+        sqlx::query!("SELECT guild_id, user_id, role_id FROM auto_roles_pending WHERE apply_at <= NOW()")
+            .fetch_all(&self.pool)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| PendingAutoRole {
+                        guild_id: Id::new(row.guild_id as u64),
+                        user_id: Id::new(row.user_id as u64),
+                        role_id: Id::new(row.role_id as u64),
+                    })
+                    .collect()
+            })
+    }
+}
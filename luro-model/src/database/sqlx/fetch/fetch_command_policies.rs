@@ -0,0 +1,32 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::command_policy::{CommandPolicy, PolicyScope};
+
+impl crate::database::sqlx::Database {
+    /// Every command policy of a guild, sorted by command
+    pub async fn fetch_command_policies(&self, guild_id: Id<GuildMarker>) -> Result<Vec<CommandPolicy>, sqlx::Error> {
+        let rows = sqlx::query!(
+            "
+            SELECT command, scope, target_id, enabled
+            FROM command_policies
+            WHERE guild_id = $1
+            ORDER BY command, scope, target_id
+            ",
+            guild_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(CommandPolicy {
+                    guild_id,
+                    command: row.command,
+                    scope: PolicyScope::from_parts(&row.scope, row.target_id)?,
+                    enabled: row.enabled,
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::word_stats::{StatsScope, WordUsage};
+
+impl crate::database::sqlx::Database {
+    /// The most used words within the scope, leaving out any in `excluded`
+    pub async fn fetch_common_words(&self, scope: &StatsScope, excluded: &[String], limit: i64) -> Result<Vec<WordUsage>, sqlx::Error> {
+        sqlx::query!(
+            r#"
+            WITH words AS (
+                SELECT regexp_split_to_table(lower(regexp_replace(content, '[^\w\s]', '', 'g')), '\s+') AS word
+                FROM messages
+                WHERE ($1::BIGINT IS NULL OR guild_id = $1)
+                    AND ($2::BIGINT IS NULL OR channel_id = $2)
+                    AND ($3::BIGINT IS NULL OR author_id = $3)
+                    AND ($4::TIMESTAMPTZ IS NULL OR timestamp >= $4)
+            )
+            SELECT word AS "word!", COUNT(*) AS "count!"
+            FROM words
+            WHERE word <> '' AND word <> ALL($5)
+            GROUP BY word
+            ORDER BY 2 DESC, 1
+            LIMIT $6
+            "#,
+            scope.guild_id.map(|id| id.get() as i64),
+            scope.channel_id.map(|id| id.get() as i64),
+            scope.user_id.map(|id| id.get() as i64),
+            scope.since,
+            excluded,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| WordUsage {
+                    word: row.word,
+                    count: row.count,
+                })
+                .collect()
+        })
+    }
+}
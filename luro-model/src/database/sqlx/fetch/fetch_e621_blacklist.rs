@@ -0,0 +1,11 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// A user's own e621 blacklist, one set of tags per line
+    pub async fn fetch_e621_blacklist(&self, user_id: Id<UserMarker>) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query!("SELECT blacklist FROM e621_blacklists WHERE user_id = $1", user_id.get() as i64)
+            .fetch_optional(&self.pool)
+            .await
+            .map(|row| row.map(|row| row.blacklist))
+    }
+}
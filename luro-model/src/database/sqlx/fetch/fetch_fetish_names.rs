@@ -0,0 +1,14 @@
+impl crate::database::sqlx::Database {
+    /// Names of fetishes containing `search`, ignoring case
+    pub async fn fetch_fetish_names(&self, search: &str, limit: i64) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query!(
+            // strpos instead of ILIKE, so `%` and `_` typed by the user are matched literally
+            r#"SELECT name AS "name!" FROM fetishes WHERE strpos(lower(name), lower($1)) > 0 ORDER BY name LIMIT $2"#,
+            search,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| rows.into_iter().map(|row| row.name).collect())
+    }
+}
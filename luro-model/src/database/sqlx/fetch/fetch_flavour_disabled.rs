@@ -0,0 +1,15 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// The built in entries of a pool that a guild has disabled
+    pub async fn fetch_flavour_disabled(&self, guild_id: Id<GuildMarker>, pool: &str) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query!(
+            "SELECT content FROM flavour_disabled WHERE guild_id = $1 AND pool = $2",
+            guild_id.get() as i64,
+            pool
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| rows.into_iter().map(|x| x.content).collect())
+    }
+}
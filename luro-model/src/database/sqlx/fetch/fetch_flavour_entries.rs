@@ -0,0 +1,27 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::flavour::FlavourEntry;
+
+use super::fetch_flavour_entry::FlavourEntryRow;
+
+impl crate::database::sqlx::Database {
+    /// Fetch a guild's entries for a pool, or for all pools if no pool is given. Highest scoring entries come first.
+    pub async fn fetch_flavour_entries(&self, guild_id: Id<GuildMarker>, pool: Option<&str>) -> Result<Vec<FlavourEntry>, sqlx::Error> {
+        let entries = sqlx::query_as!(
+            FlavourEntryRow,
+            r#"
+            SELECT id, guild_id, pool, content, added_by, disabled,
+                COALESCE((SELECT SUM(vote) FROM flavour_votes WHERE entry_id = id), 0)::BIGINT AS "score!"
+            FROM flavour_entries
+            WHERE guild_id = $1 AND ($2::TEXT IS NULL OR pool = $2)
+            ORDER BY "score!" DESC, id
+            "#,
+            guild_id.get() as i64,
+            pool
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries.into_iter().map(|x| x.into()).collect())
+    }
+}
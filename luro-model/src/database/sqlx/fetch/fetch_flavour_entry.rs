@@ -0,0 +1,47 @@
+use twilight_model::id::Id;
+
+use crate::flavour::FlavourEntry;
+
+/// A row of the flavour_entries table with its vote score, converted into a [FlavourEntry]
+pub(crate) struct FlavourEntryRow {
+    pub id: i64,
+    pub guild_id: i64,
+    pub pool: String,
+    pub content: String,
+    pub added_by: i64,
+    pub disabled: bool,
+    pub score: i64,
+}
+
+impl From<FlavourEntryRow> for FlavourEntry {
+    fn from(row: FlavourEntryRow) -> Self {
+        Self {
+            id: row.id,
+            guild_id: Id::new(row.guild_id as u64),
+            pool: row.pool,
+            content: row.content,
+            added_by: Id::new(row.added_by as u64),
+            disabled: row.disabled,
+            score: row.score,
+        }
+    }
+}
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_flavour_entry(&self, entry_id: i64) -> Result<Option<FlavourEntry>, sqlx::Error> {
+        let entry = sqlx::query_as!(
+            FlavourEntryRow,
+            r#"
+            SELECT id, guild_id, pool, content, added_by, disabled,
+                COALESCE((SELECT SUM(vote) FROM flavour_votes WHERE entry_id = id), 0)::BIGINT AS "score!"
+            FROM flavour_entries
+            WHERE id = $1
+            "#,
+            entry_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry.map(|x| x.into()))
+    }
+}
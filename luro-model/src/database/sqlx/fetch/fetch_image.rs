@@ -0,0 +1,20 @@
+use crate::image::Image;
+
+use super::fetch_images::ImageRow;
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_image(&self, img_id: i64) -> Result<Option<Image>, sqlx::Error> {
+        sqlx::query_as!(
+            ImageRow,
+            "
+            SELECT img_id, name, nsfw, owner_id, source, url, artist, tags, status, reviewed_by, phash
+            FROM images
+            WHERE img_id = $1
+            ",
+            img_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map(|row| row.map(|row| row.into()))
+    }
+}
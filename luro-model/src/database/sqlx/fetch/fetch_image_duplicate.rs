@@ -0,0 +1,24 @@
+use crate::image::{Image, IMAGE_DUPLICATE_DISTANCE};
+
+use super::fetch_images::ImageRow;
+
+impl crate::database::sqlx::Database {
+    /// An approved or pending image that looks the same as the perceptual hash, if there is one
+    pub async fn fetch_image_duplicate(&self, phash: i64) -> Result<Option<Image>, sqlx::Error> {
+        sqlx::query_as!(
+            ImageRow,
+            "
+            SELECT img_id, name, nsfw, owner_id, source, url, artist, tags, status, reviewed_by, phash
+            FROM images
+            WHERE status != 'rejected' AND phash IS NOT NULL AND bit_count((phash # $1)::BIT(64)) <= $2
+            ORDER BY bit_count((phash # $1)::BIT(64))
+            LIMIT 1
+            ",
+            phash,
+            IMAGE_DUPLICATE_DISTANCE as i64
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map(|row| row.map(|row| row.into()))
+    }
+}
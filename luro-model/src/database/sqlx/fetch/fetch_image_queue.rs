@@ -0,0 +1,27 @@
+use crate::image::Image;
+
+use super::fetch_images::ImageRow;
+
+impl crate::database::sqlx::Database {
+    /// The oldest image waiting for review, and how many are waiting in total
+    pub async fn fetch_image_queue(&self) -> Result<(Option<Image>, i64), sqlx::Error> {
+        let image = sqlx::query_as!(
+            ImageRow,
+            "
+            SELECT img_id, name, nsfw, owner_id, source, url, artist, tags, status, reviewed_by, phash
+            FROM images
+            WHERE status = 'pending'
+            ORDER BY submitted_at
+            LIMIT 1
+            "
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        let waiting = sqlx::query!("SELECT COUNT(*) as \"count!\" FROM images WHERE status = 'pending'")
+            .fetch_one(&self.pool)
+            .await?
+            .count;
+
+        Ok((image.map(|image| image.into()), waiting))
+    }
+}
@@ -1,17 +1,52 @@
 use crate::image::Image;
 
+/// A row of the images table
+pub(crate) struct ImageRow {
+    pub img_id: i64,
+    pub name: String,
+    pub nsfw: bool,
+    pub owner_id: i64,
+    pub source: Option<String>,
+    pub url: String,
+    pub artist: Option<String>,
+    pub tags: Vec<String>,
+    pub status: String,
+    pub reviewed_by: Option<i64>,
+    pub phash: Option<i64>,
+}
+
+impl From<ImageRow> for Image {
+    fn from(row: ImageRow) -> Self {
+        Self {
+            img_id: row.img_id,
+            name: row.name,
+            nsfw: row.nsfw,
+            owner_id: row.owner_id,
+            source: row.source,
+            url: row.url,
+            artist: row.artist,
+            tags: row.tags,
+            status: row.status.as_str().into(),
+            reviewed_by: row.reviewed_by,
+            phash: row.phash,
+        }
+    }
+}
+
 impl crate::database::sqlx::Database {
+    /// Every approved image, either NSFW or not
     pub async fn fetch_images(&self, nsfw: bool) -> Result<Vec<Image>, sqlx::Error> {
         sqlx::query_as!(
-            Image,
+            ImageRow,
             "
-            SELECT *
+            SELECT img_id, name, nsfw, owner_id, source, url, artist, tags, status, reviewed_by, phash
             FROM images
-            WHERE nsfw = $1
+            WHERE nsfw = $1 AND status = 'approved'
             ",
             nsfw
         )
         .fetch_all(&self.pool)
         .await
+        .map(|rows| rows.into_iter().map(|row| row.into()).collect())
     }
 }
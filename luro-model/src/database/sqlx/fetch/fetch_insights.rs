@@ -0,0 +1,22 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::insights::InsightsDay;
+
+impl crate::database::sqlx::Database {
+    /// The daily snapshots of a guild for the last `days` days, oldest first
+    pub async fn fetch_insights(&self, guild_id: Id<GuildMarker>, days: i64) -> Result<Vec<InsightsDay>, sqlx::Error> {
+        sqlx::query_as!(
+            InsightsDay,
+            "
+            SELECT day, members, joins, leaves, messages, active_members
+            FROM guild_insights
+            WHERE guild_id = $1 AND day > CURRENT_DATE - $2::INT
+            ORDER BY day
+            ",
+            guild_id.get() as i64,
+            days as i32
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+}
@@ -1,31 +1,51 @@
+use time::OffsetDateTime;
 use twilight_model::id::{marker::UserMarker, Id};
 
 use crate::user::Marriage;
 
+/// A row of the marriages table, converted into a [Marriage]
+pub(crate) struct MarriageRow {
+    pub proposer_id: i64,
+    pub proposee_id: i64,
+    pub divorced: bool,
+    pub rejected: bool,
+    pub reason: String,
+    pub guild_id: Option<i64>,
+    pub channel_id: Option<i64>,
+    pub married_at: Option<OffsetDateTime>,
+}
+
+impl From<MarriageRow> for Marriage {
+    fn from(row: MarriageRow) -> Self {
+        Self {
+            proposer_id: Id::new(row.proposer_id as u64),
+            proposee_id: Id::new(row.proposee_id as u64),
+            divorced: row.divorced,
+            rejected: row.rejected,
+            reason: row.reason,
+            guild_id: row.guild_id.map(|x| Id::new(x as u64)),
+            channel_id: row.channel_id.map(|x| Id::new(x as u64)),
+            married_at: row.married_at,
+        }
+    }
+}
+
 impl crate::database::sqlx::Database {
     pub async fn fetch_marriage(&self, first_user: Id<UserMarker>, second_user: Id<UserMarker>) -> Result<Option<Marriage>, sqlx::Error> {
-        let proposee_id = first_user.min(second_user);
-        let proposer_id = first_user.max(second_user);
-        sqlx::query!(
+        sqlx::query_as!(
+            MarriageRow,
             "
-            SELECT * FROM user_marriages WHERE
+            SELECT proposer_id, proposee_id, divorced, rejected, reason, guild_id, channel_id, married_at
+            FROM user_marriages WHERE
                 (proposer_id = $1 AND proposee_id = $2)
                     OR
                 (proposer_id = $2 AND proposee_id = $1)
             ",
-            proposee_id.get() as i64,
-            proposer_id.get() as i64,
+            first_user.get() as i64,
+            second_user.get() as i64,
         )
         .fetch_optional(&self.pool)
         .await
-        .map(|some| {
-            some.map(|marriage| Marriage {
-                proposer_id,
-                proposee_id,
-                divorced: marriage.divorced,
-                rejected: marriage.rejected,
-                reason: marriage.reason,
-            })
-        })
+        .map(|some| some.map(|x| x.into()))
     }
 }
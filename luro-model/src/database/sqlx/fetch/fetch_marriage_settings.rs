@@ -0,0 +1,17 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::user::MarriageSettings;
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_marriage_settings(&self, guild_id: Id<GuildMarker>) -> Result<Option<MarriageSettings>, sqlx::Error> {
+        let settings = sqlx::query!("SELECT * FROM marriage_settings WHERE guild_id = $1", guild_id.get() as i64)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(settings.map(|settings| MarriageSettings {
+            guild_id,
+            partner_limit: settings.partner_limit,
+            anniversary_channel_id: settings.anniversary_channel_id.map(|x| Id::new(x as u64)),
+        }))
+    }
+}
@@ -0,0 +1,46 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::insights::MemberRetention;
+
+impl crate::database::sqlx::Database {
+    /// Of the members that joined in the last `lookback` days, how many had not left `days` days later.
+    /// Members that joined too recently to know are not counted.
+    pub async fn fetch_member_retention(
+        &self,
+        guild_id: Id<GuildMarker>,
+        days: i64,
+        lookback: i64,
+    ) -> Result<MemberRetention, sqlx::Error> {
+        sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) AS "joined!",
+                COUNT(*) FILTER (
+                    WHERE NOT EXISTS (
+                        SELECT 1 FROM guild_member_events leaves
+                        WHERE leaves.guild_id = joins.guild_id
+                            AND leaves.user_id = joins.user_id
+                            AND leaves.kind = 'leave'
+                            AND leaves.happened_at > joins.happened_at
+                            AND leaves.happened_at <= joins.happened_at + make_interval(days => $2)
+                    )
+                ) AS "retained!"
+            FROM guild_member_events joins
+            WHERE joins.guild_id = $1
+                AND joins.kind = 'join'
+                AND joins.happened_at <= NOW() - make_interval(days => $2)
+                AND joins.happened_at > NOW() - make_interval(days => $3)
+            "#,
+            guild_id.get() as i64,
+            days as i32,
+            lookback as i32
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map(|row| MemberRetention {
+            days,
+            joined: row.joined,
+            retained: row.retained,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::music::{MusicQueue, QueuedTrack};
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_music_queue(&self, guild_id: Id<GuildMarker>) -> Result<Option<MusicQueue>, sqlx::Error> {
+        let queue = match sqlx::query!("SELECT * FROM music_queues WHERE guild_id = $1", guild_id.get() as i64)
+            .fetch_optional(&self.pool)
+            .await?
+        {
+            Some(queue) => queue,
+            None => return Ok(None),
+        };
+
+        let tracks = sqlx::query!(
+            "SELECT * FROM music_tracks WHERE guild_id = $1 ORDER BY track_index",
+            guild_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|track| QueuedTrack {
+            encoded: track.encoded,
+            title: track.title,
+            author: track.author,
+            uri: track.uri,
+            length: track.length,
+            requested_by: Id::new(track.requested_by as u64),
+        })
+        .collect();
+
+        Ok(Some(MusicQueue {
+            guild_id,
+            voice_channel_id: queue.voice_channel_id.map(|x| Id::new(x as u64)),
+            text_channel_id: queue.text_channel_id.map(|x| Id::new(x as u64)),
+            now_playing_message_id: queue.now_playing_message_id.map(|x| Id::new(x as u64)),
+            loop_mode: queue.loop_mode.as_str().into(),
+            volume: queue.volume,
+            paused: queue.paused,
+            position: queue.position,
+            tracks,
+        }))
+    }
+}
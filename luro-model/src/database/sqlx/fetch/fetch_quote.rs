@@ -0,0 +1,50 @@
+use twilight_model::id::Id;
+
+use crate::quote::Quote;
+
+/// A row of the quotes table, converted into a [Quote]
+pub(crate) struct QuoteRow {
+    pub id: i64,
+    pub guild_id: Option<i64>,
+    pub channel_id: i64,
+    pub message_id: i64,
+    pub author_id: Option<i64>,
+    pub added_by: i64,
+    pub content: String,
+    pub nsfw: bool,
+    pub starboard_message_id: Option<i64>,
+}
+
+impl From<QuoteRow> for Quote {
+    fn from(row: QuoteRow) -> Self {
+        Self {
+            id: row.id,
+            guild_id: row.guild_id.map(|x| Id::new(x as u64)),
+            channel_id: Id::new(row.channel_id as u64),
+            message_id: Id::new(row.message_id as u64),
+            author_id: row.author_id.map(|x| Id::new(x as u64)),
+            added_by: Id::new(row.added_by as u64),
+            content: row.content,
+            nsfw: row.nsfw,
+            starboard_message_id: row.starboard_message_id.map(|x| Id::new(x as u64)),
+        }
+    }
+}
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_quote(&self, quote_id: i64) -> Result<Option<Quote>, sqlx::Error> {
+        let quote = sqlx::query_as!(
+            QuoteRow,
+            "
+            SELECT id, guild_id, channel_id, message_id, author_id, added_by, content, nsfw, starboard_message_id
+            FROM quotes
+            WHERE id = $1
+            ",
+            quote_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(quote.map(|x| x.into()))
+    }
+}
@@ -0,0 +1,26 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::quote::Quote;
+
+use super::fetch_quote::QuoteRow;
+
+impl crate::database::sqlx::Database {
+    /// Fetch the quote of a message, if it has already been quoted
+    pub async fn fetch_quote_by_message(&self, message_id: Id<MessageMarker>) -> Result<Option<Quote>, sqlx::Error> {
+        let quote = sqlx::query_as!(
+            QuoteRow,
+            "
+            SELECT id, guild_id, channel_id, message_id, author_id, added_by, content, nsfw, starboard_message_id
+            FROM quotes
+            WHERE message_id = $1
+            ORDER BY id
+            LIMIT 1
+            ",
+            message_id.get() as i64
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(quote.map(|x| x.into()))
+    }
+}
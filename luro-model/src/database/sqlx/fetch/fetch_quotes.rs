@@ -0,0 +1,33 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::quote::{Quote, QuoteSearch};
+
+use super::fetch_quote::QuoteRow;
+
+impl crate::database::sqlx::Database {
+    /// Search a guild's quotes. Results are ranked by how well they match the query, then newest first.
+    pub async fn fetch_quotes(&self, guild_id: Id<GuildMarker>, search: &QuoteSearch) -> Result<Vec<Quote>, sqlx::Error> {
+        let quotes = sqlx::query_as!(
+            QuoteRow,
+            "
+            SELECT id, guild_id, channel_id, message_id, author_id, added_by, content, nsfw, starboard_message_id
+            FROM quotes
+            WHERE guild_id = $1
+                AND ($2::TEXT IS NULL OR content_search @@ websearch_to_tsquery('english', $2))
+                AND ($3::BIGINT IS NULL OR author_id = $3)
+                AND ($4 OR NOT nsfw)
+            ORDER BY ts_rank(content_search, websearch_to_tsquery('english', COALESCE($2, ''))) DESC, id DESC
+            LIMIT $5
+            ",
+            guild_id.get() as i64,
+            search.query,
+            search.author_id.map(|x| x.get() as i64),
+            search.nsfw,
+            search.limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(quotes.into_iter().map(|x| x.into()).collect())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::image::Image;
+
+use super::fetch_images::ImageRow;
+
+impl crate::database::sqlx::Database {
+    /// A random approved image that has every one of the tags
+    pub async fn fetch_random_image(&self, nsfw: bool, tags: &[String]) -> Result<Option<Image>, sqlx::Error> {
+        sqlx::query_as!(
+            ImageRow,
+            "
+            SELECT img_id, name, nsfw, owner_id, source, url, artist, tags, status, reviewed_by, phash
+            FROM images
+            WHERE nsfw = $1 AND status = 'approved' AND tags @> $2
+            ORDER BY random()
+            LIMIT 1
+            ",
+            nsfw,
+            tags
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map(|row| row.map(|row| row.into()))
+    }
+}
@@ -0,0 +1,27 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::quote::Quote;
+
+use super::fetch_quote::QuoteRow;
+
+impl crate::database::sqlx::Database {
+    /// Fetch a random quote from a guild. NSFW quotes are only included if `nsfw` is set.
+    pub async fn fetch_random_quote(&self, guild_id: Id<GuildMarker>, nsfw: bool) -> Result<Option<Quote>, sqlx::Error> {
+        let quote = sqlx::query_as!(
+            QuoteRow,
+            "
+            SELECT id, guild_id, channel_id, message_id, author_id, added_by, content, nsfw, starboard_message_id
+            FROM quotes
+            WHERE guild_id = $1 AND ($2 OR NOT nsfw)
+            ORDER BY random()
+            LIMIT 1
+            ",
+            guild_id.get() as i64,
+            nsfw
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(quote.map(|x| x.into()))
+    }
+}
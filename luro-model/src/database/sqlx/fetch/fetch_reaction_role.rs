@@ -0,0 +1,39 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::auto_role::ReactionRole;
+
+/// A row of the reaction_roles table
+pub(crate) struct ReactionRoleRow {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub message_id: i64,
+    pub emoji: String,
+    pub role_id: i64,
+}
+
+impl From<ReactionRoleRow> for ReactionRole {
+    fn from(row: ReactionRoleRow) -> Self {
+        Self {
+            guild_id: Id::new(row.guild_id as u64),
+            channel_id: Id::new(row.channel_id as u64),
+            message_id: Id::new(row.message_id as u64),
+            emoji: row.emoji,
+            role_id: Id::new(row.role_id as u64),
+        }
+    }
+}
+
+impl crate::database::sqlx::Database {
+    /// The role bound to an emoji on a message, if there is one
+    pub async fn fetch_reaction_role(&self, message_id: Id<MessageMarker>, emoji: &str) -> Result<Option<ReactionRole>, sqlx::Error> {
+        sqlx::query_as!(
+            ReactionRoleRow,
+            "SELECT guild_id, channel_id, message_id, emoji, role_id FROM reaction_roles WHERE message_id = $1 AND emoji = $2",
+            message_id.get() as i64,
+            emoji
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map(|row| row.map(|row| row.into()))
+    }
+}
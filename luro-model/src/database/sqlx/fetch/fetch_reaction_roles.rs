@@ -0,0 +1,19 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::auto_role::ReactionRole;
+
+use super::fetch_reaction_role::ReactionRoleRow;
+
+impl crate::database::sqlx::Database {
+    /// All of a guild's reaction roles, grouped by message
+    pub async fn fetch_reaction_roles(&self, guild_id: Id<GuildMarker>) -> Result<Vec<ReactionRole>, sqlx::Error> {
+        sqlx::query_as!(
+            ReactionRoleRow,
+            "SELECT guild_id, channel_id, message_id, emoji, role_id FROM reaction_roles WHERE guild_id = $1 ORDER BY message_id",
+            guild_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| rows.into_iter().map(|row| row.into()).collect())
+    }
+}
@@ -0,0 +1,17 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// The roles members can't give themselves in a guild
+    pub async fn fetch_role_blacklist(&self, guild_id: Id<GuildMarker>) -> Result<Vec<Id<RoleMarker>>, sqlx::Error> {
+        sqlx::query!(
+            "SELECT role_id FROM guild_role_blacklist WHERE guild_id = $1",
+            guild_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| rows.into_iter().map(|x| Id::new(x.role_id as u64)).collect())
+    }
+}
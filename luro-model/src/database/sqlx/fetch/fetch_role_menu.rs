@@ -0,0 +1,83 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::role_menu::{RoleMenu, RoleMenuOption};
+
+/// A row of the role_menus table, converted into a [RoleMenu] without its options
+pub(crate) struct RoleMenuRow {
+    pub id: i64,
+    pub guild_id: i64,
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub style: String,
+    pub single: bool,
+    pub required_roles: Vec<i64>,
+    pub channel_id: Option<i64>,
+    pub message_id: Option<i64>,
+}
+
+impl From<RoleMenuRow> for RoleMenu {
+    fn from(row: RoleMenuRow) -> Self {
+        Self {
+            id: row.id,
+            guild_id: Id::new(row.guild_id as u64),
+            name: row.name,
+            title: row.title,
+            description: row.description,
+            style: row.style.as_str().into(),
+            single: row.single,
+            required_roles: row.required_roles.into_iter().map(|x| Id::new(x as u64)).collect(),
+            channel_id: row.channel_id.map(|x| Id::new(x as u64)),
+            message_id: row.message_id.map(|x| Id::new(x as u64)),
+            options: vec![],
+        }
+    }
+}
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_role_menu(&self, guild_id: Id<GuildMarker>, name: &str) -> Result<Option<RoleMenu>, sqlx::Error> {
+        let menu = sqlx::query_as!(
+            RoleMenuRow,
+            "
+            SELECT id, guild_id, name, title, description, style, single, required_roles, channel_id, message_id
+            FROM role_menus
+            WHERE guild_id = $1 AND name = $2
+            ",
+            guild_id.get() as i64,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match menu {
+            Some(menu) => Ok(Some(self.role_menu_with_options(menu).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Convert a menu row into a [RoleMenu], fetching the roles it offers
+    pub(crate) async fn role_menu_with_options(&self, row: RoleMenuRow) -> Result<RoleMenu, sqlx::Error> {
+        let mut menu = RoleMenu::from(row);
+        menu.options = sqlx::query!(
+            "
+            SELECT role_id, label, emoji, description
+            FROM role_menu_options
+            WHERE menu_id = $1
+            ORDER BY position
+            ",
+            menu.id
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|option| RoleMenuOption {
+            role_id: Id::new(option.role_id as u64),
+            label: option.label,
+            emoji: option.emoji,
+            description: option.description,
+        })
+        .collect();
+
+        Ok(menu)
+    }
+}
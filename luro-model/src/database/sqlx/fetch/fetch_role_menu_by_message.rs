@@ -0,0 +1,27 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+use crate::role_menu::RoleMenu;
+
+use super::fetch_role_menu::RoleMenuRow;
+
+impl crate::database::sqlx::Database {
+    /// Fetch the menu posted as a message, used when someone picks from it
+    pub async fn fetch_role_menu_by_message(&self, message_id: Id<MessageMarker>) -> Result<Option<RoleMenu>, sqlx::Error> {
+        let menu = sqlx::query_as!(
+            RoleMenuRow,
+            "
+            SELECT id, guild_id, name, title, description, style, single, required_roles, channel_id, message_id
+            FROM role_menus
+            WHERE message_id = $1
+            ",
+            message_id.get() as i64
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match menu {
+            Some(menu) => Ok(Some(self.role_menu_with_options(menu).await?)),
+            None => Ok(None),
+        }
+    }
+}
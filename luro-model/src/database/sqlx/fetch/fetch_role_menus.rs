@@ -0,0 +1,29 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::role_menu::RoleMenu;
+
+use super::fetch_role_menu::RoleMenuRow;
+
+impl crate::database::sqlx::Database {
+    /// Fetch all of a guild's role menus, sorted by name
+    pub async fn fetch_role_menus(&self, guild_id: Id<GuildMarker>) -> Result<Vec<RoleMenu>, sqlx::Error> {
+        let rows = sqlx::query_as!(
+            RoleMenuRow,
+            "
+            SELECT id, guild_id, name, title, description, style, single, required_roles, channel_id, message_id
+            FROM role_menus
+            WHERE guild_id = $1
+            ORDER BY name
+            ",
+            guild_id.get() as i64
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut menus = Vec::with_capacity(rows.len());
+        for row in rows {
+            menus.push(self.role_menu_with_options(row).await?);
+        }
+        Ok(menus)
+    }
+}
@@ -0,0 +1,36 @@
+use twilight_model::id::Id;
+
+use crate::{
+    staff::{StaffAction, StaffAuditEntry},
+    user::UserPermissions,
+};
+
+impl crate::database::sqlx::Database {
+    /// The most recent changes to the staff, newest first
+    pub async fn fetch_staff_audit(&self, limit: i64) -> Result<Vec<StaffAuditEntry>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT actor_id, target_id, action, permissions as "permissions: UserPermissions", created_at
+            FROM staff_audit_log
+            ORDER BY created_at DESC
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(StaffAuditEntry {
+                    actor_id: Id::new_checked(row.actor_id as u64)?,
+                    target_id: Id::new_checked(row.target_id as u64)?,
+                    action: StaffAction::from_name(&row.action)?,
+                    permissions: row.permissions,
+                    created_at: row.created_at,
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,17 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::quote::Starboard;
+
+impl crate::database::sqlx::Database {
+    pub async fn fetch_starboard(&self, guild_id: Id<GuildMarker>) -> Result<Option<Starboard>, sqlx::Error> {
+        let starboard = sqlx::query!("SELECT * FROM starboards WHERE guild_id = $1", guild_id.get() as i64)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(starboard.map(|starboard| Starboard {
+            guild_id,
+            channel_id: Id::new(starboard.channel_id as u64),
+            threshold: starboard.threshold,
+        }))
+    }
+}
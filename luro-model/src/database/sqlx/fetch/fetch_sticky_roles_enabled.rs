@@ -0,0 +1,11 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// If a guild gives members back their roles when they rejoin
+    pub async fn fetch_sticky_roles_enabled(&self, guild_id: Id<GuildMarker>) -> Result<bool, sqlx::Error> {
+        sqlx::query!("SELECT guild_id FROM sticky_role_guilds WHERE guild_id = $1", guild_id.get() as i64)
+            .fetch_optional(&self.pool)
+            .await
+            .map(|row| row.is_some())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::word_stats::{LeaderboardEntry, LeaderboardKind, StatsScope};
+
+impl crate::database::sqlx::Database {
+    /// The users or channels with the most messages within the scope
+    pub async fn fetch_word_leaderboard(
+        &self,
+        scope: &StatsScope,
+        kind: LeaderboardKind,
+        limit: i64,
+    ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
+        sqlx::query!(
+            r#"
+            SELECT
+                CASE WHEN $5 THEN channel_id ELSE author_id END AS "id!",
+                COUNT(*) AS "messages!",
+                COALESCE(SUM(array_length(regexp_split_to_array(trim(content), '\s+'), 1)) FILTER (WHERE trim(content) <> ''), 0)::BIGINT AS "words!"
+            FROM messages
+            WHERE ($1::BIGINT IS NULL OR guild_id = $1)
+                AND ($2::BIGINT IS NULL OR channel_id = $2)
+                AND ($3::BIGINT IS NULL OR author_id = $3)
+                AND ($4::TIMESTAMPTZ IS NULL OR timestamp >= $4)
+            GROUP BY 1
+            ORDER BY 2 DESC, 3 DESC
+            LIMIT $6
+            "#,
+            scope.guild_id.map(|id| id.get() as i64),
+            scope.channel_id.map(|id| id.get() as i64),
+            scope.user_id.map(|id| id.get() as i64),
+            scope.since,
+            kind == LeaderboardKind::Channels,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| LeaderboardEntry {
+                    id: row.id,
+                    messages: row.messages,
+                    words: row.words,
+                })
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::word_stats::{StatsScope, WordTimelinePoint};
+
+impl crate::database::sqlx::Database {
+    /// Messages and words within the scope, grouped into periods of `bucket` such as `day`
+    pub async fn fetch_word_timeline(&self, scope: &StatsScope, bucket: &str) -> Result<Vec<WordTimelinePoint>, sqlx::Error> {
+        sqlx::query!(
+            r#"
+            SELECT
+                date_trunc($5, timestamp) AS "period!",
+                COUNT(*) AS "messages!",
+                COALESCE(SUM(array_length(regexp_split_to_array(trim(content), '\s+'), 1)) FILTER (WHERE trim(content) <> ''), 0)::BIGINT AS "words!"
+            FROM messages
+            WHERE ($1::BIGINT IS NULL OR guild_id = $1)
+                AND ($2::BIGINT IS NULL OR channel_id = $2)
+                AND ($3::BIGINT IS NULL OR author_id = $3)
+                AND ($4::TIMESTAMPTZ IS NULL OR timestamp >= $4)
+            GROUP BY 1
+            ORDER BY 1
+            "#,
+            scope.guild_id.map(|id| id.get() as i64),
+            scope.channel_id.map(|id| id.get() as i64),
+            scope.user_id.map(|id| id.get() as i64),
+            scope.since,
+            bucket,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| WordTimelinePoint {
+                    period: row.period,
+                    messages: row.messages,
+                    words: row.words,
+                })
+                .collect()
+        })
+    }
+}
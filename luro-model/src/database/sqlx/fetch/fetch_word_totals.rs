@@ -0,0 +1,38 @@
+use crate::word_stats::{StatsScope, WordTotals};
+
+impl crate::database::sqlx::Database {
+    /// Message and word totals for messages within the scope
+    pub async fn fetch_word_totals(&self, scope: &StatsScope) -> Result<WordTotals, sqlx::Error> {
+        sqlx::query!(
+            r#"
+            WITH scoped AS (
+                SELECT content FROM messages
+                WHERE ($1::BIGINT IS NULL OR guild_id = $1)
+                    AND ($2::BIGINT IS NULL OR channel_id = $2)
+                    AND ($3::BIGINT IS NULL OR author_id = $3)
+                    AND ($4::TIMESTAMPTZ IS NULL OR timestamp >= $4)
+            ),
+            words AS (
+                SELECT regexp_split_to_table(lower(regexp_replace(content, '[^\w\s]', '', 'g')), '\s+') AS word
+                FROM scoped
+            )
+            SELECT
+                (SELECT COUNT(*) FROM scoped) AS "messages!",
+                COUNT(word) FILTER (WHERE word <> '') AS "words!",
+                COUNT(DISTINCT word) FILTER (WHERE word <> '') AS "unique_words!"
+            FROM words
+            "#,
+            scope.guild_id.map(|id| id.get() as i64),
+            scope.channel_id.map(|id| id.get() as i64),
+            scope.user_id.map(|id| id.get() as i64),
+            scope.since,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map(|row| WordTotals {
+            messages: row.messages,
+            words: row.words,
+            unique_words: row.unique_words,
+        })
+    }
+}
@@ -1,3 +1,11 @@
+mod fetch_activity_heatmap;
+mod fetch_adoptions;
+mod fetch_ai_channel;
+mod fetch_ai_history;
+mod fetch_ai_opt_out;
+mod fetch_anniversaries;
+mod fetch_auto_roles;
+mod fetch_auto_roles_due;
 mod fetch_channel;
 mod fetch_character;
 mod fetch_character_fetishes;
@@ -5,14 +13,47 @@ mod fetch_character_image;
 mod fetch_character_images;
 mod fetch_character_prefix;
 mod fetch_characters;
+mod fetch_command_policies;
+mod fetch_common_words;
+mod fetch_e621_blacklist;
+mod fetch_fetish_names;
 mod fetch_fetishes;
+mod fetch_flavour_disabled;
+mod fetch_flavour_entries;
+mod fetch_flavour_entry;
 mod fetch_guild;
+mod fetch_image;
+mod fetch_image_duplicate;
+mod fetch_image_queue;
 mod fetch_images;
+mod fetch_insights;
 mod fetch_interaction;
 mod fetch_marriage;
+mod fetch_marriage_settings;
 mod fetch_marriages;
 mod fetch_member;
+mod fetch_member_retention;
 mod fetch_member_roles;
 mod fetch_message;
+mod fetch_music_queue;
+mod fetch_quote;
+mod fetch_quote_by_message;
+mod fetch_quotes;
+mod fetch_random_image;
+mod fetch_random_quote;
+mod fetch_reaction_role;
+mod fetch_reaction_roles;
+mod fetch_role_blacklist;
+mod fetch_role_menu;
+mod fetch_role_menu_by_message;
+mod fetch_role_menus;
 mod fetch_staff;
+mod fetch_staff_audit;
+mod fetch_starboard;
+mod fetch_sticky_roles_enabled;
 mod fetch_user;
+mod fetch_word_leaderboard;
+mod fetch_word_timeline;
+mod fetch_word_totals;
+
+pub(crate) use fetch_marriage::MarriageRow;
@@ -0,0 +1,21 @@
+use crate::user::Adoption;
+
+impl crate::database::sqlx::Database {
+    pub async fn update_adoption(&self, adoption: &Adoption) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO user_adoptions (parent_id, child_id, guild_id, adopted_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (parent_id, child_id)
+            DO UPDATE SET guild_id = excluded.guild_id, adopted_at = excluded.adopted_at
+            ",
+            adoption.parent_id.get() as i64,
+            adoption.child_id.get() as i64,
+            adoption.guild_id.map(|x| x.get() as i64),
+            adoption.adopted_at
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
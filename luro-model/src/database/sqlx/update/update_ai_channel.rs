@@ -0,0 +1,26 @@
+use crate::ai::AiChannel;
+
+impl crate::database::sqlx::Database {
+    pub async fn update_ai_channel(&self, channel: &AiChannel) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO ai_channels (channel_id, persona, character_owner, character_name, enabled)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (channel_id)
+            DO UPDATE SET
+                persona = $2,
+                character_owner = $3,
+                character_name = $4,
+                enabled = $5
+            ",
+            channel.channel_id.get() as i64,
+            channel.persona,
+            channel.character_owner.map(|x| x.get() as i64),
+            channel.character_name,
+            channel.enabled,
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
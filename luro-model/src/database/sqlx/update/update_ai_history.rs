@@ -0,0 +1,23 @@
+use crate::ai::AiHistoryMessage;
+
+impl crate::database::sqlx::Database {
+    pub async fn update_ai_history(&self, message: &AiHistoryMessage) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO ai_history (message_id, channel_id, author_id, assistant, content)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (message_id)
+            DO UPDATE SET
+                content = $5
+            ",
+            message.message_id.get() as i64,
+            message.channel_id.get() as i64,
+            message.author_id.get() as i64,
+            message.assistant,
+            message.content,
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
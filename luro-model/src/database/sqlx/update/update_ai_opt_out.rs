@@ -0,0 +1,29 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Opt a user in or out of having their messages used as context.
+    ///
+    /// Opting out also removes any of their messages that have already been recorded.
+    pub async fn update_ai_opt_out(&self, user_id: Id<UserMarker>, opt_out: bool) -> Result<u64, sqlx::Error> {
+        let user_id = user_id.get() as i64;
+
+        match opt_out {
+            true => {
+                let mut transaction = self.pool.begin().await?;
+                sqlx::query!("INSERT INTO ai_opt_outs (user_id) VALUES ($1) ON CONFLICT DO NOTHING", user_id)
+                    .execute(&mut *transaction)
+                    .await?;
+                let removed = sqlx::query!("DELETE FROM ai_history WHERE author_id = $1 AND assistant = false", user_id)
+                    .execute(&mut *transaction)
+                    .await?
+                    .rows_affected();
+                transaction.commit().await?;
+                Ok(removed)
+            }
+            false => sqlx::query!("DELETE FROM ai_opt_outs WHERE user_id = $1", user_id)
+                .execute(&self.pool)
+                .await
+                .map(|x| x.rows_affected()),
+        }
+    }
+}
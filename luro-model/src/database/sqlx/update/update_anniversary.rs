@@ -0,0 +1,21 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Record that a couple's anniversary has been posted for this year
+    pub async fn update_anniversary(
+        &self,
+        proposer_id: Id<UserMarker>,
+        proposee_id: Id<UserMarker>,
+        year: i32,
+    ) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "UPDATE user_marriages SET anniversary_year = $3 WHERE proposer_id = $1 AND proposee_id = $2",
+            proposer_id.get() as i64,
+            proposee_id.get() as i64,
+            year
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
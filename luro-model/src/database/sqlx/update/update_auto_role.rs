@@ -0,0 +1,20 @@
+use crate::auto_role::AutoRole;
+
+impl crate::database::sqlx::Database {
+    /// Give a role to members when they join, replacing the settings if it already is
+    pub async fn update_auto_role(&self, auto_role: &AutoRole) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO auto_roles (guild_id, role_id, delay_seconds, after_screening) VALUES ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, role_id) DO UPDATE SET delay_seconds = $3, after_screening = $4
+            ",
+            auto_role.guild_id.get() as i64,
+            auto_role.role_id.get() as i64,
+            auto_role.delay_seconds,
+            auto_role.after_screening
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,30 @@
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Queue an auto role for a member. Without `apply_at`, the role waits until the member passes screening.
+    pub async fn update_auto_role_pending(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_id: Id<RoleMarker>,
+        apply_at: Option<OffsetDateTime>,
+    ) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO auto_roles_pending (guild_id, user_id, role_id, apply_at) VALUES ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, user_id, role_id) DO UPDATE SET apply_at = $4
+            ",
+            guild_id.get() as i64,
+            user_id.get() as i64,
+            role_id.get() as i64,
+            apply_at
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
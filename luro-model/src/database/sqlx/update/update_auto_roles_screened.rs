@@ -0,0 +1,27 @@
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// A member passed screening, so start the delay on any auto roles waiting for it
+    pub async fn update_auto_roles_screened(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            UPDATE auto_roles_pending
+            SET apply_at = NOW() + auto_roles.delay_seconds * INTERVAL '1 second'
+            FROM auto_roles
+            WHERE auto_roles_pending.guild_id = $1
+                AND auto_roles_pending.user_id = $2
+                AND auto_roles_pending.apply_at IS NULL
+                AND auto_roles.guild_id = auto_roles_pending.guild_id
+                AND auto_roles.role_id = auto_roles_pending.role_id
+            ",
+            guild_id.get() as i64,
+            user_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::command_policy::CommandPolicy;
+
+impl crate::database::sqlx::Database {
+    /// Create or replace a command policy
+    pub async fn update_command_policy(&self, policy: &CommandPolicy) -> Result<u64, sqlx::Error> {
+        let (scope, target_id) = policy.scope.as_parts();
+
+        sqlx::query!(
+            "
+            INSERT INTO command_policies (guild_id, command, scope, target_id, enabled)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, command, scope, target_id)
+            DO UPDATE SET enabled = excluded.enabled
+            ",
+            policy.guild_id.get() as i64,
+            policy.command,
+            scope,
+            target_id,
+            policy.enabled
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,15 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Replace a user's own e621 blacklist
+    pub async fn update_e621_blacklist(&self, user_id: Id<UserMarker>, blacklist: &str) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO e621_blacklists (user_id, blacklist) VALUES ($1, $2) ON CONFLICT (user_id) DO UPDATE SET blacklist = $2",
+            user_id.get() as i64,
+            blacklist
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,20 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Disable a built in entry of a pool for a guild
+    pub async fn update_flavour_disabled(&self, guild_id: Id<GuildMarker>, pool: &str, content: &str) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO flavour_disabled (guild_id, pool, content)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING
+            ",
+            guild_id.get() as i64,
+            pool,
+            content
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::flavour::FlavourEntry;
+
+impl crate::database::sqlx::Database {
+    pub async fn update_flavour_entry(&self, entry: &FlavourEntry) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "UPDATE flavour_entries SET content = $2, disabled = $3 WHERE id = $1",
+            entry.id,
+            entry.content,
+            entry.disabled
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,21 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Set a user's vote on an entry, replacing any earlier vote. An upvote is `1` and a downvote is `-1`.
+    pub async fn update_flavour_vote(&self, entry_id: i64, user_id: Id<UserMarker>, vote: i16) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO flavour_votes (entry_id, user_id, vote)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (entry_id, user_id)
+            DO UPDATE SET vote = excluded.vote
+            ",
+            entry_id,
+            user_id.get() as i64,
+            vote
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::image::Image;
+
+impl crate::database::sqlx::Database {
+    /// Update an image's details and review status
+    pub async fn update_image(&self, image: &Image) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            UPDATE images
+            SET name = $2, nsfw = $3, source = $4, artist = $5, tags = $6, status = $7, reviewed_by = $8
+            WHERE img_id = $1
+            ",
+            image.img_id,
+            image.name,
+            image.nsfw,
+            image.source,
+            image.artist,
+            &image.tags,
+            image.status.as_str(),
+            image.reviewed_by
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,50 @@
+impl crate::database::sqlx::Database {
+    /// Write the daily snapshots of every guild for yesterday and today. Member counts are only known at the time this runs,
+    /// so yesterday keeps the count from its last snapshot.
+    pub async fn update_insights(&self) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO guild_insights (guild_id, day, members, joins, leaves, messages, active_members)
+            SELECT
+                guilds.guild_id,
+                days.day,
+                (
+                    SELECT COUNT(*) FROM guild_members
+                    WHERE guild_members.guild_id = guilds.guild_id
+                        AND (guild_members.left_at IS NULL OR guild_members.left_at < guild_members.joined_at)
+                ),
+                (
+                    SELECT COUNT(*) FROM guild_member_events
+                    WHERE guild_member_events.guild_id = guilds.guild_id AND kind = 'join'
+                        AND happened_at >= days.day AND happened_at < days.day + 1
+                ),
+                (
+                    SELECT COUNT(*) FROM guild_member_events
+                    WHERE guild_member_events.guild_id = guilds.guild_id AND kind = 'leave'
+                        AND happened_at >= days.day AND happened_at < days.day + 1
+                ),
+                (
+                    SELECT COUNT(*) FROM messages
+                    WHERE messages.guild_id = guilds.guild_id
+                        AND timestamp >= days.day AND timestamp < days.day + 1
+                ),
+                (
+                    SELECT COUNT(DISTINCT author_id) FROM messages
+                    WHERE messages.guild_id = guilds.guild_id
+                        AND timestamp >= days.day AND timestamp < days.day + 1
+                )
+            FROM guilds
+            CROSS JOIN (VALUES (CURRENT_DATE - 1), (CURRENT_DATE)) AS days (day)
+            ON CONFLICT (guild_id, day) DO UPDATE SET
+                members = CASE WHEN EXCLUDED.day = CURRENT_DATE THEN EXCLUDED.members ELSE guild_insights.members END,
+                joins = EXCLUDED.joins,
+                leaves = EXCLUDED.leaves,
+                messages = EXCLUDED.messages,
+                active_members = EXCLUDED.active_members
+            "
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
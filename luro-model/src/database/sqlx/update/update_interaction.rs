@@ -52,7 +52,7 @@ impl crate::database::sqlx::Database {
             interaction.locale,
             interaction.message.as_ref().map(|x| x.id.get() as i64),
             interaction.token,
-            interaction.author_id().unwrap().get() as i64,
+            interaction.author_id().ok_or(Error::MissingField("author"))?.get() as i64,
         )
         .execute(&self.pool)
         .await?
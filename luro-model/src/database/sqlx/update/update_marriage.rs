@@ -1,17 +1,33 @@
 use crate::user::Marriage;
 
 impl crate::database::sqlx::Database {
-    pub async fn marriage_update(&self, marriage: Marriage) -> Result<u64, sqlx::Error> {
-        sqlx::query_file!(
+    /// Save a marriage. Any record of the pair the other way around is replaced, so each couple only has one marriage.
+    pub async fn update_marriage(&self, marriage: &Marriage) -> Result<u64, sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query!(
+            "DELETE FROM user_marriages WHERE proposer_id = $1 AND proposee_id = $2",
+            marriage.proposee_id.get() as i64,
+            marriage.proposer_id.get() as i64,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        let rows = sqlx::query_file!(
             "queries/marriage/marriage_update.sql",
             marriage.divorced,
-            marriage.proposee_id.min(marriage.proposer_id).get() as i64,
-            marriage.proposee_id.max(marriage.proposer_id).get() as i64,
+            marriage.proposer_id.get() as i64,
+            marriage.proposee_id.get() as i64,
             marriage.reason,
             marriage.rejected,
+            marriage.guild_id.map(|x| x.get() as i64),
+            marriage.channel_id.map(|x| x.get() as i64),
+            marriage.married_at,
         )
-        .execute(&self.pool)
-        .await
-        .map(|x| x.rows_affected())
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+
+        transaction.commit().await?;
+        Ok(rows)
     }
 }
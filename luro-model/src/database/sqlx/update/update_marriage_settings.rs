@@ -0,0 +1,20 @@
+use crate::user::MarriageSettings;
+
+impl crate::database::sqlx::Database {
+    pub async fn update_marriage_settings(&self, settings: &MarriageSettings) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO marriage_settings (guild_id, partner_limit, anniversary_channel_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id)
+            DO UPDATE SET partner_limit = excluded.partner_limit, anniversary_channel_id = excluded.anniversary_channel_id
+            ",
+            settings.guild_id.get() as i64,
+            settings.partner_limit,
+            settings.anniversary_channel_id.map(|x| x.get() as i64)
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
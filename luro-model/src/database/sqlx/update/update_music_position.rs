@@ -0,0 +1,15 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Record how far into the current track playback is, without touching the rest of the queue
+    pub async fn update_music_position(&self, guild_id: Id<GuildMarker>, position: i64) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "UPDATE music_queues SET position = $2 WHERE guild_id = $1",
+            guild_id.get() as i64,
+            position
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
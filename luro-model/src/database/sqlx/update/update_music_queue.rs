@@ -0,0 +1,63 @@
+use crate::music::MusicQueue;
+
+impl crate::database::sqlx::Database {
+    /// Save a guild's queue, replacing all tracks that were previously saved
+    pub async fn update_music_queue(&self, queue: &MusicQueue) -> Result<u64, sqlx::Error> {
+        let guild_id = queue.guild_id.get() as i64;
+        let mut transaction = self.pool.begin().await?;
+
+        let mut rows_affected = sqlx::query!(
+            "
+            INSERT INTO music_queues (guild_id, voice_channel_id, text_channel_id, now_playing_message_id, loop_mode, volume, paused, position)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (guild_id)
+            DO UPDATE SET
+                voice_channel_id = $2,
+                text_channel_id = $3,
+                now_playing_message_id = $4,
+                loop_mode = $5,
+                volume = $6,
+                paused = $7,
+                position = $8
+            ",
+            guild_id,
+            queue.voice_channel_id.map(|x| x.get() as i64),
+            queue.text_channel_id.map(|x| x.get() as i64),
+            queue.now_playing_message_id.map(|x| x.get() as i64),
+            queue.loop_mode.as_str(),
+            queue.volume,
+            queue.paused,
+            queue.position,
+        )
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+
+        sqlx::query!("DELETE FROM music_tracks WHERE guild_id = $1", guild_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        for (index, track) in queue.tracks.iter().enumerate() {
+            rows_affected += sqlx::query!(
+                "
+                INSERT INTO music_tracks (guild_id, track_index, encoded, title, author, uri, length, requested_by)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ",
+                guild_id,
+                index as i32,
+                track.encoded,
+                track.title,
+                track.author,
+                track.uri,
+                track.length,
+                track.requested_by.get() as i64,
+            )
+            .execute(&mut *transaction)
+            .await?
+            .rows_affected();
+        }
+
+        transaction.commit().await?;
+        Ok(rows_affected)
+    }
+}
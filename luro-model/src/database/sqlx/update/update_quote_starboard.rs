@@ -0,0 +1,15 @@
+use twilight_model::id::{marker::MessageMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Record the starboard repost of a quote, unless it already has one. Returns 0 if another repost was recorded first.
+    pub async fn update_quote_starboard(&self, quote_id: i64, starboard_message_id: Id<MessageMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "UPDATE quotes SET starboard_message_id = $2 WHERE id = $1 AND starboard_message_id IS NULL",
+            quote_id,
+            starboard_message_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
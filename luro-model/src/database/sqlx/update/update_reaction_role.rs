@@ -0,0 +1,21 @@
+use crate::auto_role::ReactionRole;
+
+impl crate::database::sqlx::Database {
+    /// Bind an emoji on a message to a role, replacing any role it was already bound to
+    pub async fn update_reaction_role(&self, reaction_role: &ReactionRole) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO reaction_roles (guild_id, channel_id, message_id, emoji, role_id) VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (message_id, emoji) DO UPDATE SET role_id = $5
+            ",
+            reaction_role.guild_id.get() as i64,
+            reaction_role.channel_id.get() as i64,
+            reaction_role.message_id.get() as i64,
+            reaction_role.emoji,
+            reaction_role.role_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
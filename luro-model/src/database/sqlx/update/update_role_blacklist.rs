@@ -0,0 +1,18 @@
+use twilight_model::id::{
+    marker::{GuildMarker, RoleMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Stop members from giving themselves a role
+    pub async fn update_role_blacklist(&self, guild_id: Id<GuildMarker>, role_id: Id<RoleMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO guild_role_blacklist (guild_id, role_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+            guild_id.get() as i64,
+            role_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
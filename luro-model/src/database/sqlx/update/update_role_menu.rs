@@ -0,0 +1,60 @@
+use crate::role_menu::RoleMenu;
+
+impl crate::database::sqlx::Database {
+    /// Save a menu and the roles it offers, returning the menu ID. Menus are matched by guild and name.
+    pub async fn update_role_menu(&self, menu: &RoleMenu) -> Result<i64, sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        let menu_id = sqlx::query!(
+            "
+            INSERT INTO role_menus (guild_id, name, title, description, style, single, required_roles, channel_id, message_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (guild_id, name)
+            DO UPDATE SET
+                title = excluded.title,
+                description = excluded.description,
+                style = excluded.style,
+                single = excluded.single,
+                required_roles = excluded.required_roles,
+                channel_id = excluded.channel_id,
+                message_id = excluded.message_id
+            RETURNING id
+            ",
+            menu.guild_id.get() as i64,
+            menu.name,
+            menu.title,
+            menu.description,
+            menu.style.as_str(),
+            menu.single,
+            &menu.required_roles.iter().map(|x| x.get() as i64).collect::<Vec<_>>(),
+            menu.channel_id.map(|x| x.get() as i64),
+            menu.message_id.map(|x| x.get() as i64),
+        )
+        .fetch_one(&mut *transaction)
+        .await?
+        .id;
+
+        sqlx::query!("DELETE FROM role_menu_options WHERE menu_id = $1", menu_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        for (position, option) in menu.options.iter().enumerate() {
+            sqlx::query!(
+                "
+                INSERT INTO role_menu_options (menu_id, role_id, position, label, emoji, description)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ",
+                menu_id,
+                option.role_id.get() as i64,
+                position as i32,
+                option.label,
+                option.emoji,
+                option.description,
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(menu_id)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::quote::Starboard;
+
+impl crate::database::sqlx::Database {
+    pub async fn update_starboard(&self, starboard: &Starboard) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO starboards (guild_id, channel_id, threshold)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id)
+            DO UPDATE SET channel_id = excluded.channel_id, threshold = excluded.threshold
+            ",
+            starboard.guild_id.get() as i64,
+            starboard.channel_id.get() as i64,
+            starboard.threshold
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+impl crate::database::sqlx::Database {
+    /// Remember the roles a member held when they left, copied from their synced roles
+    pub async fn update_sticky_roles(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Result<u64, sqlx::Error> {
+        sqlx::query!(
+            "
+            INSERT INTO sticky_roles (guild_id, user_id, role_id)
+            SELECT guild_id, user_id, role_id FROM guild_member_roles
+            WHERE guild_id = $1 AND user_id = $2 AND role_id != guild_id
+            ON CONFLICT DO NOTHING
+            ",
+            guild_id.get() as i64,
+            user_id.get() as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,30 @@
+use twilight_model::id::{marker::GuildMarker, Id};
+
+impl crate::database::sqlx::Database {
+    /// Turn sticky roles on or off for a guild. Turning them off forgets the roles of members who have left.
+    pub async fn update_sticky_roles_enabled(&self, guild_id: Id<GuildMarker>, enabled: bool) -> Result<u64, sqlx::Error> {
+        let guild_id = guild_id.get() as i64;
+        match enabled {
+            true => sqlx::query!(
+                "INSERT INTO sticky_role_guilds (guild_id) VALUES ($1) ON CONFLICT DO NOTHING",
+                guild_id
+            )
+            .execute(&self.pool)
+            .await
+            .map(|x| x.rows_affected()),
+            false => {
+                let mut transaction = self.pool.begin().await?;
+                let mut rows_modified = sqlx::query!("DELETE FROM sticky_roles WHERE guild_id = $1", guild_id)
+                    .execute(&mut *transaction)
+                    .await?
+                    .rows_affected();
+                rows_modified += sqlx::query!("DELETE FROM sticky_role_guilds WHERE guild_id = $1", guild_id)
+                    .execute(&mut *transaction)
+                    .await?
+                    .rows_affected();
+                transaction.commit().await?;
+                Ok(rows_modified)
+            }
+        }
+    }
+}
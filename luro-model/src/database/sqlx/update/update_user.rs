@@ -217,7 +217,7 @@ async fn twilight_member(db: &Database, guild_id: Id<GuildMarker>, member: &Memb
         },
         member.deaf,
         guild_id.get() as i64,
-        OffsetDateTime::from_unix_timestamp(member.joined_at.unwrap().as_secs())?,
+        OffsetDateTime::from_unix_timestamp(member.joined_at.ok_or(Error::MissingField("joined_at"))?.as_secs())?,
         member.avatar.map(|x| x.to_string()),
         member.flags.bits() as i64,
         member.mute,
@@ -277,12 +277,12 @@ async fn handle_partial_member(db: &Database, guild_id: Id<GuildMarker>, member:
         },
         member.deaf,
         guild_id.get() as i64,
-        OffsetDateTime::from_unix_timestamp(member.joined_at.unwrap().as_secs())?,
+        OffsetDateTime::from_unix_timestamp(member.joined_at.ok_or(Error::MissingField("joined_at"))?.as_secs())?,
         member.avatar.map(|x| x.to_string()),
         member.flags.bits() as i64,
         member.mute,
         member.nick,
-        member.user.as_ref().map(|x| x.id.get() as i64).unwrap()
+        member.user.as_ref().map(|x| x.id.get() as i64).ok_or(Error::MissingField("user"))?
     )
     .execute(&db.pool)
     .await?
@@ -307,7 +307,7 @@ async fn handle_member_add(db: &Database, member: &MemberAdd) -> Result<u64, Err
         },
         member.deaf,
         member.guild_id.get() as i64,
-        OffsetDateTime::from_unix_timestamp(member.joined_at.unwrap().as_secs())?,
+        OffsetDateTime::from_unix_timestamp(member.joined_at.ok_or(Error::MissingField("joined_at"))?.as_secs())?,
         member.avatar.map(|x| x.to_string()),
         member.flags.bits() as i64,
         member.mute,
@@ -348,7 +348,7 @@ async fn handle_member_update(db: &Database, member: &MemberUpdate) -> Result<u6
             None => None,
         },
         member.guild_id.get() as i64,
-        OffsetDateTime::from_unix_timestamp(member.joined_at.unwrap().as_secs())?,
+        OffsetDateTime::from_unix_timestamp(member.joined_at.ok_or(Error::MissingField("joined_at"))?.as_secs())?,
         member.avatar.map(|x| x.to_string()),
         member.nick,
         member.pending,
@@ -0,0 +1,17 @@
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::user::UserPermissions;
+
+impl crate::database::sqlx::Database {
+    /// Set the permissions of a user, such as making them staff
+    pub async fn update_user_permissions(&self, user_id: Id<UserMarker>, permissions: UserPermissions) -> Result<u64, sqlx::Error> {
+        sqlx::query_file!(
+            "queries/user/user_update_user_permissions.sql",
+            user_id.get() as i64,
+            permissions as _
+        )
+        .execute(&self.pool)
+        .await
+        .map(|x| x.rows_affected())
+    }
+}
@@ -0,0 +1,503 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use futures_util::{FutureExt, StreamExt};
+use tokio::sync::Notify;
+use twilight_gateway::Event;
+use twilight_model::{
+    gateway::presence::UserOrId,
+    id::{
+        marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::database_sync::SyncConfig;
+
+use super::sqlx::MAX_CONNECTIONS;
+
+/// A bounded queue of gateway events waiting to be written to the database.
+///
+/// Events that only carry the latest state of something, such as a member update, replace the same kind of event
+/// for the same thing if it is still waiting. The replacement moves to the back of the queue, so it is still
+/// written after any event that was queued between the two.
+///
+/// Events for the same guild are written in the order they were queued, as are events outside of a guild for the
+/// same channel or user. Only events for different guilds are written at the same time.
+#[derive(Debug)]
+pub struct SyncQueue {
+    pending: Mutex<Pending>,
+    /// Woken when an event is queued
+    queued: Notify,
+    capacity: usize,
+    batch_size: usize,
+    connections: usize,
+    report_interval: Duration,
+    pub metrics: SyncMetrics,
+}
+
+/// What an event writes to, so events for the same thing are written in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum OrderKey {
+    Guild(Id<GuildMarker>),
+    Channel(Id<ChannelMarker>),
+    User(Id<UserMarker>),
+    /// Events that write to many guilds, such as ready
+    Global,
+}
+
+/// What an event writes, so a newer event can replace an older one still waiting in the queue
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum SyncKey {
+    Channel(Id<ChannelMarker>),
+    Guild(Id<GuildMarker>),
+    Member(Id<GuildMarker>, Id<UserMarker>),
+    Presence(Id<UserMarker>),
+    Role(Id<RoleMarker>),
+    User(Id<UserMarker>),
+    /// Events that are always written, such as creations, deletions and member chunks
+    Unique(u64),
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    next_sequence: u64,
+    events: HashMap<SyncKey, (u64, Event)>,
+    /// The order to write events in. Entries whose sequence no longer matches the event were replaced, and are skipped
+    order: VecDeque<(u64, SyncKey)>,
+}
+
+impl Pending {
+    /// If there is space for an event, either as a new event or by replacing one
+    fn has_space(&self, key: Option<SyncKey>, capacity: usize) -> bool {
+        self.events.len() < capacity || key.is_some_and(|key| self.events.contains_key(&key))
+    }
+
+    /// Queue an event, returning true if it replaced one
+    fn insert(&mut self, key: Option<SyncKey>, event: Event, capacity: usize) -> bool {
+        let sequence = self.next_sequence;
+        let key = key.unwrap_or(SyncKey::Unique(sequence));
+        let replaced = self.events.contains_key(&key);
+
+        self.next_sequence += 1;
+        self.events.insert(key, (sequence, event));
+        self.order.push_back((sequence, key));
+
+        // Drop the entries of replaced events, so a storm of updates can't grow the order without bound
+        if self.order.len() > capacity * 2 {
+            let events = &self.events;
+            self.order
+                .retain(|(sequence, key)| events.get(key).is_some_and(|(current, _)| current == sequence));
+        }
+
+        replaced
+    }
+
+    fn take(&mut self, amount: usize) -> Vec<Event> {
+        let mut batch = Vec::with_capacity(amount.min(self.events.len()));
+        while batch.len() < amount {
+            let Some((sequence, key)) = self.order.pop_front() else {
+                break;
+            };
+
+            if self.events.get(&key).is_some_and(|(current, _)| *current == sequence) {
+                batch.extend(self.events.remove(&key).map(|(_, event)| event));
+            }
+        }
+        batch
+    }
+}
+
+impl SyncQueue {
+    pub fn new(config: &SyncConfig) -> Self {
+        Self {
+            pending: Default::default(),
+            queued: Notify::new(),
+            capacity: config.queue_size.max(1),
+            batch_size: config.batch_size.max(1),
+            connections: config.connections.clamp(1, MAX_CONNECTIONS as usize - 1),
+            report_interval: config.report_interval(),
+            metrics: Default::default(),
+        }
+    }
+
+    /// Queue an event to be written, if it holds data that is synced. Never waits, so it is safe to call from a shard's event loop.
+    ///
+    /// A full queue still takes events that replace one already waiting. Other events are dropped and counted,
+    /// as the database is falling behind.
+    pub fn push(&self, event: &Event) {
+        if !is_synced(event) {
+            return;
+        }
+
+        let key = sync_key(event);
+        let replaced = {
+            let mut pending = self.pending.lock().unwrap();
+            pending
+                .has_space(key, self.capacity)
+                .then(|| pending.insert(key, event.clone(), self.capacity))
+        };
+
+        match replaced {
+            Some(true) => self.metrics.coalesced.fetch_add(1, Ordering::Relaxed),
+            Some(false) => self.metrics.queued.fetch_add(1, Ordering::Relaxed),
+            None => {
+                let dropped = self.metrics.dropped.fetch_add(1, Ordering::Relaxed);
+                // Presence updates fill the queue quickest, and are not worth a warning each
+                match key {
+                    Some(SyncKey::Presence(_)) => tracing::trace!("DATABASE: Sync queue is full, dropped a presence update"),
+                    _ if dropped % 1000 == 0 => tracing::warn!(
+                        "DATABASE: Sync queue is full, dropped {:?} ({} dropped so far)",
+                        event.kind(),
+                        dropped + 1
+                    ),
+                    _ => tracing::debug!("DATABASE: Sync queue is full, dropped {:?}", event.kind()),
+                }
+                return;
+            }
+        };
+
+        self.queued.notify_one();
+    }
+
+    /// The number of events waiting to be written
+    pub fn len(&self) -> usize {
+        self.pending.lock().unwrap().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn stats(&self) -> SyncStats {
+        self.metrics.stats(self.len())
+    }
+
+    fn take_batch(&self) -> Vec<Event> {
+        self.pending.lock().unwrap().take(self.batch_size)
+    }
+}
+
+impl super::Database {
+    /// Queue an event to be written to the database by [`Self::sync_runner`].
+    ///
+    /// Never waits. If the queue is full, which means the database is falling behind, the event is dropped.
+    pub fn queue_sync(&self, event: &Event) {
+        self.sync_queue.push(event)
+    }
+
+    /// Write queued events to the database, forever. This should be spawned as its own task.
+    ///
+    /// Events are taken in batches, and each batch is written using up to the configured number of connections.
+    /// A panic while writing an event is caught and counted as a failure, so the runner keeps going.
+    pub async fn sync_runner(&self) {
+        let queue = &self.sync_queue;
+        let mut last_report = Instant::now();
+
+        loop {
+            if last_report.elapsed() >= queue.report_interval {
+                tracing::info!("DATABASE: Sync queue - {}", queue.stats());
+                last_report = Instant::now();
+            }
+
+            let batch = queue.take_batch();
+            if batch.is_empty() {
+                let report_in = queue.report_interval.saturating_sub(last_report.elapsed());
+                let _ = tokio::time::timeout(report_in, queue.queued.notified()).await;
+                continue;
+            }
+
+            let started = Instant::now();
+            futures_util::stream::iter(lanes(batch))
+                .for_each_concurrent(queue.connections, |lane| async move {
+                    for event in lane {
+                        let write = std::panic::AssertUnwindSafe(self.sqlx_driver.sync_gateway(&event));
+                        match write.catch_unwind().await {
+                            Ok(Ok(rows)) => {
+                                queue.metrics.written.fetch_add(1, Ordering::Relaxed);
+                                queue.metrics.rows.fetch_add(rows, Ordering::Relaxed);
+                            }
+                            Ok(Err(why)) => {
+                                queue.metrics.failed.fetch_add(1, Ordering::Relaxed);
+                                tracing::warn!(?why, "DATABASE: Failed to sync {:?}", event.kind());
+                            }
+                            Err(_) => {
+                                queue.metrics.failed.fetch_add(1, Ordering::Relaxed);
+                                tracing::error!("DATABASE: Panicked while syncing {:?}", event.kind());
+                            }
+                        }
+                    }
+                })
+                .await;
+
+            queue.metrics.batches.fetch_add(1, Ordering::Relaxed);
+            tracing::debug!(
+                "DATABASE: Synced a batch in {:?}, {} events waiting",
+                started.elapsed(),
+                queue.len()
+            );
+        }
+    }
+}
+
+/// Counters for the sync queue, since the bot started
+#[derive(Debug, Default)]
+pub struct SyncMetrics {
+    /// Events added to the queue
+    pub queued: AtomicU64,
+    /// Events that replaced an older event still in the queue
+    pub coalesced: AtomicU64,
+    /// Events dropped because the queue was full
+    pub dropped: AtomicU64,
+    /// Events written to the database
+    pub written: AtomicU64,
+    /// Events that failed to write
+    pub failed: AtomicU64,
+    /// Rows changed by the written events
+    pub rows: AtomicU64,
+    /// Batches taken from the queue
+    pub batches: AtomicU64,
+}
+
+impl SyncMetrics {
+    pub fn stats(&self, pending: usize) -> SyncStats {
+        SyncStats {
+            pending,
+            queued: self.queued.load(Ordering::Relaxed),
+            coalesced: self.coalesced.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            written: self.written.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            rows: self.rows.load(Ordering::Relaxed),
+            batches: self.batches.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A snapshot of [`SyncMetrics`], along with how many events are waiting
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SyncStats {
+    pub pending: usize,
+    pub queued: u64,
+    pub coalesced: u64,
+    pub dropped: u64,
+    pub written: u64,
+    pub failed: u64,
+    pub rows: u64,
+    pub batches: u64,
+}
+
+impl Display for SyncStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} waiting, {} queued, {} merged, {} dropped, {} written ({} rows) in {} batches, {} failed",
+            self.pending, self.queued, self.coalesced, self.dropped, self.written, self.rows, self.batches, self.failed
+        )
+    }
+}
+
+/// Events handled by [`super::sqlx::Database::sync_gateway`]
+fn is_synced(event: &Event) -> bool {
+    match event {
+        Event::PresenceUpdate(event) => matches!(event.user, UserOrId::User(_)),
+        event => matches!(
+            event,
+            Event::ChannelCreate(_)
+                | Event::ChannelDelete(_)
+                | Event::ChannelPinsUpdate(_)
+                | Event::ChannelUpdate(_)
+                | Event::GuildCreate(_)
+                | Event::GuildUpdate(_)
+                | Event::InteractionCreate(_)
+                | Event::MemberAdd(_)
+                | Event::MemberChunk(_)
+                | Event::MemberRemove(_)
+                | Event::MemberUpdate(_)
+                | Event::MessageCreate(_)
+                | Event::MessageDelete(_)
+                | Event::MessageDeleteBulk(_)
+                | Event::MessageUpdate(_)
+                | Event::Ready(_)
+                | Event::RoleCreate(_)
+                | Event::RoleDelete(_)
+                | Event::RoleUpdate(_)
+                | Event::UserUpdate(_)
+        ),
+    }
+}
+
+/// Split a batch into lanes that can be written at the same time. Events in a lane keep the order they were queued in
+fn lanes(batch: Vec<Event>) -> Vec<Vec<Event>> {
+    let mut lanes: Vec<(OrderKey, Vec<Event>)> = vec![];
+    for event in batch {
+        let key = order_key(&event);
+        match lanes.iter_mut().find(|(lane, _)| *lane == key) {
+            Some((_, events)) => events.push(event),
+            None => lanes.push((key, vec![event])),
+        }
+    }
+    lanes.into_iter().map(|(_, events)| events).collect()
+}
+
+/// What an event writes to, for keeping events in order
+fn order_key(event: &Event) -> OrderKey {
+    let guild_id = match event {
+        Event::ChannelCreate(event) => event.guild_id,
+        Event::ChannelDelete(event) => event.guild_id,
+        Event::ChannelPinsUpdate(event) => event.guild_id,
+        Event::ChannelUpdate(event) => event.guild_id,
+        Event::GuildCreate(event) => Some(event.id()),
+        Event::GuildUpdate(event) => Some(event.id),
+        Event::InteractionCreate(event) => event.guild_id,
+        Event::MemberAdd(event) => Some(event.guild_id),
+        Event::MemberChunk(event) => Some(event.guild_id),
+        Event::MemberRemove(event) => Some(event.guild_id),
+        Event::MemberUpdate(event) => Some(event.guild_id),
+        Event::MessageCreate(event) => event.guild_id,
+        Event::MessageDelete(event) => event.guild_id,
+        Event::MessageDeleteBulk(event) => event.guild_id,
+        Event::MessageUpdate(event) => event.guild_id,
+        Event::PresenceUpdate(event) => Some(event.guild_id),
+        Event::RoleCreate(event) => Some(event.guild_id),
+        Event::RoleDelete(event) => Some(event.guild_id),
+        Event::RoleUpdate(event) => Some(event.guild_id),
+        _ => None,
+    };
+
+    if let Some(guild_id) = guild_id {
+        return OrderKey::Guild(guild_id);
+    }
+
+    match event {
+        Event::ChannelCreate(event) => OrderKey::Channel(event.id),
+        Event::ChannelDelete(event) => OrderKey::Channel(event.id),
+        Event::ChannelPinsUpdate(event) => OrderKey::Channel(event.channel_id),
+        Event::ChannelUpdate(event) => OrderKey::Channel(event.id),
+        Event::MessageCreate(event) => OrderKey::Channel(event.channel_id),
+        Event::MessageDelete(event) => OrderKey::Channel(event.channel_id),
+        Event::MessageDeleteBulk(event) => OrderKey::Channel(event.channel_id),
+        Event::MessageUpdate(event) => OrderKey::Channel(event.channel_id),
+        Event::InteractionCreate(event) => event.author_id().map(OrderKey::User).unwrap_or(OrderKey::Global),
+        Event::UserUpdate(event) => OrderKey::User(event.id),
+        _ => OrderKey::Global,
+    }
+}
+
+/// The key of events that only carry the latest state, which can replace each other
+fn sync_key(event: &Event) -> Option<SyncKey> {
+    match event {
+        Event::ChannelUpdate(event) => Some(SyncKey::Channel(event.id)),
+        Event::GuildUpdate(event) => Some(SyncKey::Guild(event.id)),
+        Event::MemberUpdate(event) => Some(SyncKey::Member(event.guild_id, event.user.id)),
+        Event::PresenceUpdate(event) => Some(SyncKey::Presence(event.user.id())),
+        Event::RoleUpdate(event) => Some(SyncKey::Role(event.role.id)),
+        Event::UserUpdate(event) => Some(SyncKey::User(event.id)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::{gateway::payload::incoming::RoleDelete, id::Id};
+
+    use super::*;
+
+    fn event(guild_id: u64, role_id: u64) -> Event {
+        Event::RoleDelete(RoleDelete {
+            guild_id: Id::new(guild_id),
+            role_id: Id::new(role_id),
+        })
+    }
+
+    fn role_ids(events: &[Event]) -> Vec<u64> {
+        events
+            .iter()
+            .map(|event| match event {
+                Event::RoleDelete(event) => event.role_id.get(),
+                event => panic!("unexpected event {event:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn replacements_move_to_the_back() {
+        let mut pending = Pending::default();
+        let key = Some(SyncKey::Role(Id::new(1)));
+
+        assert!(!pending.insert(key, event(1, 1), 10));
+        assert!(!pending.insert(None, event(1, 2), 10));
+        assert!(pending.insert(key, event(1, 3), 10));
+
+        assert_eq!(pending.events.len(), 2);
+        assert_eq!(role_ids(&pending.take(10)), [2, 3]);
+        assert!(pending.events.is_empty());
+    }
+
+    #[test]
+    fn full_queue_only_has_space_for_replacements() {
+        let mut pending = Pending::default();
+        let key = Some(SyncKey::Role(Id::new(1)));
+
+        pending.insert(key, event(1, 1), 1);
+
+        assert!(pending.has_space(key, 1));
+        assert!(!pending.has_space(Some(SyncKey::Role(Id::new(2))), 1));
+        assert!(!pending.has_space(None, 1));
+    }
+
+    #[test]
+    fn replacements_do_not_grow_the_order() {
+        let mut pending = Pending::default();
+        let key = Some(SyncKey::Role(Id::new(1)));
+
+        for role_id in 1..=100 {
+            pending.insert(key, event(1, role_id), 4);
+        }
+
+        assert!(pending.order.len() <= 8);
+        assert_eq!(role_ids(&pending.take(10)), [100]);
+    }
+
+    #[test]
+    fn batches_are_taken_in_order() {
+        let mut pending = Pending::default();
+        for role_id in 1..=5 {
+            pending.insert(None, event(1, role_id), 10);
+        }
+
+        assert_eq!(role_ids(&pending.take(3)), [1, 2, 3]);
+        assert_eq!(role_ids(&pending.take(3)), [4, 5]);
+    }
+
+    #[test]
+    fn full_queue_drops_instead_of_waiting() {
+        let queue = SyncQueue::new(&SyncConfig {
+            queue_size: 1,
+            ..Default::default()
+        });
+
+        queue.push(&event(1, 1));
+        queue.push(&event(1, 2));
+
+        let stats = queue.stats();
+        assert_eq!((stats.pending, stats.queued, stats.dropped), (1, 1, 1));
+    }
+
+    #[test]
+    fn lanes_keep_the_order_of_each_guild() {
+        let batch = vec![event(1, 1), event(2, 2), event(1, 3), event(2, 4), event(1, 5)];
+        let lanes = lanes(batch);
+
+        assert_eq!(lanes.len(), 2);
+        assert_eq!(role_ids(&lanes[0]), [1, 3, 5]);
+        assert_eq!(role_ids(&lanes[1]), [2, 4]);
+    }
+}
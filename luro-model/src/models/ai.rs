@@ -0,0 +1,250 @@
+use std::{collections::HashMap, time::Duration};
+
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+use crate::character::CharacterProfile;
+
+const DEFAULT_ENDPOINT: &str = "http://luro.local:11434/api/chat";
+const DEFAULT_MODEL: &str = "wizard-vicuna-uncensored";
+const DEFAULT_TOKEN_BUDGET: usize = 2048;
+const DEFAULT_TIMEOUT: u64 = 120;
+const DEFAULT_USER_CAPACITY: u32 = 5;
+const DEFAULT_GUILD_CAPACITY: u32 = 30;
+const DEFAULT_REFILL_SECONDS: u64 = 60;
+const DEFAULT_QUEUE_SIZE: usize = 5;
+
+/// Configuration for the AI module, loaded from the `[ai]` table of the configuration file.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct AiConfig {
+    /// The persona used when neither the channel nor the guild has one set.
+    pub default_persona: Option<String>,
+    /// All personas that can be selected, keyed by their name.
+    #[serde(default)]
+    pub personas: HashMap<String, AiPersona>,
+    /// A persona to use for a guild, keyed by the guild ID.
+    #[serde(default)]
+    pub guild_personas: HashMap<Id<GuildMarker>, String>,
+    /// Guilds where I will respond. If empty, I respond in every guild and in DMs.
+    #[serde(default)]
+    pub allowed_guilds: Vec<Id<GuildMarker>>,
+    /// If true, channels need to be enabled with `/ai respond` before I respond in them.
+    #[serde(default)]
+    pub channel_opt_in: bool,
+    /// If true, personas marked as `nsfw` refuse to respond outside of NSFW channels.
+    #[serde(default = "default_true")]
+    pub nsfw_gate: bool,
+    /// How often users and guilds can ask for a response.
+    #[serde(default)]
+    pub rate_limits: AiRateLimits,
+}
+
+impl AiConfig {
+    /// Returns true if responses are allowed in this guild, or in DMs if no guild is passed
+    pub fn guild_allowed(&self, guild_id: Option<Id<GuildMarker>>) -> bool {
+        match guild_id {
+            Some(guild_id) => self.allowed_guilds.is_empty() || self.allowed_guilds.contains(&guild_id),
+            None => self.allowed_guilds.is_empty(),
+        }
+    }
+
+    /// Get a persona by name, if it exists
+    pub fn persona(&self, name: &str) -> Option<&AiPersona> {
+        self.personas.get(name)
+    }
+
+    /// Resolve the persona that should be used for a guild.
+    ///
+    /// Guild Persona -> Default Persona -> Built in persona
+    pub fn guild_persona(&self, guild_id: Option<Id<GuildMarker>>) -> AiPersona {
+        guild_id
+            .and_then(|guild_id| self.guild_personas.get(&guild_id))
+            .or(self.default_persona.as_ref())
+            .and_then(|name| self.persona(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Token bucket rate limits for AI responses. Each response takes a token, and buckets refill completely over `refill_seconds`.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct AiRateLimits {
+    /// How many responses a single user can ask for before being limited
+    #[serde(default = "default_user_capacity")]
+    pub user_capacity: u32,
+    /// How many responses a guild can ask for before requests are queued
+    #[serde(default = "default_guild_capacity")]
+    pub guild_capacity: u32,
+    /// How many seconds it takes for an empty bucket to completely refill
+    #[serde(default = "default_refill_seconds")]
+    pub refill_seconds: u64,
+    /// How many requests can wait for a guild's bucket to refill before new requests are refused
+    #[serde(default = "default_queue_size")]
+    pub queue_size: usize,
+}
+
+impl Default for AiRateLimits {
+    fn default() -> Self {
+        Self {
+            user_capacity: default_user_capacity(),
+            guild_capacity: default_guild_capacity(),
+            refill_seconds: default_refill_seconds(),
+            queue_size: default_queue_size(),
+        }
+    }
+}
+
+/// The API a chat endpoint speaks.
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AiBackend {
+    /// Ollama's streaming `/api/chat` endpoint
+    #[default]
+    Ollama,
+    /// Any OpenAI compatible `/v1/chat/completions` endpoint, such as llama.cpp's server, vLLM or LocalAI
+    OpenAi,
+}
+
+/// How the bot should behave when generating a response.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct AiPersona {
+    /// The API spoken by the endpoint.
+    #[serde(default)]
+    pub backend: AiBackend,
+    /// A prompt sent before the conversation, used to give the model its personality.
+    pub system_prompt: Option<String>,
+    /// The model to request from the endpoint.
+    #[serde(default = "default_model")]
+    pub model: String,
+    /// How creative the model should be. Leave unset to use the model's default.
+    pub temperature: Option<f32>,
+    /// The chat endpoint to send requests to.
+    #[serde(default = "default_endpoint")]
+    pub endpoint: String,
+    /// A key sent as a bearer token, for endpoints that need one.
+    pub api_key: Option<String>,
+    /// How many seconds to wait for the endpoint to respond before giving up.
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+    /// Roughly how many tokens of conversation history to send with each request.
+    #[serde(default = "default_token_budget")]
+    pub token_budget: usize,
+    /// Set for uncensored models. If the NSFW gate is enabled, this persona only responds in NSFW channels.
+    #[serde(default)]
+    pub nsfw: bool,
+}
+
+impl Default for AiPersona {
+    fn default() -> Self {
+        Self {
+            backend: Default::default(),
+            system_prompt: None,
+            model: default_model(),
+            temperature: None,
+            endpoint: default_endpoint(),
+            api_key: None,
+            timeout: default_timeout(),
+            token_budget: default_token_budget(),
+            nsfw: false,
+        }
+    }
+}
+
+impl AiPersona {
+    /// How long to wait for the endpoint to respond before giving up
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+
+    /// Use a character profile as the personality, keeping the model and endpoint of this persona.
+    pub fn with_character(mut self, character: &CharacterProfile, nsfw: bool) -> Self {
+        let name = character.nickname.as_ref().unwrap_or(&character.name);
+        let mut prompt = format!("You are {name}, a character roleplaying in a Discord chat. Always stay in character.\n\n");
+
+        match nsfw {
+            true => {
+                prompt.push_str(character.nsfw_summary.as_ref().unwrap_or(&character.sfw_summary));
+                prompt.push_str("\n\n");
+                prompt.push_str(character.nsfw_description.as_ref().unwrap_or(&character.sfw_description));
+            }
+            false => {
+                prompt.push_str(&character.sfw_summary);
+                prompt.push_str("\n\n");
+                prompt.push_str(&character.sfw_description);
+            }
+        }
+
+        self.system_prompt = Some(prompt);
+        self
+    }
+}
+
+fn default_model() -> String {
+    DEFAULT_MODEL.to_owned()
+}
+
+fn default_endpoint() -> String {
+    DEFAULT_ENDPOINT.to_owned()
+}
+
+fn default_token_budget() -> usize {
+    DEFAULT_TOKEN_BUDGET
+}
+
+fn default_timeout() -> u64 {
+    DEFAULT_TIMEOUT
+}
+
+fn default_user_capacity() -> u32 {
+    DEFAULT_USER_CAPACITY
+}
+
+fn default_guild_capacity() -> u32 {
+    DEFAULT_GUILD_CAPACITY
+}
+
+fn default_refill_seconds() -> u64 {
+    DEFAULT_REFILL_SECONDS
+}
+
+fn default_queue_size() -> usize {
+    DEFAULT_QUEUE_SIZE
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// The AI settings for a single channel, stored in the database.
+#[derive(Clone, Debug, Default)]
+pub struct AiChannel {
+    pub channel_id: Id<ChannelMarker>,
+    /// A persona from the configuration to use in this channel
+    pub persona: Option<String>,
+    /// The owner of the character to roleplay as
+    pub character_owner: Option<Id<UserMarker>>,
+    /// The name of the character to roleplay as
+    pub character_name: Option<String>,
+    /// If I can respond in this channel, when channels need to opt in
+    pub enabled: bool,
+}
+
+/// A message that is part of a channel's conversation with the bot.
+#[derive(Clone, Debug)]
+pub struct AiHistoryMessage {
+    pub channel_id: Id<ChannelMarker>,
+    pub message_id: Id<MessageMarker>,
+    pub author_id: Id<UserMarker>,
+    /// True if this message was generated by the bot
+    pub assistant: bool,
+    pub content: String,
+}
+
+impl AiHistoryMessage {
+    /// A rough estimate of how many tokens this message will use. Roughly four characters make up a token.
+    pub fn estimated_tokens(&self) -> usize {
+        self.content.chars().count() / 4 + 1
+    }
+}
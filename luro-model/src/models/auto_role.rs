@@ -0,0 +1,48 @@
+use twilight_model::{
+    channel::message::ReactionType,
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// The longest an auto role can be delayed by, one week
+pub const AUTO_ROLE_MAX_DELAY: i64 = 60 * 60 * 24 * 7;
+
+/// A role given to anyone who reacts to a message with an emoji, and taken away when they remove the reaction
+#[derive(Clone, Debug)]
+pub struct ReactionRole {
+    pub guild_id: Id<GuildMarker>,
+    pub channel_id: Id<ChannelMarker>,
+    pub message_id: Id<MessageMarker>,
+    /// See [reaction_key]
+    pub emoji: String,
+    pub role_id: Id<RoleMarker>,
+}
+
+/// A role given to members when they join
+#[derive(Clone, Debug)]
+pub struct AutoRole {
+    pub guild_id: Id<GuildMarker>,
+    pub role_id: Id<RoleMarker>,
+    /// How long to wait before giving the role, in seconds
+    pub delay_seconds: i64,
+    /// Wait until the member has passed membership screening. The delay starts once they have.
+    pub after_screening: bool,
+}
+
+/// An auto role that is due to be given to a member
+#[derive(Clone, Debug)]
+pub struct PendingAutoRole {
+    pub guild_id: Id<GuildMarker>,
+    pub user_id: Id<UserMarker>,
+    pub role_id: Id<RoleMarker>,
+}
+
+/// The key reaction roles are stored under. Custom emoji use their ID, as their name can change, while unicode emoji use the emoji itself.
+pub fn reaction_key(emoji: &ReactionType) -> String {
+    match emoji {
+        ReactionType::Custom { id, .. } => id.to_string(),
+        ReactionType::Unicode { name } => name.clone(),
+    }
+}
@@ -2,7 +2,7 @@ mod prefix_command;
 mod slash_command;
 
 pub use self::prefix_command::PrefixCommand;
-pub use self::slash_command::{SlashCommand, SlashCommandParameter, SlashError, SlashResult};
+pub use self::slash_command::{SlashCommand, SlashError, SlashHandler, SlashOutput, SlashResult};
 
 pub type InteractionSuccess = ();
 pub type InteractionResponse = Result<InteractionSuccess, InteractionError>;
@@ -15,12 +15,12 @@ pub type PrefixContext = ();
 pub type ComponentContext = ();
 pub type ModalContext = ();
 
-pub enum Command {
-    SlashCommand(SlashCommand),
+pub enum Command<C, E = SlashError> {
+    SlashCommand(SlashCommand<C, E>),
     PrefixCommand(PrefixCommand),
 }
 
-impl Command {}
+impl<C, E> Command<C, E> {}
 
 #[derive(Debug, thiserror::Error)]
 pub enum InteractionError {
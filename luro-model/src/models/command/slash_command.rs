@@ -1,59 +1,54 @@
+use twilight_interactions::command::{CommandChecks, CommandInputData};
 use twilight_model::{
-    application::command::{Command, CommandOption, CommandOptionType},
+    application::command::{Command, CommandOption},
     id::Id,
 };
 
+use crate::BoxFuture;
+
 pub type SlashResult = Result<(), SlashError>;
 pub type SlashError = ();
 
-pub struct SlashCommandParameter {
-    pub name: String,
-    pub description: String,
-    pub required: bool,
-    // pub choices: Vec<CommandParameterChoice>,
+/// Runs a slash command with the context `C`, parsing the options from the command data
+pub type SlashHandler<C, E> = for<'a> fn(&'a mut C, CommandInputData<'static>) -> BoxFuture<'a, Result<(), E>>;
+
+/// The error type of a slash command function, so functions can return an alias such as `InteractionResult<()>`
+pub trait SlashOutput {
+    type Error;
 }
 
-/// Implements an interaction command.
-pub struct SlashCommand {
-    // pub command: Box<dyn FnOnce() -> BoxFuture<'static, SlashResult> + Send>,
-    // pub command: for<'a> fn(SlashContext) -> BoxFuture<'static, SlashResult>,
-    // pub subcommand: Vec<SlashCommand>,
+impl<E> SlashOutput for Result<(), E> {
+    type Error = E;
+}
+
+/// Implements an interaction command. Usually made from an async function with `#[luro_derive::slash_command]`.
+pub struct SlashCommand<C, E = SlashError> {
     pub name: String,
     pub description: String,
+    /// Help text, from the paragraphs after the first in the doc comment
     pub long_description: Option<String>,
     pub nsfw: bool,
+    /// If true, responses to the command are only shown to the user who ran it
     pub ephemeral: bool,
-    pub parameters: Vec<SlashCommandParameter>,
-    // pub checks: Vec<fn() -> BoxFuture<'static, Result<bool, SlashError>>>,
+    /// The options of the command, with their types mapped from the function parameters
+    pub parameters: Vec<CommandOption>,
+    /// Checks to run before the command is handled
+    pub checks: CommandChecks,
+    pub handler: SlashHandler<C, E>,
 }
 
-impl SlashCommand {
-    pub fn twilight_command(&self) -> Command {
-        let options = self
-            .parameters
-            .iter()
-            .map(|option| CommandOption {
-                autocomplete: None,
-                channel_types: None,
-                choices: None,
-                description: option.description.clone(),
-                description_localizations: None,
-                kind: CommandOptionType::String,
-                max_length: None,
-                max_value: None,
-                min_length: None,
-                min_value: None,
-                name: option.name.clone(),
-                name_localizations: None,
-                options: None,
-                required: Some(option.required),
-            })
-            .collect();
+impl<C, E> SlashCommand<C, E> {
+    /// Parse the options and run the command
+    pub fn run<'a>(&self, context: &'a mut C, data: CommandInputData<'static>) -> BoxFuture<'a, Result<(), E>> {
+        (self.handler)(context, data)
+    }
 
+    pub fn twilight_command(&self) -> Command {
         Command {
             application_id: None,
             default_member_permissions: None,
-            dm_permission: None,
+            // Guild only commands are hidden in DMs, like commands deriving `CreateCommand`
+            dm_permission: self.checks.guild_only.then_some(false),
             description: self.description.clone(),
             description_localizations: None,
             guild_id: None,
@@ -62,7 +57,7 @@ impl SlashCommand {
             name: self.name.clone(),
             name_localizations: None,
             nsfw: Some(self.nsfw),
-            options,
+            options: self.parameters.clone(),
             version: Id::new(1),
         }
     }
@@ -0,0 +1,266 @@
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, RoleMarker},
+    Id,
+};
+
+/// The name used for keyword responses, which are not commands but can be turned off like one
+pub const KEYWORD_COMMAND: &str = "keywords";
+
+/// The command that manages policies. It can't be disabled, otherwise a guild could never turn it back on
+pub const SETTINGS_COMMAND: &str = "settings";
+
+/// Prefix commands, components and modals that belong to another command, along with the command path they belong to
+const COMMAND_ALIASES: &[(&str, &str)] = &[
+    ("roll", "dice roll"),
+    ("ai-privacy", "ai"),
+    ("base64-decode", "base64"),
+    ("base64-encode", "base64"),
+    ("music-loop", "music"),
+    ("music-pause", "music"),
+    ("music-shuffle", "music"),
+    ("music-skip", "music"),
+    ("music-stop", "music"),
+    ("role-menu", "roles"),
+    ("Save as quote", "quote"),
+];
+
+/// The command path that a command, prefix alias or component route belongs to, such as `dice roll` for `roll`.
+///
+/// Routes ending in an ID, such as `role-menu-123`, are matched without it.
+pub fn command_path(route: &str) -> &str {
+    let route = match route.rsplit_once('-') {
+        Some((base, id)) if id.parse::<u64>().is_ok() => base,
+        _ => route,
+    };
+
+    COMMAND_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == route)
+        .map(|(_, command)| *command)
+        .unwrap_or(route)
+}
+
+/// Where a command policy applies
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolicyScope {
+    /// Everywhere in the guild
+    Guild,
+    /// In a single channel
+    Channel(Id<ChannelMarker>),
+    /// For members with a role
+    Role(Id<RoleMarker>),
+}
+
+impl PolicyScope {
+    /// The scope as stored in the database, as its name and target ID. Guild policies have no target, stored as 0.
+    pub fn as_parts(&self) -> (&'static str, i64) {
+        match self {
+            Self::Guild => ("guild", 0),
+            Self::Channel(channel_id) => ("channel", channel_id.get() as i64),
+            Self::Role(role_id) => ("role", role_id.get() as i64),
+        }
+    }
+
+    /// Read a scope back from its name and target ID
+    pub fn from_parts(scope: &str, target_id: i64) -> Option<Self> {
+        match scope {
+            "guild" => Some(Self::Guild),
+            "channel" => Id::new_checked(target_id as u64).map(Self::Channel),
+            "role" => Id::new_checked(target_id as u64).map(Self::Role),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PolicyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Guild => write!(f, "the whole server"),
+            Self::Channel(channel_id) => write!(f, "<#{channel_id}>"),
+            Self::Role(role_id) => write!(f, "<@&{role_id}>"),
+        }
+    }
+}
+
+/// Enables or disables a command or subcommand within a guild
+#[derive(Clone, Debug)]
+pub struct CommandPolicy {
+    pub guild_id: Id<GuildMarker>,
+    /// The command, with subcommands separated by spaces such as `quote get`
+    pub command: String,
+    pub scope: PolicyScope,
+    pub enabled: bool,
+}
+
+/// Everything needed to decide if a command may run
+#[derive(Clone, Copy, Debug)]
+pub struct CommandInvocation<'a> {
+    /// The command, with subcommands separated by spaces such as `quote get`
+    pub command: &'a str,
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// The roles of the member running the command
+    pub roles: &'a [Id<RoleMarker>],
+}
+
+impl CommandInvocation<'_> {
+    /// Decide if the command may run under a guild's policies. Commands without a policy are allowed.
+    ///
+    /// Channel policies win over role policies, which win over guild policies. Within a scope, a policy for a subcommand
+    /// wins over one for its command. If a member has roles that both enable and disable a command, enabling wins.
+    pub fn allowed(&self, policies: &[CommandPolicy]) -> bool {
+        let commands = self.commands();
+        if commands.last() == Some(&SETTINGS_COMMAND) {
+            return true;
+        }
+
+        for scope in [Scope::Channel, Scope::Role, Scope::Guild] {
+            for command in &commands {
+                let matching: Vec<_> = policies
+                    .iter()
+                    .filter(|policy| policy.command == *command && scope.matches(policy.scope, self))
+                    .collect();
+
+                if !matching.is_empty() {
+                    return matching.iter().any(|policy| policy.enabled);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// The command and each of its parents, most specific first
+    fn commands(&self) -> Vec<&str> {
+        let mut commands = vec![self.command];
+        let mut command = self.command;
+        while let Some((parent, _)) = command.rsplit_once(' ') {
+            commands.push(parent);
+            command = parent;
+        }
+        commands
+    }
+}
+
+/// The order scopes are checked in
+#[derive(Clone, Copy)]
+enum Scope {
+    Channel,
+    Role,
+    Guild,
+}
+
+impl Scope {
+    fn matches(&self, scope: PolicyScope, invocation: &CommandInvocation) -> bool {
+        match (self, scope) {
+            (Self::Channel, PolicyScope::Channel(channel_id)) => invocation.channel_id == Some(channel_id),
+            (Self::Role, PolicyScope::Role(role_id)) => invocation.roles.contains(&role_id),
+            (Self::Guild, PolicyScope::Guild) => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GUILD: Id<GuildMarker> = Id::new(1);
+    const CHANNEL: Id<ChannelMarker> = Id::new(2);
+    const ROLE: Id<RoleMarker> = Id::new(3);
+    const OTHER_ROLE: Id<RoleMarker> = Id::new(4);
+
+    fn policy(command: &str, scope: PolicyScope, enabled: bool) -> CommandPolicy {
+        CommandPolicy {
+            guild_id: GUILD,
+            command: command.to_owned(),
+            scope,
+            enabled,
+        }
+    }
+
+    fn invocation<'a>(command: &'a str, roles: &'a [Id<RoleMarker>]) -> CommandInvocation<'a> {
+        CommandInvocation {
+            command,
+            guild_id: Some(GUILD),
+            channel_id: Some(CHANNEL),
+            roles,
+        }
+    }
+
+    #[test]
+    fn commands_without_policies_are_allowed() {
+        assert!(invocation("quote get", &[]).allowed(&[]));
+        assert!(invocation("quote get", &[]).allowed(&[policy("dice", PolicyScope::Guild, false)]));
+    }
+
+    #[test]
+    fn parents_cover_subcommands() {
+        let policies = [policy("quote", PolicyScope::Guild, false)];
+
+        assert!(!invocation("quote", &[]).allowed(&policies));
+        assert!(!invocation("quote get", &[]).allowed(&policies));
+        assert!(invocation("quotes", &[]).allowed(&policies));
+    }
+
+    #[test]
+    fn subcommands_win_over_parents() {
+        let policies = [
+            policy("quote", PolicyScope::Guild, false),
+            policy("quote get", PolicyScope::Guild, true),
+        ];
+
+        assert!(invocation("quote get", &[]).allowed(&policies));
+        assert!(!invocation("quote add", &[]).allowed(&policies));
+    }
+
+    #[test]
+    fn channels_win_over_roles_over_guilds() {
+        let policies = [
+            policy("dice", PolicyScope::Guild, false),
+            policy("dice", PolicyScope::Role(ROLE), true),
+        ];
+        assert!(invocation("dice roll", &[ROLE]).allowed(&policies));
+        assert!(!invocation("dice roll", &[OTHER_ROLE]).allowed(&policies));
+
+        let policies = [
+            policy("dice", PolicyScope::Role(ROLE), true),
+            policy("dice", PolicyScope::Channel(CHANNEL), false),
+        ];
+        assert!(!invocation("dice roll", &[ROLE]).allowed(&policies));
+    }
+
+    #[test]
+    fn enabling_role_wins_over_disabling_role() {
+        let policies = [
+            policy("dice", PolicyScope::Role(ROLE), false),
+            policy("dice", PolicyScope::Role(OTHER_ROLE), true),
+        ];
+
+        assert!(invocation("dice", &[ROLE, OTHER_ROLE]).allowed(&policies));
+        assert!(!invocation("dice", &[ROLE]).allowed(&policies));
+    }
+
+    #[test]
+    fn settings_can_not_be_disabled() {
+        let policies = [
+            policy(SETTINGS_COMMAND, PolicyScope::Guild, false),
+            policy("settings commands", PolicyScope::Channel(CHANNEL), false),
+        ];
+
+        assert!(invocation(SETTINGS_COMMAND, &[]).allowed(&policies));
+        assert!(invocation("settings commands", &[]).allowed(&policies));
+    }
+
+    #[test]
+    fn aliases_resolve_to_their_command() {
+        assert_eq!(command_path("roll"), "dice roll");
+        assert_eq!(command_path("music-skip"), "music");
+        assert_eq!(command_path("role-menu-1234"), "roles");
+        assert_eq!(command_path("base64"), "base64");
+        assert_eq!(command_path("quote"), "quote");
+
+        let policies = [policy("dice", PolicyScope::Guild, false)];
+        assert!(!invocation(command_path("roll"), &[]).allowed(&policies));
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{BTreeMap, HashMap};
+
+use twilight_model::{
+    application::command::{Command, CommandOption},
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::config::Error;
+
+/// Where the commands last registered with Discord are saved, so changes can be inspected without connecting
+pub const COMMAND_SNAPSHOT: &str = "./config/commands.json";
+
+/// Configuration for registering commands, loaded from the `[registration]` table of the configuration file.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct RegistrationConfig {
+    /// Commands that are only registered in these guilds instead of globally, keyed by the guild ID.
+    /// Useful for staff-only commands, or commands made for a single guild.
+    #[serde(default)]
+    pub guild_commands: HashMap<Id<GuildMarker>, Vec<String>>,
+}
+
+/// The commands registered globally and in each guild
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct CommandSnapshot {
+    #[serde(default)]
+    pub global: Vec<Command>,
+    #[serde(default)]
+    pub guilds: BTreeMap<Id<GuildMarker>, Vec<Command>>,
+}
+
+impl CommandSnapshot {
+    /// Split commands into global and guild commands, following [RegistrationConfig::guild_commands]
+    pub fn new(commands: Vec<Command>, config: &RegistrationConfig) -> Self {
+        let mut snapshot = Self::default();
+
+        for (guild_id, names) in &config.guild_commands {
+            for name in names {
+                match commands.iter().find(|command| &command.name == name) {
+                    Some(command) => snapshot.guilds.entry(*guild_id).or_default().push(command.clone()),
+                    None => tracing::warn!("The command `{name}` is set to register in guild {guild_id}, but does not exist"),
+                }
+            }
+        }
+
+        let guild_only = config.guild_commands.values().flatten().collect::<Vec<_>>();
+        snapshot.global = commands
+            .into_iter()
+            .filter(|command| !guild_only.contains(&&command.name))
+            .collect();
+        snapshot
+    }
+
+    /// Load a snapshot saved with [CommandSnapshot::save]. A missing file results in an empty snapshot.
+    pub async fn load(file_name: &str) -> Result<Self, Error> {
+        match tokio::fs::read_to_string(file_name).await {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(why) if why.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(why) => Err(why.into()),
+        }
+    }
+
+    pub async fn save(&self, file_name: &str) -> Result<(), Error> {
+        Ok(tokio::fs::write(file_name, serde_json::to_string_pretty(self)?).await?)
+    }
+
+    /// The commands registered in a guild, which is empty if there are none
+    pub fn guild(&self, guild_id: Id<GuildMarker>) -> &[Command] {
+        self.guilds.get(&guild_id).map(|x| x.as_slice()).unwrap_or_default()
+    }
+
+    /// Every guild with commands in either snapshot
+    pub fn guild_ids(&self, other: &Self) -> Vec<Id<GuildMarker>> {
+        let mut guild_ids = self.guilds.keys().chain(other.guilds.keys()).copied().collect::<Vec<_>>();
+        guild_ids.sort_unstable();
+        guild_ids.dedup();
+        guild_ids
+    }
+
+    /// What would change if this snapshot was registered over `registered`. Scopes without changes are left out.
+    pub fn diff(&self, registered: &Self) -> Vec<CommandDiff> {
+        let global = CommandDiff::new(None, &registered.global, &self.global);
+        let guilds = self
+            .guild_ids(registered)
+            .into_iter()
+            .map(|guild_id| CommandDiff::new(Some(guild_id), registered.guild(guild_id), self.guild(guild_id)));
+
+        std::iter::once(global).chain(guilds).filter(|diff| !diff.is_empty()).collect()
+    }
+}
+
+/// The commands that changed between two sets of commands in the same scope
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommandDiff {
+    /// The guild the commands are registered in, or `None` for global commands
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl CommandDiff {
+    /// Compare the commands registered with Discord with the commands that should be registered.
+    /// Fields set by Discord, such as IDs, are ignored.
+    pub fn new(guild_id: Option<Id<GuildMarker>>, registered: &[Command], wanted: &[Command]) -> Self {
+        let mut diff = Self {
+            guild_id,
+            ..Default::default()
+        };
+
+        for command in wanted {
+            match registered.iter().find(|x| x.name == command.name && x.kind == command.kind) {
+                Some(existing) if normalize(existing) != normalize(command) => diff.changed.push(command.name.clone()),
+                Some(_) => (),
+                None => diff.added.push(command.name.clone()),
+            }
+        }
+
+        for command in registered {
+            if !wanted.iter().any(|x| x.name == command.name && x.kind == command.kind) {
+                diff.removed.push(command.name.clone())
+            }
+        }
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for CommandDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.guild_id {
+            Some(guild_id) => write!(f, "Guild {guild_id}:")?,
+            None => write!(f, "Global:")?,
+        }
+
+        if self.is_empty() {
+            return write!(f, " no changes");
+        }
+
+        for (symbol, names) in [("+", &self.added), ("-", &self.removed), ("~", &self.changed)] {
+            for name in names {
+                write!(f, " {symbol}{name}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Clear the fields Discord sets, and fill in the defaults Discord leaves out, so commands can be compared
+fn normalize(command: &Command) -> Command {
+    let mut command = command.clone();
+    command.application_id = None;
+    command.guild_id = None;
+    command.id = None;
+    command.version = Id::new(1);
+    command.dm_permission = command.dm_permission.filter(|x| !x);
+    command.nsfw = command.nsfw.filter(|x| *x);
+    command.name_localizations = command.name_localizations.filter(|x| !x.is_empty());
+    command.description_localizations = command.description_localizations.filter(|x| !x.is_empty());
+    command.options.iter_mut().for_each(normalize_option);
+    command
+}
+
+fn normalize_option(option: &mut CommandOption) {
+    option.autocomplete = option.autocomplete.filter(|x| *x);
+    option.required = option.required.filter(|x| *x);
+    option.choices = option.choices.take().filter(|x| !x.is_empty());
+    option.channel_types = option.channel_types.take().filter(|x| !x.is_empty());
+    option.name_localizations = option.name_localizations.take().filter(|x| !x.is_empty());
+    option.description_localizations = option.description_localizations.take().filter(|x| !x.is_empty());
+    option.options = option.options.take().filter(|x| !x.is_empty());
+    option.options.iter_mut().flatten().for_each(normalize_option);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn localized_command() -> serde_json::Value {
+        json!({
+            "type": 1,
+            "name": "roll",
+            "name_localizations": { "de": "wuerfeln" },
+            "description": "Roll some dice",
+            "description_localizations": { "de": "Wirf ein paar Wuerfel" },
+            "version": "1",
+            "options": [{
+                "type": 3,
+                "name": "dice",
+                "name_localizations": { "de": "wuerfel" },
+                "description": "The dice to roll",
+                "description_localizations": { "de": "Die Wuerfel zum Werfen" },
+                "required": true
+            }]
+        })
+    }
+
+    /// The command as Discord returns it, with the fields it sets filled in
+    fn registered(mut command: serde_json::Value) -> Command {
+        command["id"] = json!("10");
+        command["application_id"] = json!("20");
+        command["version"] = json!("30");
+        command["dm_permission"] = json!(true);
+        command["nsfw"] = json!(false);
+        serde_json::from_value(command).unwrap()
+    }
+
+    #[test]
+    fn localized_commands_match_their_round_trip() {
+        let wanted: Command = serde_json::from_value(localized_command()).unwrap();
+        let round_trip = registered(serde_json::to_value(&wanted).unwrap());
+
+        assert!(CommandDiff::new(None, &[round_trip], &[wanted]).is_empty());
+    }
+
+    #[test]
+    fn missing_localizations_are_a_change() {
+        let wanted: Command = serde_json::from_value(localized_command()).unwrap();
+        let mut fetched = localized_command();
+        fetched.as_object_mut().unwrap().remove("name_localizations");
+        fetched.as_object_mut().unwrap().remove("description_localizations");
+
+        assert_eq!(CommandDiff::new(None, &[registered(fetched)], &[wanted]).changed, ["roll"]);
+    }
+}
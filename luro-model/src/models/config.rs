@@ -1,5 +1,14 @@
 use tokio::{fs::File, io::AsyncReadExt};
 
+use crate::{
+    ai::AiConfig,
+    command_registration::RegistrationConfig,
+    database_sync::SyncConfig,
+    e621::{E621_DEFAULT_USER_AGENT, E621_URL, E926_URL},
+    music::MusicConfig,
+    staff::StaffConfig,
+};
+
 const ACCENT_COLOUR: u32 = 0xDABEEF;
 
 #[derive(thiserror::Error, Debug)]
@@ -10,6 +19,8 @@ pub enum Error {
     TomlIOError(#[from] toml::de::Error),
     #[error("There was an error serializing the TOML content of the configuration")]
     TomlSerializeError(#[from] toml::ser::Error),
+    #[error("There was an error with the JSON content of a flavour text file")]
+    JsonIOError(#[from] serde_json::Error),
 }
 
 #[derive(Debug, Default, ::serde::Deserialize, ::serde::Serialize, Clone)]
@@ -20,11 +31,25 @@ pub struct Config {
     pub description: Option<String>,
     pub e621_blacklist: Option<String>,
     pub e621_useragent: Option<String>,
+    /// Overrides the e621 API, such as for a mirror or a local mock server
+    pub e621_base_url: Option<String>,
+    /// Overrides the e926 API, used outside of NSFW channels
+    pub e926_base_url: Option<String>,
     pub webhook_name: Option<String>,
     pub git_url: Option<String>,
     pub commands_enabled: Option<Vec<String>>,
     pub commands_disabled: Option<Vec<String>>,
     pub prefix: Option<String>,
+    #[serde(default)]
+    pub ai: AiConfig,
+    #[serde(default)]
+    pub music: MusicConfig,
+    #[serde(default)]
+    pub registration: RegistrationConfig,
+    #[serde(default)]
+    pub database_sync: SyncConfig,
+    #[serde(default)]
+    pub staff: StaffConfig,
 }
 
 impl Config {
@@ -50,8 +75,8 @@ impl Config {
                     false => false,
                 }
             }
-            // If commands_enabled is NOT present (None)
-            None => self.command_disabled(command),
+            // If commands_enabled is NOT present (None), the command is allowed unless it is disabled
+            None => !self.command_disabled(command),
         }
     }
 
@@ -119,4 +144,16 @@ impl Config {
     pub fn accent_colour(&self) -> u32 {
         self.accent_colour.unwrap_or(ACCENT_COLOUR)
     }
+
+    /// The API to search, e621 in NSFW channels and e926 everywhere else
+    pub fn e621_api(&self, nsfw: bool) -> &str {
+        match nsfw {
+            true => self.e621_base_url.as_deref().unwrap_or(E621_URL),
+            false => self.e926_base_url.as_deref().unwrap_or(E926_URL),
+        }
+    }
+
+    pub fn e621_user_agent(&self) -> &str {
+        self.e621_useragent.as_deref().unwrap_or(E621_DEFAULT_USER_AGENT)
+    }
 }
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+const DEFAULT_QUEUE_SIZE: usize = 10_000;
+const DEFAULT_BATCH_SIZE: usize = 100;
+const DEFAULT_CONNECTIONS: usize = 2;
+const DEFAULT_REPORT_INTERVAL: u64 = 300;
+
+/// Settings for the queue that writes gateway events to the database
+#[derive(Debug, Clone, ::serde::Deserialize, ::serde::Serialize)]
+pub struct SyncConfig {
+    /// How many events can wait to be written. Once full, new events are dropped unless they replace one that is waiting
+    #[serde(default = "default_queue_size")]
+    pub queue_size: usize,
+    /// How many events are taken from the queue at once
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// How many database connections the sync may use at once. Always leaves one for commands
+    #[serde(default = "default_connections")]
+    pub connections: usize,
+    /// How often the sync statistics are logged, in seconds
+    #[serde(default = "default_report_interval")]
+    pub report_interval: u64,
+}
+
+impl SyncConfig {
+    pub fn report_interval(&self) -> Duration {
+        Duration::from_secs(self.report_interval.max(1))
+    }
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            queue_size: default_queue_size(),
+            batch_size: default_batch_size(),
+            connections: default_connections(),
+            report_interval: default_report_interval(),
+        }
+    }
+}
+
+fn default_queue_size() -> usize {
+    DEFAULT_QUEUE_SIZE
+}
+
+fn default_batch_size() -> usize {
+    DEFAULT_BATCH_SIZE
+}
+
+fn default_connections() -> usize {
+    DEFAULT_CONNECTIONS
+}
+
+fn default_report_interval() -> u64 {
+    DEFAULT_REPORT_INTERVAL
+}
@@ -0,0 +1,301 @@
+use std::fmt::Display;
+
+/// The API used in NSFW channels
+pub const E621_URL: &str = "https://e621.net";
+/// The safe API used everywhere else, which only serves safe posts
+pub const E926_URL: &str = "https://e926.net";
+/// e621 requires a descriptive user agent, this is used if `e621_useragent` is not configured
+pub const E621_DEFAULT_USER_AGENT: &str = concat!("Luro/", env!("CARGO_PKG_VERSION"));
+/// Artist tags that say something about the post rather than who made it
+const NOT_ARTISTS: [&str; 4] = ["conditional_dnp", "sound_warning", "unknown_artist", "third-party_edit"];
+
+/// How explicit a post is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum E621Rating {
+    Safe,
+    Questionable,
+    Explicit,
+}
+
+impl E621Rating {
+    /// The metatag used to search for this rating, such as `rating:s`
+    pub fn as_tag(&self) -> &'static str {
+        match self {
+            Self::Safe => "rating:s",
+            Self::Questionable => "rating:q",
+            Self::Explicit => "rating:e",
+        }
+    }
+
+    /// Parse a rating as written by the API or in a blacklist, such as `s` or `safe`
+    pub fn parse(rating: &str) -> Option<Self> {
+        match rating.to_lowercase().chars().next()? {
+            's' => Some(Self::Safe),
+            'q' => Some(Self::Questionable),
+            'e' => Some(Self::Explicit),
+            _ => None,
+        }
+    }
+}
+
+impl Display for E621Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Safe => write!(f, "Safe"),
+            Self::Questionable => write!(f, "Questionable"),
+            Self::Explicit => write!(f, "Explicit"),
+        }
+    }
+}
+
+/// A page of results from `/posts.json`
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct E621Posts {
+    pub posts: Vec<E621Post>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct E621Post {
+    pub id: u64,
+    pub file: E621File,
+    #[serde(default)]
+    pub sample: E621Sample,
+    pub score: E621Score,
+    #[serde(default)]
+    pub fav_count: u64,
+    pub rating: String,
+    pub tags: E621Tags,
+    #[serde(default)]
+    pub sources: Vec<String>,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct E621File {
+    pub ext: Option<String>,
+    /// Missing for posts that are hidden from anonymous users
+    pub url: Option<String>,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct E621Sample {
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct E621Score {
+    pub total: i64,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct E621Tags {
+    pub general: Vec<String>,
+    pub artist: Vec<String>,
+    pub copyright: Vec<String>,
+    pub character: Vec<String>,
+    pub species: Vec<String>,
+    pub invalid: Vec<String>,
+    pub meta: Vec<String>,
+    pub lore: Vec<String>,
+}
+
+impl E621Post {
+    pub fn rating(&self) -> Option<E621Rating> {
+        E621Rating::parse(&self.rating)
+    }
+
+    /// Every tag on the post, regardless of category
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        let tags = &self.tags;
+        [
+            &tags.general,
+            &tags.artist,
+            &tags.copyright,
+            &tags.character,
+            &tags.species,
+            &tags.invalid,
+            &tags.meta,
+            &tags.lore,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|tag| tag.as_str())
+    }
+
+    /// The artists of the post, leaving out tags like `conditional_dnp`
+    pub fn artists(&self) -> impl Iterator<Item = &str> {
+        self.tags
+            .artist
+            .iter()
+            .map(|tag| tag.as_str())
+            .filter(|tag| !NOT_ARTISTS.contains(tag))
+    }
+
+    /// An image that can be embedded. Videos and flash use the sample, which is a still image.
+    pub fn image_url(&self) -> Option<&str> {
+        match self.file.ext.as_deref() {
+            Some("png" | "jpg" | "jpeg" | "gif" | "webp") => self.file.url.as_deref(),
+            _ => self
+                .sample
+                .url
+                .as_deref()
+                .filter(|url| !url.ends_with(".webm") && !url.ends_with(".mp4")),
+        }
+    }
+}
+
+/// A single tag in a blacklist line
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum BlacklistTag {
+    Tag(String),
+    Rating(E621Rating),
+}
+
+/// An e621 style blacklist. Each line is a set of tags, and a post is blacklisted if it matches every tag on any line.
+/// Tags starting with `-` must be missing from the post, and `rating:s`, `rating:q` and `rating:e` match the rating.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct E621Blacklist {
+    lines: Vec<Vec<(bool, BlacklistTag)>>,
+}
+
+impl E621Blacklist {
+    pub fn parse(blacklist: &str) -> Self {
+        let mut parsed = Self::default();
+        parsed.extend(blacklist);
+        parsed
+    }
+
+    /// Add the lines of another blacklist to this one
+    pub fn extend(&mut self, blacklist: &str) {
+        for line in blacklist.lines() {
+            let line = line
+                .split_whitespace()
+                .map(|tag| {
+                    let tag = tag.to_lowercase();
+                    let (negated, tag) = match tag.strip_prefix('-') {
+                        Some(tag) => (true, tag),
+                        None => (false, tag.as_str()),
+                    };
+                    let tag = match tag.strip_prefix("rating:").and_then(E621Rating::parse) {
+                        Some(rating) => BlacklistTag::Rating(rating),
+                        None => BlacklistTag::Tag(tag.to_owned()),
+                    };
+                    (negated, tag)
+                })
+                .collect::<Vec<_>>();
+
+            if !line.is_empty() {
+                self.lines.push(line);
+            }
+        }
+    }
+
+    /// True if any line matches the post
+    pub fn blacklisted(&self, post: &E621Post) -> bool {
+        self.lines.iter().any(|line| {
+            line.iter().all(|(negated, tag)| {
+                let present = match tag {
+                    BlacklistTag::Tag(tag) => post.tags().any(|post_tag| post_tag == tag),
+                    BlacklistTag::Rating(rating) => post.rating() == Some(*rating),
+                };
+                present != *negated
+            })
+        })
+    }
+
+    /// The number of lines in the blacklist
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(rating: &str, general: &[&str]) -> E621Post {
+        E621Post {
+            id: 1,
+            file: E621File {
+                ext: Some("png".to_owned()),
+                url: Some("https://static1.e621.net/data/1.png".to_owned()),
+                width: 100,
+                height: 100,
+            },
+            sample: E621Sample::default(),
+            score: E621Score { total: 0 },
+            fav_count: 0,
+            rating: rating.to_owned(),
+            tags: E621Tags {
+                general: general.iter().map(|tag| tag.to_string()).collect(),
+                ..Default::default()
+            },
+            sources: vec![],
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn blank_lines_are_ignored() {
+        let blacklist = E621Blacklist::parse("gore\n\n   \nscat");
+
+        assert_eq!(blacklist.len(), 2);
+        assert!(!E621Blacklist::parse("").blacklisted(&post("s", &["gore"])));
+    }
+
+    #[test]
+    fn every_tag_on_a_line_must_match() {
+        let blacklist = E621Blacklist::parse("feral gore");
+
+        assert!(blacklist.blacklisted(&post("s", &["feral", "gore", "solo"])));
+        assert!(!blacklist.blacklisted(&post("s", &["feral", "solo"])));
+        assert!(!blacklist.blacklisted(&post("s", &["gore"])));
+    }
+
+    #[test]
+    fn any_line_can_match() {
+        let blacklist = E621Blacklist::parse("feral gore\nscat");
+
+        assert!(blacklist.blacklisted(&post("s", &["scat"])));
+        assert!(!blacklist.blacklisted(&post("s", &["feral"])));
+    }
+
+    #[test]
+    fn negated_tags_must_be_missing() {
+        let blacklist = E621Blacklist::parse("gore -cartoon");
+
+        assert!(blacklist.blacklisted(&post("s", &["gore"])));
+        assert!(!blacklist.blacklisted(&post("s", &["gore", "cartoon"])));
+    }
+
+    #[test]
+    fn ratings_match_the_post_rating() {
+        let blacklist = E621Blacklist::parse("rating:e");
+
+        assert!(blacklist.blacklisted(&post("e", &[])));
+        assert!(!blacklist.blacklisted(&post("q", &[])));
+        assert!(!blacklist.blacklisted(&post("s", &["rating:e"])));
+
+        let blacklist = E621Blacklist::parse("gore -rating:safe");
+        assert!(blacklist.blacklisted(&post("q", &["gore"])));
+        assert!(!blacklist.blacklisted(&post("s", &["gore"])));
+    }
+
+    #[test]
+    fn tags_and_ratings_ignore_case() {
+        let blacklist = E621Blacklist::parse("Gore\nRATING:Q\n-Solo Feral");
+
+        assert!(blacklist.blacklisted(&post("s", &["gore"])));
+        assert!(blacklist.blacklisted(&post("q", &[])));
+        assert!(blacklist.blacklisted(&post("s", &["feral"])));
+        assert!(!blacklist.blacklisted(&post("s", &["feral", "solo"])));
+    }
+}
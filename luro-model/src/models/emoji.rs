@@ -9,3 +9,22 @@ pub const GUIDE_EMOJI: &str = "<:guide:1175114529701625977>";
 // pub const MEMBER: &str = "<:member:1175114506465198171>";
 // pub const MEMBER: &str = "<:member:1175114506465198171>";
 // pub const MEMBER: &str = "<:member:1175114506465198171>";
+
+/// Parse an emoji written in a message or command option. Custom emoji are written as `<:name:id>` or `<a:name:id>`, anything else is treated as a unicode emoji.
+pub fn parse_emoji(emoji: &str) -> Option<twilight_model::channel::message::ReactionType> {
+    use twilight_model::{channel::message::ReactionType, id::Id};
+
+    let emoji = emoji.trim();
+    if let Some(custom) = emoji.strip_prefix('<').and_then(|x| x.strip_suffix('>')) {
+        let mut parts = custom.split(':');
+        let animated = parts.next()? == "a";
+        let name = parts.next().filter(|x| !x.is_empty()).map(|x| x.to_owned());
+        let id = parts.next()?.parse().ok().and_then(Id::new_checked)?;
+        return Some(ReactionType::Custom { animated, id, name });
+    }
+
+    match emoji.is_empty() || emoji.is_ascii() || emoji.contains(char::is_whitespace) {
+        true => None,
+        false => Some(ReactionType::Unicode { name: emoji.to_owned() }),
+    }
+}
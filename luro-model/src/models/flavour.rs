@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::config::Error;
+
+/// Where the flavour text pools are loaded from. Each file is a pool named after the file, such as `muzzle.toml`.
+pub const FLAVOUR_DIRECTORY: &str = "./config/flavour";
+/// Guild entries with a score at or below this are no longer picked
+pub const HIDDEN_SCORE: i64 = -3;
+
+/// The contents of a pool file, either `entries = [...]` in TOML or `{ "entries": [...] }` in JSON
+#[derive(Debug, Default, ::serde::Deserialize, ::serde::Serialize)]
+struct FlavourFile {
+    #[serde(default)]
+    entries: Vec<String>,
+}
+
+/// The flavour text shipped with the bot, loaded from [FLAVOUR_DIRECTORY]
+#[derive(Debug, Default, Clone)]
+pub struct FlavourPools {
+    pools: HashMap<String, Vec<String>>,
+}
+
+impl FlavourPools {
+    /// Load every `.toml` and `.json` file in the directory as a pool. A missing directory results in no pools.
+    pub async fn load(directory: &str) -> Result<Self, Error> {
+        let mut pools = HashMap::new();
+
+        let mut files = match tokio::fs::read_dir(directory).await {
+            Ok(files) => files,
+            Err(why) if why.kind() == std::io::ErrorKind::NotFound => {
+                tracing::warn!("No flavour text directory found at {directory}, flavour text will use fallbacks");
+                return Ok(Self { pools });
+            }
+            Err(why) => return Err(why.into()),
+        };
+
+        while let Some(file) = files.next_entry().await? {
+            let path = file.path();
+            let (Some(name), Some(extension)) = (path.file_stem().and_then(|x| x.to_str()), path.extension()) else {
+                continue;
+            };
+
+            let contents = tokio::fs::read_to_string(&path).await?;
+            let flavour_file = match extension.to_str() {
+                Some("toml") => toml::from_str::<FlavourFile>(&contents)?,
+                Some("json") => serde_json::from_str::<FlavourFile>(&contents)?,
+                _ => continue,
+            };
+
+            tracing::debug!("Loaded flavour pool `{name}` with {} entries", flavour_file.entries.len());
+            pools.insert(name.to_owned(), flavour_file.entries);
+        }
+
+        Ok(Self { pools })
+    }
+
+    /// The entries of a pool, which is empty if the pool does not exist
+    pub fn pool(&self, name: &str) -> &[String] {
+        self.pools.get(name).map(|x| x.as_slice()).unwrap_or_default()
+    }
+
+    /// The names of all loaded pools, sorted alphabetically
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self.pools.keys().map(|x| x.as_str()).collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// The total number of entries across all pools
+    pub fn len(&self) -> usize {
+        self.pools.values().map(|x| x.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An entry a guild added to one of its pools
+#[derive(Clone, Debug)]
+pub struct FlavourEntry {
+    /// The entry ID, set by the database when the entry is added
+    pub id: i64,
+    pub guild_id: Id<GuildMarker>,
+    pub pool: String,
+    pub content: String,
+    pub added_by: Id<UserMarker>,
+    /// Disabled entries are kept, but never picked
+    pub disabled: bool,
+    /// Upvotes minus downvotes
+    pub score: i64,
+}
+
+impl FlavourEntry {
+    pub fn new(guild_id: Id<GuildMarker>, pool: String, content: String, added_by: Id<UserMarker>) -> Self {
+        Self {
+            id: 0,
+            guild_id,
+            pool,
+            content,
+            added_by,
+            disabled: false,
+            score: 0,
+        }
+    }
+
+    /// True if this entry can be picked. Entries voted down to [HIDDEN_SCORE] are skipped.
+    pub fn pickable(&self) -> bool {
+        !self.disabled && self.score > HIDDEN_SCORE
+    }
+
+    /// How likely this entry is to be picked, compared to a built in entry with a weight of one
+    pub fn weight(&self) -> u64 {
+        self.score.max(0) as u64 + 1
+    }
+}
+
+/// Replace `<author>` and `<user>` in flavour text with mentions
+pub fn render(text: &str, author_id: Id<UserMarker>, user_id: Option<Id<UserMarker>>) -> String {
+    let text = text.replace("<author>", &format!("<@{author_id}>"));
+    match user_id {
+        Some(user_id) => text.replace("<user>", &format!("<@{user_id}>")),
+        None => text,
+    }
+}
@@ -1,8 +1,71 @@
+/// Perceptual hashes within this many bits of each other are treated as the same image
+pub const IMAGE_DUPLICATE_DISTANCE: i32 = 6;
+
+/// Where an image is in the review queue
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImageStatus {
+    /// Submitted and waiting for staff to review it
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ImageStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Rejected => "rejected",
+        }
+    }
+}
+
+impl std::fmt::Display for ImageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for ImageStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "approved" => Self::Approved,
+            "rejected" => Self::Rejected,
+            _ => Self::Pending,
+        }
+    }
+}
+
+/// An image in the community library
+#[derive(Clone, Debug)]
 pub struct Image {
     pub img_id: i64,
     pub name: String,
     pub nsfw: bool,
+    /// Who submitted the image
     pub owner_id: i64,
     pub source: Option<String>,
     pub url: String,
+    /// Who made the image, if known
+    pub artist: Option<String>,
+    /// Lowercase tags, used to filter random images
+    pub tags: Vec<String>,
+    pub status: ImageStatus,
+    /// The staff member who approved or rejected the image
+    pub reviewed_by: Option<i64>,
+    /// A 64 bit difference hash, used to find duplicates
+    pub phash: Option<i64>,
+}
+
+/// Turn user input into tags, lowercase and with spaces replaced by underscores. Tags are separated by commas.
+pub fn parse_image_tags(tags: &str) -> Vec<String> {
+    let mut tags = tags
+        .split(',')
+        .map(|tag| tag.split_whitespace().collect::<Vec<_>>().join("_").to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>();
+    tags.sort_unstable();
+    tags.dedup();
+    tags
 }
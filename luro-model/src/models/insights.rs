@@ -0,0 +1,51 @@
+use time::Date;
+
+/// How many days of insights can be shown at once
+pub const INSIGHTS_MAX_DAYS: i64 = 90;
+
+/// A member joining or leaving a guild
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemberEventKind {
+    Join,
+    Leave,
+}
+
+impl MemberEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Join => "join",
+            Self::Leave => "leave",
+        }
+    }
+}
+
+/// A daily snapshot of a guild
+#[derive(Clone, Debug)]
+pub struct InsightsDay {
+    pub day: Date,
+    /// Members in the guild at the end of the day
+    pub members: i64,
+    pub joins: i64,
+    pub leaves: i64,
+    pub messages: i64,
+    /// Members who sent at least one message
+    pub active_members: i64,
+}
+
+/// How many new members were still around a number of days after joining
+#[derive(Clone, Debug, Default)]
+pub struct MemberRetention {
+    pub days: i64,
+    pub joined: i64,
+    pub retained: i64,
+}
+
+impl MemberRetention {
+    /// The percentage of new members that stayed, if anyone joined
+    pub fn percentage(&self) -> Option<f64> {
+        match self.joined {
+            0 => None,
+            joined => Some(self.retained as f64 / joined as f64 * 100.0),
+        }
+    }
+}
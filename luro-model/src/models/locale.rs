@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use twilight_model::application::command::{Command, CommandOption, CommandOptionType};
+
+use crate::config::Error;
+
+/// Where the message catalogs are loaded from. Each file is named after a Discord locale, such as `de.toml` or `es-ES.toml`.
+pub const LOCALE_DIRECTORY: &str = "./config/locales";
+/// The locale used when a message is missing from the user's locale. Commands are registered in this locale.
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// Messages loaded from [LOCALE_DIRECTORY], keyed by locale and then by message key.
+///
+/// Catalogs are TOML files where nested tables are joined with dots, so `[responses.ping]` with `pong = "..."` is the
+/// key `responses.ping.pong`. Commands are localized from `commands.<command>.name` and `commands.<command>.description`,
+/// with subcommands and options nested below, such as `commands.quote.get.options.id.description`.
+#[derive(Debug, Default, Clone)]
+pub struct Locales {
+    catalogs: HashMap<String, HashMap<String, String>>,
+}
+
+impl Locales {
+    /// Load every `.toml` file in the directory as a catalog. A missing directory results in no catalogs.
+    pub async fn load(directory: &str) -> Result<Self, Error> {
+        let mut catalogs = HashMap::new();
+
+        let mut files = match tokio::fs::read_dir(directory).await {
+            Ok(files) => files,
+            Err(why) if why.kind() == std::io::ErrorKind::NotFound => {
+                tracing::warn!("No locale directory found at {directory}, responses will use their message keys");
+                return Ok(Self { catalogs });
+            }
+            Err(why) => return Err(why.into()),
+        };
+
+        while let Some(file) = files.next_entry().await? {
+            let path = file.path();
+            let (Some(locale), Some("toml")) = (path.file_stem().and_then(|x| x.to_str()), path.extension().and_then(|x| x.to_str()))
+            else {
+                continue;
+            };
+
+            let contents = tokio::fs::read_to_string(&path).await?;
+            let mut catalog = HashMap::new();
+            flatten(String::new(), toml::from_str::<toml::Table>(&contents)?, &mut catalog);
+
+            tracing::debug!("Loaded locale `{locale}` with {} messages", catalog.len());
+            catalogs.insert(locale.to_owned(), catalog);
+        }
+
+        Ok(Self { catalogs })
+    }
+
+    /// Get a message from a locale's catalog. If there is no catalog for the exact locale, such as `es-419`, a catalog
+    /// for the same language is used instead.
+    pub fn get(&self, locale: &str, key: &str) -> Option<&str> {
+        let catalog = match self.catalogs.get(locale) {
+            Some(catalog) => catalog,
+            None => {
+                let wanted = language(locale);
+                self.catalogs
+                    .iter()
+                    .filter(|(name, _)| language(name) == wanted)
+                    .min_by_key(|(name, _)| *name)?
+                    .1
+            }
+        };
+
+        catalog.get(key).map(|x| x.as_str())
+    }
+
+    /// Get a message from the first of the locales that has it, then from [DEFAULT_LOCALE].
+    /// Returns the key itself if no catalog has the message, so a missing message is easy to spot.
+    pub fn text(&self, locales: &[&str], key: &str) -> String {
+        locales
+            .iter()
+            .chain([&DEFAULT_LOCALE])
+            .find_map(|locale| self.get(locale, key))
+            .unwrap_or(key)
+            .to_owned()
+    }
+
+    /// The names of all loaded locales, sorted alphabetically
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = self.catalogs.keys().map(|x| x.as_str()).collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    /// The translations of a message in every locale apart from [DEFAULT_LOCALE], or `None` if it has not been translated
+    pub fn localizations(&self, key: &str) -> Option<HashMap<String, String>> {
+        let localizations = self
+            .catalogs
+            .iter()
+            .filter(|(locale, _)| *locale != DEFAULT_LOCALE)
+            .filter_map(|(locale, catalog)| catalog.get(key).map(|message| (locale.clone(), message.clone())))
+            .collect::<HashMap<_, _>>();
+
+        match localizations.is_empty() {
+            true => None,
+            false => Some(localizations),
+        }
+    }
+
+    /// Add the name and description localizations from the catalogs to commands before they are registered.
+    /// Localizations already set on a command are kept unless a catalog replaces them.
+    pub fn localize_commands(&self, commands: &mut [Command]) {
+        for command in commands {
+            let key = format!("commands.{}", command.name);
+            if let Some(names) = self.names_for(&key) {
+                command.name_localizations.get_or_insert_with(HashMap::new).extend(names);
+            }
+            if let Some(descriptions) = self.localizations(&format!("{key}.description")) {
+                command
+                    .description_localizations
+                    .get_or_insert_with(HashMap::new)
+                    .extend(descriptions);
+            }
+
+            self.localize_options(&key, &mut command.options);
+        }
+    }
+
+    /// Localize options, subcommands and subcommand groups below the command with the given key
+    fn localize_options(&self, parent: &str, options: &mut [CommandOption]) {
+        for option in options {
+            let key = match matches!(option.kind, CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup) {
+                true => format!("{parent}.{}", option.name),
+                false => format!("{parent}.options.{}", option.name),
+            };
+
+            if let Some(names) = self.names_for(&key) {
+                option.name_localizations.get_or_insert_with(HashMap::new).extend(names);
+            }
+            if let Some(descriptions) = self.localizations(&format!("{key}.description")) {
+                option
+                    .description_localizations
+                    .get_or_insert_with(HashMap::new)
+                    .extend(descriptions);
+            }
+
+            if let Some(options) = &mut option.options {
+                self.localize_options(&key, options);
+            }
+        }
+    }
+
+    /// Name localizations, leaving out any Discord would reject
+    fn names_for(&self, key: &str) -> Option<HashMap<String, String>> {
+        let mut names = self.localizations(&format!("{key}.name"))?;
+        names.retain(|locale, name| {
+            let valid = valid_name(name);
+            if !valid {
+                tracing::warn!(
+                    "Skipping the `{locale}` name `{name}` for `{key}`, names must be 1 to 32 lowercase characters without spaces"
+                );
+            }
+            valid
+        });
+
+        match names.is_empty() {
+            true => None,
+            false => Some(names),
+        }
+    }
+}
+
+/// Replace `{name}` placeholders in a message with their values
+pub fn format(message: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    args.iter().fold(message.to_owned(), |message, (name, value)| {
+        message.replace(&format!("{{{name}}}"), &value.to_string())
+    })
+}
+
+/// The language of a locale, such as `es` for `es-ES`
+fn language(locale: &str) -> &str {
+    locale.split_once('-').map(|(language, _)| language).unwrap_or(locale)
+}
+
+/// Discord only accepts lowercase command and option names of 1 to 32 characters without whitespace
+fn valid_name(name: &str) -> bool {
+    (1..=32).contains(&name.chars().count()) && !name.chars().any(|x| x.is_whitespace() || x.is_uppercase())
+}
+
+/// Join nested tables into dotted keys, keeping only string values
+fn flatten(prefix: String, table: toml::Table, catalog: &mut HashMap<String, String>) {
+    for (key, value) in table {
+        let key = match prefix.is_empty() {
+            true => key,
+            false => format!("{prefix}.{key}"),
+        };
+
+        match value {
+            toml::Value::String(message) => {
+                catalog.insert(key, message);
+            }
+            toml::Value::Table(table) => flatten(key, table, catalog),
+            _ => tracing::warn!("Skipping the locale message `{key}`, messages must be strings"),
+        }
+    }
+}
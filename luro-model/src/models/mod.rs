@@ -1,11 +1,25 @@
+pub mod ai;
+pub mod auto_role;
 pub mod character;
 pub mod command;
+pub mod command_policy;
+pub mod command_registration;
 pub mod config;
 pub mod context;
+pub mod database_sync;
+pub mod e621;
 pub mod emoji;
+pub mod flavour;
 pub mod gender;
 pub mod guild;
 pub mod image;
+pub mod insights;
 pub mod interaction;
+pub mod locale;
 pub mod message;
+pub mod music;
+pub mod quote;
+pub mod role_menu;
+pub mod staff;
 pub mod user;
+pub mod word_stats;
@@ -0,0 +1,174 @@
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+const DEFAULT_LAVALINK_HOST: &str = "127.0.0.1:2333";
+const DEFAULT_LAVALINK_PASSWORD: &str = "youshallnotpass";
+const DEFAULT_VOLUME: i64 = 100;
+
+/// Configuration for the music module, loaded from the `[music]` table of the configuration file.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct MusicConfig {
+    /// The address of the Lavalink node, such as `127.0.0.1:2333`
+    #[serde(default = "default_lavalink_host")]
+    pub lavalink_host: String,
+    /// The password set in the Lavalink node's `application.yml`
+    #[serde(default = "default_lavalink_password")]
+    pub lavalink_password: String,
+}
+
+impl Default for MusicConfig {
+    fn default() -> Self {
+        Self {
+            lavalink_host: default_lavalink_host(),
+            lavalink_password: default_lavalink_password(),
+        }
+    }
+}
+
+fn default_lavalink_host() -> String {
+    DEFAULT_LAVALINK_HOST.to_owned()
+}
+
+fn default_lavalink_password() -> String {
+    DEFAULT_LAVALINK_PASSWORD.to_owned()
+}
+
+/// What happens when a track finishes playing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Move on to the next track, stopping at the end of the queue
+    #[default]
+    Off,
+    /// Play the current track again
+    Track,
+    /// Move the finished track to the end of the queue
+    Queue,
+}
+
+impl LoopMode {
+    /// The next loop mode, used for cycling through them with a button
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Track,
+            Self::Track => Self::Queue,
+            Self::Queue => Self::Off,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Track => "track",
+            Self::Queue => "queue",
+        }
+    }
+}
+
+impl std::fmt::Display for LoopMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for LoopMode {
+    fn from(value: &str) -> Self {
+        match value {
+            "track" => Self::Track,
+            "queue" => Self::Queue,
+            _ => Self::Off,
+        }
+    }
+}
+
+/// A track waiting in, or playing from, a guild's queue
+#[derive(Clone, Debug)]
+pub struct QueuedTrack {
+    /// The track as encoded by Lavalink, used to play it
+    pub encoded: String,
+    pub title: String,
+    pub author: String,
+    pub uri: Option<String>,
+    /// The length of the track in milliseconds
+    pub length: i64,
+    pub requested_by: Id<UserMarker>,
+}
+
+/// A guild's music queue. The first track in the queue is the one currently playing.
+#[derive(Clone, Debug)]
+pub struct MusicQueue {
+    pub guild_id: Id<GuildMarker>,
+    /// The voice channel the bot is playing in
+    pub voice_channel_id: Option<Id<ChannelMarker>>,
+    /// The channel the now playing message is sent to
+    pub text_channel_id: Option<Id<ChannelMarker>>,
+    /// The last now playing message, replaced when the track changes
+    pub now_playing_message_id: Option<Id<MessageMarker>>,
+    pub loop_mode: LoopMode,
+    pub volume: i64,
+    pub paused: bool,
+    /// How far into the current track playback was, in milliseconds. Used to resume after a restart.
+    pub position: i64,
+    pub tracks: Vec<QueuedTrack>,
+}
+
+impl MusicQueue {
+    /// An empty queue for a guild
+    pub fn new(guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            guild_id,
+            voice_channel_id: None,
+            text_channel_id: None,
+            now_playing_message_id: None,
+            loop_mode: LoopMode::Off,
+            volume: DEFAULT_VOLUME,
+            paused: false,
+            position: 0,
+            tracks: vec![],
+        }
+    }
+
+    /// The track that is currently playing
+    pub fn current(&self) -> Option<&QueuedTrack> {
+        self.tracks.first()
+    }
+
+    /// Move on from the current track, respecting the loop mode unless `skip` is set. Returns the track to play next.
+    pub fn advance(&mut self, skip: bool) -> Option<&QueuedTrack> {
+        self.position = 0;
+
+        if self.tracks.is_empty() {
+            return None;
+        }
+
+        match (self.loop_mode, skip) {
+            (LoopMode::Track, false) => {}
+            (LoopMode::Queue, _) => self.tracks.rotate_left(1),
+            _ => {
+                self.tracks.remove(0);
+            }
+        }
+
+        self.tracks.first()
+    }
+
+    /// Remove a track by its position in the queue, where `0` is the current track
+    pub fn remove(&mut self, index: usize) -> Option<QueuedTrack> {
+        match index < self.tracks.len() {
+            true => Some(self.tracks.remove(index)),
+            false => None,
+        }
+    }
+
+    /// Move a track to a new position in the queue. Returns false if either position is out of bounds.
+    pub fn move_track(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.tracks.len() || to >= self.tracks.len() {
+            return false;
+        }
+
+        let track = self.tracks.remove(from);
+        self.tracks.insert(to, track);
+        true
+    }
+}
@@ -0,0 +1,88 @@
+use twilight_model::{
+    channel::Message,
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+};
+
+/// The emoji that counts towards a message being added to the starboard
+pub const STARBOARD_EMOJI: &str = "⭐";
+const DEFAULT_STARBOARD_THRESHOLD: i32 = 3;
+
+/// A saved message. The content is kept with the quote, so it can be searched and survives the message being deleted.
+#[derive(Clone, Debug)]
+pub struct Quote {
+    /// The quote ID, set by the database when the quote is added
+    pub id: i64,
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub channel_id: Id<ChannelMarker>,
+    pub message_id: Id<MessageMarker>,
+    /// Who said the quote. Only unknown for old quotes whose message was never recorded.
+    pub author_id: Option<Id<UserMarker>>,
+    /// Who saved the quote. This is the bot itself for quotes added by the starboard.
+    pub added_by: Id<UserMarker>,
+    pub content: String,
+    /// Quotes from NSFW channels are only shown in NSFW channels
+    pub nsfw: bool,
+    /// The repost of this quote on the starboard, if it reached the threshold
+    pub starboard_message_id: Option<Id<MessageMarker>>,
+}
+
+impl Quote {
+    /// A new quote of a message, not yet saved to the database. Messages fetched over HTTP don't include the guild, so it is passed separately.
+    pub fn new(guild_id: Option<Id<GuildMarker>>, message: &Message, added_by: Id<UserMarker>, nsfw: bool) -> Self {
+        Self {
+            id: 0,
+            guild_id,
+            channel_id: message.channel_id,
+            message_id: message.id,
+            author_id: Some(message.author.id),
+            added_by,
+            content: message.content.clone(),
+            nsfw,
+            starboard_message_id: None,
+        }
+    }
+
+    /// A link to the original message
+    pub fn link(&self) -> String {
+        match self.guild_id {
+            Some(guild_id) => format!("https://discord.com/channels/{guild_id}/{}/{}", self.channel_id, self.message_id),
+            None => format!("https://discord.com/channels/@me/{}/{}", self.channel_id, self.message_id),
+        }
+    }
+}
+
+/// Filters for searching a guild's quotes
+#[derive(Clone, Debug, Default)]
+pub struct QuoteSearch {
+    /// Full text search over the quote content. Supports `"quoted phrases"`, `or` and `-excluded` words.
+    pub query: Option<String>,
+    /// Only return quotes said by this user
+    pub author_id: Option<Id<UserMarker>>,
+    /// Include NSFW quotes in the results
+    pub nsfw: bool,
+    /// The maximum number of quotes to return
+    pub limit: i64,
+}
+
+/// A guild's starboard settings
+#[derive(Clone, Debug)]
+pub struct Starboard {
+    pub guild_id: Id<GuildMarker>,
+    /// Where quotes that reach the threshold are reposted
+    pub channel_id: Id<ChannelMarker>,
+    /// How many stars a message needs to be added to the starboard
+    pub threshold: i32,
+}
+
+impl Starboard {
+    pub fn new(guild_id: Id<GuildMarker>, channel_id: Id<ChannelMarker>) -> Self {
+        Self {
+            guild_id,
+            channel_id,
+            threshold: DEFAULT_STARBOARD_THRESHOLD,
+        }
+    }
+}
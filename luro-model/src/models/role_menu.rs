@@ -0,0 +1,98 @@
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker},
+    Id,
+};
+
+/// The most roles a menu can offer. Both select menus and five rows of five buttons top out here.
+pub const ROLE_MENU_MAX_OPTIONS: usize = 25;
+
+/// How a role menu is presented
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoleMenuStyle {
+    /// A select menu listing every role
+    #[default]
+    Select,
+    /// A button per role, which toggles it
+    Buttons,
+}
+
+impl RoleMenuStyle {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Select => "select",
+            Self::Buttons => "buttons",
+        }
+    }
+}
+
+impl std::fmt::Display for RoleMenuStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for RoleMenuStyle {
+    fn from(value: &str) -> Self {
+        match value {
+            "buttons" => Self::Buttons,
+            _ => Self::Select,
+        }
+    }
+}
+
+/// A role offered by a menu
+#[derive(Clone, Debug)]
+pub struct RoleMenuOption {
+    pub role_id: Id<RoleMarker>,
+    /// Shown instead of the role name
+    pub label: Option<String>,
+    /// A unicode emoji, or a custom emoji written as `<:name:id>`
+    pub emoji: Option<String>,
+    /// Shown under the role in select menus
+    pub description: Option<String>,
+}
+
+/// A named, self-assignable role menu
+#[derive(Clone, Debug)]
+pub struct RoleMenu {
+    /// The menu ID, set by the database when the menu is created
+    pub id: i64,
+    pub guild_id: Id<GuildMarker>,
+    /// The name used to refer to the menu in commands, unique per guild
+    pub name: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub style: RoleMenuStyle,
+    /// Members can hold only one of the menu's roles at a time
+    pub single: bool,
+    /// Members need every one of these roles to use the menu
+    pub required_roles: Vec<Id<RoleMarker>>,
+    /// Where the menu is posted, if it has been
+    pub channel_id: Option<Id<ChannelMarker>>,
+    pub message_id: Option<Id<MessageMarker>>,
+    /// The roles offered, in the order they are shown
+    pub options: Vec<RoleMenuOption>,
+}
+
+impl RoleMenu {
+    pub fn new(guild_id: Id<GuildMarker>, name: String) -> Self {
+        Self {
+            id: 0,
+            guild_id,
+            name,
+            title: None,
+            description: None,
+            style: Default::default(),
+            single: false,
+            required_roles: vec![],
+            channel_id: None,
+            message_id: None,
+            options: vec![],
+        }
+    }
+
+    /// The option for a role, if the menu offers it
+    pub fn option(&self, role_id: Id<RoleMarker>) -> Option<&RoleMenuOption> {
+        self.options.iter().find(|option| option.role_id == role_id)
+    }
+}
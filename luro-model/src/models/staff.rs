@@ -0,0 +1,73 @@
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+use crate::user::UserPermissions;
+
+/// Staff set in the configuration. They are always staff, and can only be removed by editing the configuration.
+///
+/// Further staff are added with `/owner staff add`, which are stored in the database.
+#[derive(Debug, Default, Clone, ::serde::Deserialize, ::serde::Serialize)]
+pub struct StaffConfig {
+    /// Users that own the bot. If empty, the owner of the application (or the members of its team) are used instead
+    #[serde(default)]
+    pub owners: Vec<Id<UserMarker>>,
+    /// Users that can use the staff commands, but can't manage owners
+    #[serde(default)]
+    pub administrators: Vec<Id<UserMarker>>,
+    /// A channel that changes to the staff are posted in
+    pub audit_channel: Option<Id<ChannelMarker>>,
+}
+
+impl StaffConfig {
+    /// The permissions the configuration gives a user, if any
+    pub fn permissions(&self, user_id: Id<UserMarker>) -> Option<UserPermissions> {
+        if self.owners.contains(&user_id) {
+            return Some(UserPermissions::Owner);
+        }
+
+        match self.administrators.contains(&user_id) {
+            true => Some(UserPermissions::Administrator),
+            false => None,
+        }
+    }
+}
+
+/// A change made to the staff
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StaffAction {
+    Add,
+    Remove,
+}
+
+impl StaffAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Remove => "remove",
+        }
+    }
+
+    pub fn from_name(action: &str) -> Option<Self> {
+        match action {
+            "add" => Some(Self::Add),
+            "remove" => Some(Self::Remove),
+            _ => None,
+        }
+    }
+}
+
+/// A record of who changed the staff, and how
+#[derive(Clone, Debug)]
+pub struct StaffAuditEntry {
+    /// The staff member that made the change
+    pub actor_id: Id<UserMarker>,
+    /// The user that was added or removed
+    pub target_id: Id<UserMarker>,
+    pub action: StaffAction,
+    /// The permissions that were given or taken away
+    pub permissions: UserPermissions,
+    pub created_at: OffsetDateTime,
+}
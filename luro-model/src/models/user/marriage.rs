@@ -1,4 +1,11 @@
-use twilight_model::id::{marker::UserMarker, Id};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+/// How many partners someone may have at once, unless the guild sets its own limit
+pub const DEFAULT_PARTNER_LIMIT: i32 = 5;
 
 pub struct Marriage {
     /// Person who initiated the marriage
@@ -11,4 +18,84 @@ pub struct Marriage {
     pub rejected: bool,
     /// What was the reason for marrying
     pub reason: String,
+    /// The guild the proposal was made in. Anniversaries are posted here.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// The channel the proposal was made in, used for anniversaries if the guild has not set a channel
+    pub channel_id: Option<Id<ChannelMarker>>,
+    /// When the proposal was accepted. Unset while the proposal is waiting for an answer.
+    pub married_at: Option<OffsetDateTime>,
+}
+
+impl Marriage {
+    /// A new proposal, waiting for the proposee to answer
+    pub fn proposal(
+        proposer_id: Id<UserMarker>,
+        proposee_id: Id<UserMarker>,
+        reason: String,
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Option<Id<ChannelMarker>>,
+    ) -> Self {
+        Self {
+            proposer_id,
+            proposee_id,
+            divorced: false,
+            rejected: false,
+            reason,
+            guild_id,
+            channel_id,
+            married_at: None,
+        }
+    }
+
+    /// True if the proposal was accepted and they have not divorced
+    pub fn married(&self) -> bool {
+        self.married_at.is_some() && !self.divorced && !self.rejected
+    }
+
+    /// True if the proposal is still waiting for an answer
+    pub fn pending(&self) -> bool {
+        self.married_at.is_none() && !self.divorced && !self.rejected
+    }
+
+    /// The other person in the marriage
+    pub fn partner_of(&self, user_id: Id<UserMarker>) -> Id<UserMarker> {
+        match self.proposer_id == user_id {
+            true => self.proposee_id,
+            false => self.proposer_id,
+        }
+    }
+}
+
+/// A parent and child relation, created when someone adopts another user
+pub struct Adoption {
+    pub parent_id: Id<UserMarker>,
+    pub child_id: Id<UserMarker>,
+    /// The guild the adoption was made in
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// When the child accepted. Unset while the adoption is waiting for an answer.
+    pub adopted_at: Option<OffsetDateTime>,
+}
+
+/// A guild's settings for the marriage system
+pub struct MarriageSettings {
+    pub guild_id: Id<GuildMarker>,
+    /// How many partners someone may have at once when proposing in this guild. `0` removes the limit.
+    pub partner_limit: i32,
+    /// Where anniversaries are posted. Falls back to the channel the proposal was made in.
+    pub anniversary_channel_id: Option<Id<ChannelMarker>>,
+}
+
+impl MarriageSettings {
+    pub fn new(guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            guild_id,
+            partner_limit: DEFAULT_PARTNER_LIMIT,
+            anniversary_channel_id: None,
+        }
+    }
+
+    /// True if someone with this many partners may take another
+    pub fn allows_partner(&self, partners: usize) -> bool {
+        self.partner_limit == 0 || partners < self.partner_limit as usize
+    }
 }
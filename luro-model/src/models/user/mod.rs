@@ -12,7 +12,7 @@ mod user_context;
 mod user_permissions;
 mod word_count;
 
-pub use marriage::Marriage;
+pub use marriage::{Adoption, Marriage, MarriageSettings, DEFAULT_PARTNER_LIMIT};
 pub use member_context::MemberContext;
 pub use user_context::UserContext;
 pub use user_permissions::UserPermissions;
@@ -56,10 +56,7 @@ impl From<User> for UserContext {
     fn from(twilight_user: User) -> Self {
         Self {
             gender: None,
-            user_type: match crate::BOT_OWNERS.contains(&twilight_user.id) {
-                true => UserPermissions::Owner,
-                false => UserPermissions::User,
-            },
+            user_type: UserPermissions::User,
             sexuality: None,
             twilight_user,
         }
@@ -0,0 +1,191 @@
+use std::fmt::Write;
+
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandOption, CreateOption};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+/// Common words that are left out of the most used words list
+pub const STOPWORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be", "because", "been", "but", "by", "can",
+    "could", "did", "do", "does", "dont", "for", "from", "get", "got", "had", "has", "have", "he", "her", "him", "his", "how", "i", "if",
+    "im", "in", "into", "is", "it", "its", "just", "like", "me", "my", "no", "not", "of", "on", "one", "or", "our", "out", "she", "so",
+    "some", "that", "thats", "the", "their", "them", "then", "there", "they", "this", "to", "up", "us", "was", "we", "were", "what",
+    "when", "which", "who", "will", "with", "would", "you", "your",
+];
+
+/// The period of time statistics are gathered over
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, CommandOption, CreateOption)]
+pub enum StatsWindow {
+    #[option(name = "Day - The last 24 hours", value = "day")]
+    Day,
+    #[option(name = "Week - The last 7 days", value = "week")]
+    Week,
+    #[option(name = "Month - The last 30 days", value = "month")]
+    Month,
+    #[default]
+    #[option(name = "All time - Everything I have seen", value = "all")]
+    All,
+}
+
+impl StatsWindow {
+    /// The earliest message included in the window, or `None` for all time
+    pub fn since(&self) -> Option<OffsetDateTime> {
+        let length = match self {
+            Self::Day => Duration::days(1),
+            Self::Week => Duration::weeks(1),
+            Self::Month => Duration::days(30),
+            Self::All => return None,
+        };
+
+        Some(OffsetDateTime::now_utc() - length)
+    }
+
+    /// The `date_trunc` field each point of the time series covers
+    pub fn bucket(&self) -> &'static str {
+        match self {
+            Self::Day => "hour",
+            Self::Week | Self::Month => "day",
+            Self::All => "month",
+        }
+    }
+}
+
+impl std::fmt::Display for StatsWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "the last day"),
+            Self::Week => write!(f, "the last week"),
+            Self::Month => write!(f, "the last month"),
+            Self::All => write!(f, "all time"),
+        }
+    }
+}
+
+/// Which messages statistics are gathered from. Unset fields are not filtered on.
+#[derive(Clone, Debug, Default)]
+pub struct StatsScope {
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub channel_id: Option<Id<ChannelMarker>>,
+    pub user_id: Option<Id<UserMarker>>,
+    pub since: Option<OffsetDateTime>,
+}
+
+impl StatsScope {
+    pub fn new(window: StatsWindow) -> Self {
+        Self {
+            since: window.since(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Totals for the messages in a scope
+#[derive(Clone, Debug, Default)]
+pub struct WordTotals {
+    pub messages: i64,
+    pub words: i64,
+    pub unique_words: i64,
+}
+
+/// A single point of a word statistics time series
+#[derive(Clone, Debug)]
+pub struct WordTimelinePoint {
+    pub period: OffsetDateTime,
+    pub messages: i64,
+    pub words: i64,
+}
+
+/// What a leaderboard ranks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, CommandOption, CreateOption)]
+pub enum LeaderboardKind {
+    #[default]
+    #[option(name = "Users - Who has said the most", value = "users")]
+    Users,
+    #[option(name = "Channels - Where the most has been said", value = "channels")]
+    Channels,
+}
+
+/// A user or channel on a leaderboard
+#[derive(Clone, Debug)]
+pub struct LeaderboardEntry {
+    /// The user or channel ID, depending on the [LeaderboardKind]
+    pub id: i64,
+    pub messages: i64,
+    pub words: i64,
+}
+
+/// How many times a word has been used
+#[derive(Clone, Debug)]
+pub struct WordUsage {
+    pub word: String,
+    pub count: i64,
+}
+
+/// Messages sent, by day of the week (Monday first) and hour of the day in UTC
+pub type ActivityHeatmap = [[i64; 24]; 7];
+
+/// Quote a CSV field if it needs it
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_owned(),
+    }
+}
+
+pub fn timeline_csv(points: &[WordTimelinePoint]) -> String {
+    let mut csv = "period,messages,words\n".to_owned();
+    for point in points {
+        let _ = writeln!(
+            csv,
+            "{} {:02}:00,{},{}",
+            point.period.date(),
+            point.period.hour(),
+            point.messages,
+            point.words
+        );
+    }
+    csv
+}
+
+pub fn leaderboard_csv(kind: LeaderboardKind, entries: &[LeaderboardEntry]) -> String {
+    let mut csv = match kind {
+        LeaderboardKind::Users => "user_id,messages,words\n",
+        LeaderboardKind::Channels => "channel_id,messages,words\n",
+    }
+    .to_owned();
+    for entry in entries {
+        let _ = writeln!(csv, "{},{},{}", entry.id, entry.messages, entry.words);
+    }
+    csv
+}
+
+pub fn words_csv(words: &[WordUsage]) -> String {
+    let mut csv = "word,count\n".to_owned();
+    for word in words {
+        let _ = writeln!(csv, "{},{}", csv_field(&word.word), word.count);
+    }
+    csv
+}
+
+pub fn heatmap_csv(heatmap: &ActivityHeatmap) -> String {
+    let mut csv = "day".to_owned();
+    for hour in 0..24 {
+        let _ = write!(csv, ",{hour:02}:00");
+    }
+    csv.push('\n');
+
+    for (day, hours) in ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"]
+        .into_iter()
+        .zip(heatmap)
+    {
+        csv.push_str(day);
+        for count in hours {
+            let _ = write!(csv, ",{count}");
+        }
+        csv.push('\n');
+    }
+    csv
+}
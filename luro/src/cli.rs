@@ -0,0 +1,107 @@
+//! Command line tools, used instead of starting the bot when arguments are passed.
+//!
+//! - `luro commands diff` compares the commands that would be registered against the saved snapshot, without connecting
+//! - `luro commands sync` registers the commands that changed, then saves the snapshot
+//! - `luro commands clear` removes every global command and every command from the known guilds
+//!
+//! Each takes `--config <file>` to pick the configuration and `--snapshot <file>` to pick the snapshot.
+//! Without `--config`, the bot's own configuration is used, as when it starts. Finding it needs the `DISCORD_TOKEN`
+//! environment variable, which `sync` and `clear` always need.
+
+use luro_model::{
+    command_registration::{CommandDiff, CommandSnapshot, COMMAND_SNAPSHOT},
+    config::Config,
+    locale::{Locales, LOCALE_DIRECTORY},
+};
+
+use crate::{gateway::Gateway, models::luro::GatewayError};
+
+const USAGE: &str = "Usage: luro commands <diff|sync|clear> [--config <file>] [--snapshot <file>]";
+
+/// Run the tool picked by the arguments, not including the program name
+pub async fn run(args: &[String]) -> anyhow::Result<()> {
+    let (Some("commands"), Some(action)) = (args.first().map(|x| x.as_str()), args.get(1).map(|x| x.as_str())) else {
+        anyhow::bail!(USAGE)
+    };
+
+    let mut config_file = None;
+    let mut snapshot_file = COMMAND_SNAPSHOT.to_owned();
+    let mut options = args[2..].iter();
+    while let Some(option) = options.next() {
+        match (option.as_str(), options.next()) {
+            ("--config", Some(file)) => config_file = Some(file.clone()),
+            ("--snapshot", Some(file)) => snapshot_file = file.clone(),
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+
+    let previous = CommandSnapshot::load(&snapshot_file).await?;
+    match action {
+        "diff" => {
+            let config = load_config(config_file).await?;
+            let wanted = wanted_commands(&config).await?;
+            print_diffs(&wanted.diff(&previous));
+        }
+        "sync" | "clear" => {
+            let twilight = twilight_client()?;
+            let application = twilight.current_user_application().await?.model().await?;
+            let config = load_config(config_file).await?;
+
+            let wanted = match action {
+                "sync" => wanted_commands(&config).await?,
+                _ => CommandSnapshot {
+                    global: vec![],
+                    guilds: config
+                        .registration
+                        .guild_commands
+                        .keys()
+                        .map(|guild_id| (*guild_id, vec![]))
+                        .collect(),
+                },
+            };
+
+            let (registered, diffs) = Gateway::sync_commands(&twilight.interaction(application.id), &wanted, &previous).await?;
+            registered.save(&snapshot_file).await?;
+            print_diffs(&diffs);
+        }
+        _ => anyhow::bail!(USAGE),
+    }
+
+    Ok(())
+}
+
+/// The configuration from `--config`, otherwise `./config/<bot name>.toml` like when the bot starts
+async fn load_config(config_file: Option<String>) -> anyhow::Result<Config> {
+    let config_file = match config_file {
+        Some(file) => file,
+        None => {
+            let current_user = twilight_client()?.current_user().await?.model().await?;
+            format!("./config/{}.toml", current_user.name.to_lowercase())
+        }
+    };
+
+    Ok(Config::fetch(&config_file).await?)
+}
+
+fn twilight_client() -> Result<twilight_http::Client, GatewayError> {
+    match std::env::var("DISCORD_TOKEN") {
+        Ok(token) => Ok(twilight_http::Client::new(token)),
+        Err(_) => Err(GatewayError::NoToken),
+    }
+}
+
+async fn wanted_commands(config: &Config) -> anyhow::Result<CommandSnapshot> {
+    let locales = Locales::load(LOCALE_DIRECTORY).await?;
+    Ok(Gateway::wanted_commands(
+        &crate::commands::default_commands(),
+        &locales,
+        &config.registration,
+    ))
+}
+
+fn print_diffs(diffs: &[CommandDiff]) {
+    match diffs.is_empty() {
+        true => println!("No changes"),
+        false => diffs.iter().for_each(|diff| println!("{diff}")),
+    }
+}
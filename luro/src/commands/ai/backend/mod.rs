@@ -0,0 +1,161 @@
+use std::{collections::VecDeque, time::Duration};
+
+use luro_model::ai::{AiBackend, AiPersona};
+
+mod ollama;
+mod openai;
+
+#[derive(thiserror::Error, Debug)]
+pub enum BackendError {
+    #[error("The model `{0}` is not available on this backend")]
+    ModelUnavailable(String),
+    #[error("The backend did not respond within {0:?}")]
+    Timeout(Duration),
+    #[error("The backend responded with status {0}: {1}")]
+    Status(reqwest::StatusCode, String),
+    #[error("Failed to communicate with the backend")]
+    Http(#[from] reqwest::Error),
+    #[error("Failed to parse a response from the backend")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Who sent a message in the conversation
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+/// A chat-completion backend that can generate a response to a conversation
+pub trait ChatBackend {
+    /// Send the conversation to the backend, returning a stream of the generated response
+    fn chat(
+        &self,
+        http_client: &reqwest::Client,
+        persona: &AiPersona,
+        messages: Vec<ChatMessage>,
+    ) -> impl std::future::Future<Output = Result<ChatStream, BackendError>> + Send;
+}
+
+/// Send the conversation to whichever backend the persona is configured to use
+pub async fn chat(http_client: &reqwest::Client, persona: &AiPersona, messages: Vec<ChatMessage>) -> Result<ChatStream, BackendError> {
+    match persona.backend {
+        AiBackend::Ollama => ollama::Ollama.chat(http_client, persona, messages).await,
+        AiBackend::OpenAi => openai::OpenAi.chat(http_client, persona, messages).await,
+    }
+}
+
+/// Send a request, waiting no longer than the persona's timeout for the backend to start responding
+async fn send(request: reqwest::RequestBuilder, persona: &AiPersona) -> Result<reqwest::Response, BackendError> {
+    let timeout = persona.timeout();
+    let response = match tokio::time::timeout(timeout, request.send()).await {
+        Ok(response) => response?,
+        Err(_) => return Err(BackendError::Timeout(timeout)),
+    };
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    // A 404 can also mean the endpoint is wrong, so only blame the model when the backend says it is missing
+    let body = response.text().await.unwrap_or_default();
+    match model_missing(&body) {
+        true => Err(BackendError::ModelUnavailable(persona.model.clone())),
+        false => Err(BackendError::Status(status, body)),
+    }
+}
+
+/// If an error body says the model does not exist. Ollama says the model is `not found`, OpenAI uses `model_not_found`.
+fn model_missing(body: &str) -> bool {
+    let body = body.to_lowercase();
+    body.contains("model_not_found") || (body.contains("model") && (body.contains("not found") || body.contains("does not exist")))
+}
+
+/// What a backend got out of a single line of its response
+pub enum StreamLine {
+    /// Some more of the response was generated
+    Content(String),
+    /// The line contained nothing of interest, such as a keep-alive
+    Skip,
+    /// The backend has finished generating
+    Done,
+}
+
+/// A streaming response from a backend. Each line of the body is handed to the backend's parser as it arrives.
+pub struct ChatStream {
+    response: reqwest::Response,
+    buffer: Vec<u8>,
+    lines: VecDeque<String>,
+    parse_line: fn(&str) -> Result<StreamLine, BackendError>,
+    timeout: Duration,
+    done: bool,
+}
+
+impl ChatStream {
+    fn new(response: reqwest::Response, timeout: Duration, parse_line: fn(&str) -> Result<StreamLine, BackendError>) -> Self {
+        Self {
+            response,
+            buffer: vec![],
+            lines: VecDeque::new(),
+            parse_line,
+            timeout,
+            done: false,
+        }
+    }
+
+    /// Wait for the next piece of generated content. Returns [None] once the backend has finished.
+    pub async fn next(&mut self) -> Option<Result<String, BackendError>> {
+        loop {
+            if let Some(line) = self.lines.pop_front() {
+                match (self.parse_line)(&line) {
+                    Ok(StreamLine::Content(content)) => return Some(Ok(content)),
+                    Ok(StreamLine::Skip) => continue,
+                    Ok(StreamLine::Done) => {
+                        self.done = true;
+                        self.lines.clear();
+                        return None;
+                    }
+                    Err(why) => return Some(Err(why)),
+                }
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let chunk = match tokio::time::timeout(self.timeout, self.response.chunk()).await {
+                Ok(Ok(Some(chunk))) => chunk,
+                Ok(Ok(None)) => {
+                    // Flush whatever is left, in case the body did not end with a newline
+                    self.done = true;
+                    let remaining = String::from_utf8_lossy(&self.buffer).trim().to_owned();
+                    self.buffer.clear();
+                    if !remaining.is_empty() {
+                        self.lines.push_back(remaining);
+                    }
+                    continue;
+                }
+                Ok(Err(why)) => return Some(Err(why.into())),
+                Err(_) => return Some(Err(BackendError::Timeout(self.timeout))),
+            };
+
+            self.buffer.extend_from_slice(&chunk);
+            while let Some(position) = self.buffer.iter().position(|x| *x == b'\n') {
+                let line = self.buffer.drain(..=position).collect::<Vec<_>>();
+                let line = String::from_utf8_lossy(&line).trim().to_owned();
+                if !line.is_empty() {
+                    self.lines.push_back(line);
+                }
+            }
+        }
+    }
+}
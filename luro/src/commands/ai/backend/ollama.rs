@@ -0,0 +1,68 @@
+use luro_model::ai::AiPersona;
+use serde::{Deserialize, Serialize};
+
+use super::{BackendError, ChatBackend, ChatMessage, ChatStream, StreamLine};
+
+/// A backend using Ollama's streaming `/api/chat` endpoint
+pub struct Ollama;
+
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<RequestOptions>,
+}
+
+#[derive(Debug, Serialize)]
+struct RequestOptions {
+    temperature: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    #[serde(default)]
+    done: bool,
+    message: Option<ResponseMessage>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseMessage {
+    content: String,
+}
+
+impl ChatBackend for Ollama {
+    async fn chat(
+        &self,
+        http_client: &reqwest::Client,
+        persona: &AiPersona,
+        messages: Vec<ChatMessage>,
+    ) -> Result<ChatStream, BackendError> {
+        let request = Request {
+            model: &persona.model,
+            messages,
+            stream: true,
+            options: persona.temperature.map(|temperature| RequestOptions { temperature }),
+        };
+
+        let response = super::send(http_client.post(&persona.endpoint).json(&request), persona).await?;
+        Ok(ChatStream::new(response, persona.timeout(), parse_line))
+    }
+}
+
+/// Ollama sends a JSON object per line
+fn parse_line(line: &str) -> Result<StreamLine, BackendError> {
+    let response = serde_json::from_str::<Response>(line)?;
+
+    if let Some(error) = response.error {
+        return Err(BackendError::Status(reqwest::StatusCode::INTERNAL_SERVER_ERROR, error));
+    }
+
+    Ok(match (response.message, response.done) {
+        (Some(message), _) if !message.content.is_empty() => StreamLine::Content(message.content),
+        (_, true) => StreamLine::Done,
+        _ => StreamLine::Skip,
+    })
+}
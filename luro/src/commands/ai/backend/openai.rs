@@ -0,0 +1,79 @@
+use luro_model::ai::AiPersona;
+use serde::{Deserialize, Serialize};
+
+use super::{BackendError, ChatBackend, ChatMessage, ChatStream, StreamLine};
+
+/// A backend for anything that speaks OpenAI's `/v1/chat/completions`, such as llama.cpp's server, vLLM or LocalAI
+pub struct OpenAi;
+
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    #[serde(default)]
+    choices: Vec<Choice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Choice {
+    delta: Delta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Delta {
+    content: Option<String>,
+}
+
+impl ChatBackend for OpenAi {
+    async fn chat(
+        &self,
+        http_client: &reqwest::Client,
+        persona: &AiPersona,
+        messages: Vec<ChatMessage>,
+    ) -> Result<ChatStream, BackendError> {
+        let request = Request {
+            model: &persona.model,
+            messages,
+            stream: true,
+            temperature: persona.temperature,
+        };
+
+        let mut request = http_client.post(&persona.endpoint).json(&request);
+        if let Some(api_key) = &persona.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = super::send(request, persona).await?;
+        Ok(ChatStream::new(response, persona.timeout(), parse_line))
+    }
+}
+
+/// OpenAI compatible servers stream server-sent events, with each event containing a JSON object
+fn parse_line(line: &str) -> Result<StreamLine, BackendError> {
+    let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+        return Ok(StreamLine::Skip);
+    };
+
+    if data == "[DONE]" {
+        return Ok(StreamLine::Done);
+    }
+
+    let response = serde_json::from_str::<Response>(data)?;
+    let Some(choice) = response.choices.into_iter().next() else {
+        return Ok(StreamLine::Skip);
+    };
+
+    Ok(match (choice.delta.content, choice.finish_reason) {
+        (Some(content), _) if !content.is_empty() => StreamLine::Content(content),
+        (_, Some(_)) => StreamLine::Done,
+        _ => StreamLine::Skip,
+    })
+}
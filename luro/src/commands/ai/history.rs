@@ -0,0 +1,72 @@
+use luro_model::ai::AiHistoryMessage;
+
+use crate::models::message_context::MessageContext;
+
+/// How many messages are fetched from the database before being trimmed down to the token budget
+const HISTORY_LIMIT: i64 = 100;
+
+/// Record the message that invoked the AI, then fetch the channel's conversation trimmed to fit the token budget.
+///
+/// If the author has opted out, their message is still answered but never recorded.
+/// If the database is unavailable, the conversation is just the invoking message.
+pub async fn conversation(framework: &MessageContext, token_budget: usize, opted_out: bool) -> Vec<AiHistoryMessage> {
+    let database = &framework.gateway.database;
+    let message = AiHistoryMessage {
+        channel_id: framework.ctx.channel_id,
+        message_id: framework.ctx.id,
+        author_id: framework.ctx.author.id,
+        assistant: framework.ctx.author.id == framework.gateway.current_user.id,
+        content: framework.ctx.content.clone(),
+    };
+
+    if !opted_out && let Err(why) = database.update_ai_history(&message).await {
+        tracing::warn!(?why, "ai_handler - Failed to record message in the conversation history");
+    }
+
+    let mut history = match database.fetch_ai_history(framework.ctx.channel_id, HISTORY_LIMIT).await {
+        Ok(history) => history,
+        Err(why) => {
+            tracing::warn!(?why, "ai_handler - Failed to fetch conversation history");
+            vec![]
+        }
+    };
+
+    if !history.iter().any(|x| x.message_id == message.message_id) {
+        history.push(message);
+    }
+
+    trim_to_budget(&mut history, token_budget);
+    history
+}
+
+/// Record a response generated by the bot, so that it is remembered in the conversation
+pub async fn record_response(framework: &MessageContext, message: &twilight_model::channel::Message, content: String) {
+    let response = AiHistoryMessage {
+        channel_id: message.channel_id,
+        message_id: message.id,
+        author_id: framework.gateway.current_user.id,
+        assistant: true,
+        content,
+    };
+
+    if let Err(why) = framework.gateway.database.update_ai_history(&response).await {
+        tracing::warn!(?why, "ai_handler - Failed to record response in the conversation history");
+    }
+}
+
+/// Drop the oldest messages until the conversation fits within the token budget. The newest message is always kept.
+fn trim_to_budget(history: &mut Vec<AiHistoryMessage>, token_budget: usize) {
+    let mut tokens = 0;
+    let keep = history
+        .iter()
+        .rev()
+        .take_while(|message| {
+            tokens += message.estimated_tokens();
+            tokens <= token_budget
+        })
+        .count()
+        .max(1);
+
+    let drop = history.len().saturating_sub(keep);
+    history.drain(..drop);
+}
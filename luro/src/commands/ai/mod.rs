@@ -1,12 +1,25 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use futures_util::StreamExt;
-use serde::{Deserialize, Serialize};
+use luro_model::ai::{AiHistoryMessage, AiPersona};
 use twilight_model::http::attachment::Attachment;
 use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::models::message_context::MessageContext;
 
+use self::{
+    backend::{ChatMessage, ChatRole},
+    persona::resolve_persona,
+    rate_limit::RateLimited,
+};
+
+mod backend;
+mod history;
+mod persona;
+mod rate_limit;
+
+pub use backend::BackendError;
+pub use rate_limit::AiRateLimiter;
+
 pub async fn ai_command_handler(framework: &MessageContext) {
     // Handle keyword commands if the bot is not mentioned
     match &framework.ctx.referenced_message {
@@ -23,34 +36,54 @@ pub async fn ai_command_handler(framework: &MessageContext) {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Request {
-    model: String,
-    messages: Vec<Message>,
-}
+pub async fn ai_handler_root(framework: &MessageContext) {
+    let ai_config = &framework.gateway.config.ai;
+    let database = &framework.gateway.database;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct Message {
-    role: String,
-    content: String,
-    images: Option<String>,
-}
+    // Only respond where we have been allowed to
+    if !ai_config.guild_allowed(framework.ctx.guild_id) {
+        return;
+    }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
-struct Response {
-    created_at: String,
-    done: bool,
-    eval_count: Option<i64>,
-    eval_duration: Option<i64>,
-    load_duration: Option<i64>,
-    message: Option<Message>,
-    model: String,
-    prompt_eval_count: Option<i64>,
-    prompt_eval_duration: Option<i64>,
-    total_duration: Option<i64>,
-}
+    let channel = match database.fetch_ai_channel(framework.ctx.channel_id).await {
+        Ok(channel) => channel,
+        Err(why) => {
+            tracing::warn!(?why, "ai_handler - Failed to fetch channel settings, using the guild persona");
+            None
+        }
+    };
+
+    if ai_config.channel_opt_in && !channel.as_ref().is_some_and(|channel| channel.enabled) {
+        return;
+    }
+
+    let nsfw = match database.fetch_channel(framework.ctx.channel_id).await {
+        Ok(channel) => channel.nsfw.unwrap_or_default(),
+        Err(_) => false,
+    };
+
+    let persona = resolve_persona(framework, channel.as_ref(), nsfw).await;
+    if persona.nsfw && ai_config.nsfw_gate && !nsfw {
+        return reply(
+            framework,
+            "Sorry, the model I use in this channel is only available in NSFW channels!",
+        )
+        .await;
+    }
+
+    if let Err(limited) = framework
+        .gateway
+        .ai_rate_limiter
+        .acquire(&ai_config.rate_limits, framework.ctx.author.id, framework.ctx.guild_id)
+        .await
+    {
+        let content = match limited {
+            RateLimited::User(wait) => format!("Slow down! You can talk to me again in `{}` seconds.", wait.as_secs() + 1),
+            RateLimited::Guild => "I'm talking to too many people in this server right now, try again in a bit!".to_owned(),
+        };
+        return reply(framework, &content).await;
+    }
 
-pub async fn ai_handler_root(framework: &MessageContext) {
     // Inform the user that we are actually doing something
     let twilight_message = match framework
         .gateway
@@ -73,36 +106,16 @@ pub async fn ai_handler_root(framework: &MessageContext) {
         }
     };
 
-    let mut message_reference = framework.ctx.reference.clone();
-    let mut messages = vec![];
-
-    messages.push((
-        framework.ctx.content.clone(),
-        framework.ctx.author.id == framework.gateway.current_user.id,
-    ));
-
-    while let Some(ref message_ref) = message_reference {
-        if let Some(message_id) = message_ref.message_id {
-            if let Ok(message) = framework
-                .gateway
-                .twilight_client
-                .message(framework.ctx.channel_id, message_id)
-                .await
-            {
-                if let Ok(message) = message.model().await {
-                    messages.push((message.content.clone(), message.author.id == framework.gateway.current_user.id));
-
-                    message_reference = message.reference;
-                }
-            }
-        }
-    }
-
-    messages.reverse();
+    let opted_out = match database.fetch_ai_opt_out(framework.ctx.author.id).await {
+        Ok(opted_out) => opted_out,
+        // If we can't tell, don't record their message
+        Err(_) => true,
+    };
+    let messages = history::conversation(framework, persona.token_budget, opted_out).await;
     tracing::info!("ai_handler - Total Messages: {}", messages.len());
-    tracing::info!("ai_handler - {messages:#?}");
+    tracing::debug!("ai_handler - {messages:#?}");
 
-    if let Err(why) = ai_handler(framework, messages, twilight_message).await {
+    if let Err(why) = ai_handler(framework, &persona, messages, twilight_message).await {
         if let Err(why) = framework
             .gateway
             .twilight_client
@@ -119,96 +132,110 @@ pub async fn ai_handler_root(framework: &MessageContext) {
 pub async fn ai_handler(
     // Gateway for interacting with the bot
     framework: &MessageContext,
+    // The persona responding to this invokation
+    persona: &AiPersona,
     // The context for this invokation
-    context: Vec<(String, bool)>,
+    context: Vec<AiHistoryMessage>,
     // The placeholder message, for updating
     mut twilight_message: twilight_model::channel::Message,
 ) -> anyhow::Result<()> {
-    let mut response = Response::default();
     let mut chat_messages = vec![];
 
-    for (content, bot) in context {
-        chat_messages.push(Message {
-            role: match bot {
-                true => "assistant".into(),
-                false => "user".into(),
+    if let Some(system_prompt) = &persona.system_prompt {
+        chat_messages.push(ChatMessage {
+            role: ChatRole::System,
+            content: system_prompt.clone(),
+        })
+    }
+
+    for message in context {
+        chat_messages.push(ChatMessage {
+            role: match message.assistant {
+                true => ChatRole::Assistant,
+                false => ChatRole::User,
             },
-            content,
-            images: None,
+            content: message.content,
         })
     }
 
-    let request = Request {
-        // model: "dolphin-mixtral".into(),
-        model: "wizard-vicuna-uncensored".into(),
-        // model: "sira".into(),
-        // model: "dolphin-phi".into(),
-        messages: chat_messages,
+    let mut response = String::new();
+    let mut stream = match backend::chat(&framework.gateway.http_client, persona, chat_messages).await {
+        Ok(stream) => stream,
+        Err(why) => {
+            backend_error(framework, persona, &twilight_message, why).await;
+            return Ok(());
+        }
     };
 
-    let mut stream = framework
-        .gateway
-        .http_client
-        .post("http://luro.local:11434/api/chat")
-        // .post("http://localhost:11434/api/chat")
-        .json(&request)
-        .send()
-        .await?
-        .bytes_stream();
-
-    while let Some(Ok(chunk)) = stream.next().await {
-        tracing::debug!("Byte Chunk: {chunk:?}");
-
-        match serde_json::from_slice::<Response>(&chunk[..]) {
-            Ok(parsed_response) => {
-                response.created_at = parsed_response.created_at;
-                response.done = parsed_response.done;
-                response.eval_count = parsed_response.eval_count;
-                response.eval_duration = parsed_response.eval_duration;
-                response.load_duration = parsed_response.load_duration;
-                response.message = match response.message {
-                    Some(mut message) => match parsed_response.message {
-                        Some(parsed_message) => {
-                            message.content.push_str(&parsed_message.content);
-                            Some(message)
-                        }
-                        None => Some(message),
-                    },
-                    None => parsed_response.message,
-                };
-                response.model = parsed_response.model;
-                response.prompt_eval_count = parsed_response.prompt_eval_count;
-                response.prompt_eval_duration = parsed_response.prompt_eval_duration;
-                response.total_duration = parsed_response.total_duration;
+    while let Some(chunk) = stream.next().await {
+        match chunk {
+            Ok(content) => response.push_str(&content),
+            Err(why) => {
+                backend_error(framework, persona, &twilight_message, why).await;
+                return Ok(());
             }
-            Err(why) => tracing::error!(?why, "Failed to parse response from ollama"),
-        };
+        }
 
-        // If the last twilight message was more than a second ago... Send an update to Discord.
+        // If the last twilight message was more than three seconds ago... Send an update to Discord.
         let twilight_time = twilight_message.edited_timestamp.unwrap_or(twilight_message.timestamp).as_secs();
         let time_difference = SystemTime::now().duration_since(UNIX_EPOCH + std::time::Duration::from_secs(twilight_time as u64))?;
 
         if time_difference > Duration::from_secs(3) {
-            let content = response
-                .message
-                .as_ref()
-                .map(|x| x.content.clone())
-                .unwrap_or("Still generating response...".to_owned());
-            update_message(framework, &mut twilight_message, &content).await;
+            update_message(framework, &mut twilight_message, &response).await;
         }
     }
 
     // Generated our full response, make sure the user has the final version
-    let content = response
-        .message
-        .as_ref()
-        .map(|x| x.content.clone())
-        .unwrap_or("Still generating response...".to_owned());
-    update_message(framework, &mut twilight_message, &content).await;
+    update_message(framework, &mut twilight_message, &response).await;
+
+    if !response.is_empty() {
+        history::record_response(framework, &twilight_message, response).await;
+    }
 
     Ok(())
 }
 
+/// Reply to the message that invoked the AI, used when refusing to respond
+async fn reply(framework: &MessageContext, content: &str) {
+    if let Err(why) = framework
+        .gateway
+        .twilight_client
+        .create_message(framework.ctx.channel_id)
+        .content(content)
+        .reply(framework.ctx.id)
+        .await
+    {
+        tracing::error!(?why, "ai_handler - Failed to reply to an AI request")
+    }
+}
+
+/// Replace the placeholder message with an embed explaining why the backend could not respond
+async fn backend_error(
+    framework: &MessageContext,
+    persona: &AiPersona,
+    twilight_message: &twilight_model::channel::Message,
+    why: BackendError,
+) {
+    tracing::warn!(
+        ?why,
+        endpoint = %persona.endpoint,
+        model = %persona.model,
+        "ai_handler - Backend failed to generate a response"
+    );
+
+    let embed: twilight_model::channel::message::Embed = crate::embeds::ai_unavailable(&why).into();
+    if let Err(why) = framework
+        .gateway
+        .twilight_client
+        .update_message(twilight_message.channel_id, twilight_message.id)
+        .content(None)
+        .embeds(Some(&[embed]))
+        .await
+    {
+        tracing::error!(?why, "ai_handler - Failed to tell the user the model is unavailable")
+    }
+}
+
 /// Attempt to send a message update when some generation has been performed
 async fn update_message(framework: &MessageContext, twilight_message: &mut twilight_model::channel::Message, mut message_update: &str) {
     if message_update.len() < 2 {
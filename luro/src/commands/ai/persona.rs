@@ -0,0 +1,37 @@
+use luro_model::ai::{AiChannel, AiPersona};
+
+use crate::models::message_context::MessageContext;
+
+/// Work out which persona should respond in this channel.
+///
+/// Channel Character -> Channel Persona -> Guild Persona -> Default Persona -> Built in persona
+pub async fn resolve_persona(framework: &MessageContext, channel: Option<&AiChannel>, nsfw: bool) -> AiPersona {
+    let ai_config = &framework.gateway.config.ai;
+    let mut persona = ai_config.guild_persona(framework.ctx.guild_id);
+
+    let Some(channel) = channel else {
+        return persona;
+    };
+
+    if let Some(name) = &channel.persona {
+        match ai_config.persona(name) {
+            Some(channel_persona) => persona = channel_persona.clone(),
+            None => tracing::warn!(
+                "ai_handler - Channel `{}` uses the persona `{name}`, which no longer exists",
+                channel.channel_id
+            ),
+        }
+    }
+
+    if let Some(owner) = channel.character_owner
+        && let Some(name) = &channel.character_name
+    {
+        match framework.gateway.database.fetch_character(owner, name).await {
+            Ok(Some(character)) => persona = persona.with_character(&character, nsfw),
+            Ok(None) => tracing::warn!("ai_handler - Character `{name}` owned by `{owner}` no longer exists"),
+            Err(why) => tracing::warn!(?why, "ai_handler - Failed to fetch character `{name}`"),
+        }
+    }
+
+    persona
+}
@@ -0,0 +1,139 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use luro_model::ai::AiRateLimits;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Why a request for a response was refused
+#[derive(Debug)]
+pub enum RateLimited {
+    /// The user has asked for too many responses, and can try again after this long
+    User(Duration),
+    /// The guild's queue is full
+    Guild,
+}
+
+/// A bucket that holds up to `capacity` tokens, refilling completely over `refill`
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32) -> Self {
+        Self {
+            tokens: capacity as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    /// Take a token if one is available, otherwise return how long until one will be
+    fn take(&mut self, capacity: u32, refill: Duration) -> Result<(), Duration> {
+        let capacity = capacity.max(1) as f64;
+        let per_token = refill.as_secs_f64() / capacity;
+        let now = Instant::now();
+
+        if per_token > 0.0 {
+            self.tokens = (self.tokens + now.duration_since(self.updated).as_secs_f64() / per_token).min(capacity);
+        } else {
+            self.tokens = capacity;
+        }
+        self.updated = now;
+
+        match self.tokens >= 1.0 {
+            true => {
+                self.tokens -= 1.0;
+                Ok(())
+            }
+            false => Err(Duration::from_secs_f64((1.0 - self.tokens) * per_token)),
+        }
+    }
+
+    /// Give back a token that was taken for a request that was then refused
+    fn refund(&mut self, capacity: u32) {
+        self.tokens = (self.tokens + 1.0).min(capacity.max(1) as f64);
+    }
+}
+
+/// Per-user and per-guild rate limits for AI responses.
+///
+/// Users that run out of tokens are refused, while requests in a guild that has run out wait in a queue for the bucket to refill.
+#[derive(Debug, Default)]
+pub struct AiRateLimiter {
+    users: Mutex<HashMap<Id<UserMarker>, TokenBucket>>,
+    guilds: Mutex<HashMap<Id<GuildMarker>, TokenBucket>>,
+    queued: Mutex<HashMap<Id<GuildMarker>, usize>>,
+}
+
+impl AiRateLimiter {
+    /// Wait until a response is allowed, or return why it was refused
+    pub async fn acquire(
+        &self,
+        limits: &AiRateLimits,
+        user_id: Id<UserMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+    ) -> Result<(), RateLimited> {
+        let refill = Duration::from_secs(limits.refill_seconds);
+
+        self.users
+            .lock()
+            .unwrap()
+            .entry(user_id)
+            .or_insert_with(|| TokenBucket::new(limits.user_capacity))
+            .take(limits.user_capacity, refill)
+            .map_err(RateLimited::User)?;
+
+        let Some(guild_id) = guild_id else {
+            return Ok(());
+        };
+
+        let mut queued = false;
+        let result = loop {
+            let wait = match self.take_guild(limits, guild_id, refill) {
+                Ok(()) => break Ok(()),
+                Err(wait) => wait,
+            };
+
+            if !queued {
+                let mut queue = self.queued.lock().unwrap();
+                let position = queue.entry(guild_id).or_default();
+                if *position >= limits.queue_size {
+                    break Err(RateLimited::Guild);
+                }
+                *position += 1;
+                queued = true;
+            }
+
+            tokio::time::sleep(wait).await;
+        };
+
+        if queued && let Some(position) = self.queued.lock().unwrap().get_mut(&guild_id) {
+            *position = position.saturating_sub(1);
+        }
+
+        // The user didn't get a response, so they shouldn't lose a token for it
+        if result.is_err()
+            && let Some(bucket) = self.users.lock().unwrap().get_mut(&user_id)
+        {
+            bucket.refund(limits.user_capacity);
+        }
+
+        result
+    }
+
+    fn take_guild(&self, limits: &AiRateLimits, guild_id: Id<GuildMarker>, refill: Duration) -> Result<(), Duration> {
+        self.guilds
+            .lock()
+            .unwrap()
+            .entry(guild_id)
+            .or_insert_with(|| TokenBucket::new(limits.guild_capacity))
+            .take(limits.guild_capacity, refill)
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+use twilight_model::gateway::payload::incoming::{MemberAdd, MemberRemove, MemberUpdate};
+
+use crate::gateway::{Gateway, GatewayResult};
+
+/// Give a member their sticky roles and auto roles when they join.
+/// Roles that have a delay or wait for screening are queued for [super::auto_role_runner].
+pub async fn auto_role_join(gateway: &Gateway, event: &MemberAdd) -> GatewayResult {
+    let user_id = event.user.id;
+    if event.user.bot {
+        return Ok(());
+    }
+
+    // Always take the sticky roles, so they don't linger if the setting was turned off
+    let sticky_roles = gateway.database.delete_sticky_roles(event.guild_id, user_id).await?;
+    if !sticky_roles.is_empty() && gateway.database.fetch_sticky_roles_enabled(event.guild_id).await? {
+        let roles = gateway.twilight_client.roles(event.guild_id).await?.models().await?;
+        let restorable = sticky_roles
+            .into_iter()
+            .filter(|role_id| roles.iter().any(|role| role.id == *role_id && !role.managed));
+
+        for role_id in restorable {
+            if let Err(why) = gateway
+                .twilight_client
+                .add_guild_member_role(event.guild_id, user_id, role_id)
+                .await
+            {
+                tracing::warn!(?why, "autoroles - Failed to give sticky role `{role_id}` back to `{user_id}`");
+            }
+        }
+    }
+
+    for auto_role in gateway.database.fetch_auto_roles(event.guild_id).await? {
+        let apply_at = match (auto_role.after_screening && event.pending, auto_role.delay_seconds) {
+            (true, _) => None,
+            (false, 0) => {
+                if let Err(why) = gateway
+                    .twilight_client
+                    .add_guild_member_role(event.guild_id, user_id, auto_role.role_id)
+                    .await
+                {
+                    tracing::warn!(?why, "autoroles - Failed to give auto role `{}` to `{user_id}`", auto_role.role_id);
+                }
+                continue;
+            }
+            (false, delay) => Some(OffsetDateTime::now_utc() + Duration::from_secs(delay as u64)),
+        };
+
+        gateway
+            .database
+            .update_auto_role_pending(event.guild_id, user_id, auto_role.role_id, apply_at)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Start the delay on auto roles that were waiting for the member to pass screening
+pub async fn auto_role_screened(gateway: &Gateway, event: &MemberUpdate) -> GatewayResult {
+    if !event.pending {
+        gateway.database.update_auto_roles_screened(event.guild_id, event.user.id).await?;
+    }
+
+    Ok(())
+}
+
+/// Forget a member's queued auto roles, and remember their roles if the guild has sticky roles
+pub async fn auto_role_leave(gateway: &Gateway, event: &MemberRemove) -> GatewayResult {
+    gateway
+        .database
+        .delete_auto_roles_pending(event.guild_id, event.user.id, None)
+        .await?;
+
+    if gateway.database.fetch_sticky_roles_enabled(event.guild_id).await? {
+        gateway.database.update_sticky_roles(event.guild_id, event.user.id).await?;
+    }
+
+    Ok(())
+}
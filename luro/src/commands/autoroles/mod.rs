@@ -0,0 +1,7 @@
+mod join;
+mod reaction;
+mod runner;
+
+pub use join::{auto_role_join, auto_role_leave, auto_role_screened};
+pub use reaction::reaction_role_handler;
+pub use runner::auto_role_runner;
@@ -0,0 +1,50 @@
+use luro_model::auto_role::reaction_key;
+use twilight_model::gateway::GatewayReaction;
+
+use crate::gateway::{Gateway, GatewayResult};
+
+/// Give or take away the role bound to a reaction. `added` is false when the reaction was removed.
+pub async fn reaction_role_handler(gateway: &Gateway, event: &GatewayReaction, added: bool) -> GatewayResult {
+    let Some(guild_id) = event.guild_id else {
+        return Ok(());
+    };
+
+    // Ignore our own reactions, which are added so members have something to click
+    if event.user_id == gateway.current_user.id {
+        return Ok(());
+    }
+
+    let Some(reaction_role) = gateway
+        .database
+        .fetch_reaction_role(event.message_id, &reaction_key(&event.emoji))
+        .await?
+    else {
+        return Ok(());
+    };
+
+    if gateway
+        .database
+        .fetch_role_blacklist(guild_id)
+        .await?
+        .contains(&reaction_role.role_id)
+    {
+        return Ok(());
+    }
+
+    match added {
+        true => {
+            gateway
+                .twilight_client
+                .add_guild_member_role(guild_id, event.user_id, reaction_role.role_id)
+                .await?
+        }
+        false => {
+            gateway
+                .twilight_client
+                .remove_guild_member_role(guild_id, event.user_id, reaction_role.role_id)
+                .await?
+        }
+    };
+
+    Ok(())
+}
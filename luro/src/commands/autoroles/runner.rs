@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use crate::gateway::{Gateway, GatewayArc, GatewayResult};
+
+/// How often to check for queued auto roles that are due
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Give members the auto roles that were queued with a delay or until they passed screening
+pub async fn auto_role_runner(gateway: GatewayArc) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(why) = apply_due(&gateway).await {
+            tracing::warn!(?why, "autoroles - Failed to give queued auto roles");
+        }
+    }
+}
+
+async fn apply_due(gateway: &Gateway) -> GatewayResult {
+    for pending in gateway.database.fetch_auto_roles_due().await? {
+        if let Err(why) = gateway
+            .twilight_client
+            .add_guild_member_role(pending.guild_id, pending.user_id, pending.role_id)
+            .await
+        {
+            tracing::warn!(
+                ?why,
+                "autoroles - Failed to give auto role `{}` to `{}`",
+                pending.role_id,
+                pending.user_id
+            );
+        }
+
+        // Drop it even if it failed, as the member may have left or the role may be gone
+        gateway
+            .database
+            .delete_auto_roles_pending(pending.guild_id, pending.user_id, Some(pending.role_id))
+            .await?;
+    }
+
+    Ok(())
+}
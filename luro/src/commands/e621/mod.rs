@@ -0,0 +1,342 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use luro_model::{
+    config::Config,
+    e621::{E621Blacklist, E621Post, E621Posts},
+};
+use tokio::sync::Mutex;
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// e621 allows two requests a second, so stay comfortably under it
+const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+/// How long a page of results is reused for
+const CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+/// The most pages of results kept in the cache
+const CACHE_SIZE: usize = 256;
+/// How long a search can be paged through after the last time it was used
+const SEARCH_TTL: Duration = Duration::from_secs(15 * 60);
+/// Posts requested per page of results
+const PAGE_SIZE: usize = 75;
+/// The most pages of results a search will load, so a blacklist that hides everything doesn't page forever
+const MAX_PAGES: u32 = 10;
+
+#[derive(thiserror::Error, Debug)]
+pub enum E621Error {
+    #[error("e621 is rate limiting us")]
+    RateLimited,
+    #[error("e621 responded with status {0}")]
+    Status(reqwest::StatusCode),
+    #[error("Failed to communicate with e621")]
+    Http(#[from] reqwest::Error),
+}
+
+/// A search someone can page through with the buttons on its message. Searches are dropped a while after they were last used.
+#[derive(Debug)]
+pub struct E621Search {
+    pub author_id: Id<UserMarker>,
+    pub nsfw: bool,
+    pub tags: String,
+    blacklist: E621Blacklist,
+    /// Posts that passed the blacklist so far
+    pub posts: Vec<E621Post>,
+    /// The post being shown
+    pub index: usize,
+    /// Pages of results loaded so far
+    pages: u32,
+    /// Set once e621 runs out of results
+    pub exhausted: bool,
+    used: Instant,
+}
+
+impl E621Search {
+    pub fn new(author_id: Id<UserMarker>, nsfw: bool, tags: String, blacklist: E621Blacklist) -> Self {
+        Self {
+            author_id,
+            nsfw,
+            tags,
+            blacklist,
+            posts: vec![],
+            index: 0,
+            pages: 0,
+            exhausted: false,
+            used: Instant::now(),
+        }
+    }
+}
+
+/// A client for the e621 and e926 APIs. Requests are spaced out to respect e621's rate limit, and pages of results are cached.
+#[derive(Debug, Default)]
+pub struct E621 {
+    /// When the next request can be sent
+    next_request: Mutex<Option<Instant>>,
+    cache: Mutex<HashMap<(String, u32), (Instant, Arc<Vec<E621Post>>)>>,
+    searches: Mutex<HashMap<u64, Arc<Mutex<E621Search>>>>,
+}
+
+impl E621 {
+    /// Fetch a page of results, starting at page 1
+    pub async fn posts(
+        &self,
+        http_client: &reqwest::Client,
+        config: &Config,
+        nsfw: bool,
+        tags: &str,
+        page: u32,
+    ) -> Result<Arc<Vec<E621Post>>, E621Error> {
+        let base_url = config.e621_api(nsfw);
+        let key = (format!("{base_url} {tags}"), page);
+        if let Some((fetched, posts)) = self.cache.lock().await.get(&key)
+            && fetched.elapsed() < CACHE_TTL
+        {
+            return Ok(posts.clone());
+        }
+
+        self.wait_for_rate_limit().await;
+        let response = http_client
+            .get(format!("{base_url}/posts.json"))
+            .header(reqwest::header::USER_AGENT, config.e621_user_agent())
+            .query(&[("tags", tags), ("limit", &PAGE_SIZE.to_string()), ("page", &page.to_string())])
+            .send()
+            .await?;
+
+        match response.status() {
+            status if status.is_success() => {}
+            reqwest::StatusCode::TOO_MANY_REQUESTS | reqwest::StatusCode::SERVICE_UNAVAILABLE => return Err(E621Error::RateLimited),
+            status => return Err(E621Error::Status(status)),
+        }
+
+        let posts = Arc::new(response.json::<E621Posts>().await?.posts);
+        let mut cache = self.cache.lock().await;
+        cache.retain(|_, (fetched, _)| fetched.elapsed() < CACHE_TTL);
+        if cache.len() >= CACHE_SIZE
+            && let Some(oldest) = cache.iter().min_by_key(|(_, (fetched, _))| *fetched).map(|(key, _)| key.clone())
+        {
+            cache.remove(&oldest);
+        }
+        cache.insert(key, (Instant::now(), posts.clone()));
+
+        Ok(posts)
+    }
+
+    /// Wait until we are allowed to send another request
+    async fn wait_for_rate_limit(&self) {
+        let mut next_request = self.next_request.lock().await;
+        if let Some(next_request) = *next_request {
+            tokio::time::sleep_until(next_request.into()).await;
+        }
+        *next_request = Some(Instant::now() + REQUEST_INTERVAL);
+    }
+
+    /// Start a new search, returning its ID and the first post if there are any results
+    pub async fn search(
+        &self,
+        http_client: &reqwest::Client,
+        config: &Config,
+        mut search: E621Search,
+    ) -> Result<(u64, Option<E621Post>), E621Error> {
+        self.load(http_client, config, &mut search, 0).await?;
+        let post = search.posts.first().cloned();
+
+        let mut searches = self.searches.lock().await;
+        // Searches in use are kept, they are being paged through right now
+        searches.retain(|_, search| match search.try_lock() {
+            Ok(search) => search.used.elapsed() < SEARCH_TTL,
+            Err(_) => true,
+        });
+        let id = fastrand::u64(..);
+        searches.insert(id, Arc::new(Mutex::new(search)));
+        Ok((id, post))
+    }
+
+    /// Move a search forwards or backwards, returning the post to show. Returns [None] if the search has expired.
+    pub async fn page(
+        &self,
+        http_client: &reqwest::Client,
+        config: &Config,
+        id: u64,
+        forwards: bool,
+    ) -> Result<Option<(Option<E621Post>, E621SearchState)>, E621Error> {
+        let Some(search) = self.searches.lock().await.get(&id).cloned() else {
+            return Ok(None);
+        };
+        let mut search = search.lock().await;
+        if search.used.elapsed() >= SEARCH_TTL {
+            return Ok(None);
+        }
+
+        let index = match forwards {
+            true => search.index + 1,
+            false => search.index.saturating_sub(1),
+        };
+        self.load(http_client, config, &mut search, index).await?;
+        search.index = index.min(search.posts.len().saturating_sub(1));
+        search.used = Instant::now();
+
+        Ok(Some((search.posts.get(search.index).cloned(), E621SearchState::from(&*search))))
+    }
+
+    /// The state of a search, for checking who can page through it
+    pub async fn state(&self, id: u64) -> Option<E621SearchState> {
+        let search = self.searches.lock().await.get(&id).cloned()?;
+        let search = search.lock().await;
+        Some(E621SearchState::from(&*search))
+    }
+
+    /// Load pages of results until the search has a post at `index`, or runs out
+    async fn load(&self, http_client: &reqwest::Client, config: &Config, search: &mut E621Search, index: usize) -> Result<(), E621Error> {
+        while search.posts.len() <= index && !search.exhausted {
+            search.pages += 1;
+            let posts = self.posts(http_client, config, search.nsfw, &search.tags, search.pages).await?;
+            search.exhausted = posts.len() < PAGE_SIZE || search.pages >= MAX_PAGES;
+            search.posts.extend(
+                posts
+                    .iter()
+                    .filter(|post| post.image_url().is_some() && !search.blacklist.blacklisted(post))
+                    .cloned(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Where a search is up to
+#[derive(Clone, Debug)]
+pub struct E621SearchState {
+    pub author_id: Id<UserMarker>,
+    pub nsfw: bool,
+    pub tags: String,
+    pub index: usize,
+    /// Posts found so far
+    pub found: usize,
+    /// False if there may be more posts than have been found
+    pub exhausted: bool,
+}
+
+impl From<&E621Search> for E621SearchState {
+    fn from(search: &E621Search) -> Self {
+        Self {
+            author_id: search.author_id,
+            nsfw: search.nsfw,
+            tags: search.tags.clone(),
+            index: search.index,
+            found: search.posts.len(),
+            exhausted: search.exhausted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex as StdMutex;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// A local stand in for the e621 API. Serves `full_pages` pages of [`PAGE_SIZE`] posts, then a page of two posts.
+    /// Returns the base URL and the path of every request it received.
+    async fn mock_e621(full_pages: u32) -> (String, Arc<StdMutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(StdMutex::new(vec![]));
+
+        let received = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = vec![];
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    match stream.read(&mut buffer).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or_default().to_owned();
+                let page = path
+                    .split(['?', '&'])
+                    .find_map(|query| query.strip_prefix("page="))
+                    .and_then(|page| page.parse::<u32>().ok())
+                    .unwrap_or(1);
+                received.lock().unwrap().push(path);
+
+                let count = match page <= full_pages {
+                    true => PAGE_SIZE,
+                    false => 2,
+                };
+                let posts = (0..count)
+                    .map(|index| {
+                        let id = page as usize * 1000 + index;
+                        format!(
+                            r#"{{"id":{id},"file":{{"ext":"png","url":"https://static1.e621.net/data/{id}.png","width":1,"height":1}},"score":{{"total":0}},"rating":"s","tags":{{"general":["tag_{}"]}}}}"#,
+                            index % 2
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let body = format!(r#"{{"posts":[{}]}}"#, posts.join(","));
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn config(base_url: &str) -> Config {
+        Config {
+            e621_base_url: Some(base_url.to_owned()),
+            e926_base_url: Some(base_url.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn pages_are_cached() {
+        let (base_url, requests) = mock_e621(0).await;
+        let (client, config, e621) = (reqwest::Client::new(), config(&base_url), E621::default());
+
+        let first = e621.posts(&client, &config, true, "solo", 1).await.unwrap();
+        let second = e621.posts(&client, &config, true, "solo", 1).await.unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn searches_load_the_next_page_when_paging_past_the_end() {
+        let (base_url, requests) = mock_e621(1).await;
+        let (client, config, e621) = (reqwest::Client::new(), config(&base_url), E621::default());
+
+        // Half of the posts are tagged `tag_1`, so the blacklist leaves the other half
+        let search = E621Search::new(Id::new(1), true, "solo".to_owned(), E621Blacklist::parse("tag_1"));
+        let (id, post) = e621.search(&client, &config, search).await.unwrap();
+        assert_eq!(post.map(|post| post.id), Some(1000));
+
+        let mut state = e621.state(id).await.unwrap();
+        assert_eq!((state.found, state.exhausted), (PAGE_SIZE.div_ceil(2), false));
+
+        while state.index + 1 < state.found {
+            state = e621.page(&client, &config, id, true).await.unwrap().unwrap().1;
+        }
+        let (post, state) = e621.page(&client, &config, id, true).await.unwrap().unwrap();
+
+        assert_eq!(post.map(|post| post.id), Some(2000));
+        assert_eq!((state.found, state.exhausted), (PAGE_SIZE.div_ceil(2) + 1, true));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].contains("page=2"));
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use luro_model::insights::MemberEventKind;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::gateway::{Gateway, GatewayArc, GatewayResult};
+
+/// How often the daily snapshots are refreshed
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Keep the daily guild snapshots up to date
+pub async fn insights_runner(gateway: GatewayArc) {
+    let mut interval = tokio::time::interval(SNAPSHOT_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(why) = gateway.database.update_insights().await {
+            tracing::warn!(?why, "insights - Failed to write the daily snapshots");
+        }
+    }
+}
+
+/// Record a member joining or leaving, used for growth and retention
+pub async fn insights_member_event(
+    gateway: &Gateway,
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    kind: MemberEventKind,
+) -> GatewayResult {
+    gateway.database.create_member_event(guild_id, user_id, kind).await?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "character",
+    desc = "Have me reply in character as someone's character. Leave blank to stop"
+)]
+pub struct Character {
+    /// The name of the character
+    name: Option<String>,
+    /// The owner of the character. Defaults to yourself
+    user: Option<Id<UserMarker>>,
+}
+
+impl crate::models::CreateCommand for Character {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let owner = self.user.unwrap_or(framework.author_id());
+        let mut settings = super::channel_settings(framework).await?;
+
+        let content = match self.name {
+            Some(name) => match framework.database().fetch_character(owner, &name).await? {
+                Some(character) => {
+                    settings.character_owner = Some(owner);
+                    settings.character_name = Some(character.name.clone());
+                    format!("I'll now reply as `{}` in this channel!", character.name)
+                }
+                None => {
+                    return framework
+                        .respond(|r| {
+                            r.content(format!("<@{owner}> does not have a character called `{name}`!"))
+                                .ephemeral()
+                        })
+                        .await
+                }
+            },
+            None => {
+                settings.character_owner = None;
+                settings.character_name = None;
+                "I'll stop replying in character in this channel!".to_owned()
+            }
+        };
+
+        framework.database().update_ai_channel(&settings).await?;
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
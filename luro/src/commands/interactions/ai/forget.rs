@@ -0,0 +1,17 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "forget", desc = "Make me forget the conversation in this channel")]
+pub struct Forget {}
+
+impl crate::models::CreateCommand for Forget {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let removed = framework.database().delete_ai_history(framework.channel().id).await?;
+
+        framework
+            .respond(|r| r.content(format!("Forgot `{removed}` messages from this channel. Who are you again?")))
+            .await
+    }
+}
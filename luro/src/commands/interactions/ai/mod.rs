@@ -0,0 +1,59 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::guild::Permissions;
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+mod character;
+mod forget;
+mod persona;
+mod privacy;
+mod respond;
+
+pub use privacy::Privacy;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "ai",
+    desc = "Change how I talk when someone mentions me in this channel",
+    dm_permission = false,
+    default_permissions = "manage_channels"
+)]
+pub enum Command {
+    #[command(name = "character")]
+    Character(character::Character),
+    #[command(name = "forget")]
+    Forget(forget::Forget),
+    #[command(name = "persona")]
+    Persona(persona::Persona),
+    #[command(name = "respond")]
+    Respond(respond::Respond),
+}
+
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        match self {
+            Self::Character(cmd) => cmd.handle_command(framework).await,
+            Self::Forget(cmd) => cmd.handle_command(framework).await,
+            Self::Persona(cmd) => cmd.handle_command(framework).await,
+            Self::Respond(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+}
+
+fn manage_channels() -> Permissions {
+    Permissions::MANAGE_CHANNELS
+}
+
+/// Fetch the AI settings for the channel the interaction was run in, creating empty settings if there are none
+async fn channel_settings(framework: &InteractionContext) -> InteractionResult<luro_model::ai::AiChannel> {
+    let channel_id = framework.channel().id;
+
+    Ok(framework
+        .database()
+        .fetch_ai_channel(channel_id)
+        .await?
+        .unwrap_or(luro_model::ai::AiChannel {
+            channel_id,
+            ..Default::default()
+        }))
+}
@@ -0,0 +1,39 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "persona",
+    desc = "Set the persona I use in this channel. Leave blank to use the server default"
+)]
+pub struct Persona {
+    /// The name of the persona, as set in my configuration
+    name: Option<String>,
+}
+
+impl crate::models::CreateCommand for Persona {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        if let Some(name) = &self.name
+            && framework.gateway.config.ai.persona(name).is_none()
+        {
+            let mut personas = framework.gateway.config.ai.personas.keys().cloned().collect::<Vec<_>>();
+            personas.sort();
+            let content = match personas.is_empty() {
+                true => format!("There is no persona called `{name}`, and no personas have been configured!"),
+                false => format!("There is no persona called `{name}`! Try one of these: `{}`", personas.join("`, `")),
+            };
+            return framework.respond(|r| r.content(content).ephemeral()).await;
+        }
+
+        let mut settings = super::channel_settings(framework).await?;
+        settings.persona = self.name;
+        framework.database().update_ai_channel(&settings).await?;
+
+        let content = match settings.persona {
+            Some(name) => format!("I'll now use the persona `{name}` in this channel!"),
+            None => "I'll now use the default persona in this channel!".to_owned(),
+        };
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
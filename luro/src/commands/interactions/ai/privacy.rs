@@ -0,0 +1,25 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "ai-privacy", desc = "Choose if I can remember your messages when you talk to me")]
+pub struct Privacy {
+    /// Set to false to stop me using your messages as context. Messages I already remember are forgotten.
+    remember: bool,
+}
+
+impl crate::models::CreateCommand for Privacy {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let removed = framework
+            .database()
+            .update_ai_opt_out(framework.author_id(), !self.remember)
+            .await?;
+
+        let content = match self.remember {
+            true => "I'll now remember what you say to me, so I can keep up with the conversation!".to_owned(),
+            false => format!("I'll no longer remember what you say to me. I forgot `{removed}` of your messages."),
+        };
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
@@ -0,0 +1,27 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "respond", desc = "Choose if I respond when mentioned in this channel")]
+pub struct Respond {
+    /// Set to true to let me respond in this channel
+    enabled: bool,
+}
+
+impl crate::models::CreateCommand for Respond {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let mut settings = super::channel_settings(framework).await?;
+        settings.enabled = self.enabled;
+        framework.database().update_ai_channel(&settings).await?;
+
+        let content = match (self.enabled, framework.gateway.config.ai.channel_opt_in) {
+            (true, _) => "I'll now respond when mentioned in this channel!",
+            (false, true) => "I'll no longer respond when mentioned in this channel!",
+            (false, false) => {
+                "Noted, but channels don't need to opt in right now, so I'll still respond here until my configuration changes."
+            }
+        };
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
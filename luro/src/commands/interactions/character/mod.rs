@@ -2,14 +2,13 @@ use luro_model::{
     builders::{ComponentBuilder, InteractionResponseBuilder},
     character::CharacterProfile,
 };
-use twilight_interactions::command::{AutocompleteValue, CommandModel, CreateCommand};
-use twilight_model::{
-    application::command::{CommandOptionChoice, CommandOptionChoiceValue},
-    channel::message::component::ButtonStyle,
-    http::interaction::InteractionResponseType,
-};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{application::command::CommandOptionChoice, channel::message::component::ButtonStyle};
 
-use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
+use crate::models::{
+    autocomplete::{self, FocusedOption},
+    interaction::{InteractionContext, InteractionError, InteractionResult},
+};
 
 // mod components;
 // mod create;
@@ -112,47 +111,14 @@ impl crate::models::CreateCommand for Command {
         }
     }
 
-    async fn handle_autocomplete(ctx: &mut InteractionContext) -> InteractionResult<()> {
-        let characters = ctx.database().fetch_characters(ctx.author_id()).await?;
-
-        let choices = match CharacterNameAutocomplete::from_interaction((ctx.interaction).into())?.name {
-            AutocompleteValue::None => characters
-                .into_iter()
-                .map(|character| CommandOptionChoice {
-                    name: character.name.clone(),
-                    name_localizations: None,
-                    value: CommandOptionChoiceValue::String(character.name),
-                })
-                .collect(),
-            AutocompleteValue::Focused(input) => characters
-                .into_iter()
-                .filter_map(|character| match character.name.contains(&input) {
-                    true => Some(CommandOptionChoice {
-                        name: character.name.clone(),
-                        name_localizations: None,
-                        value: CommandOptionChoiceValue::String(character.name),
-                    }),
-                    false => None,
-                })
-                .collect(),
-            AutocompleteValue::Completed(_) => vec![],
-        };
-
-        ctx.respond(|response| {
-            response
-                .choices(choices.into_iter())
-                .response_type(InteractionResponseType::ApplicationCommandAutocompleteResult)
-        })
-        .await
+    async fn handle_autocomplete(ctx: &mut InteractionContext, focused: FocusedOption) -> InteractionResult<Vec<CommandOptionChoice>> {
+        match focused.name.as_str() {
+            "name" => autocomplete::character_names(ctx, &focused.input).await,
+            _ => Ok(vec![]),
+        }
     }
 }
 
-#[derive(CommandModel)]
-#[command(autocomplete = true)]
-pub struct CharacterNameAutocomplete {
-    name: AutocompleteValue<String>,
-}
-
 pub async fn character_response(ctx: &InteractionContext, character: &CharacterProfile, nsfw: bool) -> InteractionResponseBuilder {
     let mut response = InteractionResponseBuilder::default();
     let character_images = ctx
@@ -1,4 +1,4 @@
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::{interaction::InteractionResult, CommandContext};
 
 #[derive(twilight_interactions::command::CommandModel, twilight_interactions::command::CreateCommand)]
 #[command(name = "help", desc = "Information for how to roll your dice")]
@@ -7,8 +7,8 @@ pub struct Help {
     ephemeral: Option<bool>,
 }
 
-impl crate::models::CreateCommand for Help {
-    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+impl crate::models::UnifiedCommand for Help {
+    async fn run(self, framework: CommandContext<'_>) -> InteractionResult<()> {
         let description = "Roll some dice with a brief explanation of the output all on one line, such as `1d20 = [13] = 13`.";
 
         let shortmode_help = [
@@ -1,4 +1,7 @@
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::{
+    interaction::{InteractionContext, InteractionResult},
+    CommandContext, UnifiedCommand,
+};
 
 mod help;
 mod roll;
@@ -6,6 +9,8 @@ mod roll_direction;
 mod simple;
 mod stats;
 
+pub use roll::Roll;
+
 #[derive(twilight_interactions::command::CommandModel, twilight_interactions::command::CreateCommand)]
 #[command(name = "dice", desc = "Roll those freaking dice!!!")]
 pub enum Dice {
@@ -23,12 +28,18 @@ pub enum Dice {
 
 impl crate::models::CreateCommand for Dice {
     async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        self.run(CommandContext::Interaction(framework)).await
+    }
+}
+
+impl UnifiedCommand for Dice {
+    async fn run(self, framework: CommandContext<'_>) -> InteractionResult<()> {
         match self {
-            Self::Direction(cmd) => cmd.handle_command(framework).await,
-            Self::Help(cmd) => cmd.handle_command(framework).await,
-            Self::Roll(cmd) => cmd.handle_command(framework).await,
-            Self::Simple(cmd) => cmd.handle_command(framework).await,
-            Self::Stats(cmd) => cmd.handle_command(framework).await,
+            Self::Direction(cmd) => cmd.run(framework).await,
+            Self::Help(cmd) => cmd.run(framework).await,
+            Self::Roll(cmd) => cmd.run(framework).await,
+            Self::Simple(cmd) => cmd.run(framework).await,
+            Self::Stats(cmd) => cmd.run(framework).await,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::{interaction::InteractionResult, CommandContext};
 
 #[derive(twilight_interactions::command::CommandModel, twilight_interactions::command::CreateCommand)]
 #[command(name = "roll", desc = "Roll those freaking dice!!!")]
@@ -11,8 +11,8 @@ pub struct Roll {
     ephemeral: Option<bool>,
 }
 
-impl crate::models::CreateCommand for Roll {
-    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+impl crate::models::UnifiedCommand for Roll {
+    async fn run(self, framework: CommandContext<'_>) -> InteractionResult<()> {
         let result = luro_dice::DiceRoll::roll_inline(&self.dice, false).unwrap_or(luro_dice::RollResult {
             string_result: "I genuinely am a loss for words for whatever fucking format you just tried. Here, have a free `69` since you bewildered me so goddarn much.".to_string(),
             dice_total: luro_dice::RollValue::Int(69)
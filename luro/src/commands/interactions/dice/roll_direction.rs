@@ -1,4 +1,4 @@
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::{interaction::InteractionResult, CommandContext};
 
 #[derive(twilight_interactions::command::CommandModel, twilight_interactions::command::CreateCommand)]
 #[command(name = "direction", desc = "Roll for a direction, such as `North East`!")]
@@ -7,8 +7,8 @@ pub struct Direction {
     ephemeral: Option<bool>,
 }
 
-impl crate::models::CreateCommand for Direction {
-    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+impl crate::models::UnifiedCommand for Direction {
+    async fn run(self, framework: CommandContext<'_>) -> InteractionResult<()> {
         framework
             .respond(|r| {
                 if self.ephemeral.unwrap_or_default() {
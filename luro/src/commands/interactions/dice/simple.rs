@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::{interaction::InteractionResult, CommandContext};
 
 #[derive(twilight_interactions::command::CommandModel, twilight_interactions::command::CreateCommand)]
 #[command(name = "simple", desc = "A simpler version, for those not wanting to deal with foruma")]
@@ -41,8 +41,8 @@ pub struct Simple {
     divide: Option<i64>,
 }
 
-impl crate::models::CreateCommand for Simple {
-    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+impl crate::models::UnifiedCommand for Simple {
+    async fn run(self, framework: CommandContext<'_>) -> InteractionResult<()> {
         let mut roll = format!("{}d{}", self.dice, self.sides);
 
         if let Some(operation) = self.keep_highest {
@@ -1,4 +1,4 @@
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::{interaction::InteractionResult, CommandContext};
 
 #[derive(twilight_interactions::command::CommandModel, twilight_interactions::command::CreateCommand)]
 #[command(name = "stats", desc = "Get some stats for your character sheet")]
@@ -7,8 +7,8 @@ pub struct Stats {
     ephemeral: Option<bool>,
 }
 
-impl crate::models::CreateCommand for Stats {
-    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+impl crate::models::UnifiedCommand for Stats {
+    async fn run(self, framework: CommandContext<'_>) -> InteractionResult<()> {
         framework
             .respond(|r| {
                 if self.ephemeral.unwrap_or_default() {
@@ -0,0 +1,56 @@
+use luro_model::e621::E621Blacklist;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "blacklist",
+    desc = "View or change your own blacklist, which applies on top of the bot's blacklist"
+)]
+pub struct Blacklist {
+    /// Tags to hide. Separate lines with commas, posts matching every tag on a line are hidden
+    tags: Option<String>,
+    /// Remove your blacklist
+    clear: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Blacklist {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let user_id = framework.author_id();
+
+        if self.clear.unwrap_or_default() {
+            framework.database().delete_e621_blacklist(user_id).await?;
+            return framework
+                .respond(|r| {
+                    r.content("Cleared your blacklist! Only the bot's blacklist applies now.")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        let Some(tags) = self.tags else {
+            let content = match framework.database().fetch_e621_blacklist(user_id).await? {
+                Some(blacklist) => format!("Your blacklist:\n```\n{blacklist}\n```"),
+                None => "You don't have a blacklist! Set one with `/e621 blacklist tags:`".to_owned(),
+            };
+            return framework.respond(|r| r.content(content).ephemeral()).await;
+        };
+
+        let blacklist = tags
+            .split(',')
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let lines = E621Blacklist::parse(&blacklist).len();
+        framework.database().update_e621_blacklist(user_id, &blacklist).await?;
+
+        framework
+            .respond(|r| {
+                r.content(format!("Saved your blacklist with {lines} line(s):\n```\n{blacklist}\n```"))
+                    .ephemeral()
+            })
+            .await
+    }
+}
@@ -0,0 +1,186 @@
+use luro_model::{
+    builders::{ComponentBuilder, EmbedBuilder},
+    e621::E621Post,
+};
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand},
+    custom_id::CustomId,
+};
+use twilight_model::channel::message::component::ButtonStyle;
+
+use crate::{
+    commands::{E621Error, E621SearchState},
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+mod blacklist;
+mod search;
+
+/// The paging buttons of a search, stored in their custom ID
+#[derive(CustomId)]
+#[custom_id(prefix = "e621")]
+struct Page {
+    search_id: u64,
+    forwards: bool,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "e621",
+    desc = "Search e621 for art. Outside of NSFW channels, only safe posts from e926 are shown"
+)]
+pub enum Command {
+    #[command(name = "blacklist")]
+    Blacklist(blacklist::Blacklist),
+    #[command(name = "search")]
+    Search(search::Search),
+}
+
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        match self {
+            Self::Blacklist(cmd) => cmd.handle_command(framework).await,
+            Self::Search(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+
+    /// Someone pressed one of the paging buttons
+    async fn handle_component(framework: &mut InteractionContext) -> InteractionResult<()> {
+        let Page { search_id: id, forwards } = match framework.custom_id() {
+            Ok(page) => page,
+            Err(_) => return expired(framework).await,
+        };
+
+        let Some(state) = framework.gateway.e621.state(id).await else {
+            return expired(framework).await;
+        };
+        if state.author_id != framework.author_id() {
+            return framework
+                .respond(|r| {
+                    r.content("Only the person who searched can page through the results! Run your own search with `/e621 search`.")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        let page = framework
+            .gateway
+            .e621
+            .page(&framework.gateway.http_client, &framework.gateway.config, id, forwards)
+            .await;
+        match page {
+            Ok(Some((Some(post), state))) => respond_post(framework, id, &post, &state, true).await,
+            Ok(_) => expired(framework).await,
+            Err(why) => respond_error(framework, why).await,
+        }
+    }
+}
+
+/// Show a post, with buttons to page through the rest of the search
+async fn respond_post(
+    framework: &InteractionContext,
+    id: u64,
+    post: &E621Post,
+    state: &E621SearchState,
+    update: bool,
+) -> InteractionResult<()> {
+    let base_url = framework.gateway.config.e621_api(state.nsfw);
+    let post_url = format!("{base_url}/posts/{}", post.id);
+
+    let mut embed = EmbedBuilder::default();
+    embed
+        .colour(framework.gateway.config.accent_colour())
+        .title(format!("Post #{}", post.id))
+        .url(&post_url)
+        .image(|image| image.url(post.image_url().unwrap_or_default()));
+
+    let artists = post
+        .artists()
+        .map(|artist| format!("[{artist}]({base_url}/posts?tags={artist})"))
+        .collect::<Vec<_>>();
+    if !artists.is_empty() {
+        embed.create_field("Artists", artists.join(", "), true);
+    }
+
+    let sources = post
+        .sources
+        .iter()
+        .filter(|source| source.starts_with("http"))
+        .take(3)
+        .enumerate()
+        .map(|(index, source)| format!("[Source {}]({source})", index + 1))
+        .collect::<Vec<_>>();
+    if !sources.is_empty() {
+        embed.create_field("Sources", sources.join(", "), true);
+    }
+
+    let rating = post.rating().map(|rating| rating.to_string()).unwrap_or_default();
+    embed.create_field("Stats", format!("{rating} - ⬆️ {} - ❤️ {}", post.score.total, post.fav_count), true);
+
+    let found = match state.exhausted {
+        true => state.found.to_string(),
+        false => format!("{}+", state.found),
+    };
+    let tags = match state.tags.is_empty() {
+        true => "No tags",
+        false => state.tags.as_str(),
+    };
+    embed.footer(|footer| footer.text(format!("Result {} of {found} - {tags}", state.index + 1)));
+
+    let previous = Page {
+        search_id: id,
+        forwards: false,
+    }
+    .to_custom_id()?;
+    let next = Page {
+        search_id: id,
+        forwards: true,
+    }
+    .to_custom_id()?;
+
+    let mut components = ComponentBuilder::default();
+    components.action_row(|row| {
+        row.button(|button| {
+            button
+                .custom_id(previous)
+                .label("Previous")
+                .style(ButtonStyle::Secondary)
+                .disabled(state.index == 0)
+        })
+        .button(|button| {
+            button
+                .custom_id(next)
+                .label("Next")
+                .style(ButtonStyle::Secondary)
+                .disabled(state.exhausted && state.index + 1 >= state.found)
+        })
+        .button(|button| button.url(&post_url).label("View post").style(ButtonStyle::Link))
+    });
+
+    framework
+        .respond(|r| {
+            if update {
+                r.update();
+            }
+            r.add_embed(embed).add_components(components)
+        })
+        .await
+}
+
+async fn expired(framework: &InteractionContext) -> InteractionResult<()> {
+    framework
+        .respond(|r| {
+            r.content("This search has expired! Start a new one with `/e621 search`.")
+                .ephemeral()
+        })
+        .await
+}
+
+async fn respond_error(framework: &InteractionContext, why: E621Error) -> InteractionResult<()> {
+    tracing::warn!(?why, "e621 - Search failed");
+    let content = match why {
+        E621Error::RateLimited => "e621 is asking me to slow down, try again in a few seconds!",
+        _ => "I couldn't reach e621 right now, try again later!",
+    };
+    framework.respond(|r| r.content(content).ephemeral()).await
+}
@@ -0,0 +1,80 @@
+use luro_model::e621::{E621Blacklist, E621Rating};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+use crate::{
+    commands::E621Search,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "search", desc = "Search for posts by their tags")]
+pub struct Search {
+    /// Tags to search for, separated by spaces. Use -tag to exclude a tag
+    tags: String,
+    /// Only show posts with this rating. Outside of NSFW channels, only safe posts are shown
+    rating: Option<Rating>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum Rating {
+    #[option(name = "Safe", value = "s")]
+    Safe,
+    #[option(name = "Questionable", value = "q")]
+    Questionable,
+    #[option(name = "Explicit", value = "e")]
+    Explicit,
+}
+
+impl From<Rating> for E621Rating {
+    fn from(rating: Rating) -> Self {
+        match rating {
+            Rating::Safe => Self::Safe,
+            Rating::Questionable => Self::Questionable,
+            Rating::Explicit => Self::Explicit,
+        }
+    }
+}
+
+impl crate::models::CreateCommand for Search {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let nsfw = framework.channel().nsfw.unwrap_or_default();
+        let mut tags = self.tags.split_whitespace().collect::<Vec<_>>().join(" ");
+        if let Some(rating) = self.rating {
+            let rating = E621Rating::from(rating);
+            if !nsfw && rating != E621Rating::Safe {
+                return framework
+                    .respond(|r| r.content("Only safe posts can be shown outside of NSFW channels!").ephemeral())
+                    .await;
+            }
+            tags = format!("{tags} {}", rating.as_tag());
+        }
+
+        // The global blacklist always applies, with the user's own blacklist on top
+        let mut blacklist = E621Blacklist::parse(framework.gateway.config.e621_blacklist.as_deref().unwrap_or_default());
+        if let Some(user_blacklist) = framework.database().fetch_e621_blacklist(framework.author_id()).await? {
+            blacklist.extend(&user_blacklist);
+        }
+
+        let search = E621Search::new(framework.author_id(), nsfw, tags, blacklist);
+        let (id, post) = match framework
+            .gateway
+            .e621
+            .search(&framework.gateway.http_client, &framework.gateway.config, search)
+            .await
+        {
+            Ok(result) => result,
+            Err(why) => return super::respond_error(framework, why).await,
+        };
+
+        let (Some(post), Some(state)) = (post, framework.gateway.e621.state(id).await) else {
+            return framework
+                .respond(|r| {
+                    r.content("I couldn't find any posts matching those tags that aren't blacklisted!")
+                        .ephemeral()
+                })
+                .await;
+        };
+
+        super::respond_post(framework, id, &post, &state, false).await
+    }
+}
@@ -0,0 +1,46 @@
+use luro_model::flavour::FlavourEntry;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Add an entry to one of this server's flavour text pools",
+    user_permissions = "MANAGE_GUILD"
+)]
+pub struct Add {
+    /// The pool to add to, from `/flavour pools`
+    pool: String,
+    /// The text. `<author>` becomes whoever used the command, and `<user>` who it was used on
+    #[command(max_length = 1000)]
+    text: String,
+}
+
+impl crate::models::CreateCommand for Add {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        if super::unknown_pool(framework, &self.pool).await? {
+            return Ok(());
+        }
+
+        let built_in = framework.gateway.flavour.read().await.pool(&self.pool).contains(&self.text);
+        let entries = framework.database().fetch_flavour_entries(guild_id, Some(&self.pool)).await?;
+        let duplicate = built_in || entries.iter().any(|entry| entry.content == self.text);
+        if duplicate {
+            return framework.respond(|r| r.content("That is already in the pool!").ephemeral()).await;
+        }
+
+        let entry = FlavourEntry::new(guild_id, self.pool, self.text, framework.author_id());
+        let entry_id = framework.database().create_flavour_entry(&entry).await?;
+
+        framework
+            .respond(|r| {
+                r.content(format!(
+                    "Added entry `#{entry_id}` to `{}`! Everyone can vote on it with `/flavour vote`.",
+                    entry.pool
+                ))
+            })
+            .await
+    }
+}
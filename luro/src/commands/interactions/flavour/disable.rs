@@ -0,0 +1,22 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "disable",
+    desc = "Stop me from using an entry in this server",
+    user_permissions = "MANAGE_GUILD"
+)]
+pub struct Disable {
+    /// The pool the entry is in, from `/flavour pools`
+    pool: String,
+    /// The entry from `/flavour list`, such as `b3` or `#12`
+    entry: String,
+}
+
+impl crate::models::CreateCommand for Disable {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::set_disabled(framework, &self.pool, &self.entry, true).await
+    }
+}
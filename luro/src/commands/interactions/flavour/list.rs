@@ -0,0 +1,76 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+/// How many entries to show per page
+const PAGE_SIZE: usize = 20;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List the entries of a flavour text pool")]
+pub struct List {
+    /// The pool to list, from `/flavour pools`
+    pool: String,
+    /// The page to show, starting from 1
+    #[command(min_value = 1)]
+    page: Option<i64>,
+}
+
+impl crate::models::CreateCommand for List {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        if super::unknown_pool(framework, &self.pool).await? {
+            return Ok(());
+        }
+
+        let disabled = framework.database().fetch_flavour_disabled(guild_id, &self.pool).await?;
+        let entries = framework.database().fetch_flavour_entries(guild_id, Some(&self.pool)).await?;
+        let built_in = framework.gateway.flavour.read().await.pool(&self.pool).to_vec();
+
+        // Guild entries first, as those are the ones that can be voted on
+        let mut lines = vec![];
+        for entry in &entries {
+            let status = match entry.pickable() {
+                true => "",
+                false => " *(disabled)*",
+            };
+            lines.push(format!(
+                "`#{}` **{:+}** {}{status}",
+                entry.id,
+                entry.score,
+                super::snippet(&entry.content)
+            ));
+        }
+        for (index, content) in built_in.iter().enumerate() {
+            let status = match disabled.contains(content) {
+                true => " *(disabled)*",
+                false => "",
+            };
+            lines.push(format!("`b{}` {}{status}", index + 1, super::snippet(content)));
+        }
+
+        let pages = lines.len().div_ceil(PAGE_SIZE).max(1);
+        let page = (self.page.unwrap_or(1) as usize).min(pages);
+        let description = match lines.is_empty() {
+            true => "This pool is empty! Add something with `/flavour add`".to_owned(),
+            false => lines
+                .into_iter()
+                .skip((page - 1) * PAGE_SIZE)
+                .take(PAGE_SIZE)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title(format!("Flavour text: {}", self.pool))
+            .description(description)
+            .footer(|f| {
+                f.text(format!(
+                    "Page {page} of {pages} - Use the numbers on the left with /flavour vote, disable and enable"
+                ))
+            });
+        framework.respond(|r| r.add_embed(embed)).await
+    }
+}
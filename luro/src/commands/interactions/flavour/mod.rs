@@ -0,0 +1,131 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
+
+mod add;
+mod disable;
+mod enable;
+mod list;
+mod pools;
+mod vote;
+
+/// How much of an entry to show when listing them
+const SNIPPET_LENGTH: usize = 60;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "flavour", desc = "Manage the flavour text I use in this server", guild_only)]
+pub enum Command {
+    #[command(name = "add")]
+    Add(add::Add),
+    #[command(name = "disable")]
+    Disable(disable::Disable),
+    #[command(name = "enable")]
+    Enable(enable::Enable),
+    #[command(name = "list")]
+    List(list::List),
+    #[command(name = "pools")]
+    Pools(pools::Pools),
+    #[command(name = "vote")]
+    Vote(vote::Vote),
+}
+
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        match self {
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::Disable(cmd) => cmd.handle_command(framework).await,
+            Self::Enable(cmd) => cmd.handle_command(framework).await,
+            Self::List(cmd) => cmd.handle_command(framework).await,
+            Self::Pools(cmd) => cmd.handle_command(framework).await,
+            Self::Vote(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+}
+
+/// An entry as written in `/flavour list`. Built in entries are numbered like `b3`, guild entries use their ID.
+enum EntryRef {
+    BuiltIn(usize),
+    Guild(i64),
+}
+
+impl EntryRef {
+    fn parse(entry: &str) -> Option<Self> {
+        let entry = entry.trim().trim_start_matches('#');
+        match entry.strip_prefix('b') {
+            Some(number) => number.parse::<usize>().ok().filter(|x| *x > 0).map(|x| Self::BuiltIn(x - 1)),
+            None => entry.parse().ok().map(Self::Guild),
+        }
+    }
+}
+
+fn guild_id(framework: &InteractionContext) -> InteractionResult<Id<GuildMarker>> {
+    framework.interaction.guild_id.ok_or(InteractionError::NotGuild)
+}
+
+/// Respond with an error if the pool is not one that I use
+async fn unknown_pool(framework: &InteractionContext, pool: &str) -> InteractionResult<bool> {
+    if framework.gateway.flavour.read().await.names().contains(&pool) {
+        return Ok(false);
+    }
+
+    framework
+        .respond(|r| {
+            r.content(format!(
+                "There is no pool called `{pool}`! See `/flavour pools` for the pools I use."
+            ))
+            .ephemeral()
+        })
+        .await?;
+    Ok(true)
+}
+
+/// Disable or enable an entry of the guild's pool
+async fn set_disabled(framework: &InteractionContext, pool: &str, entry: &str, disabled: bool) -> InteractionResult<()> {
+    let guild_id = guild_id(framework)?;
+    if unknown_pool(framework, pool).await? {
+        return Ok(());
+    }
+
+    let action = match disabled {
+        true => "Disabled",
+        false => "Enabled",
+    };
+
+    let content = match EntryRef::parse(entry) {
+        Some(EntryRef::BuiltIn(index)) => {
+            let built_in = framework.gateway.flavour.read().await.pool(pool).get(index).cloned();
+            match built_in {
+                Some(content) => {
+                    match disabled {
+                        true => framework.database().update_flavour_disabled(guild_id, pool, &content).await?,
+                        false => framework.database().delete_flavour_disabled(guild_id, pool, &content).await?,
+                    };
+                    format!("{action} built in entry `b{}` of `{pool}`!", index + 1)
+                }
+                None => format!("There is no built in entry `{entry}` in `{pool}`!"),
+            }
+        }
+        Some(EntryRef::Guild(entry_id)) => match framework.database().fetch_flavour_entry(entry_id).await? {
+            Some(mut flavour_entry) if flavour_entry.guild_id == guild_id && flavour_entry.pool == pool => {
+                flavour_entry.disabled = disabled;
+                framework.database().update_flavour_entry(&flavour_entry).await?;
+                format!("{action} entry `#{entry_id}` of `{pool}`!")
+            }
+            _ => format!("This server has no entry `#{entry_id}` in `{pool}`!"),
+        },
+        None => format!("`{entry}` is not an entry! Use the number shown in `/flavour list`, such as `b3` or `#12`."),
+    };
+
+    framework.respond(|r| r.content(content).ephemeral()).await
+}
+
+/// The first line of an entry, shortened for listing
+fn snippet(content: &str) -> String {
+    let first_line = content.lines().next().unwrap_or_default();
+    let mut snippet: String = first_line.chars().take(SNIPPET_LENGTH).collect();
+    if snippet.len() < content.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
@@ -0,0 +1,39 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "pools", desc = "See the flavour text pools I use, and how many entries this server added")]
+pub struct Pools {}
+
+impl crate::models::CreateCommand for Pools {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let entries = framework
+            .database()
+            .fetch_flavour_entries(super::guild_id(framework)?, None)
+            .await?;
+
+        let pools = framework.gateway.flavour.read().await;
+
+        let mut description = String::new();
+        for name in pools.names() {
+            let built_in = pools.pool(name).len();
+            let added = entries.iter().filter(|entry| entry.pool == name).count();
+            description.push_str(&format!("- `{name}`: {built_in} built in, {added} added here\n"));
+        }
+        drop(pools);
+
+        if description.is_empty() {
+            description.push_str("I don't have any flavour text loaded!");
+        }
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title("Flavour text pools")
+            .description(description)
+            .footer(|f| f.text("Use /flavour list to see the entries of a pool"));
+        framework.respond(|r| r.add_embed(embed)).await
+    }
+}
@@ -0,0 +1,59 @@
+use luro_model::flavour::HIDDEN_SCORE;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "vote", desc = "Vote on an entry this server added. Popular entries are picked more often.")]
+pub struct Vote {
+    /// The entry ID from `/flavour list`, such as `#12`
+    entry: String,
+    /// True to upvote, false to downvote
+    upvote: bool,
+}
+
+impl crate::models::CreateCommand for Vote {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+
+        let entry = match super::EntryRef::parse(&self.entry) {
+            Some(super::EntryRef::Guild(entry_id)) => framework.database().fetch_flavour_entry(entry_id).await?,
+            Some(super::EntryRef::BuiltIn(_)) => {
+                return framework
+                    .respond(|r| {
+                        r.content("Built in entries can't be voted on, but they can be turned off with `/flavour disable`!")
+                            .ephemeral()
+                    })
+                    .await
+            }
+            None => None,
+        };
+
+        let Some(entry) = entry.filter(|entry| entry.guild_id == guild_id) else {
+            return framework
+                .respond(|r| r.content(format!("This server has no entry `{}`!", self.entry)).ephemeral())
+                .await;
+        };
+
+        let vote = match self.upvote {
+            true => 1,
+            false => -1,
+        };
+        framework
+            .database()
+            .update_flavour_vote(entry.id, framework.author_id(), vote)
+            .await?;
+        let score = framework
+            .database()
+            .fetch_flavour_entry(entry.id)
+            .await?
+            .map(|entry| entry.score)
+            .unwrap_or_default();
+
+        let mut content = format!("Voted! Entry `#{}` now has a score of **{score:+}**.", entry.id);
+        if score <= HIDDEN_SCORE {
+            content.push_str(" It has been voted down far enough that I won't use it anymore.");
+        }
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
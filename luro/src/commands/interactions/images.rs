@@ -1,25 +1,124 @@
-use luro_framework::{
-    CommandInteraction, {CreateLuroCommand, LuroCommand},
+use luro_model::{
+    builders::{ComponentBuilder, EmbedBuilder},
+    image::{Image, ImageStatus},
 };
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::{
+    command::{CommandModel, CreateCommand},
+    custom_id::CustomId,
+};
+use twilight_model::channel::message::component::ButtonStyle;
+
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
 
-mod add;
+mod get;
+mod hash;
+mod queue;
 mod random;
+mod submit;
+mod tag;
 
-#[derive(CommandModel, CreateCommand, Debug)]
-#[command(name = "images", desc = "Get some images!")]
-pub enum Images {
-    #[command(name = "add")]
-    Add(add::Add),
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "images", desc = "A library of images submitted by the community")]
+pub enum Command {
+    #[command(name = "get")]
+    Get(get::Get),
+    #[command(name = "queue")]
+    Queue(queue::Queue),
     #[command(name = "random")]
     Random(random::Random),
+    #[command(name = "submit")]
+    Submit(submit::Submit),
+    #[command(name = "tag")]
+    Tag(tag::Tag),
 }
 
-impl CreateLuroCommand for Images {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
         match self {
-            Self::Add(command) => command.interaction_command(ctx).await,
-            Self::Random(command) => command.interaction_command(ctx).await,
+            Self::Get(cmd) => cmd.handle_command(framework).await,
+            Self::Queue(cmd) => cmd.handle_command(framework).await,
+            Self::Random(cmd) => cmd.handle_command(framework).await,
+            Self::Submit(cmd) => cmd.handle_command(framework).await,
+            Self::Tag(cmd) => cmd.handle_command(framework).await,
         }
     }
+
+    /// Staff pressed approve or reject on the review queue
+    async fn handle_component(framework: &mut InteractionContext) -> InteractionResult<()> {
+        queue::review(framework).await
+    }
+}
+
+/// The approve and reject buttons in the review queue, stored in their custom ID
+#[derive(CustomId)]
+#[custom_id(prefix = "images")]
+struct Review {
+    img_id: i64,
+    approve: bool,
+}
+
+/// Only bot staff can review images
+async fn require_staff(framework: &InteractionContext) -> InteractionResult<()> {
+    match framework.gateway.database.check_staff(framework.author_id()).await? {
+        Some(_) => Ok(()),
+        None => Err(InteractionError::NotOwner),
+    }
+}
+
+/// NSFW images are only shown in NSFW channels
+fn nsfw_channel(framework: &InteractionContext) -> bool {
+    framework.channel().nsfw.unwrap_or_default()
+}
+
+/// Build an embed showing an image and its credits
+fn image_embed(image: &Image, accent_colour: u32) -> EmbedBuilder {
+    let mut embed = EmbedBuilder::default();
+    embed
+        .colour(accent_colour)
+        .title(&image.name)
+        .image(|embed_image| embed_image.url(&image.url));
+    if let Some(ref source) = image.source {
+        embed.url(source);
+    }
+
+    let mut credits = format!("Submitted by <@{}>", image.owner_id);
+    if let Some(ref artist) = image.artist {
+        credits.push_str(&format!("\nArt by **{artist}**"));
+    }
+    embed.description(credits);
+    if !image.tags.is_empty() {
+        embed.create_field("Tags", image.tags.join(", "), false);
+    }
+
+    let rating = match image.nsfw {
+        true => "NSFW",
+        false => "SFW",
+    };
+    let status = match image.status {
+        ImageStatus::Approved => String::new(),
+        status => format!(" | {status}"),
+    };
+    embed.footer(|footer| footer.text(format!("{rating} | Image ID: {}{status}", image.img_id)));
+    embed
+}
+
+/// The approve and reject buttons for an image in the review queue
+fn review_components(image: &Image) -> InteractionResult<ComponentBuilder> {
+    let approve = Review {
+        img_id: image.img_id,
+        approve: true,
+    }
+    .to_custom_id()?;
+    let reject = Review {
+        img_id: image.img_id,
+        approve: false,
+    }
+    .to_custom_id()?;
+
+    let mut components = ComponentBuilder::default();
+    components.action_row(|row| {
+        row.button(|button| button.custom_id(approve).label("Approve").style(ButtonStyle::Success))
+            .button(|button| button.custom_id(reject).label("Reject").style(ButtonStyle::Danger))
+    });
+    Ok(components)
 }
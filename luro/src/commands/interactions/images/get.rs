@@ -0,0 +1,37 @@
+use luro_model::image::ImageStatus;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "get", desc = "Get an image from the library by its ID")]
+pub struct Get {
+    /// The image ID
+    id: i64,
+}
+
+impl crate::models::CreateCommand for Get {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let image = framework.database().fetch_image(self.id).await?;
+
+        // Images waiting for review can only be seen by the person who submitted them
+        let Some(image) =
+            image.filter(|image| image.status == ImageStatus::Approved || image.owner_id == framework.author_id().get() as i64)
+        else {
+            return framework
+                .respond(|r| r.content(format!("There is no image with the ID {}!", self.id)).ephemeral())
+                .await;
+        };
+
+        if image.nsfw && !super::nsfw_channel(framework) {
+            return framework
+                .respond(|r| r.content("That image is NSFW, so I can only show it in NSFW channels!").ephemeral())
+                .await;
+        }
+
+        let embed = super::image_embed(&image, framework.gateway.config.accent_colour());
+        framework
+            .respond(|r| r.add_embed(embed).ephemeral_toggle(image.status != ImageStatus::Approved))
+            .await
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use image::imageops::FilterType;
+
+/// The largest image that will be downloaded for hashing
+const MAX_IMAGE_SIZE: usize = 16 * 1024 * 1024;
+/// How long an image may take to download
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+/// How many redirects are followed when downloading an image
+const MAX_REDIRECTS: usize = 5;
+
+#[derive(thiserror::Error, Debug)]
+pub enum HashError {
+    #[error("The image could not be downloaded")]
+    Download(#[from] reqwest::Error),
+    #[error("The link does not point to a public address")]
+    Blocked,
+    #[error("The image is larger than 16MiB")]
+    TooLarge,
+    #[error("The link is not an image I can read")]
+    Decode(#[from] image::ImageError),
+    #[error("The image could not be read")]
+    Task(#[from] tokio::task::JoinError),
+}
+
+/// Download an image and compute its difference hash. Similar looking images have hashes that differ by only a few bits,
+/// even after being resized or recompressed.
+///
+/// The link comes from a user, so it may only point to a public address and is downloaded with a size limit.
+pub async fn perceptual_hash(url: &str) -> Result<i64, HashError> {
+    let url = reqwest::Url::parse(url).map_err(|_| HashError::Blocked)?;
+    if blocked_url(&url) {
+        return Err(HashError::Blocked);
+    }
+
+    let mut response = public_client()?.get(url).send().await?.error_for_status()?;
+    if response.content_length().is_some_and(|length| length > MAX_IMAGE_SIZE as u64) {
+        return Err(HashError::TooLarge);
+    }
+
+    // Read in chunks, as the length is not always sent
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if bytes.len() + chunk.len() > MAX_IMAGE_SIZE {
+            return Err(HashError::TooLarge);
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    tokio::task::spawn_blocking(move || difference_hash(&bytes)).await?
+}
+
+fn difference_hash(bytes: &[u8]) -> Result<i64, HashError> {
+    let pixels = image::load_from_memory(bytes)?.resize_exact(9, 8, FilterType::Triangle).to_luma8();
+
+    // Each bit says if a pixel is brighter than the one to its right
+    let mut hash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            hash <<= 1;
+            if pixels.get_pixel(x, y)[0] > pixels.get_pixel(x + 1, y)[0] {
+                hash |= 1;
+            }
+        }
+    }
+
+    Ok(hash as i64)
+}
+
+/// A client that only connects to public addresses, including when following redirects
+fn public_client() -> Result<reqwest::Client, reqwest::Error> {
+    reqwest::Client::builder()
+        .dns_resolver(Arc::new(PublicResolver))
+        .redirect(reqwest::redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS || blocked_url(attempt.url()) {
+                return attempt.stop();
+            }
+            attempt.follow()
+        }))
+        .timeout(DOWNLOAD_TIMEOUT)
+        .build()
+}
+
+/// Links that are not http(s), or that point straight at an address which is not public.
+/// Hostnames are checked when they are resolved, by [`PublicResolver`].
+fn blocked_url(url: &reqwest::Url) -> bool {
+    if !matches!(url.scheme(), "http" | "https") {
+        return true;
+    }
+
+    match url.host_str() {
+        Some(host) => match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+            Ok(ip) => !public_ip(ip),
+            Err(_) => false,
+        },
+        None => true,
+    }
+}
+
+/// Resolves hostnames to their public addresses only, so a link can't reach the bot's own network
+struct PublicResolver;
+
+impl reqwest::dns::Resolve for PublicResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let addresses = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|address| public_ip(address.ip()))
+                .collect::<Vec<SocketAddr>>();
+
+            if addresses.is_empty() {
+                return Err(format!("{} does not resolve to a public address", name.as_str()).into());
+            }
+
+            let addresses: reqwest::dns::Addrs = Box::new(addresses.into_iter());
+            Ok(addresses)
+        })
+    }
+}
+
+/// If an address can be reached from the internet. Loopback, private, link-local and other reserved ranges are not.
+fn public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                || first == 0
+                || first >= 240
+                // Shared address space, used for carrier-grade NAT
+                || (first == 100 && (64..128).contains(&second)))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => public_ip(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    // Unique local
+                    || (first & 0xfe00) == 0xfc00
+                    // Link local
+                    || (first & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
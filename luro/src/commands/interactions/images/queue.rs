@@ -0,0 +1,70 @@
+use luro_model::image::ImageStatus;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::message::Component;
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "queue", desc = "Review submitted images. Only staff can use this", owner_only)]
+pub struct Queue {}
+
+impl crate::models::CreateCommand for Queue {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        respond_next(framework, None).await
+    }
+}
+
+/// Approve or reject an image from its review buttons, then show the next one
+pub async fn review(framework: &mut InteractionContext) -> InteractionResult<()> {
+    super::require_staff(framework).await?;
+
+    let review: super::Review = framework.custom_id()?;
+    let status = match review.approve {
+        true => ImageStatus::Approved,
+        false => ImageStatus::Rejected,
+    };
+
+    let note = match framework.database().fetch_image(review.img_id).await? {
+        Some(mut image) if image.status == ImageStatus::Pending => {
+            image.status = status;
+            image.reviewed_by = Some(framework.author_id().get() as i64);
+            framework.database().update_image(&image).await?;
+            format!("{status} `{}` (ID {}).", image.name, image.img_id)
+        }
+        Some(image) => format!("`{}` (ID {}) was already {}.", image.name, image.img_id, image.status),
+        None => "That image no longer exists.".to_owned(),
+    };
+
+    respond_next(framework, Some(note)).await
+}
+
+/// Show the oldest image waiting for review. When a review button was pressed, the message is updated in place.
+async fn respond_next(framework: &InteractionContext, note: Option<String>) -> InteractionResult<()> {
+    let (image, waiting) = framework.database().fetch_image_queue().await?;
+    let update = note.is_some();
+    let mut content = note.map(|note| format!("{note}\n")).unwrap_or_default();
+
+    let Some(image) = image else {
+        content.push_str("There are no images waiting for review!");
+        return framework
+            .respond(|r| {
+                if update {
+                    r.update().add_components(Vec::<Component>::new());
+                }
+                r.content(content).ephemeral()
+            })
+            .await;
+    };
+
+    content.push_str(&format!("{waiting} image(s) waiting for review."));
+    let embed = super::image_embed(&image, framework.gateway.config.accent_colour());
+    let components = super::review_components(&image)?;
+    framework
+        .respond(|r| {
+            if update {
+                r.update();
+            }
+            r.content(content).add_embed(embed).add_components(components).ephemeral()
+        })
+        .await
+}
@@ -1,48 +1,34 @@
-use anyhow::Context;
-use luro_framework::{CommandInteraction, Luro, LuroCommand};
-use rand::{seq::SliceRandom, thread_rng};
+use luro_model::image::parse_image_tags;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-#[derive(CommandModel, CreateCommand, Debug)]
-#[command(name = "random", desc = "Get some random images from my database!")]
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "random", desc = "Get a random image from the library")]
 pub struct Random {
-    /// Should I get lewd?
-    nsfw: bool,
+    /// Only show images with all of these tags, separated by commas
+    tags: Option<String>,
+    /// Should I get lewd? Only works in NSFW channels
+    nsfw: Option<bool>,
 }
 
-impl LuroCommand for Random {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let accent_colour = ctx.accent_colour();
+impl crate::models::CreateCommand for Random {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let nsfw = self.nsfw.unwrap_or_default();
+        if nsfw && !super::nsfw_channel(framework) {
+            return framework
+                .respond(|r| r.content("I can only get lewd in NSFW channels!").ephemeral())
+                .await;
+        }
 
-        let images = ctx.database.images_fetch(self.nsfw).await?;
-        let image = images.choose(&mut thread_rng()).context("There are no images in the database.")?;
-        let image_owner = ctx.fetch_user(twilight_model::id::Id::new(image.owner_id as u64)).await?;
+        let tags = self.tags.as_deref().map(parse_image_tags).unwrap_or_default();
+        let Some(image) = framework.database().fetch_random_image(nsfw, &tags).await? else {
+            return framework
+                .respond(|r| r.content("I couldn't find any images matching that!").ephemeral())
+                .await;
+        };
 
-        ctx.respond(|r| {
-            r.embed(|e| {
-                if let Some(source) = image.source.clone() {
-                    e.url(source);
-                }
-                e.colour(accent_colour)
-                    .title(image.name.clone())
-                    .image(|i| i.url(image.url.clone()))
-                    .footer(|f| {
-                        f.text(format!(
-                            "{} | Image ID: {}",
-                            match self.nsfw {
-                                true => "NSFW",
-                                false => "SFW",
-                            },
-                            image.img_id
-                        ))
-                    })
-                    .author(|author| {
-                        author
-                            .name(format!("Image by {}", image_owner.name()))
-                            .icon_url(image_owner.avatar_url())
-                    })
-            })
-        })
-        .await
+        let embed = super::image_embed(&image, framework.gateway.config.accent_colour());
+        framework.respond(|r| r.add_embed(embed)).await
     }
 }
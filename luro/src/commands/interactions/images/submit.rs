@@ -0,0 +1,82 @@
+use luro_model::image::{parse_image_tags, Image, ImageStatus};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+use super::hash::HashError;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "submit", desc = "Submit an image to the library. Staff will review it before it is shown")]
+pub struct Submit {
+    /// A name for the image
+    #[command(max_length = 100)]
+    name: String,
+    /// A direct link to the image
+    url: String,
+    /// Is this a NSFW image?
+    nsfw: bool,
+    /// Tags describing the image, separated by commas
+    tags: Option<String>,
+    /// Who made the image
+    artist: Option<String>,
+    /// Where the image was posted by the artist
+    source: Option<String>,
+}
+
+impl crate::models::CreateCommand for Submit {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        if !self.url.starts_with("https://") && !self.url.starts_with("http://") {
+            return framework
+                .respond(|r| r.content("The image needs to be a link, starting with `https://`").ephemeral())
+                .await;
+        }
+
+        let phash = match super::hash::perceptual_hash(&self.url).await {
+            Ok(phash) => phash,
+            Err(why) => {
+                tracing::info!(?why, url = %self.url, "Failed to hash a submitted image");
+                let content = match why {
+                    HashError::TooLarge => "That image is too large! It needs to be under 16MiB.",
+                    _ => "I couldn't use that image! Make sure the link is public and goes straight to the image.",
+                };
+                return framework.respond(|r| r.content(content).ephemeral()).await;
+            }
+        };
+
+        if let Some(duplicate) = framework.database().fetch_image_duplicate(phash).await? {
+            return framework
+                .respond(|r| {
+                    r.content(format!(
+                        "That image is already in the library as `{}` (ID {})!",
+                        duplicate.name, duplicate.img_id
+                    ))
+                    .ephemeral()
+                })
+                .await;
+        }
+
+        let mut image = Image {
+            img_id: 0,
+            name: self.name,
+            nsfw: self.nsfw,
+            owner_id: framework.author_id().get() as i64,
+            source: self.source,
+            url: self.url,
+            artist: self.artist,
+            tags: self.tags.as_deref().map(parse_image_tags).unwrap_or_default(),
+            status: ImageStatus::Pending,
+            reviewed_by: None,
+            phash: Some(phash),
+        };
+        image.img_id = framework.database().create_image(&image).await?;
+
+        let embed = super::image_embed(&image, framework.gateway.config.accent_colour());
+        framework
+            .respond(|r| {
+                r.content("Thanks! Your image has been submitted, and will show up once staff have approved it.")
+                    .add_embed(embed)
+                    .ephemeral()
+            })
+            .await
+    }
+}
@@ -0,0 +1,63 @@
+use luro_model::image::parse_image_tags;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "tag",
+    desc = "Change an image's tags or credits. Only staff and the person who submitted it can"
+)]
+pub struct Tag {
+    /// The image ID
+    id: i64,
+    /// Tags to add, separated by commas
+    add: Option<String>,
+    /// Tags to remove, separated by commas
+    remove: Option<String>,
+    /// Who made the image. Set to `-` to remove
+    artist: Option<String>,
+    /// Where the image was posted by the artist. Set to `-` to remove
+    source: Option<String>,
+    /// Is this a NSFW image?
+    nsfw: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Tag {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let Some(mut image) = framework.database().fetch_image(self.id).await? else {
+            return framework
+                .respond(|r| r.content(format!("There is no image with the ID {}!", self.id)).ephemeral())
+                .await;
+        };
+
+        if image.owner_id != framework.author_id().get() as i64 {
+            super::require_staff(framework).await?;
+        }
+
+        if let Some(add) = self.add {
+            image.tags.extend(parse_image_tags(&add));
+            image.tags.sort_unstable();
+            image.tags.dedup();
+        }
+        if let Some(remove) = self.remove {
+            let remove = parse_image_tags(&remove);
+            image.tags.retain(|tag| !remove.contains(tag));
+        }
+        if let Some(artist) = self.artist {
+            image.artist = Some(artist).filter(|artist| artist != "-");
+        }
+        if let Some(source) = self.source {
+            image.source = Some(source).filter(|source| source != "-");
+        }
+        if let Some(nsfw) = self.nsfw {
+            image.nsfw = nsfw;
+        }
+        framework.database().update_image(&image).await?;
+
+        let embed = super::image_embed(&image, framework.gateway.config.accent_colour());
+        framework
+            .respond(|r| r.content("Updated the image!").add_embed(embed).ephemeral())
+            .await
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::Write;
+
+use luro_model::{
+    builders::EmbedBuilder,
+    insights::{InsightsDay, MemberRetention, INSIGHTS_MAX_DAYS},
+    word_stats::{LeaderboardKind, StatsScope},
+};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::guild::Permissions;
+
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "insights",
+    desc = "How this server is growing, and how active it is",
+    dm_permission = false,
+    default_permissions = "manage_guild"
+)]
+pub struct Command {
+    /// How many days to look back over. Defaults to 30
+    #[command(min_value = 7, max_value = 90)]
+    days: Option<i64>,
+}
+
+fn manage_guild() -> Permissions {
+    Permissions::MANAGE_GUILD
+}
+
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = framework.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
+        let days = self.days.unwrap_or(30).clamp(7, INSIGHTS_MAX_DAYS);
+        framework.ack_interaction(false).await?;
+
+        let snapshots = framework.database().fetch_insights(guild_id, days).await?;
+        let week = framework.database().fetch_member_retention(guild_id, 7, days + 7).await?;
+        let month = framework.database().fetch_member_retention(guild_id, 30, days + 30).await?;
+        let scope = StatsScope {
+            guild_id: Some(guild_id),
+            since: Some(OffsetDateTime::now_utc() - Duration::days(days)),
+            ..Default::default()
+        };
+        let channels = framework
+            .database()
+            .fetch_word_leaderboard(&scope, LeaderboardKind::Channels, 5)
+            .await?;
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title("Server Insights")
+            .footer(|footer| footer.text("Snapshots are taken hourly, in UTC"));
+
+        if snapshots.is_empty() {
+            embed.description(format!(
+                "I don't have any snapshots for the last {days} days yet. They are written every hour, so check back soon!"
+            ));
+            return framework.respond(|r| r.add_embed(embed)).await;
+        }
+
+        embed
+            .description(format!(
+                "The last {days} days, from {} to {}",
+                snapshots[0].day,
+                snapshots[snapshots.len() - 1].day
+            ))
+            .create_field("Growth", growth(&snapshots), false);
+
+        // Messages only count from when they started being synced, so days before the first message are left out
+        match snapshots.iter().position(|day| day.messages > 0) {
+            Some(first) => embed.create_field("Activity", activity(&snapshots[first..]), false),
+            None => embed.create_field("Activity", "No messages have been synced for these days yet", false),
+        };
+        embed.create_field("Retention", format!("{}\n{}", retention(&week), retention(&month)), true);
+
+        let mut busiest = String::new();
+        for (position, channel) in channels.iter().enumerate() {
+            let _ = writeln!(busiest, "{}. <#{}> - {} messages", position + 1, channel.id, channel.messages);
+        }
+        if !busiest.is_empty() {
+            embed.create_field("Most Active Channels", busiest, true);
+        }
+
+        framework.respond(|r| r.add_embed(embed)).await
+    }
+}
+
+fn growth(snapshots: &[InsightsDay]) -> String {
+    let first = &snapshots[0];
+    let last = &snapshots[snapshots.len() - 1];
+    let joins = snapshots.iter().map(|day| day.joins).collect::<Vec<_>>();
+    let leaves = snapshots.iter().map(|day| day.leaves).collect::<Vec<_>>();
+
+    format!(
+        "**{}** members ({:+} since {})\nJoins: **{}** `{}`\nLeaves: **{}** `{}`",
+        last.members,
+        last.members - first.members,
+        first.day,
+        joins.iter().sum::<i64>(),
+        crate::embeds::sparkline(&joins),
+        leaves.iter().sum::<i64>(),
+        crate::embeds::sparkline(&leaves),
+    )
+}
+
+fn activity(snapshots: &[InsightsDay]) -> String {
+    let messages = snapshots.iter().map(|day| day.messages).collect::<Vec<_>>();
+    let total = messages.iter().sum::<i64>();
+    let active = snapshots.iter().map(|day| day.active_members).sum::<i64>() / snapshots.len() as i64;
+
+    format!(
+        "**{total}** messages, about **{}** a day `{}`\nAbout **{active}** members chat each day",
+        total / snapshots.len() as i64,
+        crate::embeds::sparkline(&messages),
+    )
+}
+
+fn retention(retention: &MemberRetention) -> String {
+    match retention.percentage() {
+        Some(percentage) => format!(
+            "After {} days: **{percentage:.0}%** ({} of {})",
+            retention.days, retention.retained, retention.joined
+        ),
+        None => format!("After {} days: nobody to measure yet", retention.days),
+    }
+}
@@ -1,33 +1,27 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-use crate::interaction::LuroSlash;
-use luro_model::database::drivers::LuroDatabaseDriver;
-
-use crate::luro_command::LuroCommand;
-
-use self::muzzle::MuzzleCommand;
+use crate::models::interaction::{InteractionContext, InteractionResult};
 
 mod muzzle;
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(
-    name = "lewd",
-    desc = "Whoa! How very lewd of you! These are more... Adult orientated commands."
-)]
-pub enum LewdCommands {
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "lewd", desc = "Whoa! How very lewd of you! These are more... Adult orientated commands.")]
+pub enum Command {
     #[command(name = "muzzle")]
-    Muzzle(MuzzleCommand),
+    Muzzle(muzzle::Command),
 }
 
-impl LuroCommand for LewdCommands {
-    async fn run_command(self, ctx: LuroSlash<D>) -> anyhow::Result<()> {
-        if !ctx.interaction.channel.as_ref().unwrap().nsfw.unwrap_or_default() {
-            return ctx.nsfw_in_sfw_response().await;
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        if !framework.channel().nsfw.unwrap_or_default() {
+            return framework
+                .respond(|r| r.content("Hey! These commands can only be used in NSFW channels!").ephemeral())
+                .await;
         }
 
         // Call the appropriate subcommand.
         match self {
-            Self::Muzzle(command) => command.run_command(ctx).await,
+            Self::Muzzle(command) => command.handle_command(framework).await,
         }
     }
 }
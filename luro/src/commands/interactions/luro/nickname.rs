@@ -1,9 +1,14 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-use crate::models::interaction::{InteractionContext, InteractionResult};
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "nickname", desc = "Change my nickname! Or clear it.", dm_permission = false)]
+#[command(
+    name = "nickname",
+    desc = "Change my nickname! Or clear it.",
+    guild_only,
+    bot_permissions = "CHANGE_NICKNAME"
+)]
 pub struct Command {
     /// Set my nickname to this! Leave me blank to clear my nickname
     name: Option<String>,
@@ -11,10 +16,7 @@ pub struct Command {
 
 impl crate::models::CreateCommand for Command {
     async fn handle_command(self, ctx: &mut InteractionContext) -> InteractionResult<()> {
-        let guild_id = match ctx.interaction.guild_id {
-            Some(guild_id) => guild_id,
-            None => return Err(crate::models::interaction::InteractionError::NotGuild),
-        };
+        let guild_id = ctx.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
 
         ctx.gateway
             .twilight_client
@@ -0,0 +1,146 @@
+use luro_model::{builders::EmbedBuilder, user::Adoption};
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::ancestors,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+use super::buttons;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "adopt", desc = "Ask to become someone's parent!")]
+pub struct Adopt {
+    /// Who would you like to adopt?
+    user: Id<UserMarker>,
+}
+
+impl crate::models::CreateCommand for Adopt {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        let parent_id = framework.author_id();
+
+        if let Some(content) = refusal(framework, parent_id, self.user).await? {
+            return framework.respond(|r| r.content(content).ephemeral()).await;
+        }
+
+        let adoption = Adoption {
+            parent_id,
+            child_id: self.user,
+            guild_id: Some(guild_id),
+            adopted_at: None,
+        };
+        framework.database().update_adoption(&adoption).await?;
+
+        let parent = framework.fetch_user(parent_id).await?;
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title(format!("{} would like to adopt you!", parent.name()))
+            .description(format!("<@{}>, will you join <@{parent_id}>'s family?", self.user))
+            .thumbnail(|t| t.url(parent.avatar_url()));
+
+        let components = buttons(parent_id, true, "Join the family", "No thanks")?;
+        framework
+            .respond(|response| {
+                response
+                    .content(format!("<@{}>", self.user))
+                    .add_embed(embed)
+                    .add_components(components)
+            })
+            .await
+    }
+}
+
+/// Accept or deny being adopted, answered by the child
+pub async fn answer(framework: &InteractionContext, parent_id: Id<UserMarker>, accepted: bool) -> InteractionResult<()> {
+    let child_id = framework.author_id();
+    let pending = framework
+        .database()
+        .fetch_adoptions(child_id)
+        .await?
+        .into_iter()
+        .find(|adoption| adoption.parent_id == parent_id && adoption.child_id == child_id && adoption.adopted_at.is_none());
+
+    let Some(mut adoption) = pending else {
+        return framework
+            .respond(|r| r.content("This adoption isn't waiting on you to answer it!").ephemeral())
+            .await;
+    };
+
+    let mut embed = EmbedBuilder::default();
+    match accepted {
+        true => {
+            if let Some(content) = family_refusal(framework, parent_id, child_id).await? {
+                return framework.respond(|r| r.content(content).ephemeral()).await;
+            }
+
+            adoption.adopted_at = Some(OffsetDateTime::now_utc());
+            framework.database().update_adoption(&adoption).await?;
+            embed
+                .colour(crate::COLOUR_SUCCESS)
+                .title("Welcome to the family!")
+                .description(format!("<@{child_id}> is now <@{parent_id}>'s child!"));
+        }
+        false => {
+            framework.database().delete_adoption(parent_id, child_id).await?;
+            embed
+                .colour(crate::COLOUR_DANGER)
+                .title("The adoption was declined")
+                .description(format!("<@{child_id}> would rather stay where they are."));
+        }
+    }
+
+    framework
+        .respond(|r| r.content("").add_embed(embed).components(|c| c).update())
+        .await
+}
+
+/// Check the adoption makes sense and hasn't already been asked for, returning why if it does not
+async fn refusal(
+    framework: &InteractionContext,
+    parent_id: Id<UserMarker>,
+    child_id: Id<UserMarker>,
+) -> InteractionResult<Option<&'static str>> {
+    let existing = framework.database().fetch_adoptions(child_id).await?;
+    if let Some(adoption) = existing
+        .iter()
+        .find(|adoption| adoption.parent_id == parent_id && adoption.child_id == child_id)
+    {
+        return Ok(Some(match adoption.adopted_at {
+            Some(_) => "They are already your child!",
+            None => "You have already asked to adopt them!",
+        }));
+    }
+
+    family_refusal(framework, parent_id, child_id).await
+}
+
+/// Check the two users' family allows the adoption, returning why if it does not.
+/// Run again when the adoption is accepted, as the family may have changed while it was waiting.
+async fn family_refusal(
+    framework: &InteractionContext,
+    parent_id: Id<UserMarker>,
+    child_id: Id<UserMarker>,
+) -> InteractionResult<Option<&'static str>> {
+    if parent_id == child_id {
+        return Ok(Some("You can't adopt yourself!"));
+    }
+
+    let database = framework.database();
+    if database
+        .fetch_marriage(parent_id, child_id)
+        .await?
+        .is_some_and(|marriage| marriage.married())
+    {
+        return Ok(Some("You can't adopt someone you are married to!"));
+    }
+
+    if ancestors(database, parent_id).await?.contains(&child_id) {
+        return Ok(Some("You can't adopt one of your own parents!"));
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,30 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "disown", desc = "Remove a parent or child from your family")]
+pub struct Disown {
+    /// Your parent or child
+    user: Id<UserMarker>,
+}
+
+impl crate::models::CreateCommand for Disown {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let author_id = framework.author_id();
+        let database = framework.database();
+
+        let content = if database.delete_adoption(author_id, self.user).await? != 0 {
+            format!("<@{author_id}> has disowned <@{}>...", self.user)
+        } else if database.delete_adoption(self.user, author_id).await? != 0 {
+            format!("<@{author_id}> has run away from <@{}>!", self.user)
+        } else {
+            return framework
+                .respond(|r| r.content("They are not your parent or your child!").ephemeral())
+                .await;
+        };
+
+        framework.respond(|r| r.content(content)).await
+    }
+}
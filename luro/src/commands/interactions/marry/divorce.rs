@@ -1,9 +1,8 @@
-use luro_framework::{CommandInteraction, Luro, LuroCommand};
-use luro_model::{types::Marriage, COLOUR_DANGER};
+use luro_model::builders::EmbedBuilder;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
-use super::buttons;
+use crate::models::interaction::{InteractionContext, InteractionResult};
 
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "divorce", desc = "It's over.")]
@@ -11,43 +10,36 @@ pub struct Divorce {
     /// Who no longer deserves your love?
     pub user: Id<UserMarker>,
     /// Why do they no longer desrve your love?
-    reason: String,
+    reason: Option<String>,
 }
 
-impl LuroCommand for Divorce {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let proposer = ctx.author.clone();
-        let proposee = ctx.fetch_user(self.user).await?;
+impl crate::models::CreateCommand for Divorce {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let author_id = framework.author_id();
+        let mut marriage = match framework.database().fetch_marriage(author_id, self.user).await? {
+            Some(marriage) if marriage.married() => marriage,
+            _ => return framework.respond(|r| r.content("You are not married to them!").ephemeral()).await,
+        };
 
-        ctx.database
-            .sqlx
-            .marriage_update(Marriage {
-                proposer_id: proposer.user_id,
-                proposee_id: proposee.user_id,
-                divorced: true,
-                rejected: false,
-                reason: self.reason.clone(),
-            })
-            .await?;
+        marriage.divorced = true;
+        framework.database().update_marriage(&marriage).await?;
 
-        ctx.respond(|response| {
-            response
-                .embed(|embed| {
-                    embed
-                        .colour(COLOUR_DANGER)
-                        .title(format!(
-                            "{} has terminated their marriage with {}!",
-                            proposer.name(),
-                            proposee.name()
-                        ))
-                        .thumbnail(|t| t.url(proposer.avatar_url()))
-                        .create_field("Their Reason", &self.reason, false)
-                        .create_field("Approvers", "None!", false)
-                        .create_field("Disapprovers", "None!", false)
-                })
-                .content(format!("Bad news <@{}>...", &self.user))
-                .add_components(buttons())
-        })
-        .await
+        let author = framework.fetch_user(author_id).await?;
+        let proposee = framework.fetch_user(self.user).await?;
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(crate::COLOUR_DANGER)
+            .title(format!("{} has terminated their marriage with {}!", author.name(), proposee.name()))
+            .thumbnail(|t| t.url(author.avatar_url()));
+        if let Some(married_at) = marriage.married_at {
+            embed.create_field("Married Since", format!("<t:{}:D>", married_at.unix_timestamp()), true);
+        }
+        if let Some(reason) = self.reason {
+            embed.create_field("Their Reason", reason, false);
+        }
+
+        framework
+            .respond(|response| response.content(format!("Bad news <@{}>...", self.user)).add_embed(embed))
+            .await
     }
 }
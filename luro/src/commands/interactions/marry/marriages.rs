@@ -1,8 +1,11 @@
-use luro_framework::{CommandInteraction, Luro, LuroCommand};
 use std::fmt::Write;
+
+use luro_model::builders::EmbedBuilder;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::UserMarker, Id};
 
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "marriages", desc = "Fetches someones marriages")]
 pub struct Marriages {
@@ -10,115 +13,60 @@ pub struct Marriages {
     user: Option<Id<UserMarker>>,
 }
 
-impl LuroCommand for Marriages {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let accent_colour = ctx.accent_colour();
-        let author = ctx.get_specified_user_or_author(self.user).await?;
-        let marriages = ctx.database.user_fetch_marriages(author.user_id).await?;
+impl crate::models::CreateCommand for Marriages {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let user_id = self.user.unwrap_or(framework.author_id());
+        let user = framework.fetch_user(user_id).await?;
+        let marriages = framework.database().fetch_marriages(user_id).await?;
 
-        let mut marriages_detailed = vec![];
-        let mut rejected_proposals = 0;
-        let mut marriages_ended = 0;
+        let mut partners = String::new();
+        let mut partner_count = 0;
+        let mut pending = 0;
+        let mut ended = 0;
+        let mut rejected = 0;
 
-        for marriage in marriages {
+        for marriage in &marriages {
             if marriage.rejected {
-                rejected_proposals += 1;
-                continue;
-            }
-
-            if marriage.divorced {
-                marriages_ended += 1;
-                continue;
+                rejected += 1;
+            } else if marriage.divorced {
+                ended += 1;
+            } else if marriage.pending() {
+                pending += 1;
+            } else if let Some(married_at) = marriage.married_at {
+                partner_count += 1;
+                writeln!(
+                    partners,
+                    "- <@{}> since <t:{}:D>\n  - {}",
+                    marriage.partner_of(user_id),
+                    married_at.unix_timestamp(),
+                    marriage.reason
+                )?;
             }
-
-            let proposer = ctx.fetch_user(marriage.proposer_id).await?;
-            let proposee = ctx.fetch_user(marriage.proposee_id).await?;
-            let all_approvals = ctx
-                .database
-                .sqlx
-                .marriage_fetch_approvals(marriage.proposer_id, marriage.proposee_id)
-                .await?;
-
-            let mut approvals = 0;
-            let mut disapprovals = 0;
-            for approval in all_approvals {
-                if approval.approve {
-                    approvals += 1;
-                }
-                if approval.disapprove {
-                    disapprovals += 1;
-                }
-            }
-
-            marriages_detailed.push((marriage, proposer, proposee, approvals, disapprovals))
         }
 
-        ctx.respond(|response| {
-            response.embed(|embed| {
-                embed
-                    .title(format!("{}'s marriages | {} total", author.name(), marriages_detailed.len()))
-                    .thumbnail(|t| t.url(author.avatar_url()))
-                    .colour(accent_colour);
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title(format!("{}'s marriages | {partner_count} total", user.name()))
+            .thumbnail(|t| t.url(user.avatar_url()));
 
-                if marriages_detailed.is_empty() {
-                    embed.description("Looks like they have no marriages yet :(");
-                }
+        match partners.is_empty() {
+            true => embed.description("Looks like they have no marriages yet :("),
+            false => embed.description(partners),
+        };
 
-                if marriages_ended != 0 {
-                    embed.create_field("Ended Marriages", &format!("A total of `{}` time(s)", marriages_ended), true);
-                }
-
-                if rejected_proposals != 0 {
-                    embed.create_field("Rejected Total", &format!("Rejected `{}` time(s)", rejected_proposals), true);
-                }
-
-                match marriages_detailed.len() < 25 {
-                    true => {
-                        for marriage in marriages_detailed {
-                            let mut status = String::new();
-
-                            if marriage.3 != 0 {
-                                write!(status, " | {} Approvals", marriage.3).unwrap();
-                            }
-
-                            if marriage.4 != 0 {
-                                write!(status, " | {} Disapprovals", marriage.4).unwrap();
-                            }
-
-                            if marriage.0.divorced {
-                                write!(status, " | Devorced").unwrap();
-                            }
+        if pending != 0 {
+            embed.create_field("Waiting For An Answer", format!("`{pending}` proposal(s)"), true);
+        }
 
-                            if marriage.0.rejected {
-                                write!(status, " | Rejected").unwrap();
-                            }
+        if ended != 0 {
+            embed.create_field("Ended Marriages", format!("A total of `{ended}` time(s)"), true);
+        }
 
-                            embed.create_field(
-                                &format!("{} and {}{status}", marriage.1.name(), marriage.2.name()),
-                                &marriage.0.reason,
-                                false,
-                            );
-                        }
-                    }
-                    false => {
-                        let mut description = String::new();
-                        for marriage in marriages_detailed {
-                            writeln!(
-                                description,
-                                "- {} and {}\n  - {}",
-                                marriage.1.name(),
-                                marriage.2.name(),
-                                marriage.0.reason
-                            )
-                            .unwrap();
-                        }
-                        embed.description(description);
-                    }
-                }
+        if rejected != 0 {
+            embed.create_field("Rejected Total", format!("Rejected `{rejected}` time(s)"), true);
+        }
 
-                embed
-            })
-        })
-        .await
+        framework.respond(|r| r.add_embed(embed)).await
     }
 }
@@ -0,0 +1,57 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "settings",
+    desc = "Change how marriages work in this server. Leave empty to see the current settings"
+)]
+pub struct Settings {
+    /// How many partners someone may have at once. Set to 0 to remove the limit
+    #[command(min_value = 0, max_value = 100)]
+    partner_limit: Option<i64>,
+    /// Where to post anniversaries. Defaults to the channel the couple married in
+    #[command(channel_types = "guild_text guild_announcement")]
+    anniversary_channel: Option<Id<ChannelMarker>>,
+}
+
+impl crate::models::CreateCommand for Settings {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        let mut settings = framework.database().fetch_marriage_settings(guild_id).await?;
+
+        if self.partner_limit.is_some() || self.anniversary_channel.is_some() {
+            super::require_manage_guild(framework)?;
+
+            if let Some(partner_limit) = self.partner_limit {
+                settings.partner_limit = partner_limit as i32;
+            }
+
+            if let Some(channel_id) = self.anniversary_channel {
+                settings.anniversary_channel_id = Some(channel_id);
+            }
+
+            framework.database().update_marriage_settings(&settings).await?;
+        }
+
+        let partner_limit = match settings.partner_limit {
+            0 => "Unlimited".to_owned(),
+            limit => format!("`{limit}` at once"),
+        };
+        let anniversary_channel = match settings.anniversary_channel_id {
+            Some(channel_id) => format!("<#{channel_id}>"),
+            None => "Where the couple married".to_owned(),
+        };
+
+        framework
+            .respond(|r| {
+                r.content(format!(
+                    "- **Partners:** {partner_limit}\n- **Anniversaries:** {anniversary_channel}"
+                ))
+                .ephemeral()
+            })
+            .await
+    }
+}
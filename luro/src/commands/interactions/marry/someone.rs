@@ -1,11 +1,14 @@
-use anyhow::Context;
-use luro_framework::{CommandInteraction, Luro, LuroCommand};
-use luro_model::types::Marriage;
-use rand::{seq::SliceRandom, thread_rng};
+use luro_model::{builders::EmbedBuilder, flavour, user::Marriage};
+use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{marker::UserMarker, Id};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
 
-use super::{buttons, MARRIAGE_REASONS};
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+use super::{buttons, FALLBACK_REASON};
 
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "someone", desc = "Propose to someone! So lucky, aww~")]
@@ -16,44 +19,145 @@ pub struct Someone {
     reason: Option<String>,
 }
 
-impl LuroCommand for Someone {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let proposer = ctx.author.clone();
-        let accent_colour = ctx.accent_colour();
-
-        let reason = self.reason.unwrap_or(
-            MARRIAGE_REASONS
-                .choose(&mut thread_rng())
-                .context("Expected to be able to choose a random reason")?
-                .replace("<user>", &format!("<@{}>", &self.marry))
-                .replace("<author>", &format!("<@{}>", &proposer.user_id)),
+impl crate::models::CreateCommand for Someone {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        let proposer_id = framework.author_id();
+
+        if self.marry == proposer_id {
+            return framework
+                .respond(|r| {
+                    r.content("You can't marry yourself! Although self love is important <3")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        if let Some(marriage) = framework.database().fetch_marriage(proposer_id, self.marry).await? {
+            if marriage.married() {
+                return framework
+                    .respond(|r| r.content("You are already married to them!").ephemeral())
+                    .await;
+            }
+
+            if marriage.pending() {
+                return framework
+                    .respond(|r| {
+                        r.content("There is already a proposal between you two waiting for an answer!")
+                            .ephemeral()
+                    })
+                    .await;
+            }
+        }
+
+        if let Some(content) = partner_limit_reached(framework, guild_id, proposer_id, self.marry).await? {
+            return framework.respond(|r| r.content(content).ephemeral()).await;
+        }
+
+        let reason = match self.reason {
+            Some(reason) => reason,
+            None => {
+                let reason = framework.gateway.flavour(Some(guild_id), "marriage_reasons", FALLBACK_REASON).await;
+                flavour::render(&reason, proposer_id, Some(self.marry))
+            }
+        };
+
+        let marriage = Marriage::proposal(
+            proposer_id,
+            self.marry,
+            reason,
+            Some(guild_id),
+            framework.interaction.channel.as_ref().map(|channel| channel.id),
         );
+        framework.database().update_marriage(&marriage).await?;
+
+        let proposer = framework.fetch_user(proposer_id).await?;
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title(format!("{} has proposed!", proposer.name()))
+            .thumbnail(|t| t.url(proposer.avatar_url()))
+            .create_field("Their Reason", &marriage.reason, false);
 
-        ctx.database
-            .sqlx
-            .marriage_update(Marriage {
-                proposer_id: proposer.user_id,
-                proposee_id: self.marry,
-                divorced: false,
-                rejected: false,
-                reason: reason.clone(),
+        let components = buttons(proposer_id, false, "Do you accept?", "Do you deny?")?;
+        framework
+            .respond(|response| {
+                response
+                    .content(format!("<@{}>", self.marry))
+                    .add_embed(embed)
+                    .add_components(components)
             })
-            .await?;
-
-        ctx.respond(|response| {
-            response
-                .embed(|embed| {
-                    embed
-                        .colour(accent_colour)
-                        .title(format!("{} has proposed!", proposer.name()))
-                        .thumbnail(|t| t.url(proposer.avatar_url()))
-                        .create_field("Their Reason", &reason, false)
-                        .create_field("Approvers", "None!", false)
-                        .create_field("Disapprovers", "None!", false)
-                })
-                .content(format!("<@{}>", &self.marry))
-                .add_components(buttons())
-        })
+            .await
+    }
+}
+
+/// Accept or deny a proposal, answered by the person who was proposed to
+pub async fn answer(framework: &InteractionContext, proposer_id: Id<UserMarker>, accepted: bool) -> InteractionResult<()> {
+    let proposee_id = framework.author_id();
+    let mut marriage = match framework.database().fetch_marriage(proposer_id, proposee_id).await? {
+        Some(marriage) if marriage.pending() && marriage.proposee_id == proposee_id => marriage,
+        _ => {
+            return framework
+                .respond(|r| r.content("This proposal isn't waiting on you to answer it!").ephemeral())
+                .await
+        }
+    };
+
+    // The limit may have been reached while the proposal was waiting
+    if accepted && let Some(guild_id) = framework.interaction.guild_id {
+        if let Some(content) = partner_limit_reached(framework, guild_id, proposer_id, proposee_id).await? {
+            return framework.respond(|r| r.content(content).ephemeral()).await;
+        }
+    }
+
+    let mut embed = EmbedBuilder::default();
+    embed.create_field("Their Reason", &marriage.reason, false);
+    let content = match accepted {
+        true => {
+            marriage.married_at = Some(OffsetDateTime::now_utc());
+            embed
+                .colour(crate::COLOUR_SUCCESS)
+                .title("The marriage proceeded! May they live happily forever after!");
+            format!("Congratulations <@{proposer_id}> & <@{proposee_id}>!!!")
+        }
+        false => {
+            marriage.rejected = true;
+            embed
+                .colour(crate::COLOUR_DANGER)
+                .title("The proposal was denied...")
+                .description(format!("Maybe someone else will catch <@{proposer_id}>'s eye soon."));
+            String::new()
+        }
+    };
+
+    framework.database().update_marriage(&marriage).await?;
+    framework
+        .respond(|r| r.content(content).add_embed(embed).components(|c| c).update())
         .await
+}
+
+/// Check neither person already has as many partners as the guild allows, returning why if they do.
+/// The message speaks to the user of the interaction, who may be either the proposer or the proposee.
+async fn partner_limit_reached(
+    framework: &InteractionContext,
+    guild_id: Id<GuildMarker>,
+    proposer_id: Id<UserMarker>,
+    proposee_id: Id<UserMarker>,
+) -> InteractionResult<Option<String>> {
+    let settings = framework.database().fetch_marriage_settings(guild_id).await?;
+
+    for user_id in [proposer_id, proposee_id] {
+        let partners = super::partner_count(framework, user_id).await?;
+        if !settings.allows_partner(partners) {
+            let who = match user_id == framework.author_id() {
+                true => "You already have".to_owned(),
+                false => format!("<@{user_id}> already has"),
+            };
+            return Ok(Some(format!(
+                "{who} `{partners}` partners, which is as many as this server allows!"
+            )));
+        }
     }
+
+    Ok(None)
 }
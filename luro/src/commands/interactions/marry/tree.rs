@@ -0,0 +1,36 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    commands::family_tree,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "tree", desc = "See someone's family tree")]
+pub struct Tree {
+    /// Whose family to show. Shows yours if not set
+    user: Option<Id<UserMarker>>,
+}
+
+impl crate::models::CreateCommand for Tree {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let user_id = self.user.unwrap_or(framework.author_id());
+        let user = framework.fetch_user(user_id).await?;
+        let tree = family_tree(framework.database(), user_id).await?;
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title(format!("{}'s family", user.name()))
+            .thumbnail(|t| t.url(user.avatar_url()));
+
+        match tree.is_empty() {
+            true => embed.description("They don't have any family yet! Try `/marry someone` or `/marry adopt`"),
+            false => embed.description(tree.render()),
+        };
+
+        framework.respond(|r| r.add_embed(embed)).await
+    }
+}
@@ -1,12 +1,19 @@
+use luro_model::command::SlashCommand;
 use twilight_interactions::command::{CommandOption, CreateOption};
+use twilight_model::application::interaction::{InteractionData, InteractionType};
 
 use crate::{
-    models::{interaction::InteractionContext, CreateCommand},
+    models::{
+        interaction::{InteractionContext, InteractionError, InteractionResult},
+        CreateCommand,
+    },
     responses::StandardResponse,
 };
 
 #[cfg(feature = "command-about")]
 mod about;
+#[cfg(feature = "command-ai")]
+mod ai;
 #[cfg(feature = "command-ban")]
 mod ban;
 #[cfg(feature = "command-base64")]
@@ -14,16 +21,35 @@ mod base64;
 #[cfg(feature = "command-boop")]
 mod boop;
 #[cfg(feature = "command-dice")]
-mod dice;
+pub mod dice;
+#[cfg(feature = "command-e621")]
+mod e621;
+#[cfg(feature = "command-flavour")]
+mod flavour;
 #[cfg(feature = "command-hello")]
 mod hello;
+#[cfg(feature = "command-images")]
+mod images;
+#[cfg(feature = "command-insights")]
+mod insights;
+#[cfg(feature = "command-marry")]
+mod marry;
+#[cfg(feature = "command-music")]
+mod music;
 #[cfg(feature = "command-owner")]
 mod owner;
 #[cfg(feature = "command-ping")]
 mod ping;
-mod test;
+#[cfg(feature = "command-quote")]
+mod quote;
+#[cfg(feature = "command-roles")]
+mod roles;
+#[cfg(feature = "command-settings")]
+mod settings;
 #[cfg(feature = "command-uwu")]
 mod uwu;
+#[cfg(feature = "command-words")]
+mod words;
 
 mod kick;
 #[cfg(feature = "command-lewd")]
@@ -36,6 +62,10 @@ pub fn default_commands() -> Vec<twilight_model::application::command::Command>
     vec![
         #[cfg(feature = "command-about")]
         about::Command::setup_command(),
+        #[cfg(feature = "command-ai")]
+        ai::Command::setup_command(),
+        #[cfg(feature = "command-ai")]
+        ai::Privacy::setup_command(),
         #[cfg(feature = "command-ban")]
         ban::Command::setup_command(),
         #[cfg(feature = "command-base64")]
@@ -44,8 +74,30 @@ pub fn default_commands() -> Vec<twilight_model::application::command::Command>
         boop::Command::setup_command(),
         #[cfg(feature = "command-dice")]
         dice::Dice::setup_command(),
+        #[cfg(feature = "command-e621")]
+        e621::Command::setup_command(),
+        #[cfg(feature = "command-images")]
+        images::Command::setup_command(),
+        #[cfg(feature = "command-insights")]
+        insights::Command::setup_command(),
+        #[cfg(feature = "command-flavour")]
+        flavour::Command::setup_command(),
+        #[cfg(feature = "command-marry")]
+        marry::Command::setup_command(),
+        #[cfg(feature = "command-music")]
+        music::Command::setup_command(),
+        #[cfg(feature = "command-quote")]
+        quote::Command::setup_command(),
+        #[cfg(feature = "command-quote")]
+        quote::SaveQuote::setup_command(),
+        #[cfg(feature = "command-roles")]
+        roles::Command::setup_command(),
+        #[cfg(feature = "command-settings")]
+        settings::Command::setup_command(),
         #[cfg(feature = "command-uwu")]
         uwu::UwU::setup_command(),
+        #[cfg(feature = "command-words")]
+        words::Command::setup_command(),
         #[cfg(feature = "command-ping")]
         ping::Ping::setup_command(),
         #[cfg(feature = "command-lewd")]
@@ -55,21 +107,76 @@ pub fn default_commands() -> Vec<twilight_model::application::command::Command>
         warn::Command::setup_command(),
         unban::Command::setup_command(),
         luro::Command::setup_command(),
-        // test::test_command_v2().twilight_command(),
+    ]
+    .into_iter()
+    .chain(slash_commands().iter().map(|command| command.twilight_command()))
+    .collect()
+}
+
+/// Commands written as plain async functions with `#[luro_derive::slash_command]`
+pub fn slash_commands() -> Vec<SlashCommand<InteractionContext, InteractionError>> {
+    vec![
+        #[cfg(feature = "command-hello")]
+        hello::hello(),
     ]
 }
 
 /// Handle a command spawned from an interaction context
+///
+/// Components and modals are routed by the prefix of their custom ID, see [`InteractionContext::route`].
 pub async fn interaction_handler(mut framework: InteractionContext) {
     tracing::info!("Handling interaction `{}`", framework.command_name());
 
-    let response = match framework.command_name() {
+    let checked = matches!(
+        framework.interaction.kind,
+        InteractionType::ApplicationCommand | InteractionType::MessageComponent | InteractionType::ModalSubmit
+    );
+    if checked && !framework.command_allowed().await {
+        let route = framework.command_path();
+        let command = luro_model::command_policy::command_path(&route).to_owned();
+        if let Err(why) = framework.standard_response(StandardResponse::CommandDisabled(&command)).await {
+            tracing::warn!(?why, "Failed to respond to a disabled command");
+        }
+        return;
+    }
+
+    let response = match framework.route() {
         "about" => about::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-ai")]
+        "ai" => ai::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-ai")]
+        "ai-privacy" => ai::Privacy::interaction_handler(&mut framework).await,
         "ban" => ban::Command::interaction_handler(&mut framework).await,
         "base64" | "base64-encode" | "base64-decode" => base64::Command::interaction_handler(&mut framework).await,
         "boop" => boop::Command::interaction_handler(&mut framework).await,
         "dice" => dice::Dice::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-e621")]
+        "e621" => e621::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-images")]
+        "images" => images::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-insights")]
+        "insights" => insights::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-flavour")]
+        "flavour" => flavour::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-marry")]
+        "marry" => marry::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-music")]
+        "music" | "music-pause" | "music-skip" | "music-loop" | "music-shuffle" | "music-stop" => {
+            music::Command::interaction_handler(&mut framework).await
+        }
+        #[cfg(feature = "command-quote")]
+        "quote" => quote::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-quote")]
+        "Save as quote" => quote::SaveQuote::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-roles")]
+        "roles" | "role-menu" => roles::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-roles")]
+        name if name.starts_with("role-menu-") => roles::Command::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-settings")]
+        "settings" => settings::Command::interaction_handler(&mut framework).await,
         "uwu" => uwu::UwU::interaction_handler(&mut framework).await,
+        #[cfg(feature = "command-words")]
+        "words" => words::Command::interaction_handler(&mut framework).await,
         "ping" => ping::Ping::interaction_handler(&mut framework).await,
         "owner" => owner::Owner::interaction_handler(&mut framework).await,
         "unban" => unban::Command::interaction_handler(&mut framework).await,
@@ -77,7 +184,10 @@ pub async fn interaction_handler(mut framework: InteractionContext) {
         "warn" => warn::Command::interaction_handler(&mut framework).await,
         "luro" => luro::Command::interaction_handler(&mut framework).await,
         "lewd" => lewd::Command::interaction_handler(&mut framework).await,
-        name => framework.standard_response(StandardResponse::UnknownCommand(name)).await,
+        name => match slash_commands().into_iter().find(|command| command.name == name) {
+            Some(command) => slash_command_handler(&mut framework, command).await,
+            None => framework.standard_response(StandardResponse::UnknownCommand(name)).await,
+        },
     };
 
     // Exit early if no error happened
@@ -92,6 +202,32 @@ pub async fn interaction_handler(mut framework: InteractionContext) {
     }
 }
 
+/// Run a command written as a function. These only handle commands, not components, modals or autocomplete.
+async fn slash_command_handler(
+    framework: &mut InteractionContext,
+    command: SlashCommand<InteractionContext, InteractionError>,
+) -> InteractionResult<()> {
+    // A message is not a valid response to autocomplete. The macro refuses `#[autocomplete]`, so this is not expected
+    if framework.interaction.kind == InteractionType::ApplicationCommandAutocomplete {
+        tracing::warn!("The function command `{}` received an autocomplete interaction", command.name);
+        return Ok(());
+    }
+
+    let Some(InteractionData::ApplicationCommand(data)) = framework.interaction.data.clone() else {
+        return framework.standard_response(StandardResponse::UnknownCommand(&command.name)).await;
+    };
+
+    if !framework.run_checks(&command.checks).await? {
+        return Ok(());
+    }
+
+    if command.ephemeral {
+        framework.response.ephemeral();
+    }
+
+    command.run(framework, (*data).into()).await
+}
+
 // =====
 // Shared Structs Below
 // =====
@@ -1,21 +1,26 @@
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand, InteractionTrait};
 use std::fmt::Write;
+
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "info", desc = "Information about the music player", dm_permission = false)]
-pub struct InfoCommand {}
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
 
-impl ExecuteLuroCommand for InfoCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "info", desc = "Information about the music player")]
+pub struct Info {}
 
-        let mut description = String::new();
+impl crate::models::CreateCommand for Info {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = framework.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
+        let player = framework
+            .gateway
+            .music
+            .lavalink
+            .player(guild_id)
+            .await
+            .map_err(anyhow::Error::from)?;
+        let stats = player.node().stats().await;
 
-        let stats = ctx.lavalink.player(guild_id).await?.node().stats().await;
+        let mut description = String::new();
         writeln!(
             description,
             "**Consumption:** `{}` cores assigned - `{:.2}` lavalink load - `{:.2}` system load",
@@ -30,17 +35,18 @@ impl ExecuteLuroCommand for InfoCommand {
             stats.memory.free / 1024 / 1024
         )?;
 
-        let accent_colour = ctx.accent_colour();
-        ctx.respond(|response| {
-            response.embed(|embed| {
-                embed
-                    .description(description)
-                    .create_field("Total Players", &stats.players.to_string(), true)
-                    .create_field("Players playing Music", &stats.playing_players.to_string(), true)
-                    .create_field("Uptime", &stats.uptime.to_string(), true)
-                    .colour(accent_colour)
+        let accent_colour = framework.gateway.config.accent_colour();
+        framework
+            .respond(|response| {
+                response.embed(|embed| {
+                    embed
+                        .description(description)
+                        .create_field("Total Players", stats.players, true)
+                        .create_field("Players playing Music", stats.playing_players, true)
+                        .create_field("Uptime", stats.uptime, true)
+                        .colour(accent_colour)
+                })
             })
-        })
-        .await
+            .await
     }
 }
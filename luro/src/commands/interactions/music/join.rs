@@ -1,28 +1,28 @@
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand};
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
 
-use twilight_model::{
-    gateway::payload::outgoing::UpdateVoiceState,
-    id::{marker::ChannelMarker, Id},
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
 };
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "join", desc = "Get me to join a voice channel to play some music!", dm_permission = false)]
-pub struct JoinCommand {
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "join", desc = "Get me to join a voice channel to play some music!")]
+pub struct Join {
     /// The channel to join
     #[command(channel_types = "guild_voice guild_stage_voice")]
     channel: Id<ChannelMarker>,
 }
 
-impl ExecuteLuroCommand for JoinCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
-        ctx.shard
-            .command(&UpdateVoiceState::new(guild_id, Some(self.channel), false, false))?;
+impl crate::models::CreateCommand for Join {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::update_queue(framework, async |queue| {
+            music::join(&framework.shard, queue, self.channel)?;
+            queue.text_channel_id = Some(framework.channel().id);
+            Ok(())
+        })
+        .await?;
 
-        ctx.respond(|r| r.content(format!("Joined <#{}>!", self.channel))).await
+        framework.respond(|r| r.content(format!("Joined <#{}>!", self.channel))).await
     }
 }
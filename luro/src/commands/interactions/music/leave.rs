@@ -1,22 +1,19 @@
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_lavalink::model::Destroy;
-use twilight_model::gateway::payload::outgoing::UpdateVoiceState;
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "leave", desc = "Leave voice in a guild", dm_permission = false)]
-pub struct LeaveCommand {}
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionError, InteractionResult},
+};
 
-impl ExecuteLuroCommand for LeaveCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
-        let player = ctx.lavalink.player(guild_id).await?;
-        player.send(Destroy::from(guild_id))?;
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "leave", desc = "Leave voice, clearing the queue")]
+pub struct Leave {}
 
-        ctx.shard.command(&UpdateVoiceState::new(guild_id, None, false, false))?;
-        ctx.respond(|r| r.content("Left the channel. Goodbye!")).await
+impl crate::models::CreateCommand for Leave {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = framework.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
+        music::leave(&framework.gateway, &framework.shard, guild_id).await?;
+
+        framework.respond(|r| r.content("Left the channel. Goodbye!")).await
     }
 }
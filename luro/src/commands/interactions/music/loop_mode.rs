@@ -0,0 +1,38 @@
+use luro_model::music::LoopMode;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "loop", desc = "Choose what happens when a track finishes")]
+pub struct Loop {
+    /// The loop mode to use
+    mode: Mode,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum Mode {
+    #[option(name = "Off - Play the next track, stopping at the end of the queue", value = "off")]
+    Off,
+    #[option(name = "Track - Play the current track again", value = "track")]
+    Track,
+    #[option(name = "Queue - Move finished tracks to the end of the queue", value = "queue")]
+    Queue,
+}
+
+impl crate::models::CreateCommand for Loop {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let loop_mode = match self.mode {
+            Mode::Off => LoopMode::Off,
+            Mode::Track => LoopMode::Track,
+            Mode::Queue => LoopMode::Queue,
+        };
+
+        let content = super::update_queue(framework, async |queue| {
+            queue.loop_mode = loop_mode;
+            Ok(format!("Set the loop mode to `{}`", queue.loop_mode))
+        })
+        .await?;
+        framework.respond(|r| r.content(content)).await
+    }
+}
@@ -0,0 +1,151 @@
+use luro_model::{
+    builders::{ComponentBuilder, EmbedBuilder},
+    music::MusicQueue,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionError, InteractionResult},
+};
+
+mod info;
+mod join;
+mod leave;
+mod loop_mode;
+mod move_track;
+mod pause;
+mod play;
+mod queue;
+mod remove;
+mod seek;
+mod shuffle;
+mod skip;
+mod stop;
+mod volume;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "music", desc = "Music commands!", dm_permission = false)]
+pub enum Command {
+    #[command(name = "info")]
+    Info(info::Info),
+    #[command(name = "join")]
+    Join(join::Join),
+    #[command(name = "leave")]
+    Leave(leave::Leave),
+    #[command(name = "loop")]
+    Loop(loop_mode::Loop),
+    #[command(name = "move")]
+    Move(move_track::Move),
+    #[command(name = "pause")]
+    Pause(pause::Pause),
+    #[command(name = "play")]
+    Play(play::Play),
+    #[command(name = "queue")]
+    Queue(queue::Queue),
+    #[command(name = "remove")]
+    Remove(remove::Remove),
+    #[command(name = "seek")]
+    Seek(seek::Seek),
+    #[command(name = "shuffle")]
+    Shuffle(shuffle::Shuffle),
+    #[command(name = "skip")]
+    Skip(skip::Skip),
+    #[command(name = "stop")]
+    Stop(stop::Stop),
+    #[command(name = "volume")]
+    Volume(volume::Volume),
+}
+
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        match self {
+            Self::Info(cmd) => cmd.handle_command(framework).await,
+            Self::Join(cmd) => cmd.handle_command(framework).await,
+            Self::Leave(cmd) => cmd.handle_command(framework).await,
+            Self::Loop(cmd) => cmd.handle_command(framework).await,
+            Self::Move(cmd) => cmd.handle_command(framework).await,
+            Self::Pause(cmd) => cmd.handle_command(framework).await,
+            Self::Play(cmd) => cmd.handle_command(framework).await,
+            Self::Queue(cmd) => cmd.handle_command(framework).await,
+            Self::Remove(cmd) => cmd.handle_command(framework).await,
+            Self::Seek(cmd) => cmd.handle_command(framework).await,
+            Self::Shuffle(cmd) => cmd.handle_command(framework).await,
+            Self::Skip(cmd) => cmd.handle_command(framework).await,
+            Self::Stop(cmd) => cmd.handle_command(framework).await,
+            Self::Volume(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+
+    /// The buttons on the now playing message
+    async fn handle_component(framework: &mut InteractionContext) -> InteractionResult<()> {
+        let gateway = framework.gateway.clone();
+        let command_name = framework.command_name().to_owned();
+
+        let response = update_queue(framework, async |queue| {
+            match command_name.as_str() {
+                "music-pause" => music::toggle_pause(&gateway, queue).await?,
+                "music-loop" => queue.loop_mode = queue.loop_mode.next(),
+                "music-shuffle" => music::shuffle(queue),
+                "music-skip" => {
+                    music::skip(&gateway, queue).await?;
+                    return Ok(ControlResponse::Message("Skipped!"));
+                }
+                "music-stop" => {
+                    music::stop(&gateway, queue).await?;
+                    return Ok(ControlResponse::Message("Stopped the music and cleared the queue!"));
+                }
+                name => {
+                    tracing::warn!("No music control matching {name}");
+                    return Ok(ControlResponse::None);
+                }
+            }
+
+            let embed = music::now_playing_embed(queue, gateway.config.accent_colour());
+            let components = music::now_playing_components(queue);
+            Ok(ControlResponse::NowPlaying(embed, components))
+        })
+        .await?;
+
+        match response {
+            // Skipping and stopping post a new now playing message, so just acknowledge the button
+            ControlResponse::Message(content) => framework.respond(|r| r.content(content).ephemeral()).await,
+            ControlResponse::NowPlaying(embed, components) => {
+                framework.respond(|r| r.add_embed(embed).add_components(components).update()).await
+            }
+            ControlResponse::None => Ok(()),
+        }
+    }
+}
+
+/// How to answer a button on the now playing message
+enum ControlResponse {
+    Message(&'static str),
+    NowPlaying(EmbedBuilder, ComponentBuilder),
+    None,
+}
+
+/// Get a copy of the queue for the guild the interaction was used in
+async fn guild_queue(framework: &InteractionContext) -> InteractionResult<MusicQueue> {
+    let guild_id = framework.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
+    Ok(framework.gateway.music.queue(&framework.gateway.database, guild_id).await)
+}
+
+/// Change the queue for the guild the interaction was used in, see [`music::Music::update`]
+async fn update_queue<R>(
+    framework: &InteractionContext,
+    change: impl AsyncFnOnce(&mut MusicQueue) -> InteractionResult<R>,
+) -> InteractionResult<R> {
+    let guild_id = framework.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
+    framework.gateway.music.update(&framework.gateway.database, guild_id, change).await
+}
+
+/// Respond with an error if nothing is playing
+async fn nothing_playing(framework: &InteractionContext) -> InteractionResult<()> {
+    framework
+        .respond(|r| {
+            r.content("Nothing is playing right now! Add something with `/music play`")
+                .ephemeral()
+        })
+        .await
+}
@@ -0,0 +1,36 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "move", desc = "Move a track to a different position in the queue")]
+pub struct Move {
+    /// The position of the track, as shown in `/music queue`
+    #[command(min_value = 1)]
+    from: i64,
+    /// Where to move the track to. 1 is the next track to play
+    #[command(min_value = 1)]
+    to: i64,
+}
+
+impl crate::models::CreateCommand for Move {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let title = super::update_queue(framework, async |queue| {
+            Ok(match queue.move_track(self.from as usize, self.to as usize) {
+                true => Some(queue.tracks[self.to as usize].title.clone()),
+                false => None,
+            })
+        })
+        .await?;
+
+        let Some(title) = title else {
+            return framework
+                .respond(|r| r.content("One of those positions is not in the queue!").ephemeral())
+                .await;
+        };
+
+        framework
+            .respond(|r| r.content(format!("Moved **{title}** to position `{}`!", self.to)))
+            .await
+    }
+}
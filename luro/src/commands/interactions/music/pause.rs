@@ -1,23 +1,32 @@
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_lavalink::model::Pause;
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "pause", desc = "Pause / Unpause the current playing music", dm_permission = false)]
-pub struct PauseCommand {}
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
+};
 
-impl ExecuteLuroCommand for PauseCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "pause", desc = "Pause / Unpause the current playing music")]
+pub struct Pause {}
+
+impl crate::models::CreateCommand for Pause {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let paused = super::update_queue(framework, async |queue| {
+            if queue.current().is_none() {
+                return Ok(None);
+            }
 
-        let player = ctx.lavalink.player(guild_id).await?;
-        let paused = player.paused();
-        player.send(Pause::from((guild_id, !paused)))?;
+            music::toggle_pause(&framework.gateway, queue).await?;
+            Ok(Some(queue.paused))
+        })
+        .await?;
+
+        let actioned = match paused {
+            Some(true) => "Paused",
+            Some(false) => "Unpaused",
+            None => return super::nothing_playing(framework).await,
+        };
 
-        let actioned = if paused { "Unpaused " } else { "Paused" };
-        ctx.respond(|r| r.content(format!("{actioned} the track!"))).await
+        framework.respond(|r| r.content(format!("{actioned} the track!"))).await
     }
 }
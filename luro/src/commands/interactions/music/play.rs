@@ -1,69 +1,68 @@
-use hyper::{Body, Request};
-
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand};
-use tracing::info;
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_lavalink::{
-    http::{LoadType, LoadedTracks},
-    model::{Play, Volume},
+
+use crate::{
+    commands::music::{self, MusicError},
+    models::interaction::{InteractionContext, InteractionResult},
 };
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "play", desc = "Give me a link or something and I'll try to play it", dm_permission = false)]
-pub struct PlayCommand {
-    /// What you would like me to play
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "play", desc = "Give me a link or something to search for, and I'll add it to the queue")]
+pub struct Play {
+    /// What you would like me to play. Playlists are added in full
     song: String,
-    /// Sets the volume between 0 and 1000! 10 is the default
-    #[command(min_value = 0, max_value = 1_000)]
-    volume: Option<i64>,
 }
 
-impl ExecuteLuroCommand for PlayCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let volume = self.volume.unwrap_or(10);
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
-
-        let player = ctx.lavalink.player(guild_id).await?;
-        let (parts, body) =
-            twilight_lavalink::http::load_track(player.node().config().address, &self.song, &player.node().config().authorization)?
-                .into_parts();
-        let req = Request::from_parts(parts, Body::from(body));
-        let res = ctx.http_client.request(req).await?;
-        let response_bytes = hyper::body::to_bytes(res.into_body()).await?;
-        let loaded = serde_json::from_slice::<LoadedTracks>(&response_bytes)?;
+impl crate::models::CreateCommand for Play {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let queue = super::guild_queue(framework).await?;
+        if queue.voice_channel_id.is_none() {
+            return framework
+                .respond(|r| r.content("I'm not in a voice channel! Use `/music join` first.").ephemeral())
+                .await;
+        }
 
-        let loadtype = match loaded.load_type {
-            LoadType::LoadFailed => "LoadFailed",
-            LoadType::NoMatches => "Failed",
-            LoadType::SearchResult => "SearchResult",
-            LoadType::TrackLoaded => "TrackLoaded",
-            LoadType::PlaylistLoaded => "PlaylistLoaded",
-            _ => "Unknown",
+        // Load the tracks before locking the queue, as searching can take a while
+        framework.ack_interaction(false).await?;
+        let gateway = framework.gateway.clone();
+        let loaded = match music::load_tracks(&gateway.http_client, &gateway.config.music, &self.song, framework.author_id()).await {
+            Ok(loaded) => loaded,
+            Err(why) => {
+                let content = match why {
+                    MusicError::NoMatches => format!("Didn't find anything for `{}`", self.song),
+                    why => format!("Failed to load that track: {why}"),
+                };
+                return framework.respond(|r| r.content(content)).await;
+            }
         };
-        info!(loadtype);
 
-        let content;
-        if let Some(track) = loaded.tracks.first() {
-            info!(track.track);
+        let content = super::update_queue(framework, async |queue| {
+            let was_empty = queue.current().is_none();
+            let content = match (&loaded.playlist, loaded.tracks.first()) {
+                (Some(playlist), _) => format!("Added `{}` tracks from **{playlist}** to the queue!", loaded.tracks.len()),
+                (None, Some(track)) if was_empty => format!("Playing **{}** by **{}**!", track.title, track.author),
+                (None, Some(track)) => format!(
+                    "Added **{}** by **{}** to the queue at position `{}`!",
+                    track.title,
+                    track.author,
+                    queue.tracks.len()
+                ),
+                (None, None) => format!("Didn't find anything for `{}`", self.song),
+            };
 
-            player.send(Volume::from((guild_id, volume)))?;
-            player.send(Play::from((guild_id, &track.track)))?;
+            queue.text_channel_id = Some(framework.channel().id);
+            queue.tracks.extend(loaded.tracks);
 
-            content = if let (Some(title), Some(author)) = (&track.info.title, &track.info.author) {
-                format!("- Playing **{title}** by **{author}** - Volume {volume}")
-            } else {
-                format!(
-                    "- Playing **{:#?}** by **{:#?}** - Volume {volume}",
-                    track.info.title, track.info.author
-                )
-            };
-        } else {
-            content = "Didn't find any results".to_owned();
-        }
+            if was_empty && queue.current().is_some() {
+                queue.position = 0;
+                queue.paused = false;
+                music::play_current(&gateway, queue).await?;
+                music::now_playing(&gateway, queue).await;
+            }
+
+            Ok(content)
+        })
+        .await?;
 
-        ctx.respond(|r| r.content(content)).await
+        framework.respond(|r| r.content(content)).await
     }
 }
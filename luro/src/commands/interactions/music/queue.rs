@@ -0,0 +1,45 @@
+use std::fmt::Write;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+/// How many upcoming tracks to show
+const SHOWN_TRACKS: usize = 15;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "queue", desc = "See what's playing, and what's coming up next")]
+pub struct Queue {}
+
+impl crate::models::CreateCommand for Queue {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let queue = super::guild_queue(framework).await?;
+        let mut embed = music::now_playing_embed(&queue, framework.gateway.config.accent_colour());
+
+        if queue.tracks.len() > 1 {
+            let mut upcoming = String::new();
+            for (position, track) in queue.tracks.iter().enumerate().skip(1).take(SHOWN_TRACKS) {
+                writeln!(
+                    upcoming,
+                    "`{position}.` **{}** by {} - `{}` - <@{}>",
+                    track.title,
+                    track.author,
+                    music::format_length(track.length),
+                    track.requested_by
+                )?;
+            }
+
+            let remaining = queue.tracks.len().saturating_sub(SHOWN_TRACKS + 1);
+            if remaining != 0 {
+                writeln!(upcoming, "...and `{remaining}` more")?;
+            }
+
+            embed.create_field("Queue", upcoming, false);
+        }
+
+        framework.respond(|r| r.add_embed(embed)).await
+    }
+}
@@ -0,0 +1,26 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Remove a track from the queue")]
+pub struct Remove {
+    /// The position of the track, as shown in `/music queue`
+    #[command(min_value = 1)]
+    position: i64,
+}
+
+impl crate::models::CreateCommand for Remove {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let removed = super::update_queue(framework, async |queue| Ok(queue.remove(self.position as usize))).await?;
+        let Some(track) = removed else {
+            return framework
+                .respond(|r| r.content(format!("There is no track at position `{}`!", self.position)).ephemeral())
+                .await;
+        };
+
+        framework
+            .respond(|r| r.content(format!("Removed **{}** from the queue!", track.title)))
+            .await
+    }
+}
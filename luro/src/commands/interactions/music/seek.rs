@@ -1,24 +1,36 @@
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_lavalink::model::Seek;
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "seek", desc = "Seek through the track", dm_permission = false)]
-pub struct SeekCommand {
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "seek", desc = "Seek through the track")]
+pub struct Seek {
     /// Where in the track do you want to seek to (in seconds)?
+    #[command(min_value = 0)]
     position: i64,
 }
 
-impl ExecuteLuroCommand for SeekCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
+impl crate::models::CreateCommand for Seek {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let seeked = super::update_queue(framework, async |queue| {
+            if queue.current().is_none() {
+                return Ok(false);
+            }
+
+            music::seek(&framework.gateway, queue, self.position * 1000).await?;
+            Ok(true)
+        })
+        .await?;
 
-        let player = ctx.lavalink.player(guild_id).await?;
-        player.send(Seek::from((guild_id, self.position * 1000)))?;
+        if !seeked {
+            return super::nothing_playing(framework).await;
+        }
 
-        ctx.respond(|r| r.content(format!("Seeked to {}s", self.position))).await
+        framework
+            .respond(|r| r.content(format!("Seeked to {}", music::format_length(self.position * 1000))))
+            .await
     }
 }
@@ -0,0 +1,22 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "shuffle", desc = "Shuffle the tracks waiting in the queue")]
+pub struct Shuffle {}
+
+impl crate::models::CreateCommand for Shuffle {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let waiting = super::update_queue(framework, async |queue| {
+            music::shuffle(queue);
+            Ok(queue.tracks.len().saturating_sub(1))
+        })
+        .await?;
+
+        framework.respond(|r| r.content(format!("Shuffled `{waiting}` tracks!"))).await
+    }
+}
@@ -0,0 +1,30 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "skip", desc = "Skip the current track")]
+pub struct Skip {}
+
+impl crate::models::CreateCommand for Skip {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let skipped = super::update_queue(framework, async |queue| {
+            let Some(skipped) = queue.current().map(|track| track.title.clone()) else {
+                return Ok(None);
+            };
+
+            music::skip(&framework.gateway, queue).await?;
+            Ok(Some(skipped))
+        })
+        .await?;
+
+        let Some(skipped) = skipped else {
+            return super::nothing_playing(framework).await;
+        };
+
+        framework.respond(|r| r.content(format!("Skipped **{skipped}**!"))).await
+    }
+}
@@ -1,21 +1,18 @@
-use luro_framework::{responses::Response, CommandInteraction, ExecuteLuroCommand};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_lavalink::model::Stop;
 
-#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
-#[command(name = "stop", desc = "Stop the currently playing track", dm_permission = false)]
-pub struct StopCommand {}
+use crate::{
+    commands::music,
+    models::interaction::{InteractionContext, InteractionResult},
+};
 
-impl ExecuteLuroCommand for StopCommand {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = match ctx.guild_id {
-            Some(guild_id) => guild_id,
-            None => return ctx.response_simple(Response::NotGuild).await,
-        };
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "stop", desc = "Stop playing and clear the queue")]
+pub struct Stop {}
 
-        let player = ctx.lavalink.player(guild_id).await?;
-        player.send(Stop::from(guild_id))?;
+impl crate::models::CreateCommand for Stop {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::update_queue(framework, async |queue| Ok(music::stop(&framework.gateway, queue).await?)).await?;
 
-        ctx.respond(|r| r.content("Stopped the track!")).await
+        framework.respond(|r| r.content("Stopped the music and cleared the queue!")).await
     }
 }
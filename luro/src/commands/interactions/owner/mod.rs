@@ -1,6 +1,6 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
+use crate::models::interaction::{InteractionContext, InteractionResult};
 
 // mod clear_warnings;
 mod fakeban;
@@ -15,9 +15,11 @@ mod fakeban;
 // pub mod mass_assign;
 // mod message;
 // mod modify_role;
+mod reload_flavour;
+mod staff;
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "owner", desc = "Bot owner commands, for those with special privileges!")]
+#[command(name = "owner", desc = "Bot owner commands, for those with special privileges!", owner_only)]
 pub enum Owner {
     // #[command(name = "abuse")]
     // Abuse(abuse::Abuse),
@@ -43,6 +45,10 @@ pub enum Owner {
     Fakeban(fakeban::Fakeban),
     // #[command(name = "log")]
     // Log(log::Log),
+    #[command(name = "reload_flavour")]
+    ReloadFlavour(reload_flavour::ReloadFlavour),
+    #[command(name = "staff")]
+    Staff(staff::Staff),
 }
 
 // pub enum OwnerCommands {
@@ -70,6 +76,8 @@ impl std::fmt::Display for Owner {
             // Self::MassAssign(_) => "mass_assign",
             // Self::ModifyRole(_) => "owner_modify",
             // Self::Message(_) => "message",
+            Self::ReloadFlavour(_) => "owner_reload_flavour",
+            Self::Staff(_) => "owner_staff",
         };
 
         write!(f, "{}", name)
@@ -78,10 +86,6 @@ impl std::fmt::Display for Owner {
 
 impl crate::models::CreateCommand for Owner {
     async fn handle_command(self, ctx: &mut InteractionContext) -> InteractionResult<()> {
-        if ctx.gateway.database.check_staff(ctx.author_id()).await?.is_none() {
-            return Err(InteractionError::NotOwner);
-        }
-
         match self {
             // Self::Abuse(cmd) => cmd.handle_command(ctx).await,
             // Self::Assign(cmd) => cmd.handle_command(ctx).await,
@@ -97,6 +101,8 @@ impl crate::models::CreateCommand for Owner {
             // Self::MassAssign(cmd) => cmd.handle_command(ctx).await,
             // Self::ModifyRole(cmd) => cmd.handle_command(ctx).await,
             // Self::Message(cmd) => cmd.handle_command(ctx).await,
+            Self::ReloadFlavour(cmd) => cmd.handle_command(ctx).await,
+            Self::Staff(cmd) => cmd.handle_command(ctx).await,
         }
     }
 
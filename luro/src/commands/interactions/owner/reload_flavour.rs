@@ -0,0 +1,21 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reload_flavour", desc = "Reload the flavour text pools from disk")]
+pub struct ReloadFlavour {}
+
+impl crate::models::CreateCommand for ReloadFlavour {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let content = match framework.gateway.reload_flavour().await {
+            Ok(entries) => format!("Reloaded the flavour text! I now have `{entries}` built in entries."),
+            Err(why) => {
+                tracing::warn!(?why, "Failed to reload the flavour text pools");
+                format!("Failed to reload the flavour text, so I'm keeping the old pools!\n```{why}```")
+            }
+        };
+
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt::Write;
+
+use luro_model::{
+    builders::EmbedBuilder,
+    staff::{StaffAction, StaffAuditEntry},
+    user::UserPermissions,
+};
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+/// How many changes are shown by `/owner staff list`
+const AUDIT_ENTRIES_SHOWN: i64 = 10;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "staff", desc = "Manage who can use the staff commands")]
+pub enum Staff {
+    #[command(name = "add")]
+    Add(StaffAdd),
+    #[command(name = "remove")]
+    Remove(StaffRemove),
+    #[command(name = "list")]
+    List(StaffList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Make someone staff, or change their permissions")]
+pub struct StaffAdd {
+    /// The user to make staff
+    user: Id<UserMarker>,
+    /// What they can do. Only owners can add owners
+    permissions: StaffPermissions,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Take away someone's staff permissions")]
+pub struct StaffRemove {
+    /// The staff member to remove
+    user: Id<UserMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Show the staff, and the latest changes to them")]
+pub struct StaffList {}
+
+#[derive(CommandOption, CreateOption)]
+pub enum StaffPermissions {
+    #[option(name = "Administrator - Can use the staff commands", value = "administrator")]
+    Administrator,
+    #[option(name = "Owner - Can also manage the staff", value = "owner")]
+    Owner,
+}
+
+impl From<StaffPermissions> for UserPermissions {
+    fn from(permissions: StaffPermissions) -> Self {
+        match permissions {
+            StaffPermissions::Administrator => Self::Administrator,
+            StaffPermissions::Owner => Self::Owner,
+        }
+    }
+}
+
+impl crate::models::CreateCommand for Staff {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        match self {
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::Remove(cmd) => cmd.handle_command(framework).await,
+            Self::List(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+}
+
+impl crate::models::CreateCommand for StaffAdd {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let permissions = UserPermissions::from(self.permissions);
+        let actor = actor_permissions(framework).await?;
+
+        if permissions == UserPermissions::Owner && actor != UserPermissions::Owner {
+            return framework.respond(|r| r.content("Only owners can add owners!").ephemeral()).await;
+        }
+
+        if framework.database().staff.permissions(self.user).is_some() {
+            return framework
+                .respond(|r| {
+                    r.content(format!(
+                        "<@{}> is set in my configuration, so they can only be changed there!",
+                        self.user
+                    ))
+                    .ephemeral()
+                })
+                .await;
+        }
+
+        // Admins can't demote an owner by adding them again
+        let current = framework.database().check_staff(self.user).await?.map(|staff| staff.user_type);
+        if current == Some(UserPermissions::Owner) && actor != UserPermissions::Owner {
+            return framework.respond(|r| r.content("Only owners can change owners!").ephemeral()).await;
+        }
+
+        framework.database().update_user_permissions(self.user, permissions.clone()).await?;
+        audit(framework, self.user, StaffAction::Add, permissions.clone()).await?;
+
+        framework
+            .respond(|r| r.content(format!("<@{}> is now staff, as `{permissions}`!", self.user)).ephemeral())
+            .await
+    }
+}
+
+impl crate::models::CreateCommand for StaffRemove {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let actor = actor_permissions(framework).await?;
+
+        if framework.database().staff.permissions(self.user).is_some() {
+            return framework
+                .respond(|r| {
+                    r.content(format!(
+                        "<@{}> is set in my configuration, so they can only be removed there!",
+                        self.user
+                    ))
+                    .ephemeral()
+                })
+                .await;
+        }
+
+        let permissions = match framework.database().check_staff(self.user).await? {
+            Some(staff) => staff.user_type,
+            None => {
+                return framework
+                    .respond(|r| r.content(format!("<@{}> is not staff!", self.user)).ephemeral())
+                    .await
+            }
+        };
+
+        if permissions == UserPermissions::Owner && actor != UserPermissions::Owner {
+            return framework.respond(|r| r.content("Only owners can remove owners!").ephemeral()).await;
+        }
+
+        framework
+            .database()
+            .update_user_permissions(self.user, UserPermissions::User)
+            .await?;
+        audit(framework, self.user, StaffAction::Remove, permissions.clone()).await?;
+
+        framework
+            .respond(|r| r.content(format!("<@{}> is no longer `{permissions}`!", self.user)).ephemeral())
+            .await
+    }
+}
+
+impl crate::models::CreateCommand for StaffList {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let staff = framework.database().fetch_staff().await?;
+        let audit_log = framework.database().fetch_staff_audit(AUDIT_ENTRIES_SHOWN).await?;
+
+        let mut owners = String::new();
+        let mut administrators = String::new();
+        for staff in staff {
+            let list = match staff.user_type {
+                UserPermissions::Owner => &mut owners,
+                UserPermissions::Administrator => &mut administrators,
+                UserPermissions::User => continue,
+            };
+
+            let source = match framework.database().staff.permissions(staff.twilight_user.id).is_some() {
+                true => " - *config*",
+                false => "",
+            };
+            writeln!(list, "- <@{}>{source}", staff.twilight_user.id)?;
+        }
+
+        let mut changes = String::new();
+        for entry in &audit_log {
+            let action = match entry.action {
+                StaffAction::Add => "added",
+                StaffAction::Remove => "removed",
+            };
+            writeln!(
+                changes,
+                "- <t:{}:R> <@{}> {action} <@{}> as `{}`",
+                entry.created_at.unix_timestamp(),
+                entry.actor_id,
+                entry.target_id,
+                entry.permissions
+            )?;
+        }
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .title("Bot Staff")
+            .colour(framework.accent_colour().await)
+            .create_field("Owners", none_if_empty(owners), true)
+            .create_field("Administrators", none_if_empty(administrators), true)
+            .create_field("Recent Changes", none_if_empty(changes), false)
+            .footer(|footer| footer.text("Staff from the config can only be changed by editing it"));
+
+        framework.respond(|r| r.add_embed(embed).ephemeral()).await
+    }
+}
+
+/// The permissions of the staff member running the command
+async fn actor_permissions(framework: &InteractionContext) -> InteractionResult<UserPermissions> {
+    Ok(framework
+        .database()
+        .check_staff(framework.author_id())
+        .await?
+        .map(|staff| staff.user_type)
+        .unwrap_or_default())
+}
+
+/// Record a change to the staff in the database, the log and the audit channel if one is configured
+async fn audit(
+    framework: &InteractionContext,
+    target_id: Id<UserMarker>,
+    action: StaffAction,
+    permissions: UserPermissions,
+) -> InteractionResult<()> {
+    let entry = StaffAuditEntry {
+        actor_id: framework.author_id(),
+        target_id,
+        action,
+        permissions,
+        created_at: OffsetDateTime::now_utc(),
+    };
+
+    framework.database().create_staff_audit(&entry).await?;
+    tracing::info!(
+        "STAFF: {} used `{}` on {} with the permissions `{}`",
+        entry.actor_id,
+        entry.action.as_str(),
+        entry.target_id,
+        entry.permissions
+    );
+
+    if let Some(channel_id) = framework.database().staff.audit_channel {
+        let content = format!(
+            "<@{}> used `{}` on <@{}> with the permissions `{}`",
+            entry.actor_id,
+            entry.action.as_str(),
+            entry.target_id,
+            entry.permissions
+        );
+        if let Err(why) = framework
+            .gateway
+            .twilight_client
+            .create_message(channel_id)
+            .content(&content)
+            .allowed_mentions(Some(&Default::default()))
+            .await
+        {
+            tracing::warn!(?why, "Failed to post a staff change to the audit channel");
+        }
+    }
+
+    Ok(())
+}
+
+fn none_if_empty(list: String) -> String {
+    match list.is_empty() {
+        true => "None".to_owned(),
+        false => list,
+    }
+}
@@ -12,28 +12,36 @@ pub struct Ping {}
 impl crate::models::CreateCommand for Ping {
     async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
         let mut embed = EmbedBuilder::default();
-        embed.colour(framework.accent_colour().await).description("🏓 Pinging!");
+        embed
+            .colour(framework.accent_colour().await)
+            .description(framework.text("responses.ping.pinging", &[]));
 
         if let Some(average) = framework.latency.average() {
             embed.create_field(
-                "Average Latency",
-                &format!("`{}` milliseconds", average.as_millis().to_string()),
+                &framework.text("responses.ping.average_latency", &[]),
+                &framework.text("responses.ping.milliseconds", &[("milliseconds", &average.as_millis())]),
                 true,
             );
         }
 
         if let Some(average) = framework.latency.received() {
             embed.create_field(
-                "Last Acknowledgement",
-                &format!("{} milliseconds ago", average.elapsed().as_millis()),
+                &framework.text("responses.ping.last_acknowledgement", &[]),
+                &framework.text(
+                    "responses.ping.milliseconds_ago",
+                    &[("milliseconds", &average.elapsed().as_millis())],
+                ),
                 true,
             );
         }
 
         if let Some(average) = framework.latency.sent() {
             embed.create_field(
-                "Hearbeat Sent",
-                &format!("{} milliseconds ago", average.elapsed().as_millis()),
+                &framework.text("responses.ping.heartbeat_sent", &[]),
+                &framework.text(
+                    "responses.ping.milliseconds_ago",
+                    &[("milliseconds", &average.elapsed().as_millis())],
+                ),
                 true,
             );
         }
@@ -45,12 +53,10 @@ impl crate::models::CreateCommand for Ping {
             writeln!(heartbeats, "{num} - {} milliseconds", heartbeat.as_millis())?
         }
         if !heartbeats.is_empty() {
+            let total = framework.text("responses.ping.total_heartbeats", &[("total", &framework.latency.periods())]);
             embed.create_field(
-                "Hearbeats",
-                &format!(
-                    "**Total Heartbeats Recorded:** `{}`\n```{heartbeats}```",
-                    framework.latency.periods().to_string()
-                ),
+                &framework.text("responses.ping.heartbeats", &[]),
+                &format!("{total}\n```{heartbeats}```"),
                 false,
             );
         }
@@ -59,10 +65,7 @@ impl crate::models::CreateCommand for Ping {
         let mut response = InteractionResponseBuilder::default();
         response.add_embed(embed);
         framework.response_send(&response).await?;
-        let sent = format!(
-            "Pong!\n`Send MESSAGE` API request achnowledged and received in `{}` milliseconds!",
-            start.elapsed().as_millis()
-        );
+        let sent = framework.text("responses.ping.pong", &[("milliseconds", &start.elapsed().as_millis())]);
         response.content(sent.clone());
 
         framework.response_update(&response).await?;
@@ -71,9 +74,8 @@ impl crate::models::CreateCommand for Ping {
         let start = Instant::now();
         let _ = framework.gateway.twilight_client.user(framework.author_id()).await?.model().await?;
         let content = format!(
-            "{}\n`Get USER` API request achnowledged and received in `{}` milliseconds!",
-            sent,
-            start.elapsed().as_millis()
+            "{sent}\n{}",
+            framework.text("responses.ping.get_user", &[("milliseconds", &start.elapsed().as_millis())])
         );
         response.content(content);
         framework.response_update(&response).await
@@ -1,9 +1,10 @@
-use anyhow::Context;
-use luro_framework::Luro;
-use luro_model::response::SimpleResponse;
-use regex::Regex;
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::Id;
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
 
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "add", desc = "Save what someone said!")]
@@ -12,57 +13,57 @@ pub struct Add {
     message: String,
 }
 
-impl luro_framework::LuroCommand for Add {
-    async fn interaction_command(self, ctx: luro_framework::CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let link_regex = Regex::new(r"^https.*/(\d+)/(\d+)")?;
-        let number_regex = Regex::new(r"\d+")?;
-
-        let message = if let Some(captures) = link_regex.captures(&self.message) {
-            tracing::debug!("{captures:#?}");
-            let message_id = captures.get(2).context("Expected to get message_id")?.as_str().parse()?;
-            let channel_id = captures.get(1).context("Expected to get channel_id")?.as_str().parse()?;
-
-            match ctx.database.message_fetch(Id::new(message_id), Some(Id::new(channel_id))).await {
-                Ok(message) => message,
-                Err(why) => return ctx.simple_response(SimpleResponse::InternalError(&why)).await,
-            }
-        } else if let Some(message_id) = number_regex.find(&self.message).map(|mat| mat.as_str().parse()) {
-            match ctx
-                .database
-                .message_fetch(Id::new(message_id.context("Expected to get message_id")?), Some(ctx.channel.id))
-                .await
-            {
-                Ok(message) => message,
-                Err(why) => return ctx.simple_response(SimpleResponse::InternalError(&why)).await,
-            }
-        } else {
-            return ctx
+impl crate::models::CreateCommand for Add {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        let Some((channel_id, message_id)) = parse_message(&self.message, framework.channel().id) else {
+            return framework
                 .respond(|r| r.content("No message ID or message link passed!").ephemeral())
                 .await;
         };
 
-        let quote_id = ctx
-            .database
-            .sqlx
-            .quote_add(ctx.author.user_id, &message, ctx.channel.nsfw.unwrap_or_default())
-            .await?;
+        let twilight_client = framework.gateway.twilight_client.clone();
+        let (channel, message) = match twilight_client.channel(channel_id).await {
+            Ok(channel) => (channel.model().await?, twilight_client.message(channel_id, message_id).await),
+            Err(why) => {
+                tracing::debug!(?why, "quote - Failed to fetch channel `{channel_id}`");
+                return framework
+                    .respond(|r| r.content("I can't see that channel, so I can't quote from it!").ephemeral())
+                    .await;
+            }
+        };
 
-        ctx.respond(|response| {
-            response.embed(|embed| {
-                embed.colour(ctx.accent_colour()).description(message.content).author(|author| {
-                    author
-                        .name(format!("{} - Quote {quote_id}", message.author.name()))
-                        .icon_url(message.author.avatar_url());
-                    match message.guild_id {
-                        Some(guild_id) => author.url(format!(
-                            "https://discord.com/channels/{guild_id}/{}/{}",
-                            message.channel_id, message.id
-                        )),
-                        None => author.url(format!("https://discord.com/channels/{}/{}", message.channel_id, message.id)),
-                    }
-                })
-            })
-        })
-        .await
+        if channel.guild_id != Some(guild_id) {
+            return framework
+                .respond(|r| r.content("You can only quote messages from this server!").ephemeral())
+                .await;
+        }
+
+        let message = match message {
+            Ok(message) => message.model().await?,
+            Err(why) => {
+                tracing::debug!(?why, "quote - Failed to fetch message `{message_id}`");
+                return framework
+                    .respond(|r| r.content("I couldn't find that message, are you sure it exists?").ephemeral())
+                    .await;
+            }
+        };
+
+        let nsfw = channel.nsfw.unwrap_or_default();
+        let (quote, created) =
+            crate::commands::save_quote(framework.database(), Some(guild_id), &message, framework.author_id(), nsfw).await?;
+        let content = match created {
+            true => None,
+            false => Some("That message has already been quoted!".to_owned()),
+        };
+        super::respond_quote(framework, &quote, content).await
     }
 }
+
+/// Get the channel and message ID from a message link, or a bare message ID in the current channel
+fn parse_message(input: &str, current_channel: Id<ChannelMarker>) -> Option<(Id<ChannelMarker>, Id<MessageMarker>)> {
+    let mut ids = input.trim().rsplit('/').map(|id| id.parse().ok().and_then(Id::new_checked));
+    let message_id = ids.next().flatten()?;
+    let channel_id = ids.next().flatten().unwrap_or(current_channel);
+    Some((channel_id, message_id))
+}
@@ -1,31 +1,111 @@
-use luro_framework::{CommandInteraction, CreateLuroCommand, LuroCommand};
+use luro_model::{builders::EmbedBuilder, quote::Quote};
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    application::command::CommandOptionChoice,
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::models::{
+    autocomplete::{self, FocusedOption},
+    interaction::{InteractionContext, InteractionError, InteractionResult},
+};
 
 mod add;
 mod get;
 mod list;
-// mod remove;
+mod save;
+mod search;
+mod starboard;
+
+pub use save::SaveQuote;
+
+/// How much of a quote to show when listing them
+const SNIPPET_LENGTH: usize = 75;
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "quote", desc = "Get or save some quotes")]
-pub enum Quote {
-    #[command(name = "get")]
-    Get(get::Get),
+#[command(name = "quote", desc = "Get or save some quotes", dm_permission = false)]
+pub enum Command {
     #[command(name = "add")]
     Add(add::Add),
+    #[command(name = "get")]
+    Get(get::Get),
     #[command(name = "list")]
     List(list::List),
-    // #[command(name = "remove")]
-    // Remove(remove::Remove),
+    #[command(name = "search")]
+    Search(search::Search),
+    #[command(name = "starboard")]
+    Starboard(starboard::Starboard),
 }
 
-impl CreateLuroCommand for Quote {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
         match self {
-            Self::Get(cmd) => cmd.interaction_command(ctx).await,
-            Self::Add(cmd) => cmd.interaction_command(ctx).await,
-            Self::List(cmd) => cmd.interaction_command(ctx).await,
-            // Self::Remove(cmd) => cmd.interaction_command(ctx).await,
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::Get(cmd) => cmd.handle_command(framework).await,
+            Self::List(cmd) => cmd.handle_command(framework).await,
+            Self::Search(cmd) => cmd.handle_command(framework).await,
+            Self::Starboard(cmd) => cmd.handle_command(framework).await,
         }
     }
+
+    async fn handle_autocomplete(
+        framework: &mut InteractionContext,
+        focused: FocusedOption,
+    ) -> InteractionResult<Vec<CommandOptionChoice>> {
+        match (focused.subcommand(), focused.name.as_str()) {
+            (Some("get"), "id") => autocomplete::quote_ids(framework, &focused.input).await,
+            _ => Ok(vec![]),
+        }
+    }
+}
+
+fn guild_id(framework: &InteractionContext) -> InteractionResult<Id<GuildMarker>> {
+    framework.interaction.guild_id.ok_or(InteractionError::NotGuild)
+}
+
+/// NSFW quotes are only shown in NSFW channels
+fn nsfw_channel(framework: &InteractionContext) -> bool {
+    framework.channel().nsfw.unwrap_or_default()
+}
+
+/// Respond with an embed of the quote
+async fn respond_quote(framework: &InteractionContext, quote: &Quote, content: Option<String>) -> InteractionResult<()> {
+    let author = match quote.author_id {
+        Some(author_id) => Some(framework.fetch_user(author_id).await?),
+        None => None,
+    };
+    let embed = crate::embeds::quote(quote, author.as_ref(), framework.gateway.config.accent_colour());
+    framework
+        .respond(|r| {
+            if let Some(content) = content {
+                r.content(content);
+            }
+            r.add_embed(embed)
+        })
+        .await
+}
+
+/// Respond with a short list of quotes, one per line
+async fn respond_quote_list(framework: &InteractionContext, title: &str, quotes: &[Quote]) -> InteractionResult<()> {
+    let mut description = String::new();
+    for quote in quotes {
+        let first_line = quote.content.lines().next().unwrap_or_default();
+        let mut snippet: String = first_line.chars().take(SNIPPET_LENGTH).collect();
+        if snippet.len() < quote.content.len() {
+            snippet.push_str("...");
+        }
+        let author = match quote.author_id {
+            Some(author_id) => format!("<@{author_id}>"),
+            None => "Unknown".to_owned(),
+        };
+        description.push_str(&format!("- `{}` {author}: [{snippet}]({})\n", quote.id, quote.link()));
+    }
+
+    let mut embed = EmbedBuilder::default();
+    embed
+        .colour(framework.gateway.config.accent_colour())
+        .title(title)
+        .description(description)
+        .footer(|f| f.text("Use /quote get with an ID to see the full quote"));
+    framework.respond(|r| r.add_embed(embed)).await
 }
@@ -0,0 +1,25 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Message;
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+/// The "Save as quote" message context menu, a shortcut for `/quote add`.
+#[derive(CommandModel, CreateCommand)]
+#[command(kind = "message", name = "Save as quote", dm_permission = false)]
+pub struct SaveQuote {
+    message: Message,
+}
+
+impl crate::models::CreateCommand for SaveQuote {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        let nsfw = super::nsfw_channel(framework);
+        let (quote, created) =
+            crate::commands::save_quote(framework.database(), Some(guild_id), &self.message, framework.author_id(), nsfw).await?;
+        let content = match created {
+            true => None,
+            false => Some("That message has already been quoted!".to_owned()),
+        };
+        super::respond_quote(framework, &quote, content).await
+    }
+}
@@ -0,0 +1,40 @@
+use luro_model::quote::QuoteSearch;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "search", desc = "Search for a quote by what was said, or who said it")]
+pub struct Search {
+    /// Words to search for. Use "quotes" for a phrase, `or` for either word, and -word to exclude a word
+    query: Option<String>,
+    /// Only show quotes from this person
+    author: Option<Id<UserMarker>>,
+}
+
+impl crate::models::CreateCommand for Search {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        if self.query.is_none() && self.author.is_none() {
+            return framework
+                .respond(|r| r.content("Give me something to search for, or someone to search by!").ephemeral())
+                .await;
+        }
+
+        let search = QuoteSearch {
+            query: self.query,
+            author_id: self.author,
+            nsfw: super::nsfw_channel(framework),
+            limit: 10,
+        };
+        let quotes = framework.database().fetch_quotes(super::guild_id(framework)?, &search).await?;
+
+        if quotes.is_empty() {
+            return framework
+                .respond(|r| r.content("I couldn't find any quotes matching that!").ephemeral())
+                .await;
+        }
+
+        super::respond_quote_list(framework, "Matching quotes", &quotes).await
+    }
+}
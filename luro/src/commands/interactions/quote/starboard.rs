@@ -0,0 +1,73 @@
+use luro_model::quote::STARBOARD_EMOJI;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    guild::Permissions,
+    id::{marker::ChannelMarker, Id},
+};
+
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "starboard", desc = "Repost messages that get enough stars to a channel")]
+pub struct Starboard {
+    /// Where to repost starred messages. Leave empty to see the current settings
+    #[command(channel_types = "guild_text guild_announcement")]
+    channel: Option<Id<ChannelMarker>>,
+    /// How many stars a message needs. Defaults to 3
+    #[command(min_value = 1, max_value = 100)]
+    threshold: Option<i64>,
+    /// Set to true to turn the starboard off. Messages already on the board stay quoted
+    disable: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Starboard {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::guild_id(framework)?;
+        let existing = framework.database().fetch_starboard(guild_id).await?;
+
+        let changing = self.channel.is_some() || self.threshold.is_some() || self.disable.is_some();
+        if changing {
+            let permissions = framework.interaction.member.as_ref().and_then(|member| member.permissions);
+            if !permissions.is_some_and(|permissions| permissions.contains(Permissions::MANAGE_GUILD)) {
+                return Err(InteractionError::MissingPermission(Permissions::MANAGE_GUILD));
+            }
+        }
+
+        if self.disable.unwrap_or_default() {
+            framework.database().delete_starboard(guild_id).await?;
+            return framework.respond(|r| r.content("The starboard is now off!").ephemeral()).await;
+        }
+
+        let mut starboard = match (existing, self.channel) {
+            (Some(mut starboard), Some(channel_id)) => {
+                starboard.channel_id = channel_id;
+                starboard
+            }
+            (None, Some(channel_id)) => luro_model::quote::Starboard::new(guild_id, channel_id),
+            (Some(starboard), None) if !changing => {
+                let content = format!(
+                    "Messages with `{}` {STARBOARD_EMOJI} are reposted to <#{}>",
+                    starboard.threshold, starboard.channel_id
+                );
+                return framework.respond(|r| r.content(content).ephemeral()).await;
+            }
+            (Some(starboard), None) => starboard,
+            (None, None) => {
+                return framework
+                    .respond(|r| r.content("There is no starboard yet! Set a channel to create one.").ephemeral())
+                    .await
+            }
+        };
+
+        if let Some(threshold) = self.threshold {
+            starboard.threshold = threshold as i32;
+        }
+        framework.database().update_starboard(&starboard).await?;
+
+        let content = format!(
+            "Messages with `{}` {STARBOARD_EMOJI} will now be quoted and reposted to <#{}>!",
+            starboard.threshold, starboard.channel_id
+        );
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
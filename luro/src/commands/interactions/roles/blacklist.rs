@@ -1,25 +1,26 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
 mod add;
-// mod remove;
+mod remove;
 
 #[derive(CommandModel, CreateCommand)]
-#[command(
-    name = "blacklist",
-    desc = "Add or remove roles from the blacklist. Needs manage server permissons"
-)]
+#[command(name = "blacklist", desc = "Add or remove roles from the blacklist. Needs manage roles permissons")]
 pub enum Blacklist {
     #[command(name = "add")]
     Add(add::Add),
-    // #[command(name = "remove")]
-    // Remove(Remove),
+    #[command(name = "remove")]
+    Remove(remove::Remove),
 }
 
-impl luro_framework::LuroCommand for Blacklist {
-    async fn interaction_command(self, ctx: luro_framework::CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
+impl crate::models::CreateCommand for Blacklist {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::require_manage_roles(framework)?;
+
         match self {
-            Self::Add(cmd) => cmd.interaction_command(ctx).await,
-            // Self::Remove(cmd) => cmd.interaction_command(ctx).await,
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::Remove(cmd) => cmd.handle_command(framework).await,
         }
     }
 }
@@ -1,41 +1,23 @@
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::RoleMarker, Id};
 
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "remove", desc = "Remove a role to the blacklist", dm_permission = false)]
+#[command(name = "remove", desc = "Let members give themselves a blacklisted role again")]
 pub struct Remove {
     /// The role to remove
     role: Id<RoleMarker>,
 }
 
-impl luro_framework::LuroCommand for Remove {
-    async fn interaction_command(self, ctx: luro_framework::CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = ctx.guild_id().unwrap(); // SAFETY: Safe to unwrap as this can only be run in a guild
-        let mut owner_match = false;
-
-        // We are using global data for this one in case an owner was removed from the application live
-
-        for staff in ctx.database.user_fetch_staff().await? {
-            if ctx.author.user_id == staff.user_id {
-                owner_match = true
-            }
-        }
-
-        if !owner_match {
-            return ctx
-                .simple_response(luro_model::response::SimpleResponse::PermissionNotBotStaff)
-                .await;
-        }
-
-        let mut guild_settings = framework.database.get_guild(&guild_id).await?;
-        guild_settings.assignable_role_blacklist.retain(|&x| x != data.role);
-        framework.database.modify_guild(&guild_id, &guild_settings).await?;
+impl crate::models::CreateCommand for Remove {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let content = match framework.database().delete_role_blacklist(guild_id, self.role).await? {
+            0 => format!("Role <@&{}> was not blacklisted!", self.role),
+            _ => format!("Removed role <@&{}> from the guild blacklist!", self.role),
+        };
 
-        ctx
-            .respond(|r| {
-                r.content(format!("Added role <@&{}> to the guild blacklist!", self.role))
-                    .ephemeral()
-            })
-            .await
+        framework.respond(|r| r.content(content).ephemeral()).await
     }
 }
@@ -0,0 +1,47 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+mod add;
+mod list;
+mod remove;
+mod sticky;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "join", desc = "Roles given to members when they join. Needs manage roles permissions")]
+pub enum Join {
+    #[command(name = "add")]
+    Add(add::Add),
+    #[command(name = "list")]
+    List(list::List),
+    #[command(name = "remove")]
+    Remove(remove::Remove),
+    #[command(name = "sticky")]
+    Sticky(sticky::Sticky),
+}
+
+impl crate::models::CreateCommand for Join {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::require_manage_roles(framework)?;
+
+        match self {
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::List(cmd) => cmd.handle_command(framework).await,
+            Self::Remove(cmd) => cmd.handle_command(framework).await,
+            Self::Sticky(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+}
+
+/// Describe when an auto role is given, such as `10 minutes after passing screening`
+fn describe_delay(delay_seconds: i64, after_screening: bool) -> String {
+    let event = match after_screening {
+        true => "passing screening",
+        false => "joining",
+    };
+    match delay_seconds / 60 {
+        0 => format!("on {event}"),
+        1 => format!("1 minute after {event}"),
+        minutes => format!("{minutes} minutes after {event}"),
+    }
+}
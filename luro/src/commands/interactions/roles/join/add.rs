@@ -0,0 +1,45 @@
+use luro_model::auto_role::{AutoRole, AUTO_ROLE_MAX_DELAY};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Give a role to members when they join, or change when it is given")]
+pub struct Add {
+    /// The role to give
+    role: Id<RoleMarker>,
+    /// How many minutes to wait before giving the role. Defaults to giving it straight away
+    #[command(min_value = 0, max_value = 10080)]
+    delay: Option<i64>,
+    /// Wait until the member has passed membership screening. Any delay starts after this
+    after_screening: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Add {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        if let Some(refusal) = super::super::refusal(framework, guild_id, self.role, false).await? {
+            return framework.respond(|r| r.content(refusal).ephemeral()).await;
+        }
+
+        let auto_role = AutoRole {
+            guild_id,
+            role_id: self.role,
+            delay_seconds: (self.delay.unwrap_or_default() * 60).clamp(0, AUTO_ROLE_MAX_DELAY),
+            after_screening: self.after_screening.unwrap_or_default(),
+        };
+        framework.database().update_auto_role(&auto_role).await?;
+
+        framework
+            .respond(|r| {
+                r.content(format!(
+                    "Members will now be given <@&{}> {}!",
+                    self.role,
+                    super::describe_delay(auto_role.delay_seconds, auto_role.after_screening)
+                ))
+                .ephemeral()
+            })
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List the roles given to members when they join")]
+pub struct List {}
+
+impl crate::models::CreateCommand for List {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let auto_roles = framework.database().fetch_auto_roles(guild_id).await?;
+        let sticky = framework.database().fetch_sticky_roles_enabled(guild_id).await?;
+
+        let mut description = auto_roles
+            .iter()
+            .map(|auto_role| {
+                format!(
+                    "- <@&{}> {}\n",
+                    auto_role.role_id,
+                    super::describe_delay(auto_role.delay_seconds, auto_role.after_screening)
+                )
+            })
+            .collect::<String>();
+        if description.is_empty() {
+            description.push_str("No roles are given when members join. Add one with `/roles join add`\n");
+        }
+        description.push_str(match sticky {
+            true => "\nSticky roles are **on**, members get their roles back when they rejoin.",
+            false => "\nSticky roles are **off**.",
+        });
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title("Join roles")
+            .description(description);
+        framework.respond(|r| r.add_embed(embed).ephemeral()).await
+    }
+}
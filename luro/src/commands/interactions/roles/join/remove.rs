@@ -0,0 +1,23 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Stop giving a role to members when they join")]
+pub struct Remove {
+    /// The role to stop giving
+    role: Id<RoleMarker>,
+}
+
+impl crate::models::CreateCommand for Remove {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let content = match framework.database().delete_auto_role(guild_id, self.role).await? {
+            0 => format!("<@&{}> isn't given to members when they join!", self.role),
+            _ => format!("Members will no longer be given <@&{}> when they join!", self.role),
+        };
+
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
@@ -0,0 +1,23 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "sticky", desc = "Give members back the roles they had when they rejoin")]
+pub struct Sticky {
+    /// Turn sticky roles on or off. Turning them off forgets the roles of members who have left
+    enabled: bool,
+}
+
+impl crate::models::CreateCommand for Sticky {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        framework.database().update_sticky_roles_enabled(guild_id, self.enabled).await?;
+
+        let content = match self.enabled {
+            true => "Sticky roles are on! Members who leave will get their roles back when they rejoin.",
+            false => "Sticky roles are off, and the roles of members who have left have been forgotten.",
+        };
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
@@ -1,121 +1,221 @@
-use luro_framework::Luro;
-use luro_model::builders::components::action_row::ActionRowBuilder;
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use luro_model::{
+    builders::{ComponentBuilder, EmbedBuilder},
+    emoji::parse_emoji,
+    role_menu::{RoleMenu, RoleMenuStyle},
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::{
-    channel::message::component::{ButtonStyle, SelectMenuType},
-    id::{marker::RoleMarker, Id},
+    channel::message::{
+        component::{ButtonStyle, SelectMenuOption, SelectMenuType},
+        Component, Embed,
+    },
+    guild::Role,
+    id::{marker::GuildMarker, Id},
 };
 
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+use super::guild_roles;
+
+mod add;
+mod create;
+mod delete;
+mod edit;
+mod list;
+mod pick;
+mod post;
+mod remove;
+mod require;
+
+pub use pick::pick;
+
+/// The custom ID of a menu's select menu. Buttons add the role ID on the end, such as `role-menu-1234`.
+pub const ROLE_MENU_ID: &str = "role-menu";
+
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "menu", desc = "Show a role menu, for easily selecting roles")]
-pub struct Menu {
-    /// Customise the embed description. Leave blank for the default
-    description: Option<String>,
-    /// Customise the embed title. Leave blank for none
-    title: Option<String>,
-    /// Role to give to those who agree to the rules (e.g. Minor)
-    pub rules: Option<Id<RoleMarker>>,
-    /// The button's label. Defaults to the role name
-    rules_label: Option<String>,
-    /// Role to give to those who agree to the rules AND are over 18
-    pub adult: Option<Id<RoleMarker>>,
-    /// The button's label. Defaults to the role name
-    adult_label: Option<String>,
-    /// Role to give to those that clicked the funny button
-    pub bait: Option<Id<RoleMarker>>,
-    /// The button's label. Defaults to the role name
-    bait_label: Option<String>,
-    /// Set a banner to show below the embed
-    banner: Option<String>,
+#[command(
+    name = "menu",
+    desc = "Create and manage self-assignable role menus. Needs manage roles permissions"
+)]
+pub enum Menu {
+    #[command(name = "add")]
+    Add(add::Add),
+    #[command(name = "create")]
+    Create(create::Create),
+    #[command(name = "delete")]
+    Delete(delete::Delete),
+    #[command(name = "edit")]
+    Edit(edit::Edit),
+    #[command(name = "list")]
+    List(list::List),
+    #[command(name = "post")]
+    Post(post::Post),
+    #[command(name = "remove")]
+    Remove(remove::Remove),
+    #[command(name = "require")]
+    Require(require::Require),
 }
 
-impl luro_framework::LuroCommand for Menu {
-    async fn interaction_command(self, ctx: luro_framework::CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let guild_id = ctx.guild_id().unwrap(); // SAFETY: Safe to unwrap as this can only be run in a guild
-        let mut owner_match = false;
+impl crate::models::CreateCommand for Menu {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::require_manage_roles(framework)?;
 
-        for staff in ctx.database.user_fetch_staff().await? {
-            if ctx.author.user_id == staff.user_id {
-                owner_match = true
-            }
+        match self {
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::Create(cmd) => cmd.handle_command(framework).await,
+            Self::Delete(cmd) => cmd.handle_command(framework).await,
+            Self::Edit(cmd) => cmd.handle_command(framework).await,
+            Self::List(cmd) => cmd.handle_command(framework).await,
+            Self::Post(cmd) => cmd.handle_command(framework).await,
+            Self::Remove(cmd) => cmd.handle_command(framework).await,
+            Self::Require(cmd) => cmd.handle_command(framework).await,
         }
+    }
+}
 
-        if !owner_match {
-            return ctx
-                .simple_response(luro_model::response::SimpleResponse::PermissionNotBotStaff)
-                .await;
-        }
+#[derive(CommandOption, CreateOption)]
+pub enum Style {
+    #[option(name = "Select - A drop down listing every role", value = "select")]
+    Select,
+    #[option(name = "Buttons - A button per role, which toggles it", value = "buttons")]
+    Buttons,
+}
 
-        // SAFETY: This command can only be used in guilds
-        let add_buttons = self.rules.is_some() || self.adult.is_some() || self.bait.is_some();
-        let mut action_row = ActionRowBuilder::default();
-        if let Some(role) = self.rules {
-            let role = ctx.database.role_fetch(guild_id, role).await?;
-            action_row.button(|button| {
-                button.custom_id("roles-button-rules").style(ButtonStyle::Primary);
-                match self.rules_label {
-                    Some(label) => button.label(label),
-                    None => button.label(role.name.clone()),
-                };
-                button
-            });
-        }
-        if let Some(role) = self.adult {
-            let role = ctx.database.role_fetch(guild_id, role).await?;
-            action_row.button(|button| {
-                button.custom_id("roles-button-adult").style(ButtonStyle::Primary);
-                match self.adult_label {
-                    Some(label) => button.label(label),
-                    None => button.label(role.name.clone()),
-                };
-                button
-            });
-        }
-        if let Some(role) = self.bait {
-            let role = ctx.database.role_fetch(guild_id, role).await?;
-            action_row.button(|button| {
-                button.custom_id("roles-button-bait").style(ButtonStyle::Danger);
-                match self.bait_label {
-                    Some(label) => button.label(label),
-                    None => button.label(role.name.clone()),
-                };
-                button
-            });
+impl From<Style> for RoleMenuStyle {
+    fn from(style: Style) -> Self {
+        match style {
+            Style::Select => Self::Select,
+            Style::Buttons => Self::Buttons,
         }
+    }
+}
 
-        ctx.respond(|response| {
-            response
-                .embed(|embed| {
-                    if let Some(banner) = self.banner {
-                        embed.image(|i| i.url(banner));
-                    }
-                    match self.description {
-                        Some(description) => embed.description(description),
-                        None => embed.description("Select the roles you want"),
-                    };
-                    if let Some(title) = self.title {
-                        embed.title(title);
-                    }
-                    embed.colour(ctx.accent_colour())
+/// Fetch a menu by name, responding with an error if it does not exist
+async fn fetch_menu(framework: &InteractionContext, guild_id: Id<GuildMarker>, name: &str) -> InteractionResult<Option<RoleMenu>> {
+    let menu = framework.database().fetch_role_menu(guild_id, name).await?;
+    if menu.is_none() {
+        framework
+            .respond(|r| {
+                r.content(format!(
+                    "There is no role menu called `{name}`! See `/roles menu list` for this server's menus."
+                ))
+                .ephemeral()
+            })
+            .await?;
+    }
+    Ok(menu)
+}
+
+/// Build the message for a menu. Roles that no longer exist are left out.
+fn render(menu: &RoleMenu, roles: &[Role], accent_colour: u32) -> (EmbedBuilder, ComponentBuilder) {
+    let options = menu
+        .options
+        .iter()
+        .filter_map(|option| roles.iter().find(|role| role.id == option.role_id).map(|role| (option, role)))
+        .collect::<Vec<_>>();
+
+    let mut description = menu
+        .description
+        .clone()
+        .unwrap_or_else(|| "Pick the roles you want from the menu below!".to_owned());
+    if menu.single {
+        description.push_str("\n-# You can only have one of these roles at a time.");
+    }
+
+    let mut embed = EmbedBuilder::default();
+    embed
+        .colour(accent_colour)
+        .title(menu.title.clone().unwrap_or_else(|| menu.name.clone()))
+        .description(description);
+    if !menu.required_roles.is_empty() {
+        let required = menu
+            .required_roles
+            .iter()
+            .map(|role_id| format!("<@&{role_id}>"))
+            .collect::<Vec<_>>();
+        embed.create_field("Requires", required.join(", "), false);
+    }
+
+    let mut components = ComponentBuilder::default();
+    match menu.style {
+        RoleMenuStyle::Select => {
+            let select_options = options
+                .iter()
+                .map(|(option, role)| SelectMenuOption {
+                    default: false,
+                    description: option.description.clone(),
+                    emoji: option.emoji.as_deref().and_then(parse_emoji),
+                    label: option.label.clone().unwrap_or_else(|| role.name.clone()),
+                    value: role.id.to_string(),
                 })
-                .components(|components| {
-                    components.action_row(|row| {
-                        row.component(|component| {
-                            component.select_menu(|menu| menu.custom_id("role-menu").kind(SelectMenuType::Role).max_values(25))
-                        })
-                    })
-                });
+                .collect::<Vec<_>>();
+            let max_values = match menu.single {
+                true => 1,
+                false => select_options.len().max(1) as u8,
+            };
 
-            if add_buttons {
-                response.components(|components| {
-                    components.action_row(|a_r| {
-                        *a_r = action_row;
-                        a_r
+            components.action_row(|row| {
+                row.component(|component| {
+                    component.select_menu(|select_menu| {
+                        select_menu
+                            .custom_id(ROLE_MENU_ID)
+                            .kind(SelectMenuType::Text)
+                            .placeholder("Pick your roles")
+                            .min_values(0)
+                            .max_values(max_values)
+                            .new_options(select_options)
                     })
+                })
+            });
+        }
+        RoleMenuStyle::Buttons => {
+            for chunk in options.chunks(5) {
+                components.action_row(|row| {
+                    for (option, role) in chunk {
+                        row.button(|button| {
+                            button
+                                .custom_id(format!("{ROLE_MENU_ID}-{}", role.id))
+                                .label(option.label.clone().unwrap_or_else(|| role.name.clone()))
+                                .style(ButtonStyle::Secondary);
+                            if let Some(emoji) = option.emoji.as_deref().and_then(parse_emoji) {
+                                button.emoji(emoji);
+                            }
+                            button
+                        });
+                    }
+                    row
                 });
             }
-            response
-        })
-        .await
+        }
     }
+
+    (embed, components)
+}
+
+/// Edit the posted message of a menu in place. Returns false if the menu has not been posted.
+async fn refresh(framework: &InteractionContext, menu: &RoleMenu) -> InteractionResult<bool> {
+    let (Some(channel_id), Some(message_id)) = (menu.channel_id, menu.message_id) else {
+        return Ok(false);
+    };
+
+    let roles = guild_roles(framework, menu.guild_id).await?;
+    let (embed, components) = render(menu, &roles, framework.gateway.config.accent_colour());
+    let embed: Embed = embed.into();
+    let components: Vec<Component> = components.into();
+    framework
+        .gateway
+        .twilight_client
+        .update_message(channel_id, message_id)
+        .embeds(Some(&[embed]))
+        .components(Some(&components))
+        .await?;
+    Ok(true)
+}
+
+/// A note to add to responses when changing a menu
+async fn refreshed(framework: &InteractionContext, menu: &RoleMenu) -> InteractionResult<&'static str> {
+    Ok(match refresh(framework, menu).await? {
+        true => " The posted menu has been updated.",
+        false => " Post it with `/roles menu post` when you are ready.",
+    })
 }
@@ -0,0 +1,77 @@
+use luro_model::{
+    emoji::parse_emoji,
+    role_menu::{RoleMenuOption, ROLE_MENU_MAX_OPTIONS},
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Add a role to a menu, or change how an added role is shown")]
+pub struct Add {
+    /// The menu to add to
+    name: String,
+    /// The role to offer
+    role: Id<RoleMarker>,
+    /// Shown instead of the role name
+    #[command(max_length = 80)]
+    label: Option<String>,
+    /// An emoji to show next to the role
+    emoji: Option<String>,
+    /// Shown under the role in select menus
+    #[command(max_length = 100)]
+    description: Option<String>,
+}
+
+impl crate::models::CreateCommand for Add {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some(mut menu) = super::fetch_menu(framework, guild_id, &self.name).await? else {
+            return Ok(());
+        };
+
+        if let Some(refusal) = super::super::refusal(framework, guild_id, self.role, true).await? {
+            return framework.respond(|r| r.content(refusal).ephemeral()).await;
+        }
+
+        if let Some(ref emoji) = self.emoji
+            && parse_emoji(emoji).is_none()
+        {
+            return framework
+                .respond(|r| {
+                    r.content("That doesn't look like an emoji! Use a unicode emoji or a custom one like `<:name:id>`.")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        let option = RoleMenuOption {
+            role_id: self.role,
+            label: self.label,
+            emoji: self.emoji,
+            description: self.description,
+        };
+        match menu.options.iter_mut().find(|existing| existing.role_id == self.role) {
+            Some(existing) => *existing = option,
+            None if menu.options.len() >= ROLE_MENU_MAX_OPTIONS => {
+                return framework
+                    .respond(|r| {
+                        r.content(format!("A role menu can only offer {ROLE_MENU_MAX_OPTIONS} roles!"))
+                            .ephemeral()
+                    })
+                    .await
+            }
+            None => menu.options.push(option),
+        }
+
+        framework.database().update_role_menu(&menu).await?;
+        let note = super::refreshed(framework, &menu).await?;
+        framework
+            .respond(|r| {
+                r.content(format!("Added <@&{}> to the role menu `{}`!{note}", self.role, menu.name))
+                    .ephemeral()
+            })
+            .await
+    }
+}
@@ -0,0 +1,51 @@
+use luro_model::role_menu::RoleMenu;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "create", desc = "Create a new role menu. Add roles with /roles menu add")]
+pub struct Create {
+    /// A name for the menu, used to refer to it in other commands
+    #[command(max_length = 50)]
+    name: String,
+    /// The menu's title. Defaults to the name
+    title: Option<String>,
+    /// The menu's description
+    description: Option<String>,
+    /// How the roles are shown. Defaults to a select menu
+    style: Option<super::Style>,
+    /// If members can only hold one of the menu's roles at a time
+    single: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Create {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        if framework.database().fetch_role_menu(guild_id, &self.name).await?.is_some() {
+            return framework
+                .respond(|r| {
+                    r.content(format!("There is already a role menu called `{}`!", self.name))
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        let mut menu = RoleMenu::new(guild_id, self.name);
+        menu.title = self.title;
+        menu.description = self.description;
+        menu.style = self.style.map(|style| style.into()).unwrap_or_default();
+        menu.single = self.single.unwrap_or_default();
+        framework.database().update_role_menu(&menu).await?;
+
+        framework
+            .respond(|r| {
+                r.content(format!(
+                    "Created the role menu `{}`! Add some roles with `/roles menu add`, then post it with `/roles menu post`.",
+                    menu.name
+                ))
+                .ephemeral()
+            })
+            .await
+    }
+}
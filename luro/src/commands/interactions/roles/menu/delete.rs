@@ -0,0 +1,29 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "delete", desc = "Delete a role menu and its posted message. Members keep their roles")]
+pub struct Delete {
+    /// The menu to delete
+    name: String,
+}
+
+impl crate::models::CreateCommand for Delete {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some(menu) = super::fetch_menu(framework, guild_id, &self.name).await? else {
+            return Ok(());
+        };
+
+        if let (Some(channel_id), Some(message_id)) = (menu.channel_id, menu.message_id) {
+            // We don't care if this fails, the message may have already been removed
+            let _ = framework.gateway.twilight_client.delete_message(channel_id, message_id).await;
+        }
+
+        framework.database().delete_role_menu(menu.id).await?;
+        framework
+            .respond(|r| r.content(format!("Deleted the role menu `{}`!", menu.name)).ephemeral())
+            .await
+    }
+}
@@ -0,0 +1,46 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "edit", desc = "Change a role menu's settings, updating the posted menu in place")]
+pub struct Edit {
+    /// The menu to edit
+    name: String,
+    /// The menu's title. Set to `-` to use the name
+    title: Option<String>,
+    /// The menu's description. Set to `-` to use the default
+    description: Option<String>,
+    /// How the roles are shown
+    style: Option<super::Style>,
+    /// If members can only hold one of the menu's roles at a time
+    single: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Edit {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some(mut menu) = super::fetch_menu(framework, guild_id, &self.name).await? else {
+            return Ok(());
+        };
+
+        if let Some(title) = self.title {
+            menu.title = Some(title).filter(|title| title != "-");
+        }
+        if let Some(description) = self.description {
+            menu.description = Some(description).filter(|description| description != "-");
+        }
+        if let Some(style) = self.style {
+            menu.style = style.into();
+        }
+        if let Some(single) = self.single {
+            menu.single = single;
+        }
+
+        framework.database().update_role_menu(&menu).await?;
+        let note = super::refreshed(framework, &menu).await?;
+        framework
+            .respond(|r| r.content(format!("Updated the role menu `{}`!{note}", menu.name)).ephemeral())
+            .await
+    }
+}
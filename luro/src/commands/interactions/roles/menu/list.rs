@@ -0,0 +1,42 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List this server's role menus")]
+pub struct List {}
+
+impl crate::models::CreateCommand for List {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let menus = framework.database().fetch_role_menus(super::super::guild_id(framework)?).await?;
+
+        if menus.is_empty() {
+            return framework
+                .respond(|r| {
+                    r.content("There are no role menus yet! Create one with `/roles menu create`")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        let mut embed = EmbedBuilder::default();
+        embed.colour(framework.gateway.config.accent_colour()).title("Role menus");
+        for menu in &menus {
+            let mut value = format!("{} roles, {} style", menu.options.len(), menu.style);
+            if menu.single {
+                value.push_str(", single choice");
+            }
+            match (menu.channel_id, menu.message_id) {
+                (Some(channel_id), Some(message_id)) => value.push_str(&format!(
+                    "\n[Posted here](https://discord.com/channels/{}/{channel_id}/{message_id})",
+                    menu.guild_id
+                )),
+                _ => value.push_str("\nNot posted yet"),
+            }
+            embed.create_field(&menu.name, &value, true);
+        }
+
+        framework.respond(|r| r.add_embed(embed).ephemeral()).await
+    }
+}
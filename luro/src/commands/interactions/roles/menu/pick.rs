@@ -0,0 +1,124 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_model::{
+    application::interaction::InteractionData,
+    id::{marker::RoleMarker, Id},
+};
+
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
+
+use super::ROLE_MENU_ID;
+
+/// Someone used a posted role menu, either picking from the select menu or pressing a role's button
+pub async fn pick(framework: &mut InteractionContext) -> InteractionResult<()> {
+    let message_id = framework.compontent_message()?.id;
+    let Some(menu) = framework.database().fetch_role_menu_by_message(message_id).await? else {
+        return framework
+            .respond(|r| r.content("This role menu no longer exists!").ephemeral())
+            .await;
+    };
+
+    let member_roles = match &framework.interaction.member {
+        Some(member) => member.roles.clone(),
+        None => return Err(InteractionError::NoMemberData),
+    };
+
+    let missing = menu
+        .required_roles
+        .iter()
+        .filter(|role_id| !member_roles.contains(role_id))
+        .map(|role_id| format!("<@&{role_id}>"))
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return framework
+            .respond(|r| r.content(format!("You need {} to use this menu!", missing.join(", "))).ephemeral())
+            .await;
+    }
+
+    // Roles blacklisted after the menu was made are no longer handed out
+    let blacklist = framework.database().fetch_role_blacklist(menu.guild_id).await?;
+    let offered = menu
+        .options
+        .iter()
+        .map(|option| option.role_id)
+        .filter(|role_id| !blacklist.contains(role_id))
+        .collect::<Vec<_>>();
+
+    let wanted = match framework.command_name() {
+        ROLE_MENU_ID => match &framework.interaction.data {
+            Some(InteractionData::MessageComponent(data)) => data
+                .values
+                .iter()
+                .filter_map(|value| value.parse::<Id<RoleMarker>>().ok())
+                .filter(|role_id| offered.contains(role_id))
+                .collect::<Vec<_>>(),
+            _ => return Err(InteractionError::NotComponent),
+        },
+        custom_id => {
+            let Some(role_id) = custom_id
+                .strip_prefix(ROLE_MENU_ID)
+                .and_then(|role_id| role_id.strip_prefix('-'))
+                .and_then(|role_id| role_id.parse::<Id<RoleMarker>>().ok())
+                .filter(|role_id| offered.contains(role_id))
+            else {
+                return framework
+                    .respond(|r| r.content("This role is no longer offered by the menu!").ephemeral())
+                    .await;
+            };
+
+            let mut wanted = match menu.single {
+                true => vec![],
+                false => offered.iter().filter(|role_id| member_roles.contains(role_id)).copied().collect(),
+            };
+            match member_roles.contains(&role_id) {
+                true => wanted.retain(|wanted_id| *wanted_id != role_id),
+                false => wanted.push(role_id),
+            }
+            wanted
+        }
+    };
+
+    let mut added = vec![];
+    let mut removed = vec![];
+    let mut failed = vec![];
+    for role_id in offered {
+        let response = match (wanted.contains(&role_id), member_roles.contains(&role_id)) {
+            (true, false) => (
+                &mut added,
+                framework
+                    .gateway
+                    .twilight_client
+                    .add_guild_member_role(menu.guild_id, framework.author_id(), role_id)
+                    .await,
+            ),
+            (false, true) => (
+                &mut removed,
+                framework
+                    .gateway
+                    .twilight_client
+                    .remove_guild_member_role(menu.guild_id, framework.author_id(), role_id)
+                    .await,
+            ),
+            _ => continue,
+        };
+
+        match response {
+            (changed, Ok(_)) => changed.push(role_id),
+            (_, Err(why)) => {
+                tracing::warn!(?why, "Failed to change role {role_id} from role menu {}", menu.id);
+                failed.push(role_id)
+            }
+        }
+    }
+
+    let mut embed = EmbedBuilder::default();
+    embed.colour(framework.gateway.config.accent_colour());
+    match added.is_empty() && removed.is_empty() && failed.is_empty() {
+        true => embed.description("Nothing changed, you already have those roles!"),
+        false => embed.description("Your roles have been updated!"),
+    };
+    super::super::add_role_field(&mut embed, &added, "Roles I have added");
+    super::super::add_role_field(&mut embed, &removed, "Roles I have removed");
+    super::super::add_role_field(&mut embed, &failed, "Couldn't change");
+
+    framework.respond(|r| r.add_embed(embed).ephemeral()).await
+}
@@ -0,0 +1,62 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::message::{Component, Embed},
+    id::{marker::ChannelMarker, Id},
+};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "post", desc = "Post a role menu. If it was already posted, the old message is removed")]
+pub struct Post {
+    /// The menu to post
+    name: String,
+    /// Where to post it. Defaults to this channel
+    channel: Option<Id<ChannelMarker>>,
+}
+
+impl crate::models::CreateCommand for Post {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some(mut menu) = super::fetch_menu(framework, guild_id, &self.name).await? else {
+            return Ok(());
+        };
+
+        if menu.options.is_empty() {
+            return framework
+                .respond(|r| r.content("This menu has no roles yet! Add some with `/roles menu add`").ephemeral())
+                .await;
+        }
+
+        if let (Some(channel_id), Some(message_id)) = (menu.channel_id, menu.message_id) {
+            // We don't care if this fails, the message may have already been removed
+            let _ = framework.gateway.twilight_client.delete_message(channel_id, message_id).await;
+        }
+
+        let channel_id = self.channel.unwrap_or(framework.channel().id);
+        let roles = super::guild_roles(framework, guild_id).await?;
+        let (embed, components) = super::render(&menu, &roles, framework.gateway.config.accent_colour());
+        let embed: Embed = embed.into();
+        let components: Vec<Component> = components.into();
+        let message = framework
+            .gateway
+            .twilight_client
+            .create_message(channel_id)
+            .embeds(&[embed])
+            .components(&components)
+            .await?
+            .model()
+            .await?;
+
+        menu.channel_id = Some(channel_id);
+        menu.message_id = Some(message.id);
+        framework.database().update_role_menu(&menu).await?;
+
+        framework
+            .respond(|r| {
+                r.content(format!("Posted the role menu `{}` in <#{channel_id}>!", menu.name))
+                    .ephemeral()
+            })
+            .await
+    }
+}
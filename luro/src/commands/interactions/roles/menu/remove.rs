@@ -0,0 +1,41 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Stop a menu from offering a role. Members keep the role")]
+pub struct Remove {
+    /// The menu to remove from
+    name: String,
+    /// The role to remove
+    role: Id<RoleMarker>,
+}
+
+impl crate::models::CreateCommand for Remove {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some(mut menu) = super::fetch_menu(framework, guild_id, &self.name).await? else {
+            return Ok(());
+        };
+
+        if menu.option(self.role).is_none() {
+            return framework
+                .respond(|r| {
+                    r.content(format!("The role menu `{}` doesn't offer <@&{}>!", menu.name, self.role))
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        menu.options.retain(|option| option.role_id != self.role);
+        framework.database().update_role_menu(&menu).await?;
+        let note = super::refreshed(framework, &menu).await?;
+        framework
+            .respond(|r| {
+                r.content(format!("Removed <@&{}> from the role menu `{}`!{note}", self.role, menu.name))
+                    .ephemeral()
+            })
+            .await
+    }
+}
@@ -0,0 +1,42 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::RoleMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "require", desc = "Toggle a role members must have to use a menu, such as a verified role")]
+pub struct Require {
+    /// The menu to change
+    name: String,
+    /// The role to require, or to stop requiring if it already is
+    role: Id<RoleMarker>,
+}
+
+impl crate::models::CreateCommand for Require {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some(mut menu) = super::fetch_menu(framework, guild_id, &self.name).await? else {
+            return Ok(());
+        };
+
+        let action = match menu.required_roles.contains(&self.role) {
+            true => {
+                menu.required_roles.retain(|role_id| *role_id != self.role);
+                "no longer requires"
+            }
+            false => {
+                menu.required_roles.push(self.role);
+                "now requires"
+            }
+        };
+
+        framework.database().update_role_menu(&menu).await?;
+        let note = super::refreshed(framework, &menu).await?;
+        framework
+            .respond(|r| {
+                r.content(format!("The role menu `{}` {action} <@&{}>!{note}", menu.name, self.role))
+                    .ephemeral()
+            })
+            .await
+    }
+}
@@ -1,224 +1,137 @@
-use luro_framework::{Luro, LuroCommand};
-use luro_model::{builders::EmbedBuilder, types::CommandResponse};
-use tracing::{debug, info, warn};
+use luro_model::builders::EmbedBuilder;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
-    application::interaction::InteractionData,
-    id::{marker::RoleMarker, Id},
+    guild::{Permissions, Role},
+    id::{
+        marker::{GuildMarker, RoleMarker, UserMarker},
+        Id,
+    },
 };
 
-use self::{blacklist::Blacklist, menu::Menu};
+use crate::models::interaction::{InteractionContext, InteractionError, InteractionResult};
 
 mod blacklist;
+mod join;
 mod menu;
+mod reaction;
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "roles", desc = "Manage your roles. Can also be used to setup a role menu")]
-pub enum RoleCommands {
-    #[command(name = "menu")]
-    Menu(Menu),
+#[command(
+    name = "roles",
+    desc = "Manage your roles. Can also be used to setup a role menu",
+    dm_permission = false
+)]
+pub enum Command {
     #[command(name = "blacklist")]
-    Blacklist(Blacklist),
+    Blacklist(blacklist::Blacklist),
+    #[command(name = "join")]
+    Join(join::Join),
+    #[command(name = "menu")]
+    Menu(menu::Menu),
+    #[command(name = "reaction")]
+    Reaction(reaction::Reaction),
 }
 
-impl luro_framework::CreateLuroCommand for RoleCommands {
-    async fn interaction_command(self, ctx: luro_framework::CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
         match self {
-            Self::Menu(cmd) => cmd.interaction_command(ctx).await,
-            Self::Blacklist(cmd) => cmd.interaction_command(ctx).await,
+            Self::Blacklist(cmd) => cmd.handle_command(framework).await,
+            Self::Join(cmd) => cmd.handle_command(framework).await,
+            Self::Menu(cmd) => cmd.handle_command(framework).await,
+            Self::Reaction(cmd) => cmd.handle_command(framework).await,
         }
     }
 
-    async fn interaction_component(
-        self,
-        ctx: luro_framework::ComponentInteraction,
-        interaction: twilight_model::application::interaction::Interaction,
-    ) -> anyhow::Result<luro_model::types::CommandResponse> {
-        let mut embed = ctx.default_embed().await;
-        let (mut guild, guild_data) = match ctx.guild {
-            Some(ref guild) => match guild.data {
-                Some(ref guild_data) => (guild.clone(), guild_data),
-                None => {
-                    return ctx
-                        .respond(|r| r.content("I don't have data for this guild in my database, sorry!").ephemeral())
-                        .await
-                }
-            },
-            None => {
-                return ctx
-                    .respond(|r| r.content("You must run this command in a guild!").ephemeral())
-                    .await
-            }
-        };
-
-        let command = match interaction.data {
-            Some(InteractionData::ApplicationCommand(ref data)) => data.clone(),
-            _ => {
-                return Err(anyhow::anyhow!(
-                    "unable to parse modal data due to not receiving ApplicationCommand data\n{:#?}",
-                    interaction.data
-                ))
-            }
-        };
-
-        let data = Self::new(command)?;
-
-        let mut member_requested_roles: Vec<Id<RoleMarker>> = vec![];
-        let member_roles = ctx.database.member_fetch_roles(guild_data.guild_id, ctx.author.user_id).await?;
-        let mut member_roles_sorted = member_roles.values().collect::<Vec<_>>();
-        member_roles_sorted.sort();
-        guild.roles.sort();
-
-        let member_highest_role = match member_roles_sorted.first() {
-            Some(role) => *role,
-            None => {
-                return ctx
-                    .respond(|r| r.content("I don't have any roles recorded for you, sorry!").ephemeral())
-                    .await
-            }
-        };
-
-        for role in &ctx.data.values {
-            if let Ok(role_id) = role.parse::<u64>() {
-                member_requested_roles.push(Id::new(role_id))
-            }
-        }
-
-        let mut current_roles = vec![];
-        let mut blacklisted_roles = vec![];
-        let mut too_high_roles = vec![];
-        let mut roles_to_add = vec![];
-        let mut roles_to_remove = vec![];
-
-        for role in &guild.roles {
-            if member_roles_sorted.contains(&role) {
-                current_roles.push(role.role_id);
-
-                // Member already has this role, but they have not requested to keep it
-                if !guild_data.role_blacklist.contains(&role.role_id) && member_highest_role != role {
-                    roles_to_remove.push(role.role_id)
-                }
-            }
-
-            // The member requests this role
-            if member_requested_roles.contains(&role.role_id) {
-                if guild_data.role_blacklist.contains(&role.role_id) {
-                    // The role is blacklisted
-                    blacklisted_roles.push(role.role_id)
-                } else if member_highest_role > role {
-                    // The role is above the user in the role heirarchy
-                    too_high_roles.push(role.role_id)
-                } else if !member_roles_sorted.contains(&role) {
-                    // The user does not have the role, add it to them
-                    roles_to_add.push(role.role_id)
-                }
-            }
-        }
-
-        if !too_high_roles.is_empty() {
-            let mut embed = embed.clone();
-            add_role_field(&mut embed, &too_high_roles, "Selected Roles - Too High");
-            if let Some(log_channel) = guild_data.moderator_actions_log_channel {
-                warn!("User {} attempted to escalate their privileges", ctx.author.user_id);
-                embed
-                    .colour(luro_model::COLOUR_DANGER)
-                    .title("Privilege Escalation Attempt")
-                    .description(format!(
-                        "The user <@{}> just attempted to give themselves higher roles than they should have",
-                        ctx.author.user_id
-                    ))
-                    .author(|author| author.name(ctx.author.name()).icon_url(ctx.author.avatar_url()));
-                ctx.send_message(&log_channel, |r| r.add_embed(embed.clone())).await?;
-            }
-        };
-
-        if let Self::Menu(ref command) = data
-            && &ctx.data.custom_id == "roles-button-rules"
-        {
-            roles_to_remove.drain(..);
-            roles_to_add.push(command.rules.unwrap());
-            if let Some(adult_role) = command.adult {
-                for role in &member_roles {
-                    if role.0 == &adult_role {
-                        roles_to_remove.push(adult_role);
-                    }
-                }
-            }
-        }
+    /// Someone picked from a posted role menu
+    async fn handle_component(framework: &mut InteractionContext) -> InteractionResult<()> {
+        menu::pick(framework).await
+    }
+}
 
-        if let Self::Menu(ref command) = data
-            && &ctx.data.custom_id == "roles-button-adult"
-        {
-            roles_to_remove.drain(..);
-            roles_to_add.push(command.adult.unwrap());
-            if let Some(rules) = command.rules {
-                for role in &member_roles {
-                    if role.0 == &rules {
-                        roles_to_remove.push(rules);
-                    }
-                }
-            }
-        }
+fn guild_id(framework: &InteractionContext) -> InteractionResult<Id<GuildMarker>> {
+    framework.interaction.guild_id.ok_or(InteractionError::NotGuild)
+}
 
-        if let Self::Menu(ref command) = data
-            && &ctx.data.custom_id == "roles-button-bait"
-        {
-            roles_to_remove.drain(..);
-            roles_to_add.push(command.bait.unwrap());
-        }
+/// Managing roles requires Manage Roles
+fn require_manage_roles(framework: &InteractionContext) -> InteractionResult<()> {
+    let permissions = framework.interaction.member.as_ref().and_then(|member| member.permissions);
+    match permissions.is_some_and(|permissions| permissions.contains(Permissions::MANAGE_ROLES)) {
+        true => Ok(()),
+        false => Err(InteractionError::MissingPermission(Permissions::MANAGE_ROLES)),
+    }
+}
 
-        add_role_field(&mut embed, &current_roles, "Your current roles");
-        add_role_field(&mut embed, &roles_to_add, "Roles I have added");
-        add_role_field(&mut embed, &roles_to_remove, "Roles I have removed");
-        add_role_field(&mut embed, &blacklisted_roles, "Blacklisted roles");
-        add_role_field(&mut embed, &too_high_roles, "Roles above you");
+/// The guild's roles, used for role names and the role hierarchy
+async fn guild_roles(framework: &InteractionContext, guild_id: Id<GuildMarker>) -> InteractionResult<Vec<Role>> {
+    Ok(framework.gateway.twilight_client.roles(guild_id).await?.models().await?)
+}
 
-        embed.author(|author| {
-            author
-                .name(format!("{} - Roles Updated", ctx.author.name()))
-                .icon_url(ctx.author.avatar_url())
-        });
+/// Check a role can be handed out automatically or picked by members, returning the reason if it can't.
+/// Blacklisted roles are only refused if `self_assignable` is set.
+async fn refusal(
+    framework: &InteractionContext,
+    guild_id: Id<GuildMarker>,
+    role_id: Id<RoleMarker>,
+    self_assignable: bool,
+) -> InteractionResult<Option<&'static str>> {
+    if role_id.cast() == guild_id {
+        return Ok(Some("Everyone already has the everyone role!"));
+    }
 
-        ctx.respond(|r| r.add_embed(embed.clone()).ephemeral()).await?;
+    let roles = guild_roles(framework, guild_id).await?;
+    let Some(role) = roles.iter().find(|role| role.id == role_id) else {
+        return Ok(Some("I couldn't find that role in this server!"));
+    };
 
-        for role in roles_to_add {
-            ctx.twilight_client
-                .add_guild_member_role(guild_data.guild_id, ctx.author.user_id, role)
-                .await?;
-        }
+    if role.managed {
+        return Ok(Some("That role is managed by an integration, so nobody can be given it!"));
+    }
 
-        for role in roles_to_remove {
-            ctx.twilight_client
-                .remove_guild_member_role(guild_data.guild_id, ctx.author.user_id, role)
-                .await?;
-        }
+    if self_assignable && framework.database().fetch_role_blacklist(guild_id).await?.contains(&role_id) {
+        return Ok(Some("That role is blacklisted! Remove it with `/roles blacklist remove` first."));
+    }
 
-        let member = ctx
-            .twilight_client
-            .guild_member(guild_data.guild_id, ctx.author.user_id)
-            .await?
-            .model()
-            .await?;
-        ctx.database.member_update((guild_data.guild_id, &member)).await?;
+    let bot_position = highest_position(framework, guild_id, &roles, framework.gateway.current_user.id).await?;
+    if role.position >= bot_position {
+        return Ok(Some("That role is above my highest role, so I can't give it to anyone!"));
+    }
 
-        info!("User {} just updated their roles", ctx.author.name());
+    let guild = framework.guild().await?;
+    let author_position = highest_position(framework, guild_id, &roles, framework.author_id()).await?;
+    if guild.twilight_guild.owner_id != framework.author_id() && role.position >= author_position {
+        return Ok(Some("You can't hand out a role that is above your own highest role!"));
+    }
 
-        // if let Some(log_channel) = guild.catchall_log_channel {
-        //     ctx.send_message(&log_channel, |r| r.add_embed(embed)).await?;
-        // }
+    Ok(None)
+}
 
-        Ok(CommandResponse::default())
-    }
+/// The position of a member's highest role, used for checking the role hierarchy
+async fn highest_position(
+    framework: &InteractionContext,
+    guild_id: Id<GuildMarker>,
+    roles: &[Role],
+    user_id: Id<UserMarker>,
+) -> InteractionResult<i64> {
+    let member = framework
+        .gateway
+        .twilight_client
+        .guild_member(guild_id, user_id)
+        .await?
+        .model()
+        .await?;
+
+    Ok(roles
+        .iter()
+        .filter(|role| member.roles.contains(&role.id))
+        .map(|role| role.position)
+        .max()
+        .unwrap_or_default())
 }
 
 /// Appends a list of roles to an embed with the given name. If the passed array is empty, the field is not added
 fn add_role_field<'a>(embed: &'a mut EmbedBuilder, roles: &[Id<RoleMarker>], name: &str) -> &'a mut EmbedBuilder {
-    debug!("{:#?}", roles);
-    let mut role_description = String::new();
     if !roles.is_empty() {
-        for role_id in roles {
-            role_description.push_str(&format!("- <@&{role_id}>\n"))
-        }
+        let role_description = roles.iter().map(|role_id| format!("- <@&{role_id}>\n")).collect::<String>();
         embed.create_field(name, &role_description, true);
     }
     embed
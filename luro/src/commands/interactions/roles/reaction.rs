@@ -0,0 +1,58 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+mod add;
+mod list;
+mod remove;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "reaction",
+    desc = "Give members a role when they react to a message. Needs manage roles permissions"
+)]
+pub enum Reaction {
+    #[command(name = "add")]
+    Add(add::Add),
+    #[command(name = "list")]
+    List(list::List),
+    #[command(name = "remove")]
+    Remove(remove::Remove),
+}
+
+impl crate::models::CreateCommand for Reaction {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        super::require_manage_roles(framework)?;
+
+        match self {
+            Self::Add(cmd) => cmd.handle_command(framework).await,
+            Self::List(cmd) => cmd.handle_command(framework).await,
+            Self::Remove(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+}
+
+/// Parse a message link, or a message ID in the current channel
+fn parse_message(framework: &InteractionContext, message: &str) -> Option<(Id<ChannelMarker>, Id<MessageMarker>)> {
+    let mut parts = message.trim().trim_end_matches('/').rsplit('/');
+    let message_id = parts.next()?.parse().ok().and_then(Id::new_checked)?;
+    let channel_id = match parts.next() {
+        Some(channel_id) => channel_id.parse().ok().and_then(Id::new_checked)?,
+        None => framework.channel().id,
+    };
+    Some((channel_id, message_id))
+}
+
+/// Respond that a message could not be found
+async fn unknown_message(framework: &InteractionContext) -> InteractionResult<()> {
+    framework
+        .respond(|r| {
+            r.content("I couldn't find that message! Pass a message link, or the ID of a message in this channel.")
+                .ephemeral()
+        })
+        .await
+}
@@ -0,0 +1,82 @@
+use luro_model::{
+    auto_role::{reaction_key, ReactionRole},
+    emoji::parse_emoji,
+};
+use twilight_http::request::channel::reaction::RequestReactionType;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::message::ReactionType,
+    id::{marker::RoleMarker, Id},
+};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add", desc = "Give members a role when they react to a message with an emoji")]
+pub struct Add {
+    /// A link to the message, or its ID if it is in this channel
+    message: String,
+    /// The emoji to react with
+    emoji: String,
+    /// The role to give
+    role: Id<RoleMarker>,
+}
+
+impl crate::models::CreateCommand for Add {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let Some((channel_id, message_id)) = super::parse_message(framework, &self.message) else {
+            return super::unknown_message(framework).await;
+        };
+
+        let Some(emoji) = parse_emoji(&self.emoji) else {
+            return framework
+                .respond(|r| {
+                    r.content("That doesn't look like an emoji! Use a unicode emoji or a custom one like `<:name:id>`.")
+                        .ephemeral()
+                })
+                .await;
+        };
+
+        if let Some(refusal) = super::super::refusal(framework, guild_id, self.role, true).await? {
+            return framework.respond(|r| r.content(refusal).ephemeral()).await;
+        }
+
+        // Reacting first checks the message exists, and gives members something to click
+        let request_emoji = match &emoji {
+            ReactionType::Custom { id, name, .. } => RequestReactionType::Custom {
+                id: *id,
+                name: name.as_deref(),
+            },
+            ReactionType::Unicode { name } => RequestReactionType::Unicode { name },
+        };
+        if framework
+            .gateway
+            .twilight_client
+            .create_reaction(channel_id, message_id, &request_emoji)
+            .await
+            .is_err()
+        {
+            return super::unknown_message(framework).await;
+        }
+
+        let reaction_role = ReactionRole {
+            guild_id,
+            channel_id,
+            message_id,
+            emoji: reaction_key(&emoji),
+            role_id: self.role,
+        };
+        framework.database().update_reaction_role(&reaction_role).await?;
+
+        framework
+            .respond(|r| {
+                r.content(format!(
+                    "Reacting with {} to [that message](https://discord.com/channels/{guild_id}/{channel_id}/{message_id}) now gives <@&{}>!",
+                    self.emoji, self.role
+                ))
+                .ephemeral()
+            })
+            .await
+    }
+}
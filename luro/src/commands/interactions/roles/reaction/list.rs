@@ -0,0 +1,44 @@
+use luro_model::builders::EmbedBuilder;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List this server's reaction roles")]
+pub struct List {}
+
+impl crate::models::CreateCommand for List {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = super::super::guild_id(framework)?;
+        let reaction_roles = framework.database().fetch_reaction_roles(guild_id).await?;
+
+        if reaction_roles.is_empty() {
+            return framework
+                .respond(|r| {
+                    r.content("There are no reaction roles yet! Add one with `/roles reaction add`")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        let mut description = String::new();
+        for reaction_role in &reaction_roles {
+            // Custom emoji are stored by ID, so they are written out in full to show them
+            let emoji = match reaction_role.emoji.parse::<u64>() {
+                Ok(id) => format!("<:emoji:{id}>"),
+                Err(_) => reaction_role.emoji.clone(),
+            };
+            description.push_str(&format!(
+                "- {emoji} → <@&{}> on [this message](https://discord.com/channels/{guild_id}/{}/{})\n",
+                reaction_role.role_id, reaction_role.channel_id, reaction_role.message_id
+            ));
+        }
+
+        let mut embed = EmbedBuilder::default();
+        embed
+            .colour(framework.gateway.config.accent_colour())
+            .title("Reaction roles")
+            .description(description);
+        framework.respond(|r| r.add_embed(embed).ephemeral()).await
+    }
+}
@@ -0,0 +1,32 @@
+use luro_model::{auto_role::reaction_key, emoji::parse_emoji};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "Stop a reaction from giving a role. Members keep the role")]
+pub struct Remove {
+    /// A link to the message, or its ID if it is in this channel
+    message: String,
+    /// The emoji that gives the role
+    emoji: String,
+}
+
+impl crate::models::CreateCommand for Remove {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let Some((_, message_id)) = super::parse_message(framework, &self.message) else {
+            return super::unknown_message(framework).await;
+        };
+
+        let removed = match parse_emoji(&self.emoji) {
+            Some(emoji) => framework.database().delete_reaction_role(message_id, &reaction_key(&emoji)).await?,
+            None => 0,
+        };
+
+        let content = match removed {
+            0 => format!("Reacting with {} to that message doesn't give a role!", self.emoji),
+            _ => format!("Reacting with {} to that message no longer gives a role!", self.emoji),
+        };
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
@@ -0,0 +1,170 @@
+use luro_model::{
+    builders::EmbedBuilder,
+    command_policy::{CommandPolicy, PolicyScope, KEYWORD_COMMAND, SETTINGS_COMMAND},
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::{
+    application::command::{CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType},
+    id::{
+        marker::{ChannelMarker, RoleMarker},
+        Id,
+    },
+};
+
+use crate::models::{
+    autocomplete::{self, MAX_CHOICES},
+    interaction::{InteractionContext, InteractionError, InteractionResult},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "commands",
+    desc = "Enable or disable commands in this server. Leave empty to see what has been changed"
+)]
+pub struct Commands {
+    /// The command or subcommand, such as `quote` or `quote get`
+    #[command(autocomplete = true)]
+    command: Option<String>,
+    /// Whether the command can be used. Inherit removes the setting
+    state: Option<PolicyState>,
+    /// Only change the command in this channel
+    channel: Option<Id<ChannelMarker>>,
+    /// Only change the command for members with this role
+    role: Option<Id<RoleMarker>>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum PolicyState {
+    #[option(name = "Enabled", value = "enabled")]
+    Enabled,
+    #[option(name = "Disabled", value = "disabled")]
+    Disabled,
+    #[option(name = "Inherit - Use the server wide setting", value = "inherit")]
+    Inherit,
+}
+
+impl crate::models::CreateCommand for Commands {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let guild_id = framework.interaction.guild_id.ok_or(InteractionError::NotGuild)?;
+
+        let command = self.command.map(|command| command.trim().to_lowercase());
+        let (Some(command), Some(state)) = (command.as_ref(), self.state) else {
+            let mut policies = framework.database().fetch_command_policies(guild_id).await?;
+            if let Some(command) = &command {
+                policies.retain(|policy| policy.command == *command || policy.command.starts_with(&format!("{command} ")));
+            }
+
+            let embed = policies_embed(&policies, framework.gateway.config.accent_colour());
+            return framework.respond(|r| r.add_embed(embed).ephemeral()).await;
+        };
+
+        if command == SETTINGS_COMMAND || command.starts_with(&format!("{SETTINGS_COMMAND} ")) {
+            return framework
+                .respond(|r| {
+                    r.content("The settings command can't be disabled, otherwise it could never be turned back on!")
+                        .ephemeral()
+                })
+                .await;
+        }
+
+        if !command_paths().contains(command) {
+            return framework
+                .respond(|r| r.content(format!("I don't have a command called `{command}`!")).ephemeral())
+                .await;
+        }
+
+        let scope = match (self.channel, self.role) {
+            (Some(_), Some(_)) => {
+                return framework
+                    .respond(|r| r.content("Pick either a channel or a role, not both!").ephemeral())
+                    .await
+            }
+            (Some(channel_id), None) => PolicyScope::Channel(channel_id),
+            (None, Some(role_id)) => PolicyScope::Role(role_id),
+            (None, None) => PolicyScope::Guild,
+        };
+
+        let content = match state {
+            PolicyState::Inherit => {
+                framework.database().delete_command_policy(guild_id, command, scope).await?;
+                format!("`{command}` now inherits its setting for {scope}.")
+            }
+            state => {
+                let enabled = matches!(state, PolicyState::Enabled);
+                let policy = CommandPolicy {
+                    guild_id,
+                    command: command.clone(),
+                    scope,
+                    enabled,
+                };
+                framework.database().update_command_policy(&policy).await?;
+                match enabled {
+                    true => format!("`{command}` is now enabled for {scope}."),
+                    false => format!("`{command}` is now disabled for {scope}."),
+                }
+            }
+        };
+
+        framework.respond(|r| r.content(content).ephemeral()).await
+    }
+}
+
+/// Every command and subcommand that can have a policy, such as `quote` and `quote get`. Settings can't have one
+fn command_paths() -> Vec<String> {
+    let mut paths = vec![KEYWORD_COMMAND.to_owned()];
+    for command in super::super::default_commands() {
+        paths.push(command.name.clone());
+        for option in &command.options {
+            match option.kind {
+                CommandOptionType::SubCommand => paths.push(format!("{} {}", command.name, option.name)),
+                CommandOptionType::SubCommandGroup => {
+                    let group = format!("{} {}", command.name, option.name);
+                    for subcommand in option.options.iter().flatten() {
+                        paths.push(format!("{group} {}", subcommand.name));
+                    }
+                    paths.push(group);
+                }
+                _ => (),
+            }
+        }
+    }
+    paths.retain(|path| path != SETTINGS_COMMAND && !path.starts_with(&format!("{SETTINGS_COMMAND} ")));
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Commands containing the input, for autocomplete
+pub fn command_choices(input: &str) -> Vec<CommandOptionChoice> {
+    let input = input.trim().to_lowercase();
+    command_paths()
+        .into_iter()
+        .filter(|path| path.contains(&input))
+        .take(MAX_CHOICES)
+        .map(|path| autocomplete::choice(&path, CommandOptionChoiceValue::String(path.clone())))
+        .collect()
+}
+
+fn policies_embed(policies: &[CommandPolicy], accent_colour: u32) -> EmbedBuilder {
+    let description = match policies.is_empty() {
+        true => "Every command is using its default setting.".to_owned(),
+        false => policies
+            .iter()
+            .map(|policy| {
+                let state = match policy.enabled {
+                    true => "Enabled",
+                    false => "Disabled",
+                };
+                format!("- `{}`: {state} for {}\n", policy.command, policy.scope)
+            })
+            .collect(),
+    };
+
+    let mut embed = EmbedBuilder::default();
+    embed
+        .title("Command Settings")
+        .colour(accent_colour)
+        .description(description)
+        .footer(|footer| footer.text("Channel settings win over role settings, which win over server settings"));
+    embed
+}
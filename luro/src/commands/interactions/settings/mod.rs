@@ -0,0 +1,42 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::application::command::CommandOptionChoice;
+
+use crate::models::{
+    autocomplete::FocusedOption,
+    interaction::{InteractionContext, InteractionResult},
+};
+
+mod commands;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "settings",
+    desc = "Change how I work in this server",
+    guild_only,
+    user_permissions = "MANAGE_GUILD"
+)]
+pub enum Command {
+    #[command(name = "commands")]
+    Commands(commands::Commands),
+}
+
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        match self {
+            Self::Commands(cmd) => cmd.handle_command(framework).await,
+        }
+    }
+
+    async fn handle_autocomplete(
+        framework: &mut InteractionContext,
+        focused: FocusedOption,
+    ) -> InteractionResult<Vec<CommandOptionChoice>> {
+        match (focused.subcommand(), focused.name.as_str()) {
+            (Some("commands"), "command") => Ok(commands::command_choices(&focused.input)),
+            _ => {
+                tracing::debug!("No autocomplete for option {} of {}", focused.name, framework.command_name());
+                Ok(vec![])
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use luro_model::word_stats::{heatmap_csv, StatsWindow};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "activity",
+    desc = "A heatmap of when someone is chatty, by day of the week and hour of the day"
+)]
+pub struct Activity {
+    /// The user to show. Defaults to you
+    user: Option<Id<UserMarker>>,
+    /// The period of time to look at. Defaults to all time
+    window: Option<StatsWindow>,
+    /// Count messages from everywhere I can see, not just this server
+    global: Option<bool>,
+    /// Also send the heatmap as a CSV file
+    csv: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Activity {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let mut scope = super::stats_scope(framework, self.window, self.global);
+        scope.user_id = Some(self.user.unwrap_or(framework.author_id()));
+
+        let heatmap = framework.database().fetch_activity_heatmap(&scope).await?;
+        let mut embed = super::stats_embed(framework, "Activity", &scope, self.window);
+        embed.create_field(
+            "Hour of the day (UTC)",
+            &format!("```\n{}```", super::heatmap_grid(&heatmap)),
+            false,
+        );
+
+        framework
+            .respond(|r| {
+                if self.csv.unwrap_or_default() {
+                    r.attachments(std::iter::once(super::csv_attachment("activity", heatmap_csv(&heatmap))));
+                }
+                r.add_embed(embed)
+            })
+            .await
+    }
+}
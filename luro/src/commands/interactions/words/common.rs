@@ -0,0 +1,76 @@
+use std::fmt::Write;
+
+use luro_model::word_stats::{words_csv, StatsWindow, STOPWORDS};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "common", desc = "The most used words, leaving out common ones like 'the' and 'and'")]
+pub struct Common {
+    /// The period of time to look at. Defaults to all time
+    window: Option<StatsWindow>,
+    /// Only count words from this user
+    user: Option<Id<UserMarker>>,
+    /// Only count words in this channel
+    channel: Option<Id<ChannelMarker>>,
+    /// How many words to show. Defaults to 10
+    #[command(min_value = 1, max_value = 50)]
+    limit: Option<i64>,
+    /// Keep common words like 'the' and 'and'
+    stopwords: Option<bool>,
+    /// Count words from everywhere I can see, not just this server
+    global: Option<bool>,
+    /// Also send the words as a CSV file
+    csv: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Common {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let limit = self.limit.unwrap_or(10);
+        let csv = self.csv.unwrap_or_default();
+        let mut scope = super::stats_scope(framework, self.window, self.global);
+        scope.user_id = self.user;
+        scope.channel_id = self.channel;
+
+        let excluded = match self.stopwords.unwrap_or_default() {
+            true => vec![],
+            false => STOPWORDS.iter().map(|word| word.to_string()).collect(),
+        };
+        let words = framework
+            .database()
+            .fetch_common_words(
+                &scope,
+                &excluded,
+                match csv {
+                    true => 1000,
+                    false => limit,
+                },
+            )
+            .await?;
+
+        let mut most_used = String::new();
+        for (position, word) in words.iter().take(limit as usize).enumerate() {
+            let _ = writeln!(most_used, "{}. `{}` - {} times", position + 1, word.word, word.count);
+        }
+        if most_used.is_empty() {
+            most_used.push_str("No words have been said yet!");
+        }
+
+        let mut embed = super::stats_embed(framework, "Most Used Words", &scope, self.window);
+        embed.create_field("Words", &most_used, false);
+
+        framework
+            .respond(|r| {
+                if csv {
+                    r.attachments(std::iter::once(super::csv_attachment("common-words", words_csv(&words))));
+                }
+                r.add_embed(embed)
+            })
+            .await
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::Write;
+
+use luro_model::word_stats::{leaderboard_csv, LeaderboardKind, StatsWindow};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "leaderboard", desc = "Who has said the most, or which channels are the busiest")]
+pub struct Leaderboard {
+    /// Rank users or channels. Defaults to users
+    kind: Option<LeaderboardKind>,
+    /// The period of time to look at. Defaults to all time
+    window: Option<StatsWindow>,
+    /// Only rank users by their messages in this channel
+    channel: Option<Id<ChannelMarker>>,
+    /// How many to show. Defaults to 10
+    #[command(min_value = 1, max_value = 25)]
+    limit: Option<i64>,
+    /// Rank everywhere I can see, not just this server
+    global: Option<bool>,
+    /// Also send the full leaderboard as a CSV file
+    csv: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Leaderboard {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let kind = self.kind.unwrap_or_default();
+        let limit = self.limit.unwrap_or(10);
+        let csv = self.csv.unwrap_or_default();
+        let mut scope = super::stats_scope(framework, self.window, self.global);
+        scope.channel_id = self.channel;
+
+        // The CSV holds more than what fits in the embed
+        let entries = framework
+            .database()
+            .fetch_word_leaderboard(
+                &scope,
+                kind,
+                match csv {
+                    true => 1000,
+                    false => limit,
+                },
+            )
+            .await?;
+
+        let mut leaderboard = String::new();
+        for (position, entry) in entries.iter().take(limit as usize).enumerate() {
+            let name = match kind {
+                LeaderboardKind::Users => format!("<@{}>", entry.id),
+                LeaderboardKind::Channels => format!("<#{}>", entry.id),
+            };
+            let _ = writeln!(
+                leaderboard,
+                "{}. {name} - {} messages, {} words",
+                position + 1,
+                entry.messages,
+                entry.words
+            );
+        }
+        if leaderboard.is_empty() {
+            leaderboard.push_str("Nobody has said anything yet!");
+        }
+
+        let mut embed = super::stats_embed(framework, "Word Leaderboard", &scope, self.window);
+        embed.create_field("Leaderboard", &leaderboard, false);
+
+        framework
+            .respond(|r| {
+                if csv {
+                    r.attachments(std::iter::once(super::csv_attachment(
+                        "word-leaderboard",
+                        leaderboard_csv(kind, &entries),
+                    )));
+                }
+                r.add_embed(embed)
+            })
+            .await
+    }
+}
@@ -1,70 +1,99 @@
-use luro_framework::{CommandInteraction, CreateLuroCommand, LuroCommand};
-use tabled::{builder::Builder, settings::Style, Table};
+use luro_model::{
+    builders::EmbedBuilder,
+    word_stats::{ActivityHeatmap, StatsScope, StatsWindow},
+};
 use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::http::attachment::Attachment;
 
-mod global;
-mod guild;
-mod personal;
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+mod activity;
+mod common;
+mod leaderboard;
+mod overview;
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "words", desc = "Stats for words said. These are global metrics (by default)!")]
-pub enum Words {
-    #[command(name = "global")]
-    Global(global::Global),
-    #[command(name = "guild")]
-    Guild(guild::Guild),
-    #[command(name = "personal")]
-    Personal(personal::Personal),
+#[command(name = "words", desc = "Stats for the words said. Only covers this server, unless asked otherwise")]
+pub enum Command {
+    #[command(name = "activity")]
+    Activity(activity::Activity),
+    #[command(name = "common")]
+    Common(common::Common),
+    #[command(name = "leaderboard")]
+    Leaderboard(leaderboard::Leaderboard),
+    #[command(name = "overview")]
+    Overview(overview::Overview),
 }
 
-impl CreateLuroCommand for Words {
-    async fn interaction_command(self, ctx: CommandInteraction) -> anyhow::Result<luro_model::types::CommandResponse> {
+impl crate::models::CreateCommand for Command {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        // Word stats can take a while on a large database
+        framework.ack_interaction(false).await?;
+
         match self {
-            Self::Global(command) => command.interaction_command(ctx).await,
-            Self::Guild(command) => command.interaction_command(ctx).await,
-            Self::Personal(command) => command.interaction_command(ctx).await,
+            Self::Activity(cmd) => cmd.handle_command(framework).await,
+            Self::Common(cmd) => cmd.handle_command(framework).await,
+            Self::Leaderboard(cmd) => cmd.handle_command(framework).await,
+            Self::Overview(cmd) => cmd.handle_command(framework).await,
         }
     }
 }
 
-#[derive(twilight_interactions::command::CommandOption, twilight_interactions::command::CreateOption)]
-pub enum TableStyle {
-    #[option(name = "ASCII - An ASCII table. What more could you ask for?", value = "ascii")]
-    Ascii,
-    #[option(name = "ASCII Rounded - Rounded table, using ASCII characters instead", value = "ascii_rounded")]
-    AsciiRounded,
-    #[option(name = "Blank - Uses empty spaces to represent the table", value = "blank")]
-    Blank,
-    #[option(name = "Dots - Dots make up the table", value = "dots")]
-    Dots,
-    #[option(name = "Markdown - A markdown table", value = "markdown")]
-    Markdown,
-    #[option(name = "Modern - Super slick using UTF-8", value = "modern")]
-    Modern,
-    #[option(name = "PSQL (Default) - Postgress looking table, super simple and minimal", value = "psql")]
-    Psql,
-    #[option(name = "Rounded - Like modern, but well... Rounded?", value = "rounded")]
-    Rounded,
-    #[option(name = "Sharp - Modern, without the horizontal line", value = "sharp")]
-    Sharp,
+/// Stats are for the current guild, unless global stats are requested or the command is used in DMs
+fn stats_scope(framework: &InteractionContext, window: Option<StatsWindow>, global: Option<bool>) -> StatsScope {
+    let mut scope = StatsScope::new(window.unwrap_or_default());
+    if !global.unwrap_or_default() {
+        scope.guild_id = framework.interaction.guild_id;
+    }
+    scope
 }
 
-pub fn table_style(table: Builder, style: Option<&TableStyle>) -> Table {
-    let mut table = table.build();
-    match style {
-        Some(style) => match style {
-            TableStyle::Ascii => table.with(Style::ascii()),
-            TableStyle::AsciiRounded => table.with(Style::ascii_rounded()),
-            TableStyle::Blank => table.with(Style::blank()),
-            TableStyle::Dots => table.with(Style::dots()),
-            TableStyle::Markdown => table.with(Style::markdown()),
-            TableStyle::Modern => table.with(Style::modern()),
-            TableStyle::Psql => table.with(Style::psql()),
-            TableStyle::Rounded => table.with(Style::rounded()),
-            TableStyle::Sharp => table.with(Style::sharp()),
-        },
-        None => table.with(Style::psql()),
+/// A short description of where the stats were gathered from
+fn scope_description(scope: &StatsScope, window: Option<StatsWindow>) -> String {
+    let place = match (scope.channel_id, scope.guild_id) {
+        (Some(channel_id), _) => format!("in <#{channel_id}>"),
+        (None, Some(_)) => "in this server".to_owned(),
+        (None, None) => "everywhere I can see".to_owned(),
+    };
+    let user = match scope.user_id {
+        Some(user_id) => format!(" by <@{user_id}>"),
+        None => String::new(),
     };
 
-    table
+    format!("Messages{user} {place}, over {}", window.unwrap_or_default())
+}
+
+fn stats_embed(framework: &InteractionContext, title: &str, scope: &StatsScope, window: Option<StatsWindow>) -> EmbedBuilder {
+    let mut embed = EmbedBuilder::default();
+    embed
+        .colour(framework.gateway.config.accent_colour())
+        .title(title)
+        .description(scope_description(scope, window));
+    embed
+}
+
+fn csv_attachment(name: &str, csv: String) -> Attachment {
+    Attachment::from_bytes(format!("{name}.csv"), csv.into_bytes(), 1)
+}
+
+/// Draw a heatmap as a grid, with a row for each day and a column for each hour
+fn heatmap_grid(heatmap: &ActivityHeatmap) -> String {
+    const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+    let max = heatmap.iter().flatten().copied().max().unwrap_or_default().max(1);
+    let mut grid = "    0     6     12    18   \n".to_owned();
+
+    for (day, hours) in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"].into_iter().zip(heatmap) {
+        grid.push_str(day);
+        grid.push(' ');
+        for count in hours {
+            let shade = match count {
+                0 => 0,
+                count => 1 + (count * (SHADES.len() as i64 - 2) / max) as usize,
+            };
+            grid.push(SHADES[shade]);
+        }
+        grid.push('\n');
+    }
+
+    grid
 }
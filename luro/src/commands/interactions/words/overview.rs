@@ -0,0 +1,66 @@
+use luro_model::word_stats::{timeline_csv, StatsWindow};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "overview", desc = "How much has been said, and how that has changed over time")]
+pub struct Overview {
+    /// The period of time to look at. Defaults to all time
+    window: Option<StatsWindow>,
+    /// Only count messages from this user
+    user: Option<Id<UserMarker>>,
+    /// Only count messages in this channel
+    channel: Option<Id<ChannelMarker>>,
+    /// Count messages from everywhere I can see, not just this server
+    global: Option<bool>,
+    /// Also send the time series as a CSV file
+    csv: Option<bool>,
+}
+
+impl crate::models::CreateCommand for Overview {
+    async fn handle_command(self, framework: &mut InteractionContext) -> InteractionResult<()> {
+        let mut scope = super::stats_scope(framework, self.window, self.global);
+        scope.user_id = self.user;
+        scope.channel_id = self.channel;
+
+        let totals = framework.database().fetch_word_totals(&scope).await?;
+        let timeline = framework
+            .database()
+            .fetch_word_timeline(&scope, self.window.unwrap_or_default().bucket())
+            .await?;
+
+        let mut embed = super::stats_embed(framework, "Word Stats", &scope, self.window);
+        embed
+            .create_field("Messages", totals.messages.to_string(), true)
+            .create_field("Words", totals.words.to_string(), true)
+            .create_field("Unique Words", totals.unique_words.to_string(), true);
+
+        if let Some(busiest) = timeline.iter().max_by_key(|point| point.messages) {
+            let messages = timeline.iter().map(|point| point.messages).collect::<Vec<_>>();
+            embed.create_field(
+                &format!("Messages per {}", self.window.unwrap_or_default().bucket()),
+                &format!(
+                    "`{}`\nBusiest was <t:{}:f>, with {} messages",
+                    crate::embeds::sparkline(&messages),
+                    busiest.period.unix_timestamp(),
+                    busiest.messages
+                ),
+                false,
+            );
+        }
+
+        framework
+            .respond(|r| {
+                if self.csv.unwrap_or_default() {
+                    r.attachments(std::iter::once(super::csv_attachment("word-timeline", timeline_csv(&timeline))));
+                }
+                r.add_embed(embed)
+            })
+            .await
+    }
+}
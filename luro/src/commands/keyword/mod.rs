@@ -1,57 +1,87 @@
 // This is a module of commands that only activate on certain keywords. These can be extremely spammy, so should be activated only in allowlisted conditions
-mod lily;
-mod luro;
-mod mention;
-mod paw;
+use luro_model::command_policy::{CommandInvocation, KEYWORD_COMMAND};
+
+use crate::models::message_context::MessageContext;
+
+/// Luro, who gets special treatment
+const LURO: u64 = 307419691268440064;
 
 /// Handle commands that are invoked by a 'keyword' being present in message content.
-pub async fn keyword_handler(ctx: crate::models::CommandContext) {
+pub async fn keyword_handler(framework: &MessageContext) {
+    let message = &framework.ctx;
+    let gateway = &framework.gateway;
+
     // Don't respond if the user is a bot
-    if ctx.data.author.bot {
+    if message.author.bot {
         return;
     }
 
     // TODO: Conditionally check we are in a guild
-    if let Some(guild_id) = ctx.data.guild_id {
+    if let Some(guild_id) = message.guild_id {
         // Only respond in Nurah's dungeon. Also short circuit if we are in the venting channel
-        if guild_id.get() != 1132063963337740379 || ctx.data.channel_id.get() == 1139232506990833725 {
+        if guild_id.get() != 1132063963337740379 || message.channel_id.get() == 1139232506990833725 {
             return;
         }
     }
 
-    // Client to send messages
-    let message_client = ctx.twilight_client.create_message(ctx.data.channel_id).reply(ctx.data.id);
-
-    let response = if !ctx.data.mentions.is_empty() && ctx.data.author.id.get() == 307419691268440064 {
-        // If Luro (307419691268440064) responds to someone, be a little shit
-        message_client.content(luro::choose_insult()).await
-    } else if ctx.data.mentions.iter().any(|x| x.id == ctx.current_user.id) {
+    let content = message.content.to_lowercase();
+    let keyword = if !message.mentions.is_empty() && message.author.id.get() == LURO {
+        // If Luro responds to someone, be a little shit
+        Keyword::Flavour("keyword_luro")
+    } else if message.mentions.iter().any(|x| x.id == gateway.current_user.id) {
         // If anyone responds to Sira (Message Reply), be a little shit
-        message_client.content(mention::choose_insult()).await
+        Keyword::Flavour("keyword_mention")
     } else {
         // Parse a few words for some silly responses if they are present.
-        match ctx.data.content.to_lowercase() {
-            x if x.contains("fops") => message_client.content("Best fops is Stripe Rose!").await,
-            x if x.contains("owo") => message_client.content("Oh no, someone is speaking furry trash in public...").await,
-            x if x.contains("uwu") => message_client.content("I'll show you some 'UwU' if you don't shush it.").await,
-            x if x.contains("horny") => message_client.content("So very lewd! So very horny uwu").await,
-            x if x.contains("sus") => {
-                message_client
-                    .content("Sus? Amongus!? I'll show you some sus by stuffing my paws in your face.")
-                    .await
-            }
-            x if x.contains("paw") => message_client.content(paw::choose_insult()).await,
-            x if x.contains("lily") => message_client.content(lily::choose_insult()).await,
-            x if x.contains("sira") && ctx.data.author.id.get() == 307419691268440064 => {
-                message_client.content(luro::choose_insult()).await
-            }
-            x if x.contains("sira") => message_client.content(mention::choose_insult()).await,
+        match content.as_str() {
+            x if x.contains("fops") => Keyword::Reply("Best fops is Stripe Rose!"),
+            x if x.contains("owo") => Keyword::Reply("Oh no, someone is speaking furry trash in public..."),
+            x if x.contains("uwu") => Keyword::Reply("I'll show you some 'UwU' if you don't shush it."),
+            x if x.contains("horny") => Keyword::Reply("So very lewd! So very horny uwu"),
+            x if x.contains("sus") => Keyword::Reply("Sus? Amongus!? I'll show you some sus by stuffing my paws in your face."),
+            x if x.contains("paw") => Keyword::Flavour("keyword_paw"),
+            x if x.contains("lily") => Keyword::Flavour("keyword_lily"),
+            x if x.contains("sira") && message.author.id.get() == LURO => Keyword::Flavour("keyword_luro"),
+            x if x.contains("sira") => Keyword::Flavour("keyword_mention"),
             _ => return,
         }
     };
 
+    let invocation = CommandInvocation {
+        command: KEYWORD_COMMAND,
+        guild_id: message.guild_id,
+        channel_id: Some(message.channel_id),
+        roles: message.member.as_ref().map(|member| member.roles.as_slice()).unwrap_or_default(),
+    };
+    if !gateway.command_allowed(invocation).await {
+        return;
+    }
+
+    let content = match keyword {
+        Keyword::Reply(content) => content.to_owned(),
+        Keyword::Flavour(pool) => {
+            let response = gateway.flavour(message.guild_id, pool, "No.").await;
+            luro_model::flavour::render(&response, message.author.id, None)
+        }
+    };
+
+    let response = gateway
+        .twilight_client
+        .create_message(message.channel_id)
+        .reply(message.id)
+        .content(&content)
+        .await;
+
     // A remarkably crude error handler
     if let Err(why) = response {
         tracing::error!(?why, "keyword_handler - failed to send message in response to keyword trigger")
     }
 }
+
+/// How to respond to a keyword
+enum Keyword {
+    /// A fixed reply
+    Reply(&'static str),
+    /// A random entry from a flavour text pool
+    Flavour(&'static str),
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use time::OffsetDateTime;
+
+use crate::gateway::{Gateway, GatewayArc, GatewayResult};
+
+/// How often to check for anniversaries
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Post a message in the guild each time a couple reaches another year of marriage
+pub async fn marriage_anniversaries(gateway: GatewayArc) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(why) = post_anniversaries(&gateway).await {
+            tracing::warn!(?why, "marriage - Failed to post today's anniversaries");
+        }
+    }
+}
+
+async fn post_anniversaries(gateway: &Gateway) -> GatewayResult {
+    let year = OffsetDateTime::now_utc().year();
+
+    for marriage in gateway.database.fetch_anniversaries().await? {
+        let (Some(guild_id), Some(married_at)) = (marriage.guild_id, marriage.married_at) else {
+            continue;
+        };
+
+        let settings = gateway.database.fetch_marriage_settings(guild_id).await?;
+        if let Some(channel_id) = settings.anniversary_channel_id.or(marriage.channel_id) {
+            let years = year - married_at.year();
+            let content = format!(
+                "💍 Happy anniversary <@{}> & <@{}>! Today marks **{years}** year{} since they married on <t:{}:D>!",
+                marriage.proposer_id,
+                marriage.proposee_id,
+                if years == 1 { "" } else { "s" },
+                married_at.unix_timestamp()
+            );
+
+            if let Err(why) = gateway.twilight_client.create_message(channel_id).content(&content).await {
+                tracing::warn!(?why, "marriage - Failed to post an anniversary in channel `{channel_id}`");
+            }
+        }
+
+        // Recorded even if posting failed, so a missing channel doesn't get retried every hour
+        gateway
+            .database
+            .update_anniversary(marriage.proposer_id, marriage.proposee_id, year)
+            .await?;
+    }
+
+    Ok(())
+}
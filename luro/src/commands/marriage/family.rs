@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use luro_model::database::{Database, Error};
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// How many generations to follow when walking a family
+const MAX_GENERATIONS: usize = 3;
+/// How far to look for ancestors when checking an adoption would not make someone their own ancestor
+const MAX_ANCESTRY: usize = 16;
+
+/// Someone in a family tree, along with their descendants
+pub struct FamilyNode {
+    pub user_id: Id<UserMarker>,
+    pub children: Vec<FamilyNode>,
+}
+
+/// A user's close family: their partners, up to two generations of parents, and their descendants
+pub struct FamilyTree {
+    pub user_id: Id<UserMarker>,
+    pub partners: Vec<Id<UserMarker>>,
+    /// Each parent, along with their own parents
+    pub parents: Vec<(Id<UserMarker>, Vec<Id<UserMarker>>)>,
+    pub descendants: FamilyNode,
+}
+
+impl FamilyTree {
+    /// Render the family as a text diagram, using mentions so names stay up to date
+    pub fn render(&self) -> String {
+        let mut diagram = String::new();
+
+        if !self.parents.is_empty() {
+            diagram.push_str("**Parents**\n");
+            for (parent, grandparents) in &self.parents {
+                diagram.push_str(&format!("- <@{parent}>"));
+                if !grandparents.is_empty() {
+                    diagram.push_str(&format!(", child of {}", mentions(grandparents)));
+                }
+                diagram.push('\n');
+            }
+        }
+
+        if !self.partners.is_empty() {
+            diagram.push_str(&format!("**Partners**\n- {}\n", mentions(&self.partners)));
+        }
+
+        diagram.push_str(&format!("**Descendants**\n<@{}>\n", self.user_id));
+        render_children(&mut diagram, &self.descendants.children, "");
+        diagram
+    }
+
+    /// True if the user has no family at all
+    pub fn is_empty(&self) -> bool {
+        self.partners.is_empty() && self.parents.is_empty() && self.descendants.children.is_empty()
+    }
+}
+
+/// Load a user's family tree from the database
+pub async fn family_tree(database: &Database, user_id: Id<UserMarker>) -> Result<FamilyTree, Error> {
+    let partners = database
+        .fetch_marriages(user_id)
+        .await?
+        .into_iter()
+        .filter(|marriage| marriage.married())
+        .map(|marriage| marriage.partner_of(user_id))
+        .collect();
+
+    let mut parents = vec![];
+    for parent in parents_of(database, user_id).await? {
+        parents.push((parent, parents_of(database, parent).await?));
+    }
+
+    let mut visited = HashSet::from([user_id]);
+    let descendants = descendants(database, user_id, 0, &mut visited).await?;
+
+    Ok(FamilyTree {
+        user_id,
+        partners,
+        parents,
+        descendants,
+    })
+}
+
+/// Every ancestor of a user, used to stop someone adopting their own parent
+pub async fn ancestors(database: &Database, user_id: Id<UserMarker>) -> Result<HashSet<Id<UserMarker>>, Error> {
+    let mut ancestors = HashSet::new();
+    let mut generation = vec![user_id];
+
+    for _ in 0..MAX_ANCESTRY {
+        let mut next_generation = vec![];
+        for user_id in generation {
+            for parent in parents_of(database, user_id).await? {
+                if ancestors.insert(parent) {
+                    next_generation.push(parent);
+                }
+            }
+        }
+
+        if next_generation.is_empty() {
+            break;
+        }
+        generation = next_generation;
+    }
+
+    Ok(ancestors)
+}
+
+async fn parents_of(database: &Database, user_id: Id<UserMarker>) -> Result<Vec<Id<UserMarker>>, Error> {
+    Ok(database
+        .fetch_adoptions(user_id)
+        .await?
+        .into_iter()
+        .filter(|adoption| adoption.child_id == user_id && adoption.adopted_at.is_some())
+        .map(|adoption| adoption.parent_id)
+        .collect())
+}
+
+async fn descendants(
+    database: &Database,
+    user_id: Id<UserMarker>,
+    generation: usize,
+    visited: &mut HashSet<Id<UserMarker>>,
+) -> Result<FamilyNode, Error> {
+    let mut node = FamilyNode { user_id, children: vec![] };
+    if generation >= MAX_GENERATIONS {
+        return Ok(node);
+    }
+
+    let children = database
+        .fetch_adoptions(user_id)
+        .await?
+        .into_iter()
+        .filter(|adoption| adoption.parent_id == user_id && adoption.adopted_at.is_some())
+        .map(|adoption| adoption.child_id);
+
+    for child in children {
+        // Two partners can adopt the same child, so only show each person once
+        if visited.insert(child) {
+            node.children
+                .push(Box::pin(descendants(database, child, generation + 1, visited)).await?);
+        }
+    }
+
+    Ok(node)
+}
+
+fn render_children(diagram: &mut String, children: &[FamilyNode], prefix: &str) {
+    for (index, child) in children.iter().enumerate() {
+        let last = index + 1 == children.len();
+        let branch = if last { "└─" } else { "├─" };
+        diagram.push_str(&format!("{prefix}{branch} <@{}>\n", child.user_id));
+
+        // Discord trims plain spaces at the start of a line, so em spaces are used for indenting
+        let indent = if last { "\u{2003}\u{2003}" } else { "│\u{2003}" };
+        render_children(diagram, &child.children, &format!("{prefix}{indent}"));
+    }
+}
+
+fn mentions(users: &[Id<UserMarker>]) -> String {
+    users.iter().map(|user| format!("<@{user}>")).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,5 @@
+mod anniversaries;
+mod family;
+
+pub use anniversaries::marriage_anniversaries;
+pub use family::{ancestors, family_tree, FamilyTree};
@@ -1,17 +1,41 @@
 #[cfg(feature = "module-ai")]
 mod ai;
+#[cfg(feature = "module-autoroles")]
+mod autoroles;
+#[cfg(feature = "module-e621")]
+mod e621;
+#[cfg(feature = "module-insights")]
+mod insights;
 #[cfg(feature = "module-interactions")]
 mod interactions;
 #[cfg(feature = "module-keywords")]
 mod keyword;
+#[cfg(feature = "module-marriage")]
+mod marriage;
+#[cfg(feature = "module-music")]
+mod music;
 #[cfg(feature = "module-prefix")]
 mod prefix;
+#[cfg(feature = "module-quote")]
+mod quote;
 
 #[cfg(feature = "module-ai")]
-pub use ai::ai_command_handler;
+pub use ai::{ai_command_handler, AiRateLimiter, BackendError};
+#[cfg(feature = "module-autoroles")]
+pub use autoroles::{auto_role_join, auto_role_leave, auto_role_runner, auto_role_screened, reaction_role_handler};
+#[cfg(feature = "module-e621")]
+pub use e621::{E621Error, E621Search, E621SearchState, E621};
+#[cfg(feature = "module-insights")]
+pub use insights::{insights_member_event, insights_runner};
 #[cfg(feature = "module-interactions")]
 pub use interactions::{default_commands, interaction_handler};
 #[cfg(feature = "module-keywords")]
 pub use keyword::keyword_handler;
+#[cfg(feature = "module-marriage")]
+pub use marriage::{ancestors, family_tree, marriage_anniversaries, FamilyTree};
+#[cfg(feature = "module-music")]
+pub use music::{music_resume, music_runner, Music};
 #[cfg(feature = "module-prefix")]
 pub use prefix::prefix_handler;
+#[cfg(feature = "module-quote")]
+pub use quote::{save_quote, starboard_handler};
@@ -0,0 +1,92 @@
+use luro_model::music::MusicQueue;
+use twilight_gateway::MessageSender;
+use twilight_lavalink::model::{Destroy, Pause, Seek, Stop, Volume};
+use twilight_model::{
+    gateway::payload::outgoing::UpdateVoiceState,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use crate::gateway::Gateway;
+
+use super::{now_playing, play_current};
+
+/// Join a voice channel, or move to it if already in another
+pub fn join(shard: &MessageSender, queue: &mut MusicQueue, channel_id: Id<ChannelMarker>) -> anyhow::Result<()> {
+    shard.command(&UpdateVoiceState::new(queue.guild_id, Some(channel_id), true, false))?;
+    queue.voice_channel_id = Some(channel_id);
+    Ok(())
+}
+
+/// Leave voice and destroy the player. The queue is forgotten.
+pub async fn leave(gateway: &Gateway, shard: &MessageSender, guild_id: Id<GuildMarker>) -> anyhow::Result<()> {
+    gateway
+        .music
+        .update(&gateway.database, guild_id, async |queue| {
+            let player = gateway.music.lavalink.player(guild_id).await?;
+            player.send(Destroy::from(guild_id))?;
+            shard.command(&UpdateVoiceState::new(guild_id, None, false, false))?;
+
+            queue.tracks.clear();
+            now_playing(gateway, queue).await;
+            anyhow::Ok(())
+        })
+        .await?;
+
+    gateway.music.remove(&gateway.database, guild_id).await;
+    Ok(())
+}
+
+/// Seek to a position in the current track, in milliseconds
+pub async fn seek(gateway: &Gateway, queue: &mut MusicQueue, position: i64) -> anyhow::Result<()> {
+    let player = gateway.music.lavalink.player(queue.guild_id).await?;
+    player.send(Seek::from((queue.guild_id, position)))?;
+    queue.position = position;
+    Ok(())
+}
+
+/// Set the volume of the player, where `100` is the track's original volume
+pub async fn set_volume(gateway: &Gateway, queue: &mut MusicQueue, volume: i64) -> anyhow::Result<()> {
+    let player = gateway.music.lavalink.player(queue.guild_id).await?;
+    player.send(Volume::from((queue.guild_id, volume)))?;
+    queue.volume = volume;
+    Ok(())
+}
+
+/// Pause or resume the player
+pub async fn toggle_pause(gateway: &Gateway, queue: &mut MusicQueue) -> anyhow::Result<()> {
+    queue.paused = !queue.paused;
+    let player = gateway.music.lavalink.player(queue.guild_id).await?;
+    player.send(Pause::from((queue.guild_id, queue.paused)))?;
+    Ok(())
+}
+
+/// Skip the current track, ignoring the track loop mode. Posts a new now playing message if something else is queued.
+pub async fn skip(gateway: &Gateway, queue: &mut MusicQueue) -> anyhow::Result<()> {
+    queue.advance(true);
+    queue.paused = false;
+    play_current(gateway, queue).await?;
+    now_playing(gateway, queue).await;
+    Ok(())
+}
+
+/// Stop playing and clear the queue, staying in the voice channel
+pub async fn stop(gateway: &Gateway, queue: &mut MusicQueue) -> anyhow::Result<()> {
+    queue.tracks.clear();
+    queue.position = 0;
+    queue.paused = false;
+
+    let player = gateway.music.lavalink.player(queue.guild_id).await?;
+    player.send(Stop::from(queue.guild_id))?;
+    now_playing(gateway, queue).await;
+    Ok(())
+}
+
+/// Shuffle everything after the current track
+pub fn shuffle(queue: &mut MusicQueue) {
+    if queue.tracks.len() > 2 {
+        fastrand::shuffle(&mut queue.tracks[1..]);
+    }
+}
@@ -0,0 +1,70 @@
+use std::net::SocketAddr;
+
+use twilight_lavalink::model::{EventData, IncomingEvent, TrackEndReason};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::gateway::{Gateway, GatewayArc};
+
+use super::{now_playing, play_current};
+
+/// Connect to the Lavalink node, then advance each guild's queue as tracks finish
+pub async fn music_runner(gateway: GatewayArc) {
+    let config = &gateway.config.music;
+    let address = match config.lavalink_host.parse::<SocketAddr>() {
+        Ok(address) => address,
+        Err(why) => {
+            tracing::error!(?why, "music - `{}` is not a valid Lavalink address", config.lavalink_host);
+            return;
+        }
+    };
+
+    let mut events = match gateway.music.lavalink.add(address, &config.lavalink_password).await {
+        Ok((_node, events)) => events,
+        Err(why) => {
+            tracing::error!(?why, "music - Failed to connect to the Lavalink node at `{address}`");
+            return;
+        }
+    };
+
+    tracing::info!("music - Connected to the Lavalink node at `{address}`");
+    while let Some(event) = events.next().await {
+        match event {
+            IncomingEvent::PlayerUpdate(update) => {
+                gateway
+                    .music
+                    .update_position(&gateway.database, update.guild_id, update.state.position)
+                    .await
+            }
+            IncomingEvent::Event(event) => {
+                let Ok(guild_id) = event.guild_id.parse::<Id<GuildMarker>>() else {
+                    continue;
+                };
+
+                if let EventData::TrackEndEvent(track_end) = event.data
+                    && matches!(track_end.reason, TrackEndReason::Finished | TrackEndReason::LoadFailed)
+                {
+                    track_finished(&gateway, guild_id).await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tracing::warn!("music - Lost connection to the Lavalink node");
+}
+
+/// Move on to the next track in the queue, respecting the loop mode
+async fn track_finished(gateway: &Gateway, guild_id: Id<GuildMarker>) {
+    gateway
+        .music
+        .update(&gateway.database, guild_id, async |queue| {
+            queue.advance(false);
+
+            if let Err(why) = play_current(gateway, queue).await {
+                tracing::warn!(?why, "music - Failed to play the next track in guild `{guild_id}`");
+            }
+
+            now_playing(gateway, queue).await;
+        })
+        .await
+}
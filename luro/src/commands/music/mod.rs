@@ -0,0 +1,155 @@
+use std::{collections::HashMap, sync::Arc};
+
+use luro_model::{database::Database, music::MusicQueue};
+use tokio::sync::Mutex;
+use twilight_lavalink::{
+    model::{Pause, Play, Volume},
+    Lavalink,
+};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::gateway::Gateway;
+
+mod controls;
+mod events;
+mod now_playing;
+mod resume;
+mod tracks;
+
+pub use controls::{join, leave, seek, set_volume, shuffle, skip, stop, toggle_pause};
+pub use events::music_runner;
+pub use now_playing::{now_playing, now_playing_components, now_playing_embed};
+pub use resume::music_resume;
+pub use tracks::{load_tracks, LoadedTracks, MusicError};
+
+/// How often the playback position is saved while a track plays. Pausing, seeking and changing tracks save it straight away.
+const POSITION_SAVE_INTERVAL: i64 = 30_000;
+
+/// The Lavalink client, and a cache of each guild's queue. Every change to a queue is also written to the database, so playback can resume after a restart.
+#[derive(Debug)]
+pub struct Music {
+    pub lavalink: Lavalink,
+    queues: Mutex<HashMap<Id<GuildMarker>, Arc<Mutex<MusicQueue>>>>,
+}
+
+impl Music {
+    pub fn new(user_id: Id<UserMarker>, shard_count: u32) -> Self {
+        Self {
+            lavalink: Lavalink::new(user_id, shard_count),
+            queues: Default::default(),
+        }
+    }
+
+    /// Get a copy of a guild's queue, for showing it. Use [`Self::update`] to change it.
+    pub async fn queue(&self, database: &Database, guild_id: Id<GuildMarker>) -> MusicQueue {
+        self.guild_queue(database, guild_id).await.lock().await.clone()
+    }
+
+    /// Change a guild's queue, then save it to the database. The queue stays locked until `change` finishes,
+    /// so commands and track events can't overwrite each other's changes.
+    pub async fn update<R>(&self, database: &Database, guild_id: Id<GuildMarker>, change: impl AsyncFnOnce(&mut MusicQueue) -> R) -> R {
+        let queue = self.guild_queue(database, guild_id).await;
+        let mut queue = queue.lock().await;
+        let result = change(&mut queue).await;
+
+        if let Err(why) = database.update_music_queue(&queue).await {
+            tracing::warn!(?why, "music - Failed to persist the queue for guild `{guild_id}`");
+        }
+
+        result
+    }
+
+    /// Forget a guild's queue entirely
+    pub async fn remove(&self, database: &Database, guild_id: Id<GuildMarker>) {
+        let queue = self.queues.lock().await.remove(&guild_id);
+        if let Some(queue) = queue {
+            // Let any change in progress save first, so it can't bring the queue back afterwards
+            drop(queue.lock().await);
+        }
+
+        if let Err(why) = database.delete_music_queue(guild_id).await {
+            tracing::warn!(?why, "music - Failed to remove the queue for guild `{guild_id}`");
+        }
+    }
+
+    /// Record how far into the current track playback is, so it can be resumed. Only saved every [`POSITION_SAVE_INTERVAL`].
+    pub async fn update_position(&self, database: &Database, guild_id: Id<GuildMarker>, position: i64) {
+        let Some(queue) = self.queues.lock().await.get(&guild_id).cloned() else {
+            return;
+        };
+
+        // Position updates arrive every few seconds, so skip one rather than waiting on a command that holds the queue
+        let Ok(mut queue) = queue.try_lock() else {
+            return;
+        };
+
+        let previous = std::mem::replace(&mut queue.position, position);
+        if previous / POSITION_SAVE_INTERVAL == position / POSITION_SAVE_INTERVAL {
+            return;
+        }
+
+        if let Err(why) = database.update_music_position(guild_id, position).await {
+            tracing::debug!(?why, "music - Failed to persist the playback position for guild `{guild_id}`");
+        }
+    }
+
+    /// The shared queue of a guild, loading it from the database if it is not cached. Starts an empty queue if the guild has none.
+    async fn guild_queue(&self, database: &Database, guild_id: Id<GuildMarker>) -> Arc<Mutex<MusicQueue>> {
+        if let Some(queue) = self.queues.lock().await.get(&guild_id) {
+            return queue.clone();
+        }
+
+        let queue = match database.fetch_music_queue(guild_id).await {
+            Ok(Some(queue)) => queue,
+            Ok(None) => MusicQueue::new(guild_id),
+            Err(why) => {
+                tracing::warn!(?why, "music - Failed to fetch the queue for guild `{guild_id}`, starting a new one");
+                MusicQueue::new(guild_id)
+            }
+        };
+
+        // Another task may have loaded it while this one was waiting on the database
+        self.queues
+            .lock()
+            .await
+            .entry(guild_id)
+            .or_insert_with(|| Arc::new(Mutex::new(queue)))
+            .clone()
+    }
+}
+
+/// Start playing the current track in the queue, from the saved position. Stops the player if the queue is empty.
+pub async fn play_current(gateway: &Gateway, queue: &MusicQueue) -> anyhow::Result<()> {
+    let player = gateway.music.lavalink.player(queue.guild_id).await?;
+
+    let Some(track) = queue.current() else {
+        player.send(twilight_lavalink::model::Stop::from(queue.guild_id))?;
+        return Ok(());
+    };
+
+    let start_time = match queue.position {
+        0 => None,
+        position => Some(position as u64),
+    };
+
+    player.send(Volume::from((queue.guild_id, queue.volume)))?;
+    player.send(Play::new(queue.guild_id, &track.encoded, start_time, None, false))?;
+
+    if queue.paused {
+        player.send(Pause::from((queue.guild_id, true)))?;
+    }
+
+    Ok(())
+}
+
+/// Format a length in milliseconds as `h:mm:ss` or `m:ss`
+pub fn format_length(length: i64) -> String {
+    let seconds = length / 1000;
+    match seconds / 3600 {
+        0 => format!("{}:{:02}", seconds / 60, seconds % 60),
+        hours => format!("{hours}:{:02}:{:02}", (seconds / 60) % 60, seconds % 60),
+    }
+}
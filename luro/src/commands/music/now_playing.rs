@@ -0,0 +1,101 @@
+use luro_model::{
+    builders::{ComponentBuilder, EmbedBuilder},
+    music::{LoopMode, MusicQueue},
+};
+use twilight_model::channel::message::{component::ButtonStyle, Component, Embed};
+
+use crate::gateway::Gateway;
+
+use super::format_length;
+
+/// Replace the guild's now playing message with a fresh one at the bottom of the channel
+pub async fn now_playing(gateway: &Gateway, queue: &mut MusicQueue) {
+    let Some(channel_id) = queue.text_channel_id else {
+        return;
+    };
+
+    if let Some(message_id) = queue.now_playing_message_id.take() {
+        // We don't care if this fails, the message may have already been removed
+        let _ = gateway.twilight_client.delete_message(channel_id, message_id).await;
+    }
+
+    if queue.current().is_none() {
+        return;
+    }
+
+    let embed: Embed = now_playing_embed(queue, gateway.config.accent_colour()).into();
+    let components: Vec<Component> = now_playing_components(queue).into();
+    match gateway
+        .twilight_client
+        .create_message(channel_id)
+        .embeds(&[embed])
+        .components(&components)
+        .await
+    {
+        Ok(response) => match response.model().await {
+            Ok(message) => queue.now_playing_message_id = Some(message.id),
+            Err(why) => tracing::warn!(?why, "music - Failed to parse the now playing message"),
+        },
+        Err(why) => tracing::warn!(?why, "music - Failed to send the now playing message"),
+    }
+}
+
+/// An embed describing the current track and the state of the queue
+pub fn now_playing_embed(queue: &MusicQueue, accent_colour: u32) -> EmbedBuilder {
+    let mut embed = EmbedBuilder::default();
+    embed.colour(accent_colour);
+
+    let Some(track) = queue.current() else {
+        embed
+            .title("Nothing is playing")
+            .description("The queue is empty! Add something with `/music play`");
+        return embed;
+    };
+
+    let description = match &track.uri {
+        Some(uri) => format!("**[{}]({uri})** by **{}**", track.title, track.author),
+        None => format!("**{}** by **{}**", track.title, track.author),
+    };
+
+    embed
+        .title(match queue.paused {
+            true => "Paused",
+            false => "Now Playing",
+        })
+        .description(description)
+        .create_field("Length", format_length(track.length), true)
+        .create_field("Requested by", format!("<@{}>", track.requested_by), true)
+        .create_field("Volume", queue.volume, true)
+        .create_field("Loop", queue.loop_mode, true)
+        .create_field("Up next", queue.tracks.len().saturating_sub(1), true);
+    embed
+}
+
+/// Buttons for controlling the player, handled by the music command
+pub fn now_playing_components(queue: &MusicQueue) -> ComponentBuilder {
+    let mut components = ComponentBuilder::default();
+    components.action_row(|row| {
+        row.button(|button| {
+            button
+                .custom_id("music-pause")
+                .label(match queue.paused {
+                    true => "Resume",
+                    false => "Pause",
+                })
+                .style(ButtonStyle::Primary)
+        })
+        .button(|button| button.custom_id("music-skip").label("Skip").style(ButtonStyle::Secondary))
+        .button(|button| {
+            button
+                .custom_id("music-loop")
+                .label(format!("Loop: {}", queue.loop_mode))
+                .style(match queue.loop_mode {
+                    LoopMode::Off => ButtonStyle::Secondary,
+                    _ => ButtonStyle::Success,
+                })
+        })
+        .button(|button| button.custom_id("music-shuffle").label("Shuffle").style(ButtonStyle::Secondary))
+        .button(|button| button.custom_id("music-stop").label("Stop").style(ButtonStyle::Danger))
+    });
+    components
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use twilight_gateway::MessageSender;
+use twilight_model::{
+    gateway::payload::outgoing::UpdateVoiceState,
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::gateway::GatewayArc;
+
+use super::{now_playing, play_current};
+
+/// How long to wait for Discord to hand the voice connection to Lavalink before playing
+const VOICE_CONNECT_DELAY: Duration = Duration::from_secs(3);
+
+/// Rejoin voice and pick up where the queue left off, if the guild was playing something before a restart
+pub async fn music_resume(gateway: GatewayArc, shard: MessageSender, guild_id: Id<GuildMarker>) {
+    let queue = gateway.music.queue(&gateway.database, guild_id).await;
+    let Some(voice_channel_id) = queue.voice_channel_id else {
+        return;
+    };
+
+    if queue.current().is_none() {
+        return;
+    }
+
+    tracing::info!("music - Resuming playback in guild `{guild_id}`");
+    if let Err(why) = shard.command(&UpdateVoiceState::new(guild_id, Some(voice_channel_id), true, false)) {
+        tracing::warn!(?why, "music - Failed to rejoin voice in guild `{guild_id}`");
+        return;
+    }
+
+    // Lavalink can only play once it has received the voice server update from the gateway
+    tokio::time::sleep(VOICE_CONNECT_DELAY).await;
+
+    // The queue may have changed while waiting, so play whatever it holds now
+    gateway
+        .music
+        .update(&gateway.database, guild_id, async |queue| {
+            if let Err(why) = play_current(&gateway, queue).await {
+                tracing::warn!(?why, "music - Failed to resume playback in guild `{guild_id}`");
+                return;
+            }
+
+            now_playing(&gateway, queue).await;
+        })
+        .await
+}
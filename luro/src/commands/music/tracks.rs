@@ -0,0 +1,114 @@
+use luro_model::music::{MusicConfig, QueuedTrack};
+use serde::Deserialize;
+use twilight_model::id::{marker::UserMarker, Id};
+
+#[derive(thiserror::Error, Debug)]
+pub enum MusicError {
+    #[error("Failed to communicate with the Lavalink node")]
+    Http(#[from] reqwest::Error),
+    #[error("Nothing matched the query")]
+    NoMatches,
+    #[error("Lavalink failed to load the track: {0}")]
+    LoadFailed(String),
+}
+
+/// The tracks found for a query
+pub struct LoadedTracks {
+    /// The name of the playlist, if one was loaded
+    pub playlist: Option<String>,
+    pub tracks: Vec<QueuedTrack>,
+}
+
+/// The response from Lavalink's `/v4/loadtracks` endpoint
+#[derive(Deserialize)]
+#[serde(tag = "loadType", content = "data", rename_all = "lowercase")]
+enum LoadResult {
+    Track(Track),
+    Playlist(Playlist),
+    Search(Vec<Track>),
+    Empty(serde::de::IgnoredAny),
+    Error(LoadError),
+}
+
+#[derive(Deserialize)]
+struct Track {
+    encoded: String,
+    info: TrackInfo,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrackInfo {
+    title: String,
+    author: String,
+    length: i64,
+    uri: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Playlist {
+    info: PlaylistInfo,
+    tracks: Vec<Track>,
+}
+
+#[derive(Deserialize)]
+struct PlaylistInfo {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct LoadError {
+    message: Option<String>,
+}
+
+/// Ask the Lavalink node for tracks matching a query. Anything that is not a link is searched for on YouTube.
+pub async fn load_tracks(
+    http_client: &reqwest::Client,
+    config: &MusicConfig,
+    query: &str,
+    requested_by: Id<UserMarker>,
+) -> Result<LoadedTracks, MusicError> {
+    let identifier = match query.starts_with("http://") || query.starts_with("https://") {
+        true => query.to_owned(),
+        false => format!("ytsearch:{query}"),
+    };
+
+    let result = http_client
+        .get(format!("http://{}/v4/loadtracks", config.lavalink_host))
+        .query(&[("identifier", identifier)])
+        .header("Authorization", &config.lavalink_password)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<LoadResult>()
+        .await?;
+
+    let queued = |track: Track| QueuedTrack {
+        encoded: track.encoded,
+        title: track.info.title,
+        author: track.info.author,
+        uri: track.info.uri,
+        length: track.info.length,
+        requested_by,
+    };
+
+    match result {
+        LoadResult::Track(track) => Ok(LoadedTracks {
+            playlist: None,
+            tracks: vec![queued(track)],
+        }),
+        LoadResult::Playlist(playlist) => Ok(LoadedTracks {
+            playlist: Some(playlist.info.name),
+            tracks: playlist.tracks.into_iter().map(queued).collect(),
+        }),
+        LoadResult::Search(tracks) => match tracks.into_iter().next() {
+            Some(track) => Ok(LoadedTracks {
+                playlist: None,
+                tracks: vec![queued(track)],
+            }),
+            None => Err(MusicError::NoMatches),
+        },
+        LoadResult::Empty(_) => Err(MusicError::NoMatches),
+        LoadResult::Error(error) => Err(MusicError::LoadFailed(error.message.unwrap_or_default())),
+    }
+}
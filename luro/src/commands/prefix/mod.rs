@@ -1,4 +1,8 @@
+use luro_model::command_policy::{command_path, CommandInvocation};
+
 use crate::models::message_context::MessageContext;
+#[cfg(all(feature = "module-interactions", feature = "command-dice"))]
+use crate::models::UnifiedCommand;
 
 mod hi;
 mod uwu;
@@ -13,20 +17,30 @@ pub async fn prefix_handler(framework: &mut MessageContext) {
 
     // A prefix is defined! Does the message start with our prefix?
     let message_content = framework.ctx.content.clone();
-    let message_content = message_content.split_once(' ');
-    let command = match message_content {
-        Some((first_word, rest_of_string)) => match &first_word.starts_with(prefix) {
-            true => {
-                framework.ctx.content = rest_of_string.to_owned();
-                first_word.split_at(prefix.len()).1
-            } // Set message content to be without the prefix + command
-            false => return, // There was no prefix match, so there is no command to match.
-        },
-        None => return, // There is either no message content, or there is nothing to split at ' ' so we can return.
+    let (first_word, rest_of_string) = message_content.split_once(' ').unwrap_or((message_content.as_str(), ""));
+    let command = match first_word.strip_prefix(prefix.as_str()) {
+        Some(command) if !command.is_empty() => {
+            framework.ctx.content = rest_of_string.to_owned();
+            command
+        } // Set message content to be without the prefix + command
+        _ => return, // There was no prefix match, so there is no command to match.
     };
 
     // We have a valid prefix! Let's check to see if the command is disabled.
-    if framework.gateway.config.command_disabled(command) {
+    let roles = framework
+        .ctx
+        .member
+        .as_ref()
+        .map(|member| member.roles.as_slice())
+        .unwrap_or_default();
+    // Aliases such as `roll` are checked as the command they run, such as `dice roll`
+    let invocation = CommandInvocation {
+        command: command_path(command),
+        guild_id: framework.ctx.guild_id,
+        channel_id: Some(framework.ctx.channel_id),
+        roles,
+    };
+    if !framework.gateway.command_allowed(invocation).await {
         disabled_command(&framework, command).await;
         return;
     }
@@ -37,6 +51,11 @@ pub async fn prefix_handler(framework: &mut MessageContext) {
         "hi" => hi::cmd(&framework).await,
         #[cfg(feature = "module-ai")]
         "ai" => super::ai::ai_handler_root(&framework).await,
+        // Commands shared with slash commands, parsing their arguments into the same options
+        #[cfg(all(feature = "module-interactions", feature = "command-dice"))]
+        "dice" => super::interactions::dice::Dice::prefix_handler(&framework).await,
+        #[cfg(all(feature = "module-interactions", feature = "command-dice"))]
+        "roll" => super::interactions::dice::Roll::prefix_handler(&framework).await,
         cmd => unknown_command(&framework, cmd).await,
     };
 }
@@ -0,0 +1,34 @@
+use luro_model::{database::Database, quote::Quote};
+use twilight_model::{
+    channel::Message,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+mod starboard;
+
+pub use starboard::starboard_handler;
+
+/// Quote a message, returning the existing quote instead if the message has already been saved. The bool is true if a new quote was added.
+///
+/// The quote is inserted first, so only one of several concurrent saves of the same message adds it.
+pub async fn save_quote(
+    database: &Database,
+    guild_id: Option<Id<GuildMarker>>,
+    message: &Message,
+    added_by: Id<UserMarker>,
+    nsfw: bool,
+) -> Result<(Quote, bool), luro_model::database::Error> {
+    let mut quote = Quote::new(guild_id, message, added_by, nsfw);
+    if let Some(id) = database.create_quote(&quote).await? {
+        quote.id = id;
+        return Ok((quote, true));
+    }
+
+    match database.fetch_quote_by_message(message.id).await? {
+        Some(quote) => Ok((quote, false)),
+        None => Err(luro_model::database::Error::DriverFailure),
+    }
+}
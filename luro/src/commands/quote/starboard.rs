@@ -0,0 +1,94 @@
+use luro_model::quote::STARBOARD_EMOJI;
+use twilight_model::{
+    channel::message::{Embed, ReactionType},
+    gateway::payload::incoming::ReactionAdd,
+};
+
+use crate::gateway::{Gateway, GatewayResult};
+
+use super::save_quote;
+
+/// Quote a message and repost it to the starboard once it has enough stars. Reposts that are already on the board have their star count updated.
+pub async fn starboard_handler(gateway: &Gateway, event: &ReactionAdd) -> GatewayResult {
+    let Some(guild_id) = event.guild_id else {
+        return Ok(());
+    };
+
+    if !is_star(&event.emoji) {
+        return Ok(());
+    }
+
+    let Some(starboard) = gateway.database.fetch_starboard(guild_id).await? else {
+        return Ok(());
+    };
+
+    // Don't let the board star itself
+    if event.channel_id == starboard.channel_id {
+        return Ok(());
+    }
+
+    let message = gateway
+        .twilight_client
+        .message(event.channel_id, event.message_id)
+        .await?
+        .model()
+        .await?;
+    let stars = message
+        .reactions
+        .iter()
+        .find(|reaction| is_star(&reaction.emoji))
+        .map(|reaction| reaction.count)
+        .unwrap_or_default();
+
+    if stars < starboard.threshold as u64 {
+        return Ok(());
+    }
+
+    let nsfw = gateway
+        .twilight_client
+        .channel(event.channel_id)
+        .await?
+        .model()
+        .await?
+        .nsfw
+        .unwrap_or_default();
+    let (quote, created) = save_quote(&gateway.database, Some(guild_id), &message, gateway.current_user.id, nsfw).await?;
+    let content = format!("{STARBOARD_EMOJI} **{stars}** <#{}>", quote.channel_id);
+
+    if let Some(starboard_message_id) = quote.starboard_message_id {
+        gateway
+            .twilight_client
+            .update_message(starboard.channel_id, starboard_message_id)
+            .content(Some(&content))
+            .await?;
+        return Ok(());
+    }
+
+    // Another star added this quote, and is reposting it
+    if !created && quote.added_by == gateway.current_user.id {
+        return Ok(());
+    }
+
+    tracing::info!("starboard - Message `{}` reached {stars} stars in guild `{guild_id}`", message.id);
+    let author = gateway.database.fetch_member_or_user(Some(guild_id), message.author.id).await?;
+    let embed: Embed = crate::embeds::quote(&quote, Some(&author), gateway.config.accent_colour()).into();
+    let repost = gateway
+        .twilight_client
+        .create_message(starboard.channel_id)
+        .content(&content)
+        .embeds(&[embed])
+        .await?
+        .model()
+        .await?;
+
+    // A quote added by someone else can be reposted by two stars at once, so only the first repost is kept
+    if gateway.database.update_quote_starboard(quote.id, repost.id).await? == 0 {
+        gateway.twilight_client.delete_message(starboard.channel_id, repost.id).await?;
+    }
+
+    Ok(())
+}
+
+fn is_star(emoji: &ReactionType) -> bool {
+    matches!(emoji, ReactionType::Unicode { name } if name == STARBOARD_EMOJI)
+}
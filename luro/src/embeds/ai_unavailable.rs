@@ -0,0 +1,21 @@
+use luro_model::builders::EmbedBuilder;
+
+use crate::commands::BackendError;
+
+pub fn ai_unavailable(error: &BackendError) -> EmbedBuilder {
+    let footer = match error {
+        BackendError::ModelUnavailable(model) => {
+            format!("The model `{model}` is not installed. Ask a bot owner to pull it, or pick another persona.")
+        }
+        BackendError::Timeout(_) => "The model took too long to respond, it may still be loading. Try again in a moment.".to_owned(),
+        _ => "Failed to get a response from the model. A bot owner can check the logs for details.".to_owned(),
+    };
+
+    let mut embed = EmbedBuilder::default();
+    embed
+        .title("Model unavailable")
+        .colour(crate::COLOUR_DANGER)
+        .description(format!("```rs\n{}```", error))
+        .footer(|f| f.text(footer));
+    embed
+}
@@ -1,3 +1,15 @@
+#[cfg(feature = "module-ai")]
+mod ai_unavailable;
 mod internal_error;
+#[cfg(feature = "module-quote")]
+mod quote;
+#[cfg(any(feature = "command-insights", feature = "command-words"))]
+mod sparkline;
 
+#[cfg(feature = "module-ai")]
+pub use ai_unavailable::ai_unavailable;
 pub use internal_error::internal_error;
+#[cfg(feature = "module-quote")]
+pub use quote::quote;
+#[cfg(any(feature = "command-insights", feature = "command-words"))]
+pub use sparkline::sparkline;
@@ -0,0 +1,22 @@
+use luro_model::{builders::EmbedBuilder, quote::Quote, user::User};
+
+/// An embed of a quote. The author is `None` for old quotes whose author is unknown.
+pub fn quote(quote: &Quote, author: Option<&User>, accent_colour: u32) -> EmbedBuilder {
+    let mut embed = EmbedBuilder::default();
+    embed
+        .colour(accent_colour)
+        .description(match quote.content.is_empty() {
+            true => "*This message has no text*",
+            false => quote.content.as_str(),
+        })
+        .author(|embed_author| {
+            embed_author.url(quote.link());
+            match author {
+                Some(author) => embed_author
+                    .name(format!("{} - Quote {}", author.name(), quote.id))
+                    .icon_url(author.avatar_url()),
+                None => embed_author.name(format!("Quote {}", quote.id)),
+            }
+        });
+    embed
+}
@@ -0,0 +1,10 @@
+/// Draw a series of values as a line of bars
+pub fn sparkline(values: &[i64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or_default().max(1);
+
+    values
+        .iter()
+        .map(|value| BARS[(*value * (BARS.len() as i64 - 1) / max) as usize])
+        .collect()
+}
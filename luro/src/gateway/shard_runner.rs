@@ -10,7 +10,9 @@ use crate::SHUTDOWN;
 
 use self::{
     guild_create::guild_create_handler, guild_delete::guild_delete_handler, interaction_create::interaction_create,
-    message_create::message_create_handler, no_handler::no_handler, ready::ready_listener,
+    member_add::member_add_handler, member_remove::member_remove_handler, member_update::member_update_handler,
+    message_create::message_create_handler, no_handler::no_handler, reaction_add::reaction_add_handler,
+    reaction_remove::reaction_remove_handler, ready::ready_listener,
 };
 
 use super::{GatewayArc, GatewayResult};
@@ -29,13 +31,27 @@ pub async fn shard_runner(gateway: GatewayArc, mut shard: Shard) {
             Ok(event) => event,
         };
 
+        // Guilds, members, roles and channels are written to the database in the background
+        gateway.database.queue_sync(&event);
+
+        // Lavalink needs the voice events to connect players, as well as Ready to know the session
+        #[cfg(feature = "module-music")]
+        if let Err(why) = gateway.music.lavalink.process(&event).await {
+            tracing::warn!(?why, "GATEWAY: Lavalink failed to process an event");
+        }
+
         let shrd_sndr = shard.sender();
         let latency = shard.latency().clone();
         match event {
             Event::Ready(event) => spawn(ready_listener(gateway.clone(), shrd_sndr, event)),
             Event::GuildCreate(event) => guild_create_handler(gateway.clone(), shrd_sndr, event),
             Event::GuildDelete(event) => guild_delete_handler(gateway.clone(), shrd_sndr, event),
+            Event::MemberAdd(event) => spawn(member_add_handler(gateway.clone(), shrd_sndr, event)),
+            Event::MemberRemove(event) => spawn(member_remove_handler(gateway.clone(), shrd_sndr, event)),
+            Event::MemberUpdate(event) => spawn(member_update_handler(gateway.clone(), shrd_sndr, event)),
             Event::MessageCreate(event) => spawn(message_create_handler(gateway.clone(), shrd_sndr, event)),
+            Event::ReactionAdd(event) => spawn(reaction_add_handler(gateway.clone(), shrd_sndr, event)),
+            Event::ReactionRemove(event) => spawn(reaction_remove_handler(gateway.clone(), shrd_sndr, event)),
             Event::InteractionCreate(event) => spawn(interaction_create(gateway.clone(), shrd_sndr, latency, event)),
             event => no_handler(event),
         };
@@ -76,6 +92,11 @@ fn handle_error(error: ReceiveMessageError, shard: &Shard) -> bool {
 mod guild_create;
 mod guild_delete;
 mod interaction_create;
+mod member_add;
+mod member_remove;
+mod member_update;
 mod message_create;
 mod no_handler;
+mod reaction_add;
+mod reaction_remove;
 mod ready;
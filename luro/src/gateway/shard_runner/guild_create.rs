@@ -3,6 +3,11 @@ use twilight_model::gateway::payload::incoming::GuildCreate;
 
 use crate::gateway::GatewayArc;
 
-pub fn guild_create_handler(_gateway: GatewayArc, _shard: MessageSender, event: Box<GuildCreate>) {
-    tracing::info!("Joined guild {} ({})", event.name, event.id)
+pub fn guild_create_handler(gateway: GatewayArc, shard: MessageSender, event: Box<GuildCreate>) {
+    tracing::info!("Joined guild {} ({})", event.name, event.id);
+
+    #[cfg(feature = "module-music")]
+    tokio::spawn(crate::commands::music_resume(gateway, shard, event.id));
+    #[cfg(not(feature = "module-music"))]
+    let _ = (gateway, shard);
 }
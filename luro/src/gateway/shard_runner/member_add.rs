@@ -0,0 +1,15 @@
+use twilight_gateway::MessageSender;
+use twilight_model::gateway::payload::incoming::MemberAdd;
+
+use crate::gateway::{GatewayArc, GatewayResult};
+
+pub async fn member_add_handler(gateway: GatewayArc, _shard: MessageSender, event: Box<MemberAdd>) -> GatewayResult {
+    #[cfg(feature = "module-insights")]
+    crate::commands::insights_member_event(&gateway, event.guild_id, event.user.id, luro_model::insights::MemberEventKind::Join).await?;
+    #[cfg(feature = "module-autoroles")]
+    crate::commands::auto_role_join(&gateway, &event).await?;
+    #[cfg(not(any(feature = "module-autoroles", feature = "module-insights")))]
+    let _ = (gateway, event);
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use twilight_gateway::MessageSender;
+use twilight_model::gateway::payload::incoming::MemberRemove;
+
+use crate::gateway::{GatewayArc, GatewayResult};
+
+pub async fn member_remove_handler(gateway: GatewayArc, _shard: MessageSender, event: MemberRemove) -> GatewayResult {
+    #[cfg(feature = "module-insights")]
+    crate::commands::insights_member_event(
+        &gateway,
+        event.guild_id,
+        event.user.id,
+        luro_model::insights::MemberEventKind::Leave,
+    )
+    .await?;
+    #[cfg(feature = "module-autoroles")]
+    crate::commands::auto_role_leave(&gateway, &event).await?;
+    #[cfg(not(any(feature = "module-autoroles", feature = "module-insights")))]
+    let _ = (gateway, event);
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use twilight_gateway::MessageSender;
+use twilight_model::gateway::payload::incoming::MemberUpdate;
+
+use crate::gateway::{GatewayArc, GatewayResult};
+
+pub async fn member_update_handler(gateway: GatewayArc, _shard: MessageSender, event: Box<MemberUpdate>) -> GatewayResult {
+    #[cfg(feature = "module-autoroles")]
+    crate::commands::auto_role_screened(&gateway, &event).await?;
+    #[cfg(not(feature = "module-autoroles"))]
+    let _ = (gateway, event);
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use twilight_gateway::MessageSender;
+use twilight_model::gateway::payload::incoming::ReactionAdd;
+
+use crate::gateway::{GatewayArc, GatewayResult};
+
+pub async fn reaction_add_handler(gateway: GatewayArc, _shard: MessageSender, event: Box<ReactionAdd>) -> GatewayResult {
+    #[cfg(feature = "module-autoroles")]
+    crate::commands::reaction_role_handler(&gateway, &event, true).await?;
+    #[cfg(feature = "module-quote")]
+    crate::commands::starboard_handler(&gateway, &event).await?;
+    #[cfg(not(any(feature = "module-autoroles", feature = "module-quote")))]
+    let _ = (gateway, event);
+
+    Ok(())
+}
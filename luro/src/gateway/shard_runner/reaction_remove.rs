@@ -0,0 +1,13 @@
+use twilight_gateway::MessageSender;
+use twilight_model::gateway::payload::incoming::ReactionRemove;
+
+use crate::gateway::{GatewayArc, GatewayResult};
+
+pub async fn reaction_remove_handler(gateway: GatewayArc, _shard: MessageSender, event: Box<ReactionRemove>) -> GatewayResult {
+    #[cfg(feature = "module-autoroles")]
+    crate::commands::reaction_role_handler(&gateway, &event, false).await?;
+    #[cfg(not(feature = "module-autoroles"))]
+    let _ = (gateway, event);
+
+    Ok(())
+}
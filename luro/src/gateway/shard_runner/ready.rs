@@ -34,29 +34,17 @@ pub async fn ready_listener(gateway: GatewayArc, shard: MessageSender, event: Bo
         Err(why) => tracing::info!(?why, "Failed to create new presence object"),
     }
 
-    // let mut staff = framework.database.user_fetch_staff().await?;
-
-    // if staff.is_empty() {
-    //     info!("-- Registering Staff in DB --");
-
-    //     // Register staff
-    //     for staff in BOT_OWNERS {
-    //         let mut user = framework.fetch_user(staff).await?;
-    //         if let Some(ref mut user_data) = user.data {
-    //             user_data.permissions = UserPermissions::Administrator;
-    //             framework.database.user_update_permissions(user_data).await?;
-    //         }
-    //     }
-
-    //     // Register primary owner
-    //     let mut owner = framework.fetch_user(PRIMARY_BOT_OWNER).await?;
-    //     if let Some(ref mut user_data) = owner.data {
-    //         user_data.permissions = UserPermissions::Owner;
-    //         framework.database.user_update_permissions(user_data).await?;
-    //     }
-
-    //     staff = framework.database.user_fetch_staff().await?;
-    // }
+    // Staff come from the configuration and the database, so show who ended up with access
+    match gateway.database.fetch_staff().await {
+        Ok(staff) => {
+            let staff = staff
+                .iter()
+                .map(|staff| format!("{} ({})", staff.username(), staff.user_type))
+                .collect::<Vec<_>>();
+            tracing::info!("Staff:          {}", staff.join(", "))
+        }
+        Err(why) => tracing::warn!(?why, "Failed to fetch the bot staff"),
+    }
 
     #[cfg(not(feature = "pretty-tables"))]
     standard_output(&event).await;
@@ -1,6 +1,8 @@
 #![feature(string_remove_matches)]
 #![feature(let_chains)]
 
+#[cfg(feature = "module-interactions")]
+mod cli;
 pub mod commands;
 pub mod embeds;
 mod error_handler;
@@ -20,18 +22,6 @@ pub const COLOUR_DANGER: u32 = 0xD35F5F;
 pub const COLOUR_TRANSPARENT: u32 = 0x2F3136;
 /// Luro's SUCCESS colour
 pub const COLOUR_SUCCESS: u32 = 0xA0D995;
-/// The primary owner user ID. Used for some defaults, as well as to say who owns the bot. This MUST  be set, even if a group of people own Luro, as its used as a fallback for when data is not tied to a specific user. For example, see [Story].
-pub const PRIMARY_BOT_OWNER: twilight_model::id::Id<twilight_model::id::marker::UserMarker> =
-    twilight_model::id::Id::new(373524896187416576);
-/// Luro's primary owner(s)
-pub const BOT_OWNERS: [twilight_model::id::Id<twilight_model::id::marker::UserMarker>; 4] = [
-    twilight_model::id::Id::new(1138489661187182692), // Zeron
-    // twilight_model::id::Id::new(1146227925960638474), // Ferrona
-    twilight_model::id::Id::new(138791390279630849), // Tzat
-    twilight_model::id::Id::new(261308783546859520), // Aurora
-    twilight_model::id::Id::new(373524896187416576), // Nurah
-];
-
 /// When true, the bot will shutdown.
 pub static SHUTDOWN: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 
@@ -39,10 +29,39 @@ pub static SHUTDOWN: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBo
 async fn main() -> anyhow::Result<()> {
     #[cfg(any(feature = "logs-stdout", feature = "logs-file", feature = "logs-tokio-console"))]
     let _guards = logging::init_logging(); // Start the logging service first, for understandable reasons
+
+    // Arguments run one of the command line tools instead of starting the bot
+    #[cfg(feature = "module-interactions")]
+    {
+        let args = std::env::args().skip(1).collect::<Vec<_>>();
+        if !args.is_empty() {
+            return cli::run(&args).await;
+        }
+    }
+
     let (gateway, shards) = gateway::Gateway::create_shards(INTENTS - twilight_gateway::Intents::GUILD_PRESENCES).await?;
     let mut senders = Vec::with_capacity(shards.len()); // A collection of senders, used to communicate with the shards
     let mut tasks = Vec::with_capacity(shards.len()); // A collection of tasks, which is used to gracefully close the bot
 
+    let database_sync = gateway.clone();
+    tokio::spawn(async move {
+        // The shards wait on the sync queue when it is full, so the runner is restarted if it ever stops
+        loop {
+            let runner = database_sync.clone();
+            if let Err(why) = tokio::spawn(async move { runner.database.sync_runner().await }).await {
+                tracing::error!(?why, "The database sync stopped, restarting it");
+            }
+        }
+    });
+    #[cfg(feature = "module-autoroles")]
+    tokio::spawn(commands::auto_role_runner(gateway.clone()));
+    #[cfg(feature = "module-insights")]
+    tokio::spawn(commands::insights_runner(gateway.clone()));
+    #[cfg(feature = "module-marriage")]
+    tokio::spawn(commands::marriage_anniversaries(gateway.clone()));
+    #[cfg(feature = "module-music")]
+    tokio::spawn(commands::music_runner(gateway.clone()));
+
     // For each shard, spawn a thread to handle it
     for shard in shards {
         senders.push(shard.sender());
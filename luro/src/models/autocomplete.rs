@@ -0,0 +1,73 @@
+use twilight_model::application::{
+    command::{CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType},
+    interaction::{
+        application_command::{CommandDataOption, CommandOptionValue},
+        Interaction, InteractionData,
+    },
+};
+
+mod providers;
+
+pub use providers::{character_names, fetish_names, quote_ids};
+
+/// Discord shows at most this many autocomplete choices
+pub const MAX_CHOICES: usize = 25;
+
+/// The option a user is currently typing in, passed to [`super::CreateCommand::handle_autocomplete`]
+#[derive(Clone, Debug)]
+pub struct FocusedOption {
+    /// The subcommand group and subcommand the option belongs to, outermost first
+    pub path: Vec<String>,
+    /// The name of the focused option
+    pub name: String,
+    /// What the user has typed so far
+    pub input: String,
+    pub kind: CommandOptionType,
+}
+
+impl FocusedOption {
+    /// Find the focused option of an autocomplete interaction
+    pub fn from_interaction(interaction: &Interaction) -> Option<Self> {
+        let Some(InteractionData::ApplicationCommand(data)) = &interaction.data else {
+            return None;
+        };
+
+        Self::find(&data.options, Vec::new())
+    }
+
+    /// The subcommand the option belongs to, if any
+    pub fn subcommand(&self) -> Option<&str> {
+        self.path.last().map(String::as_str)
+    }
+
+    fn find(options: &[CommandDataOption], path: Vec<String>) -> Option<Self> {
+        options.iter().find_map(|option| match &option.value {
+            CommandOptionValue::Focused(input, kind) => Some(Self {
+                path: path.clone(),
+                name: option.name.clone(),
+                input: input.clone(),
+                kind: *kind,
+            }),
+            CommandOptionValue::SubCommand(options) | CommandOptionValue::SubCommandGroup(options) => {
+                let mut path = path.clone();
+                path.push(option.name.clone());
+                Self::find(options, path)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// A choice with its name cut down to the 100 characters Discord allows
+pub fn choice(name: &str, value: CommandOptionChoiceValue) -> CommandOptionChoice {
+    let name = match name.chars().count() > 100 {
+        true => format!("{}…", name.chars().take(99).collect::<String>()),
+        false => name.to_owned(),
+    };
+
+    CommandOptionChoice {
+        name,
+        name_localizations: None,
+        value,
+    }
+}
@@ -0,0 +1,60 @@
+//! Ready made autocomplete providers, which can be returned straight from [`crate::models::CreateCommand::handle_autocomplete`].
+
+use luro_model::quote::QuoteSearch;
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+use super::{choice, MAX_CHOICES};
+use crate::models::interaction::{InteractionContext, InteractionResult};
+
+/// How many recent quotes are searched when completing a quote ID
+const QUOTE_LOOKBACK: i64 = 200;
+
+/// The names of the author's characters containing the input
+pub async fn character_names(framework: &InteractionContext, input: &str) -> InteractionResult<Vec<CommandOptionChoice>> {
+    let input = input.to_lowercase();
+    let characters = framework.database().fetch_characters(framework.author_id()).await?;
+
+    Ok(characters
+        .into_iter()
+        .filter(|character| character.name.to_lowercase().contains(&input))
+        .take(MAX_CHOICES)
+        .map(|character| choice(&character.name, CommandOptionChoiceValue::String(character.name.clone())))
+        .collect())
+}
+
+/// The guild's most recent quotes whose ID starts with the input, showing a preview of each quote.
+/// NSFW quotes are only suggested in NSFW channels.
+pub async fn quote_ids(framework: &InteractionContext, input: &str) -> InteractionResult<Vec<CommandOptionChoice>> {
+    let Some(guild_id) = framework.interaction.guild_id else {
+        return Ok(vec![]);
+    };
+
+    let search = QuoteSearch {
+        query: None,
+        author_id: None,
+        nsfw: framework.channel().nsfw.unwrap_or_default(),
+        limit: QUOTE_LOOKBACK,
+    };
+    let quotes = framework.database().fetch_quotes(guild_id, &search).await?;
+    let input = input.trim();
+
+    Ok(quotes
+        .into_iter()
+        .filter(|quote| quote.id.to_string().starts_with(input))
+        .take(MAX_CHOICES)
+        .map(|quote| {
+            let preview = quote.content.lines().next().unwrap_or_default();
+            choice(&format!("#{} - {preview}", quote.id), CommandOptionChoiceValue::Integer(quote.id))
+        })
+        .collect())
+}
+
+/// Fetish names containing the input
+pub async fn fetish_names(framework: &InteractionContext, input: &str) -> InteractionResult<Vec<CommandOptionChoice>> {
+    let names = framework.database().fetch_fetish_names(input.trim(), MAX_CHOICES as i64).await?;
+
+    Ok(names
+        .into_iter()
+        .map(|name| choice(&name, CommandOptionChoiceValue::String(name.clone())))
+        .collect())
+}
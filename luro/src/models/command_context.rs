@@ -0,0 +1,105 @@
+use luro_model::builders::InteractionResponseBuilder;
+use twilight_interactions::command::parse_prefix;
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+use super::{
+    interaction::{InteractionContext, InteractionResult},
+    message_context::MessageContext,
+};
+
+/// Where a command was run from, so the same command can answer a slash command or a prefix command message.
+pub enum CommandContext<'a> {
+    Interaction(&'a mut InteractionContext),
+    Message(&'a MessageContext),
+}
+
+impl CommandContext<'_> {
+    pub fn author_id(&self) -> Id<UserMarker> {
+        match self {
+            Self::Interaction(framework) => framework.author_id(),
+            Self::Message(framework) => framework.ctx.author.id,
+        }
+    }
+
+    pub fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        match self {
+            Self::Interaction(framework) => framework.interaction.guild_id,
+            Self::Message(framework) => framework.ctx.guild_id,
+        }
+    }
+
+    pub async fn accent_colour(&self) -> u32 {
+        match self {
+            Self::Interaction(framework) => framework.accent_colour().await,
+            Self::Message(_) => crate::ACCENT_COLOUR,
+        }
+    }
+
+    /// Respond to the interaction, or reply to the message the command was sent in.
+    ///
+    /// Ephemeral has no effect on message replies.
+    pub async fn respond<F>(&self, response: F) -> InteractionResult<()>
+    where
+        F: FnOnce(&mut InteractionResponseBuilder) -> &mut InteractionResponseBuilder,
+    {
+        let framework = match self {
+            Self::Interaction(framework) => return framework.respond(response).await,
+            Self::Message(framework) => framework,
+        };
+
+        let mut message = InteractionResponseBuilder::default();
+        response(&mut message);
+
+        let mut client = framework
+            .gateway
+            .twilight_client
+            .create_message(framework.ctx.channel_id)
+            .reply(message.reply.unwrap_or(framework.ctx.id))
+            .allowed_mentions(message.allowed_mentions.as_ref());
+
+        if let Some(content) = &message.content {
+            client = client.content(content);
+        }
+        if let Some(embeds) = &message.embeds {
+            client = client.embeds(embeds);
+        }
+        if let Some(components) = &message.components {
+            client = client.components(components);
+        }
+        if let Some(attachments) = &message.attachments {
+            client = client.attachments(attachments);
+        }
+
+        client.await?;
+        Ok(())
+    }
+}
+
+/// A command that can be run as a slash command or as a prefix command, such as `/dice roll 2d20` and `s:roll 2d20`.
+///
+/// Prefix arguments are parsed into the same model as the slash command options, see [`parse_prefix`] for the syntax.
+/// Messages carry no resolved data, so mentions can only be parsed into IDs.
+pub trait UnifiedCommand: twilight_interactions::command::CommandModel + twilight_interactions::command::CreateCommand + Send {
+    fn run(self, framework: CommandContext<'_>) -> impl std::future::Future<Output = InteractionResult<()>> + Send;
+
+    /// Parse the arguments of a prefix command and run it, replying with the error if the arguments are invalid
+    fn prefix_handler(framework: &MessageContext) -> impl std::future::Future<Output = ()> + Send {
+        async move {
+            let result = match parse_prefix::<Self>(&framework.ctx.content, None) {
+                Ok(command) => command.run(CommandContext::Message(framework)).await,
+                Err(why) => {
+                    CommandContext::Message(framework)
+                        .respond(|r| r.content(format!("Sorry, I could not understand that: {why}")))
+                        .await
+                }
+            };
+
+            if let Err(why) = result {
+                tracing::error!(?why, "prefix_command - Failed to run command");
+            }
+        }
+    }
+}
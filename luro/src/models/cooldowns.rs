@@ -0,0 +1,28 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// When commands with a cooldown can next be used, tracked in memory so they reset on restart.
+///
+/// Cooldowns are keyed by the command path, like `quote search`, and the ID of whoever the cooldown applies to, so each subcommand has its own entry.
+#[derive(Debug, Default)]
+pub struct Cooldowns(Mutex<HashMap<(String, u64), Instant>>);
+
+impl Cooldowns {
+    /// Start the cooldown of a command, or return how long is left if it has already started
+    pub fn start(&self, command: &str, key: u64, cooldown: Duration) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut cooldowns = self.0.lock().unwrap();
+        cooldowns.retain(|_, ready_at| *ready_at > now);
+
+        match cooldowns.get(&(command.to_owned(), key)) {
+            Some(ready_at) => Err(ready_at.duration_since(now)),
+            None => {
+                cooldowns.insert((command.to_owned(), key), now + cooldown);
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,12 +1,19 @@
 use luro_model::database::Database;
 use twilight_model::{
-    application::interaction::{Interaction, InteractionData, InteractionType},
+    application::{
+        command::CommandOptionChoice,
+        interaction::{Interaction, InteractionData, InteractionType},
+    },
+    http::interaction::InteractionResponseType,
     id::{marker::InteractionMarker, Id},
 };
 
 use crate::responses::StandardResponse;
 
-use super::interaction::{InteractionContext, InteractionError, InteractionResult};
+use super::{
+    autocomplete::FocusedOption,
+    interaction::{InteractionContext, InteractionError, InteractionResult},
+};
 
 pub trait CreateCommand: twilight_interactions::command::CommandModel + twilight_interactions::command::CreateCommand {
     fn cmd(interaction: &Interaction) -> Result<Self, InteractionError> {
@@ -34,15 +41,26 @@ pub trait CreateCommand: twilight_interactions::command::CommandModel + twilight
         async {
             match f.interaction.kind {
                 InteractionType::Ping => no_handler("ping"),
-                InteractionType::ApplicationCommand => Self::cmd(&f.interaction)?.handle_command(f).await,
+                InteractionType::ApplicationCommand => {
+                    let command = Self::cmd(&f.interaction)?;
+                    let checks = twilight_interactions::command::CreateCommand::checks(&command);
+                    match f.run_checks(&checks).await? {
+                        true => command.handle_command(f).await,
+                        false => Ok(()),
+                    }
+                }
                 InteractionType::MessageComponent => Self::handle_component(f).await,
-                InteractionType::ApplicationCommandAutocomplete => no_handler("autocomplete"),
+                InteractionType::ApplicationCommandAutocomplete => autocomplete_handler::<Self>(f).await,
                 InteractionType::ModalSubmit => Self::handle_modal(f).await,
                 unknown_kind => no_handler(unknown_kind.kind()),
             }
         }
     }
 
+    /// Recover the command a component is attached to by fetching the original interaction from the database.
+    ///
+    /// Only works for components on the response to a slash command. Prefer storing state with a
+    /// [`twilight_interactions::custom_id::CustomId`], which needs no database and survives restarts.
     fn command_from_component(
         framework: &InteractionContext,
     ) -> impl std::future::Future<Output = Result<Self, InteractionError>> + Send {
@@ -88,10 +106,20 @@ pub trait CreateCommand: twilight_interactions::command::CommandModel + twilight
         }
     }
 
-    fn handle_autocomplete(framework: &mut InteractionContext) -> impl std::future::Future<Output = InteractionResult<()>> + Send {
-        async {
-            let response = StandardResponse::UnknownCommand(framework.command_name());
-            framework.standard_response(response).await
+    /// Suggest choices for the option the user is typing in. The choices are cut down to the 25 Discord allows.
+    ///
+    /// Options only reach this handler when they are marked with `#[command(autocomplete = true)]`.
+    fn handle_autocomplete(
+        framework: &mut InteractionContext,
+        focused: FocusedOption,
+    ) -> impl std::future::Future<Output = InteractionResult<Vec<CommandOptionChoice>>> + Send {
+        async move {
+            tracing::info!(
+                "Command {} has no autocomplete handler for option {}",
+                framework.command_name(),
+                focused.name
+            );
+            Ok(vec![])
         }
     }
 
@@ -107,6 +135,22 @@ pub trait CreateCommand: twilight_interactions::command::CommandModel + twilight
     }
 }
 
+/// Find the focused option and respond with the choices from the command's autocomplete handler
+async fn autocomplete_handler<T: CreateCommand>(framework: &mut InteractionContext) -> InteractionResult<()> {
+    let Some(focused) = FocusedOption::from_interaction(&framework.interaction) else {
+        return no_handler("autocomplete without a focused option");
+    };
+
+    let choices = T::handle_autocomplete(framework, focused).await?;
+    framework
+        .respond(|response| {
+            response
+                .choices(choices.into_iter().take(super::autocomplete::MAX_CHOICES))
+                .response_type(InteractionResponseType::ApplicationCommandAutocompleteResult)
+        })
+        .await
+}
+
 fn no_handler(handler: &str) -> InteractionResult<()> {
     tracing::info!("Received data for {handler} handler, which is not configured!");
     Ok(())
@@ -9,18 +9,26 @@ mod author_id;
 mod author_or_user;
 mod bot;
 mod channel;
+mod command_allowed;
 mod command_name;
+mod command_path;
 mod component_message;
+mod custom_id;
 mod database;
 mod fetch_member;
 mod fetch_user;
 mod guild;
 mod interaction_client;
 mod parse_field;
+mod parse_modal;
 mod respond;
 mod response_send;
 mod response_update;
+mod route;
+#[cfg(feature = "module-interactions")]
+mod run_checks;
 mod standard_response;
+mod text;
 
 pub struct InteractionContext {
     pub gateway: GatewayArc,
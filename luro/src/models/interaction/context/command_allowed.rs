@@ -0,0 +1,25 @@
+use luro_model::command_policy::{command_path, CommandInvocation};
+
+impl super::InteractionContext {
+    /// Check the command against the configuration and the guild's command policies.
+    /// Components and modals are checked as the command they belong to.
+    pub async fn command_allowed(&self) -> bool {
+        let route = self.command_path();
+        let command = command_path(&route);
+        let roles = self
+            .interaction
+            .member
+            .as_ref()
+            .map(|member| member.roles.as_slice())
+            .unwrap_or_default();
+
+        self.gateway
+            .command_allowed(CommandInvocation {
+                command,
+                guild_id: self.interaction.guild_id,
+                channel_id: self.interaction.channel.as_ref().map(|channel| channel.id),
+                roles,
+            })
+            .await
+    }
+}
@@ -0,0 +1,26 @@
+use twilight_model::application::interaction::{application_command::CommandOptionValue, InteractionData};
+
+impl super::InteractionContext {
+    /// The command with the subcommands that were run, separated by spaces such as `quote get`.
+    /// Components and modals return their route instead.
+    pub fn command_path(&self) -> String {
+        let Some(InteractionData::ApplicationCommand(data)) = &self.interaction.data else {
+            return self.route().to_owned();
+        };
+
+        let mut path = data.name.clone();
+        let mut options = &data.options;
+        while let Some(option) = options.first() {
+            match &option.value {
+                CommandOptionValue::SubCommand(inner) | CommandOptionValue::SubCommandGroup(inner) => {
+                    path.push(' ');
+                    path.push_str(&option.name);
+                    options = inner;
+                }
+                _ => break,
+            }
+        }
+
+        path
+    }
+}
@@ -0,0 +1,17 @@
+use twilight_interactions::custom_id::CustomId;
+use twilight_model::application::interaction::InteractionData;
+
+use crate::models::interaction::InteractionError;
+
+impl super::InteractionContext {
+    /// Decode the state stored in the custom ID of a component or modal.
+    pub fn custom_id<T: CustomId>(&self) -> Result<T, InteractionError> {
+        let custom_id = match &self.interaction.data {
+            Some(InteractionData::MessageComponent(data)) => &data.custom_id,
+            Some(InteractionData::ModalSubmit(data)) => &data.custom_id,
+            _ => return Err(InteractionError::NotComponent),
+        };
+
+        Ok(T::from_custom_id(custom_id)?)
+    }
+}
@@ -0,0 +1,17 @@
+use twilight_interactions::modal::ModalModel;
+use twilight_model::application::interaction::InteractionData;
+
+use crate::models::interaction::InteractionError;
+
+impl super::InteractionContext {
+    /// Parse [`ModalInteractionData`] into a type deriving [`ModalModel`].
+    ///
+    /// Prefer this over [`Self::parse_field`], as missing or invalid fields are returned as typed errors.
+    pub fn parse_modal<T: ModalModel>(&self) -> Result<T, InteractionError> {
+        let Some(InteractionData::ModalSubmit(data)) = self.interaction.data.clone() else {
+            return Err(InteractionError::NotModal);
+        };
+
+        Ok(T::from_interaction(data)?)
+    }
+}
@@ -0,0 +1,15 @@
+use twilight_interactions::custom_id::prefix;
+use twilight_model::application::interaction::InteractionData;
+
+impl super::InteractionContext {
+    /// The name used to route this interaction to its handler.
+    ///
+    /// This is the command name, or the prefix of the custom ID for components and modals.
+    pub fn route(&self) -> &str {
+        match &self.interaction.data {
+            Some(InteractionData::MessageComponent(data)) => prefix(&data.custom_id),
+            Some(InteractionData::ModalSubmit(data)) => prefix(&data.custom_id),
+            _ => self.command_name(),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use luro_model::user::UserPermissions;
+use twilight_interactions::command::{CommandChecks, CooldownBucket};
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{models::interaction::InteractionResult, responses::StandardResponse};
+
+impl super::InteractionContext {
+    /// Run the checks declared on a command, responding with the reason if one fails.
+    ///
+    /// Returns `true` if the command may be handled. Cooldowns are only started once every other check has passed.
+    pub async fn run_checks(&self, checks: &CommandChecks) -> InteractionResult<bool> {
+        if checks.is_empty() {
+            return Ok(true);
+        }
+
+        let guild_id = self.interaction.guild_id;
+        let needs_guild = checks.guild_only || !checks.user_permissions.is_empty() || !checks.bot_permissions.is_empty();
+        if needs_guild && guild_id.is_none() {
+            self.standard_response(StandardResponse::NotGuild).await?;
+            return Ok(false);
+        }
+
+        if checks.owner_only && !self.is_owner().await? {
+            self.standard_response(StandardResponse::NotOwner(self.author_id(), self.command_name()))
+                .await?;
+            return Ok(false);
+        }
+
+        // Discord resolves the permissions of both users in the channel, including its overwrites
+        if let Some(guild_id) = guild_id
+            && !(checks.user_permissions.is_empty() && checks.bot_permissions.is_empty())
+        {
+            if !checks.bot_permissions.is_empty() {
+                let permissions = match self.interaction.app_permissions {
+                    Some(permissions) => permissions,
+                    None => self.guild_permissions(guild_id, self.gateway.current_user.id).await?,
+                };
+                let missing = checks.bot_permissions.difference(permissions);
+                if !missing.is_empty() {
+                    self.standard_response(StandardResponse::BotMissingPermission(&missing)).await?;
+                    return Ok(false);
+                }
+            }
+
+            if !checks.user_permissions.is_empty() {
+                let permissions = match self.interaction.member.as_ref().and_then(|member| member.permissions) {
+                    Some(permissions) => permissions,
+                    None => self.guild_permissions(guild_id, self.author_id()).await?,
+                };
+                let missing = checks.user_permissions.difference(permissions);
+                if !missing.is_empty() {
+                    self.standard_response(StandardResponse::MissingPermission(&missing)).await?;
+                    return Ok(false);
+                }
+            }
+        }
+
+        if let Some(cooldown) = checks.cooldown {
+            let channel_id = self
+                .interaction
+                .channel
+                .as_ref()
+                .map(|channel| channel.id.get())
+                .unwrap_or_default();
+            let key = match cooldown.bucket {
+                CooldownBucket::User => self.author_id().get(),
+                CooldownBucket::Channel => channel_id,
+                CooldownBucket::Guild => guild_id.map(|guild_id| guild_id.get()).unwrap_or(channel_id),
+                CooldownBucket::Global => 0,
+            };
+
+            // Subcommands have their own cooldowns, so they are keyed by the full path
+            if let Err(remaining) = self.gateway.cooldowns.start(&self.command_path(), key, cooldown.duration) {
+                self.standard_response(StandardResponse::Cooldown(&remaining)).await?;
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// If the author owns the bot, either through the configuration or the database. Administrators are not owners.
+    async fn is_owner(&self) -> InteractionResult<bool> {
+        let staff = self.database().check_staff(self.author_id()).await?;
+        Ok(staff.is_some_and(|staff| staff.user_type == UserPermissions::Owner))
+    }
+
+    /// A member's permissions from their roles, ignoring channel overwrites. Only used if Discord did not resolve them.
+    async fn guild_permissions(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> InteractionResult<Permissions> {
+        let owner_id = self.guild().await?.twilight_guild.owner_id;
+        Ok(self.fetch_member(guild_id, user_id).await?.permission_matrix(owner_id))
+    }
+}
@@ -0,0 +1,17 @@
+use std::fmt::Display;
+
+use luro_model::locale::format;
+
+impl super::InteractionContext {
+    /// Get a message in the user's locale, falling back to the guild's locale and then the default locale.
+    /// `{name}` placeholders in the message are replaced with the matching argument.
+    pub fn text(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let locales = [&self.interaction.locale, &self.interaction.guild_locale]
+            .into_iter()
+            .flatten()
+            .map(|locale| locale.as_str())
+            .collect::<Vec<_>>();
+
+        format(&self.gateway.locales.text(&locales, key), args)
+    }
+}
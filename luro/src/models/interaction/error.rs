@@ -25,6 +25,10 @@ pub enum InteractionError {
     NotGuild,
     #[error("Twilight failed to parse")]
     ParseError(#[from] twilight_interactions::error::ParseError),
+    #[error("Failed to parse the submitted modal: {0}")]
+    ModalParseError(#[from] twilight_interactions::error::ModalParseError),
+    #[error("This component is no longer valid, try running the command again: {0}")]
+    CustomIdError(#[from] twilight_interactions::error::CustomIdError),
     #[error("Author not present within interaction")]
     AuthorNotPresent,
     #[error("Twilight failed to deserialize a response")]
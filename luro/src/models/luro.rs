@@ -5,7 +5,10 @@ use twilight_gateway::MessageSender;
 use twilight_model::user::CurrentUser;
 
 mod bot_name;
+mod command_allowed;
 mod create_shards;
+#[cfg(feature = "module-flavour")]
+mod flavour;
 mod interaction_client;
 mod register_commands;
 
@@ -42,4 +45,19 @@ pub struct Luro {
     pub twilight_client: Arc<twilight_http::Client>,
     pub current_user: Arc<CurrentUser>,
     pub http_client: reqwest::Client,
+    /// Message catalogs used to localize responses and command registrations
+    pub locales: luro_model::locale::Locales,
+    /// Cooldowns of commands declared with `#[command(cooldown = "...")]`
+    #[cfg(feature = "module-interactions")]
+    pub cooldowns: crate::models::Cooldowns,
+    #[cfg(feature = "module-ai")]
+    pub ai_rate_limiter: crate::commands::AiRateLimiter,
+    /// The e621 client, with its rate limit, cache and the searches being paged through
+    #[cfg(feature = "module-e621")]
+    pub e621: crate::commands::E621,
+    #[cfg(feature = "module-music")]
+    pub music: crate::commands::Music,
+    /// The built in flavour text pools, which can be reloaded while running
+    #[cfg(feature = "module-flavour")]
+    pub flavour: tokio::sync::RwLock<luro_model::flavour::FlavourPools>,
 }
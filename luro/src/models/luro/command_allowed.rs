@@ -0,0 +1,28 @@
+use luro_model::command_policy::CommandInvocation;
+
+impl super::Luro {
+    /// Decide if a slash, prefix or keyword command may run. The global configuration is checked first, then the guild's
+    /// command policies. If the policies fail to load the command is allowed, so a database outage doesn't disable everything.
+    pub async fn command_allowed(&self, invocation: CommandInvocation<'_>) -> bool {
+        let root = invocation.command.split(' ').next().unwrap_or_default();
+        if !self.config.command_allowed(root) || self.config.command_disabled(invocation.command) {
+            return false;
+        }
+
+        let Some(guild_id) = invocation.guild_id else {
+            return true;
+        };
+
+        match self.database.fetch_command_policies(guild_id).await {
+            Ok(policies) => invocation.allowed(&policies),
+            Err(why) => {
+                tracing::warn!(
+                    ?why,
+                    "Failed to fetch the command policies of guild {guild_id}, allowing `{}`",
+                    invocation.command
+                );
+                true
+            }
+        }
+    }
+}
@@ -26,16 +26,35 @@ impl super::Luro {
         let twilight = Arc::new(twilight_http::Client::new(discord_token.clone()));
         let current_user = twilight.current_user().await?.model().await?;
         let current_user_name = current_user.name.to_lowercase();
-        let gateway_config = Config::fetch(&format!("./config/{current_user_name}.toml")).await?;
+        let mut gateway_config = Config::fetch(&format!("./config/{current_user_name}.toml")).await?;
         let http_client = reqwest::ClientBuilder::new().build()?;
-        let database = Database::new(&gateway_config, twilight.clone()).await?;
         let application = twilight.current_user_application().await?.model().await?;
 
+        // Without configured owners, whoever owns the application can manage the staff
+        if gateway_config.staff.owners.is_empty() {
+            gateway_config.staff.owners = match &application.team {
+                Some(team) => team.members.iter().map(|member| member.user.id).collect(),
+                None => application.owner.iter().map(|owner| owner.id).collect(),
+            };
+            tracing::info!(
+                "GATEWAY: No owners are configured, using the application owners {:?}",
+                gateway_config.staff.owners
+            );
+        }
+
+        let database = Database::new(&gateway_config, twilight.clone()).await?;
+        let locales = luro_model::locale::Locales::load(luro_model::locale::LOCALE_DIRECTORY).await?;
+
         // Create each shard in a set, based on Discord's recommendations
         let shards =
             twilight_gateway::create_recommended(&twilight, twilight_gateway::Config::new(discord_token, intents), |_, c| c.build())
                 .await?;
 
+        #[cfg(feature = "module-flavour")]
+        let flavour = luro_model::flavour::FlavourPools::load(luro_model::flavour::FLAVOUR_DIRECTORY).await?;
+        #[cfg(feature = "module-music")]
+        let music = crate::commands::Music::new(current_user.id, shards.len() as u32);
+
         tracing::info!("GATEWAY: Finished setting up! Now kicking off the shards...");
 
         Ok((
@@ -45,8 +64,19 @@ impl super::Luro {
                 current_user: current_user.into(),
                 database,
                 http_client,
+                locales,
                 twilight_client: twilight,
                 shard: None,
+                #[cfg(feature = "module-interactions")]
+                cooldowns: Default::default(),
+                #[cfg(feature = "module-ai")]
+                ai_rate_limiter: Default::default(),
+                #[cfg(feature = "module-e621")]
+                e621: Default::default(),
+                #[cfg(feature = "module-music")]
+                music,
+                #[cfg(feature = "module-flavour")]
+                flavour: flavour.into(),
             }
             .into(),
             shards,
@@ -0,0 +1,51 @@
+use luro_model::flavour::{FlavourPools, FLAVOUR_DIRECTORY};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use super::GatewayError;
+
+impl super::Luro {
+    /// Pick a random entry from a pool, including the guild's own entries and leaving out any it disabled.
+    /// Guild entries are weighted by their votes. Returns `fallback` if there is nothing to pick from.
+    pub async fn flavour(&self, guild_id: Option<Id<GuildMarker>>, pool: &str, fallback: &str) -> String {
+        let built_in = self.flavour.read().await.pool(pool).to_vec();
+        let (mut choices, entries) = match guild_id {
+            Some(guild_id) => {
+                let disabled = self.database.fetch_flavour_disabled(guild_id, pool).await.unwrap_or_default();
+                let entries = self.database.fetch_flavour_entries(guild_id, Some(pool)).await.unwrap_or_default();
+                let built_in = built_in.into_iter().filter(|x| !disabled.contains(x)).collect::<Vec<_>>();
+                (built_in, entries)
+            }
+            None => (built_in, vec![]),
+        };
+
+        let mut weights = vec![1; choices.len()];
+        for entry in entries.into_iter().filter(|x| x.pickable()) {
+            weights.push(entry.weight());
+            choices.push(entry.content);
+        }
+
+        let total = weights.iter().sum::<u64>();
+        if total == 0 {
+            return fallback.to_owned();
+        }
+
+        let mut pick = fastrand::u64(0..total);
+        for (choice, weight) in choices.into_iter().zip(weights) {
+            if pick < weight {
+                return choice;
+            }
+            pick -= weight;
+        }
+
+        fallback.to_owned()
+    }
+
+    /// Reload the built in pools from disk, returning how many entries were loaded.
+    /// The old pools are kept if the new ones fail to load.
+    pub async fn reload_flavour(&self) -> Result<usize, GatewayError> {
+        let pools = FlavourPools::load(FLAVOUR_DIRECTORY).await?;
+        let entries = pools.len();
+        *self.flavour.write().await = pools;
+        Ok(entries)
+    }
+}
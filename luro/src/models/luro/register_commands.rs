@@ -1,16 +1,78 @@
+use luro_model::{
+    command_registration::{CommandDiff, CommandSnapshot, RegistrationConfig, COMMAND_SNAPSHOT},
+    locale::Locales,
+};
+use twilight_http::client::InteractionClient;
 use twilight_model::application::command::Command;
 
+use super::GatewayError;
 use crate::gateway::GatewayResult;
 
 impl super::Luro {
-    /// Register commands to the Discord API.
+    /// Register commands to the Discord API, globally and in the guilds set in the configuration.
+    /// Only the scopes that changed since the last registration are sent to Discord.
     pub async fn register_commands(&self, commands: &[Command]) -> GatewayResult {
-        let client = self.interaction_client();
+        let wanted = Self::wanted_commands(commands, &self.locales, &self.config.registration);
+        let previous = CommandSnapshot::load(COMMAND_SNAPSHOT).await.unwrap_or_else(|why| {
+            tracing::warn!(?why, "Failed to load the command snapshot, only configured guilds will be checked");
+            CommandSnapshot::default()
+        });
 
-        if let Ok(commands) = client.set_global_commands(commands).await?.model().await {
-            tracing::info!("Successfully registered {} global commands!", commands.len())
+        let (registered, diffs) = Self::sync_commands(&self.interaction_client(), &wanted, &previous).await?;
+        match diffs.is_empty() {
+            true => tracing::info!("Registered commands are up to date"),
+            false => diffs.iter().for_each(|diff| tracing::info!("Registered commands: {diff}")),
         }
 
-        Ok(())
+        Ok(registered.save(COMMAND_SNAPSHOT).await?)
+    }
+
+    /// The commands to register, with their localizations, split into global and guild commands
+    pub fn wanted_commands(commands: &[Command], locales: &Locales, config: &RegistrationConfig) -> CommandSnapshot {
+        let mut commands = commands.to_vec();
+        locales.localize_commands(&mut commands);
+        CommandSnapshot::new(commands, config)
+    }
+
+    /// Make the commands registered with Discord match `wanted`, returning what is now registered and what changed.
+    ///
+    /// Each scope is fetched from Discord and only replaced if it differs. Guilds in `previous` that are no longer
+    /// wanted have their commands removed.
+    pub async fn sync_commands(
+        client: &InteractionClient<'_>,
+        wanted: &CommandSnapshot,
+        previous: &CommandSnapshot,
+    ) -> Result<(CommandSnapshot, Vec<CommandDiff>), GatewayError> {
+        let mut registered = CommandSnapshot::default();
+        let mut diffs = vec![];
+
+        // Discord leaves the localizations out unless asked for them, which would make every localized command differ
+        let current = client.global_commands().with_localizations(true).await?.models().await?;
+        let diff = CommandDiff::new(None, &current, &wanted.global);
+        registered.global = match diff.is_empty() {
+            true => current,
+            false => {
+                diffs.push(diff);
+                client.set_global_commands(&wanted.global).await?.models().await?
+            }
+        };
+
+        for guild_id in wanted.guild_ids(previous) {
+            let current = client.guild_commands(guild_id).with_localizations(true).await?.models().await?;
+            let diff = CommandDiff::new(Some(guild_id), &current, wanted.guild(guild_id));
+            let commands = match diff.is_empty() {
+                true => current,
+                false => {
+                    diffs.push(diff);
+                    client.set_guild_commands(guild_id, wanted.guild(guild_id)).await?.models().await?
+                }
+            };
+
+            if !commands.is_empty() {
+                registered.guilds.insert(guild_id, commands);
+            }
+        }
+
+        Ok((registered, diffs))
     }
 }
@@ -1,4 +1,10 @@
 #[cfg(feature = "module-interactions")]
+pub mod autocomplete;
+#[cfg(feature = "module-interactions")]
+mod command_context;
+#[cfg(feature = "module-interactions")]
+mod cooldowns;
+#[cfg(feature = "module-interactions")]
 mod create_command;
 pub mod interaction;
 pub mod luro;
@@ -7,6 +13,10 @@ pub mod message_context;
 mod punishment;
 pub mod role;
 
+#[cfg(feature = "module-interactions")]
+pub use command_context::{CommandContext, UnifiedCommand};
+#[cfg(feature = "module-interactions")]
+pub use cooldowns::Cooldowns;
 #[cfg(feature = "module-interactions")]
 pub use create_command::CreateCommand;
 pub use luro_result::LuroResult;
@@ -0,0 +1,11 @@
+use luro_model::builders::EmbedBuilder;
+
+/// A response for a command that is disabled in the configuration, or by the guild
+pub fn command_disabled(name: &str) -> EmbedBuilder {
+    tracing::info!("The command {name} was used, but it is disabled here");
+    let mut embed = EmbedBuilder::default();
+    embed.title("Command Disabled").colour(crate::COLOUR_DANGER).description(format!(
+        "The command `{name}` is disabled here. The server's staff can change this with `/settings commands`."
+    ));
+    embed
+}
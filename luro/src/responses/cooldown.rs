@@ -0,0 +1,18 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use luro_model::builders::EmbedBuilder;
+
+/// A response for when a command is used again before its cooldown is over
+pub fn cooldown(remaining: &Duration) -> EmbedBuilder {
+    let ready_at = (SystemTime::now() + *remaining)
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        + 1;
+    let mut embed = EmbedBuilder::default();
+    embed
+        .title("Slow down!")
+        .colour(crate::COLOUR_DANGER)
+        .description(format!("This command is on cooldown, you can use it again <t:{ready_at}:R>."));
+    embed
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use luro_model::{builders::EmbedBuilder, guild::Guild};
 use twilight_model::{
     guild::Permissions,
@@ -6,6 +8,8 @@ use twilight_model::{
 
 // mod bot_heirarchy;
 mod bot_missing_permission;
+mod command_disabled;
+mod cooldown;
 mod internal_error;
 mod missing_permissions;
 mod not_owner;
@@ -21,12 +25,16 @@ pub enum StandardResponse<'a> {
     PermissionNotBotStaff,
     PermissionModifyServerOwner(&'a Id<UserMarker>),
     UnknownCommand(&'a str),
+    /// The command is disabled in the configuration, or by the guild
+    CommandDisabled(&'a str),
     NotGuild,
     BotMissingPermission(&'a Permissions),
     // UserHeirarchy(&'a User, &'a User),
     // BotHeirarchy(&'a User, &'a User),
     MissingPermission(&'a Permissions),
     NotOwner(Id<UserMarker>, &'a str),
+    /// The command is on cooldown for this long
+    Cooldown(&'a Duration),
     // A punishment applied to a user, such as a ban or kick. First user paramater is the moderator, second is the target
     // Punishment(&'a crate::models::Guild, crate::models::Punishment, &'a User, &'a User),
     // A ban response sent to the user banned. String parameter is the guild name.
@@ -43,11 +51,13 @@ impl<'a> StandardResponse<'a> {
             Self::PermissionNotBotStaff => permission_not_bot_staff::permission_not_bot_staff(),
             Self::PermissionModifyServerOwner(user_id) => permission_modify_server_owner::permission_server_owner(user_id),
             Self::UnknownCommand(name) => unknown_command::unknown_command(name),
+            Self::CommandDisabled(name) => command_disabled::command_disabled(name),
             Self::NotGuild => luro_model::response::not_guild::not_guild(None, None),
             Self::BotMissingPermission(permission) => bot_missing_permission::bot_missing_permission_embed(permission),
             // Self::UserHeirarchy(user, target) => user_heirarchy::user_hierarchy_embed(user, target),
             // Self::BotHeirarchy(user, bot) => bot_heirarchy::bot_hierarchy_embed(user, bot),
             Self::MissingPermission(permission) => missing_permissions::missing_permission_embed(permission),
+            Self::Cooldown(remaining) => cooldown::cooldown(remaining),
             Self::NotOwner(user_id, command_name) => not_owner::not_owner_embed(user_id, command_name), // Self::BannedUserResponse(data, guild_name) => punishment::ban_user(data, guild_name),
                                                                                                         // Self::BannedModeratorResponse(data, dm_success) => punishment::ban_logged(data, dm_success),
                                                                                                         // Self::Punishment(guild, kind, moderator, target) => {
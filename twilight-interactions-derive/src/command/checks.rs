@@ -0,0 +1,81 @@
+//! Parsing of command checks, shared by command models and subcommands.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Result;
+
+use crate::parse::{
+    attribute::NamedAttrs,
+    parsers::{CommandCooldown, PermissionNames},
+    syntax::optional,
+};
+
+/// Parsed command checks
+#[derive(Default)]
+pub struct ChecksAttribute {
+    /// Only allow the command in guilds.
+    pub guild_only: Option<bool>,
+    /// Only allow the owners of the bot to run the command.
+    pub owner_only: Option<bool>,
+    /// Permissions required by the member running the command.
+    pub user_permissions: Option<PermissionNames>,
+    /// Permissions required by the bot.
+    pub bot_permissions: Option<PermissionNames>,
+    /// Time between uses of the command.
+    pub cooldown: Option<CommandCooldown>,
+}
+
+impl ChecksAttribute {
+    pub fn parse(parser: &mut NamedAttrs) -> Result<Self> {
+        Ok(Self {
+            guild_only: parser.optional("guild_only")?,
+            owner_only: parser.optional("owner_only")?,
+            user_permissions: parser.optional("user_permissions")?,
+            bot_permissions: parser.optional("bot_permissions")?,
+            cooldown: parser.optional("cooldown")?,
+        })
+    }
+
+    /// Guild only commands are hidden in DMs unless `dm_permission` is set
+    pub fn dm_permission(&self, dm_permission: Option<bool>) -> Option<bool> {
+        match self.guild_only {
+            Some(true) => dm_permission.or(Some(false)),
+            _ => dm_permission,
+        }
+    }
+
+    /// Generate the `CHECKS` constant, which is left to its default when no checks are set
+    pub fn checks_const(&self) -> TokenStream {
+        if self.guild_only.is_none()
+            && self.owner_only.is_none()
+            && self.user_permissions.is_none()
+            && self.bot_permissions.is_none()
+            && self.cooldown.is_none()
+        {
+            return TokenStream::new();
+        }
+
+        let guild_only = self.guild_only.unwrap_or_default();
+        let owner_only = self.owner_only.unwrap_or_default();
+        let user_permissions = permissions(&self.user_permissions);
+        let bot_permissions = permissions(&self.bot_permissions);
+        let cooldown = optional(self.cooldown.as_ref());
+
+        quote! {
+            const CHECKS: ::twilight_interactions::command::CommandChecks = ::twilight_interactions::command::CommandChecks {
+                guild_only: #guild_only,
+                owner_only: #owner_only,
+                user_permissions: #user_permissions,
+                bot_permissions: #bot_permissions,
+                cooldown: #cooldown,
+            };
+        }
+    }
+}
+
+fn permissions(permissions: &Option<PermissionNames>) -> TokenStream {
+    match permissions {
+        Some(permissions) => quote!(#permissions),
+        None => quote!(::twilight_model::guild::Permissions::empty()),
+    }
+}
@@ -2,6 +2,7 @@
 
 mod impls;
 
+mod checks;
 mod description;
 mod model;
 mod subcommand;
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::{DeriveInput, Error, FieldsNamed, Result};
 
-use super::parse::{FieldType, StructField, TypeAttribute};
+use super::parse::{CommandKind, FieldType, StructField, TypeAttribute};
 use crate::{
     command::model::parse::{channel_type, command_option_value},
     parse::syntax::{find_attr, optional},
@@ -18,11 +18,22 @@ pub fn impl_command_model(input: DeriveInput, fields: Option<FieldsNamed>) -> Re
         None => Vec::new(),
     };
 
-    let autocomplete = match find_attr(&input.attrs, "command") {
-        Some(attr) => TypeAttribute::parse(attr)?.autocomplete.unwrap_or(false),
-        None => false,
+    let (autocomplete, kind) = match find_attr(&input.attrs, "command") {
+        Some(attr) => {
+            let attributes = TypeAttribute::parse(attr)?;
+            (attributes.autocomplete.unwrap_or(false), attributes.kind)
+        }
+        None => (false, CommandKind::ChatInput),
     };
 
+    if kind.context_menu() {
+        if autocomplete {
+            return Err(Error::new_spanned(input, "context menu commands do not support autocomplete"));
+        }
+
+        return impl_context_menu(&input, fields);
+    }
+
     for field in &fields {
         // If autocomplete, ensure all fields are either `AutocompleteValue` or `Option`s
         if autocomplete && ![FieldType::Autocomplete, FieldType::Optional].contains(&field.kind) {
@@ -66,6 +77,68 @@ pub fn impl_command_model(input: DeriveInput, fields: Option<FieldsNamed>) -> Re
     })
 }
 
+/// Implementation of `CommandModel` for user and message context menus, which
+/// parses the target into the only field of the model
+fn impl_context_menu(input: &DeriveInput, fields: Vec<StructField>) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+
+    if fields.len() > 1 {
+        return Err(Error::new(fields[1].span, "context menu commands only have a single target field"));
+    }
+
+    let constructor = match fields.first() {
+        Some(field) if field.kind != FieldType::Required => {
+            return Err(Error::new(
+                field.span,
+                "the target field of a context menu command cannot be optional",
+            ));
+        }
+        Some(field) => {
+            let field_ident = &field.ident;
+            let field_name = field_ident.to_string();
+            let ty = &field.ty;
+            let span = field.span;
+
+            quote_spanned! {span=>
+                let __target = match __data.target_id {
+                    ::std::option::Option::Some(__target) => __target,
+                    ::std::option::Option::None => return ::std::result::Result::Err(
+                        ::twilight_interactions::error::ParseError::Option(
+                            ::twilight_interactions::error::ParseOptionError {
+                                field: ::std::convert::From::from(#field_name),
+                                kind: ::twilight_interactions::error::ParseOptionErrorType::RequiredField,
+                        })
+                    ),
+                };
+
+                match <#ty as ::twilight_interactions::command::CommandTarget>::from_target(__target, __data.resolved.as_deref()) {
+                    ::std::result::Result::Ok(__value) => ::std::result::Result::Ok(Self { #field_ident: __value }),
+                    ::std::result::Result::Err(__kind) => ::std::result::Result::Err(
+                        ::twilight_interactions::error::ParseError::Option(
+                            ::twilight_interactions::error::ParseOptionError {
+                                field: ::std::convert::From::from(#field_name),
+                                kind: __kind,
+                        })
+                    ),
+                }
+            }
+        }
+        None => quote!(::std::result::Result::Ok(Self {})),
+    };
+
+    Ok(quote! {
+        impl #generics ::twilight_interactions::command::CommandModel for #ident #generics #where_clause {
+            fn from_interaction(
+                __data: ::twilight_interactions::command::CommandInputData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ParseError> {
+                #constructor
+            }
+        }
+    })
+}
+
 /// Generate field initialization variables
 fn field_init(field: &StructField) -> TokenStream {
     let ident = &field.ident;
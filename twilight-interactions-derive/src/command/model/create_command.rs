@@ -1,8 +1,8 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
 use syn::{spanned::Spanned, DeriveInput, Error, FieldsNamed, Result};
 
-use super::parse::{channel_type, command_option_value, StructField, TypeAttribute};
+use super::parse::{channel_type, command_kind, command_option_value, StructField, TypeAttribute};
 use crate::{
     command::description::get_description,
     parse::{
@@ -33,6 +33,10 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
         return Err(Error::new(attr_span, "cannot implement `CreateCommand` on partial model"));
     }
 
+    if attributes.kind.context_menu() {
+        return impl_context_menu(input, fields, attributes, attr_span);
+    }
+
     let desc = get_description(&attributes.desc_localizations, &attributes.desc, input.span(), &input.attrs)?;
 
     let name = match &attributes.name {
@@ -44,14 +48,16 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
         Some(path) => quote! { ::std::option::Option::Some(#path())},
         None => quote! { ::std::option::Option::None },
     };
-    let dm_permission = optional(attributes.dm_permission);
+    let dm_permission = optional(attributes.checks.dm_permission(attributes.dm_permission));
     let nsfw = optional(attributes.nsfw);
+    let checks = attributes.checks.checks_const();
 
     let field_options = fields.iter().map(field_option).collect::<Result<Vec<_>>>()?;
 
     Ok(quote! {
         impl #generics ::twilight_interactions::command::CreateCommand for #ident #generics #where_clause {
             const NAME: &'static str = #name;
+            #checks
 
             fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
                 let mut __command_options = ::std::vec::Vec::with_capacity(#capacity);
@@ -69,6 +75,58 @@ pub fn impl_create_command(input: DeriveInput, fields: Option<FieldsNamed>) -> R
                     dm_permission: #dm_permission,
                     nsfw: #nsfw,
                     group: false,
+                    kind: ::twilight_model::application::command::CommandType::ChatInput,
+                }
+            }
+        }
+    })
+}
+
+/// Implementation of `CreateCommand` for user and message context menus
+fn impl_context_menu(input: DeriveInput, fields: Vec<StructField>, attributes: TypeAttribute, attr_span: Span) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+
+    if attributes.desc.is_some() || attributes.desc_localizations.is_some() {
+        return Err(Error::new(attr_span, "context menu commands cannot have a description"));
+    }
+
+    if fields.len() > 1 {
+        return Err(Error::new(fields[1].span, "context menu commands only have a single target field"));
+    }
+
+    let name = match &attributes.name {
+        Some(name) => name,
+        None => return Err(Error::new(attr_span, "missing required attribute `name`")),
+    };
+    let name_localizations = localization_field(&attributes.name_localizations);
+    let default_permissions = match &attributes.default_permissions {
+        Some(path) => quote! { ::std::option::Option::Some(#path())},
+        None => quote! { ::std::option::Option::None },
+    };
+    let dm_permission = optional(attributes.checks.dm_permission(attributes.dm_permission));
+    let nsfw = optional(attributes.nsfw);
+    let checks = attributes.checks.checks_const();
+    let kind = command_kind(attributes.kind);
+
+    Ok(quote! {
+        impl #generics ::twilight_interactions::command::CreateCommand for #ident #generics #where_clause {
+            const NAME: &'static str = #name;
+            #checks
+
+            fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
+                ::twilight_interactions::command::ApplicationCommandData {
+                    name: ::std::convert::From::from(#name),
+                    name_localizations: #name_localizations,
+                    description: ::std::string::String::new(),
+                    description_localizations: ::std::option::Option::None,
+                    options: ::std::vec::Vec::new(),
+                    default_member_permissions: #default_permissions,
+                    dm_permission: #dm_permission,
+                    nsfw: #nsfw,
+                    group: false,
+                    kind: #kind,
                 }
             }
         }
@@ -4,10 +4,13 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{spanned::Spanned, Attribute, Error, Lit, Result, Type};
 
-use crate::parse::{
-    attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
-    parsers::{CommandDescription, CommandName, FunctionPath},
-    syntax::{extract_generic, find_attr},
+use crate::{
+    command::checks::ChecksAttribute,
+    parse::{
+        attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
+        parsers::{CommandDescription, CommandName, ContextMenuName, FunctionPath},
+        syntax::{extract_generic, find_attr},
+    },
 };
 
 /// Parsed struct field
@@ -80,8 +83,10 @@ impl FieldType {
 pub struct TypeAttribute {
     /// Whether the model is an autocomplete interaction model.
     pub autocomplete: Option<bool>,
+    /// Type of the command.
+    pub kind: CommandKind,
     /// Command name.
-    pub name: Option<CommandName>,
+    pub name: Option<String>,
     /// Localization dictionary for the command name.
     pub name_localizations: Option<FunctionPath>,
     /// Command description.
@@ -94,11 +99,14 @@ pub struct TypeAttribute {
     pub dm_permission: Option<bool>,
     /// Whether the command is nsfw.
     pub nsfw: Option<bool>,
+    /// Checks to run before the command is handled.
+    pub checks: ChecksAttribute,
 }
 
 impl TypeAttribute {
     const VALID_ATTRIBUTES: &'static [&'static str] = &[
         "autocomplete",
+        "kind",
         "name",
         "name_localizations",
         "desc",
@@ -106,24 +114,69 @@ impl TypeAttribute {
         "default_permissions",
         "dm_permission",
         "nsfw",
+        "guild_only",
+        "owner_only",
+        "user_permissions",
+        "bot_permissions",
+        "cooldown",
     ];
 
     pub fn parse(attr: &Attribute) -> Result<Self> {
         let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
+        let kind = parser.optional("kind")?.unwrap_or_default();
+
+        // Context menu names are not restricted to lowercase words
+        let name = match kind {
+            CommandKind::ChatInput => parser.optional::<CommandName>("name")?.map(Into::into),
+            CommandKind::User | CommandKind::Message => parser.optional::<ContextMenuName>("name")?.map(Into::into),
+        };
 
         Ok(Self {
             autocomplete: parser.optional("autocomplete")?,
-            name: parser.optional("name")?,
+            kind,
+            name,
             name_localizations: parser.optional("name_localizations")?,
             desc: parser.optional("desc")?,
             desc_localizations: parser.optional("desc_localizations")?,
             default_permissions: parser.optional("default_permissions")?,
             dm_permission: parser.optional("dm_permission")?,
             nsfw: parser.optional("nsfw")?,
+            checks: ChecksAttribute::parse(&mut parser)?,
         })
     }
 }
 
+/// Parsed command type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CommandKind {
+    #[default]
+    ChatInput,
+    User,
+    Message,
+}
+
+impl ParseAttribute for CommandKind {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        match spanned.inner.as_str() {
+            "chat_input" => Ok(Self::ChatInput),
+            "user" => Ok(Self::User),
+            "message" => Ok(Self::Message),
+            invalid => Err(spanned.error(format!(
+                "`{invalid}` is not a valid command kind (expected one of chat_input, user, message)"
+            ))),
+        }
+    }
+}
+
+impl CommandKind {
+    /// Whether the command is a context menu command
+    pub fn context_menu(&self) -> bool {
+        !matches!(self, Self::ChatInput)
+    }
+}
+
 /// Parsed field attribute
 #[derive(Default)]
 pub struct FieldAttribute {
@@ -256,6 +309,15 @@ impl ParseAttribute for CommandOptionValue {
     }
 }
 
+/// Convert a [`CommandKind`] into a [`TokenStream`]
+pub fn command_kind(kind: CommandKind) -> TokenStream {
+    match kind {
+        CommandKind::ChatInput => quote!(::twilight_model::application::command::CommandType::ChatInput),
+        CommandKind::User => quote!(::twilight_model::application::command::CommandType::User),
+        CommandKind::Message => quote!(::twilight_model::application::command::CommandType::Message),
+    }
+}
+
 /// Convert a [`ChannelType`] into a [`TokenStream`]
 pub fn channel_type(kind: &ChannelType) -> TokenStream {
     match kind {
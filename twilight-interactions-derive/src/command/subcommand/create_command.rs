@@ -32,14 +32,17 @@ pub fn impl_create_command(input: DeriveInput, variants: impl IntoIterator<Item
         Some(path) => quote! { ::std::option::Option::Some(#path())},
         None => quote! { ::std::option::Option::None },
     };
-    let dm_permission = optional(attribute.dm_permission);
+    let dm_permission = optional(attribute.checks.dm_permission(attribute.dm_permission));
     let nsfw = optional(attribute.nsfw);
+    let checks = attribute.checks.checks_const();
 
     let variant_options = variants.iter().map(variant_option);
+    let variant_checks = variants.iter().map(variant_checks);
 
     Ok(quote! {
         impl #generics ::twilight_interactions::command::CreateCommand for #ident #generics #where_clause {
             const NAME: &'static str = #name;
+            #checks
 
             fn create_command() -> ::twilight_interactions::command::ApplicationCommandData {
                 let desc = #desc;
@@ -57,6 +60,13 @@ pub fn impl_create_command(input: DeriveInput, variants: impl IntoIterator<Item
                     dm_permission: #dm_permission,
                     nsfw: #nsfw,
                     group: true,
+                    kind: ::twilight_model::application::command::CommandType::ChatInput,
+                }
+            }
+
+            fn checks(&self) -> ::twilight_interactions::command::CommandChecks {
+                match self {
+                    #(#variant_checks)*
                 }
             }
         }
@@ -87,3 +97,16 @@ fn variant_option(variant: &ParsedVariant) -> TokenStream {
         ));
     }
 }
+
+/// Generate variant checks match arm
+fn variant_checks(variant: &ParsedVariant) -> TokenStream {
+    let ty = &variant.inner;
+    let ident = &variant.ident;
+    let span = variant.span;
+
+    quote_spanned! {span=>
+        Self::#ident(__command) => <Self as ::twilight_interactions::command::CreateCommand>::CHECKS.merge(
+            <#ty as ::twilight_interactions::command::CreateCommand>::checks(__command)
+        ),
+    }
+}
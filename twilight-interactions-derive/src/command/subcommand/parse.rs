@@ -1,10 +1,13 @@
 use proc_macro2::{Ident, Span};
 use syn::{spanned::Spanned, Attribute, Error, Fields, Result, Type, TypePath, Variant};
 
-use crate::parse::{
-    attribute::NamedAttrs,
-    parsers::{CommandDescription, CommandName, FunctionPath},
-    syntax::find_attr,
+use crate::{
+    command::checks::ChecksAttribute,
+    parse::{
+        attribute::NamedAttrs,
+        parsers::{CommandDescription, CommandName, FunctionPath},
+        syntax::find_attr,
+    },
 };
 
 /// Parsed enum variant
@@ -90,6 +93,8 @@ pub struct TypeAttribute {
     pub dm_permission: Option<bool>,
     /// Whether the command is nsfw.
     pub nsfw: Option<bool>,
+    /// Checks to run before the command is handled.
+    pub checks: ChecksAttribute,
 }
 
 impl TypeAttribute {
@@ -101,6 +106,11 @@ impl TypeAttribute {
         "default_permissions",
         "dm_permission",
         "nsfw",
+        "guild_only",
+        "owner_only",
+        "user_permissions",
+        "bot_permissions",
+        "cooldown",
     ];
 
     pub fn parse(attr: &Attribute) -> Result<Self> {
@@ -114,6 +124,7 @@ impl TypeAttribute {
             default_permissions: parser.optional("default_permissions")?,
             dm_permission: parser.optional("dm_permission")?,
             nsfw: parser.optional("nsfw")?,
+            checks: ChecksAttribute::parse(&mut parser)?,
         })
     }
 }
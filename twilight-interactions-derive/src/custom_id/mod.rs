@@ -0,0 +1,115 @@
+//! Implementation of the `CustomId` derive macro.
+
+mod parse;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Field, Fields, Result};
+
+use self::parse::TypeAttribute;
+use crate::parse::syntax::find_attr;
+
+/// Implementation of the `CustomId` derive macro
+pub fn impl_custom_id(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+    let span = input.span();
+
+    let fields: Vec<Field> = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named.into_iter().collect(),
+            Fields::Unit => Vec::new(),
+            _ => {
+                return Err(Error::new(
+                    span,
+                    "`CustomId` can only be applied to structs with named fields or unit structs",
+                ))
+            }
+        },
+        _ => return Err(Error::new(span, "`CustomId` can only be applied to structs")),
+    };
+
+    let attributes = match find_attr(&input.attrs, "custom_id") {
+        Some(attr) => TypeAttribute::parse(attr)?,
+        None => return Err(Error::new(span, "missing required #[custom_id(...)] attribute")),
+    };
+
+    let prefix = &attributes.prefix;
+    let version = attributes.version;
+    let fields_write = fields.iter().map(field_write);
+    let fields_read = fields.iter().map(field_read);
+    let fields_ident = fields.iter().map(|field| &field.ident);
+
+    Ok(quote! {
+        impl #generics ::twilight_interactions::custom_id::CustomId for #ident #generics #where_clause {
+            const PREFIX: &'static str = #prefix;
+            const VERSION: u8 = #version;
+
+            fn to_custom_id(&self) -> ::std::result::Result<::std::string::String, ::twilight_interactions::error::CustomIdError> {
+                let mut __writer = ::twilight_interactions::custom_id::internal::CustomIdWriter::new(#prefix, #version);
+
+                #(#fields_write)*
+
+                __writer.finish()
+            }
+
+            fn from_custom_id(
+                __custom_id: &str,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::CustomIdError> {
+                let mut __reader = ::twilight_interactions::custom_id::internal::CustomIdReader::new(__custom_id, #prefix, #version)?;
+
+                #(#fields_read)*
+
+                __reader.finish()?;
+                ::std::result::Result::Ok(Self { #(#fields_ident),* })
+            }
+        }
+    })
+}
+
+/// Dummy implementation of the `CustomId` trait in case of macro error
+pub fn dummy_custom_id(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::custom_id::CustomId for #ident {
+            const PREFIX: &'static str = "";
+            const VERSION: u8 = 0;
+
+            fn to_custom_id(&self) -> ::std::result::Result<::std::string::String, ::twilight_interactions::error::CustomIdError> {
+                ::std::unimplemented!()
+            }
+
+            fn from_custom_id(
+                custom_id: &str,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::CustomIdError> {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Generate field encoding code
+fn field_write(field: &Field) -> TokenStream {
+    let ident = &field.ident;
+    let span = field.ty.span();
+
+    quote_spanned! {span=>
+        __writer.field(&self.#ident);
+    }
+}
+
+/// Generate field decoding code
+fn field_read(field: &Field) -> TokenStream {
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let name = ident.as_ref().map(ToString::to_string).unwrap_or_default();
+    let span = ty.span();
+
+    quote_spanned! {span=>
+        let #ident = __reader.field::<#ty>(#name)?;
+    }
+}
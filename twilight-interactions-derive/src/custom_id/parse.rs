@@ -0,0 +1,26 @@
+//! Parsing of custom ID attributes
+
+use syn::{Attribute, Result};
+
+use crate::parse::{attribute::NamedAttrs, parsers::CustomIdPrefix};
+
+/// Parsed type attribute
+pub struct TypeAttribute {
+    /// Prefix used to route the custom ID.
+    pub prefix: CustomIdPrefix,
+    /// Version of the encoded fields.
+    pub version: u8,
+}
+
+impl TypeAttribute {
+    const VALID_ATTRIBUTES: &'static [&'static str] = &["prefix", "version"];
+
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
+
+        Ok(Self {
+            prefix: parser.required("prefix")?,
+            version: parser.optional("version")?.unwrap_or(1),
+        })
+    }
+}
@@ -6,6 +6,8 @@
 //! information.
 
 mod command;
+mod custom_id;
+mod modal;
 mod option;
 mod parse;
 
@@ -71,3 +73,48 @@ pub fn create_option(input: TokenStream) -> TokenStream {
         Err(error) => option::dummy_create_option(ident, error).into(),
     }
 }
+
+/// Derive macro for the `ModalModel` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(ModalModel, attributes(modal))]
+pub fn modal_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+
+    match modal::impl_modal_model(input) {
+        Ok(output) => output.into(),
+        Err(error) => modal::dummy_modal_model(ident, error).into(),
+    }
+}
+
+/// Derive macro for the `CreateModal` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(CreateModal, attributes(modal))]
+pub fn create_modal(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+
+    match modal::impl_create_modal(input) {
+        Ok(output) => output.into(),
+        Err(error) => modal::dummy_create_modal(ident, error).into(),
+    }
+}
+
+/// Derive macro for the `CustomId` trait.
+///
+/// See the documentation of the trait for more information about usage of this
+/// macro.
+#[proc_macro_derive(CustomId, attributes(custom_id))]
+pub fn custom_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = input.ident.clone();
+
+    match custom_id::impl_custom_id(input) {
+        Ok(output) => output.into(),
+        Err(error) => custom_id::dummy_custom_id(ident, error).into(),
+    }
+}
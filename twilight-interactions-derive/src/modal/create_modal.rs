@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Ident, Result};
+
+use super::parse::{text_input_style, ModalField, TypeAttribute};
+use crate::parse::syntax::{find_attr, optional, parse_doc};
+
+/// Implementation of the `CreateModal` derive macro
+pub fn impl_create_modal(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+    let span = input.span();
+
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => ModalField::from_fields(fields)?,
+            _ => return Err(Error::new(span, "`CreateModal` can only be applied to structs with named fields")),
+        },
+        _ => return Err(Error::new(span, "`CreateModal` can only be applied to structs")),
+    };
+
+    let (attributes, attr_span) = match find_attr(&input.attrs, "modal") {
+        Some(attr) => (TypeAttribute::parse(attr)?, attr.span()),
+        None => return Err(Error::new(span, "missing required #[modal(...)] attribute")),
+    };
+
+    let title = match &attributes.title {
+        Some(title) => title,
+        None => return Err(Error::new(attr_span, "missing required attribute `title`")),
+    };
+
+    // Modals have a text input per action row, with a maximum of five rows
+    match fields.len() {
+        1..=5 => (),
+        _ => return Err(Error::new(span, "modals must have between 1 and 5 fields")),
+    }
+
+    let capacity = fields.len();
+    let text_inputs = fields.iter().map(text_input).collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #generics ::twilight_interactions::modal::CreateModal for #ident #generics #where_clause {
+            const TITLE: &'static str = #title;
+
+            fn create_modal(custom_id: impl ::std::convert::Into<::std::string::String>) -> ::twilight_interactions::modal::ModalData {
+                let mut __text_inputs = ::std::vec::Vec::with_capacity(#capacity);
+
+                #(#text_inputs)*
+
+                ::twilight_interactions::modal::ModalData {
+                    custom_id: ::std::convert::Into::into(custom_id),
+                    title: ::std::convert::From::from(#title),
+                    text_inputs: __text_inputs,
+                }
+            }
+        }
+    })
+}
+
+/// Dummy implementation of the `CreateModal` trait in case of macro error
+pub fn dummy_create_modal(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::modal::CreateModal for #ident {
+            const TITLE: &'static str = "";
+
+            fn create_modal(custom_id: impl ::std::convert::Into<::std::string::String>) -> ::twilight_interactions::modal::ModalData {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Generate text input code
+fn text_input(field: &ModalField) -> Result<TokenStream> {
+    let span = field.span;
+
+    let label = match &field.attributes.label {
+        Some(label) => quote!(#label),
+        None if find_attr(&field.raw_attrs, "doc").is_none() => {
+            return Err(Error::new(span, "label is required (documentation comment or `label` attribute)"));
+        }
+        None => {
+            let label = parse_doc(&field.raw_attrs, span)?;
+            if label.chars().count() > 45 {
+                return Err(Error::new(span, "label must be between 1 and 45 characters"));
+            }
+
+            quote!(#label)
+        }
+    };
+
+    let custom_id = field.custom_id();
+    let required = field.required;
+    let style = text_input_style(field.attributes.style);
+    let placeholder = match &field.attributes.placeholder {
+        Some(placeholder) => {
+            let placeholder = &placeholder.inner;
+            quote!(::std::option::Option::Some(::std::convert::From::from(#placeholder)))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let max_length = optional(field.attributes.max_length.as_ref().map(|length| length.inner));
+    let min_length = optional(field.attributes.min_length.as_ref().map(|length| length.inner));
+
+    Ok(quote_spanned! {span=>
+        __text_inputs.push(::twilight_model::channel::message::component::TextInput {
+            custom_id: ::std::convert::From::from(#custom_id),
+            label: ::std::convert::From::from(#label),
+            max_length: #max_length,
+            min_length: #min_length,
+            placeholder: #placeholder,
+            required: ::std::option::Option::Some(#required),
+            style: #style,
+            value: ::std::option::Option::None,
+        });
+    })
+}
@@ -0,0 +1,8 @@
+//! Implementation of `ModalModel` and `CreateModal` derive macros.
+
+mod create_modal;
+mod modal_model;
+mod parse;
+
+pub use create_modal::{dummy_create_modal, impl_create_modal};
+pub use modal_model::{dummy_modal_model, impl_modal_model};
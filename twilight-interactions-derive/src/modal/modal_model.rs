@@ -0,0 +1,121 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Ident, Result};
+
+use super::parse::ModalField;
+
+/// Implementation of the `ModalModel` derive macro
+pub fn impl_modal_model(input: DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+    let span = input.span();
+
+    let fields = match input.data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => ModalField::from_fields(fields)?,
+            _ => return Err(Error::new(span, "`ModalModel` can only be applied to structs with named fields")),
+        },
+        _ => return Err(Error::new(span, "`ModalModel` can only be applied to structs")),
+    };
+
+    let fields_init = fields.iter().map(field_init);
+    let fields_match_arms = fields.iter().map(field_match_arm);
+    let fields_constructor = fields.iter().map(field_constructor);
+
+    Ok(quote! {
+        impl #generics ::twilight_interactions::modal::ModalModel for #ident #generics #where_clause {
+            fn from_interaction(
+                __data: ::twilight_model::application::interaction::modal::ModalInteractionData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ModalParseError> {
+                #(#fields_init)*
+
+                for __component in __data.components.into_iter().flat_map(|__row| __row.components) {
+                    // Empty text inputs are sent as an empty string
+                    let __value = __component.value.filter(|__value| !__value.is_empty());
+
+                    match &*__component.custom_id {
+                        #(#fields_match_arms,)*
+                        __other => return ::std::result::Result::Err(
+                            ::twilight_interactions::error::ModalParseError {
+                                field: ::std::convert::From::from(__other),
+                                kind: ::twilight_interactions::error::ModalParseErrorType::UnknownField,
+                            }
+                        ),
+                    }
+                }
+
+                ::std::result::Result::Ok(Self { #(#fields_constructor),* })
+            }
+        }
+    })
+}
+
+/// Dummy implementation of the `ModalModel` trait in case of macro error
+pub fn dummy_modal_model(ident: Ident, error: Error) -> TokenStream {
+    let error = error.to_compile_error();
+
+    quote! {
+        #error
+
+        impl ::twilight_interactions::modal::ModalModel for #ident {
+            fn from_interaction(
+                data: ::twilight_model::application::interaction::modal::ModalInteractionData,
+            ) -> ::std::result::Result<Self, ::twilight_interactions::error::ModalParseError> {
+                ::std::unimplemented!()
+            }
+        }
+    }
+}
+
+/// Generate field initialization variables
+fn field_init(field: &ModalField) -> TokenStream {
+    let ident = &field.ident;
+    quote!(let mut #ident = None;)
+}
+
+/// Generate field match arm
+fn field_match_arm(field: &ModalField) -> TokenStream {
+    let ident = &field.ident;
+    let ty = &field.ty;
+    let span = field.span;
+    let custom_id = field.custom_id();
+
+    quote_spanned! {span=>
+        #custom_id => {
+            if let ::std::option::Option::Some(__value) = __value {
+                match <#ty as ::twilight_interactions::modal::ModalValue>::from_value(__value) {
+                    ::std::result::Result::Ok(__value) => #ident = Some(__value),
+                    ::std::result::Result::Err(__kind) => {
+                        return ::std::result::Result::Err(
+                            ::twilight_interactions::error::ModalParseError {
+                                field: ::std::convert::From::from(#custom_id),
+                                kind: __kind,
+                            }
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate field constructor
+fn field_constructor(field: &ModalField) -> TokenStream {
+    let ident = &field.ident;
+    let custom_id = field.custom_id();
+
+    if field.required {
+        quote! {
+            #ident: match #ident {
+                Some(__value) => __value,
+                None => return Err(::twilight_interactions::error::ModalParseError {
+                    field: ::std::convert::From::from(#custom_id),
+                    kind: ::twilight_interactions::error::ModalParseErrorType::RequiredField,
+                })
+            }
+        }
+    } else {
+        quote!(#ident)
+    }
+}
@@ -0,0 +1,186 @@
+//! Parsing of modal struct fields and attributes
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{spanned::Spanned, Attribute, Error, Lit, Result, Type};
+
+use crate::parse::{
+    attribute::{NamedAttrs, ParseAttribute, ParseSpanned},
+    parsers::ModalLabel,
+    syntax::{extract_generic, find_attr},
+};
+
+/// Parsed modal struct field
+pub struct ModalField {
+    pub span: Span,
+    pub ident: Ident,
+    pub ty: Type,
+    pub raw_attrs: Vec<Attribute>,
+    pub attributes: FieldAttribute,
+    pub required: bool,
+}
+
+impl ModalField {
+    /// Parse a [`syn::Field`] as a [`ModalField`]
+    pub fn from_field(field: syn::Field) -> Result<Self> {
+        let (required, ty) = match extract_generic(&field.ty, "Option") {
+            Some(ty) => (false, ty),
+            None => (true, field.ty.clone()),
+        };
+
+        let attributes = match find_attr(&field.attrs, "modal") {
+            Some(attr) => FieldAttribute::parse(attr)?,
+            None => FieldAttribute::default(),
+        };
+
+        let Some(ident) = field.ident else {
+            return Err(Error::new_spanned(field, "expected struct field to have an identifier"));
+        };
+
+        Ok(Self {
+            span: field.ty.span(),
+            ident,
+            ty,
+            raw_attrs: field.attrs,
+            attributes,
+            required,
+        })
+    }
+
+    /// Parse [`syn::FieldsNamed`] as a [`Vec<ModalField>`]
+    pub fn from_fields(fields: syn::FieldsNamed) -> Result<Vec<Self>> {
+        fields.named.into_iter().map(Self::from_field).collect()
+    }
+
+    /// Custom ID of the text input, which defaults to the field name
+    pub fn custom_id(&self) -> String {
+        match &self.attributes.rename {
+            Some(name) => name.inner.clone(),
+            None => self.ident.to_string(),
+        }
+    }
+}
+
+/// Parsed type attribute
+#[derive(Default)]
+pub struct TypeAttribute {
+    /// Title of the modal.
+    pub title: Option<ModalLabel>,
+}
+
+impl TypeAttribute {
+    const VALID_ATTRIBUTES: &'static [&'static str] = &["title"];
+
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
+
+        Ok(Self {
+            title: parser.optional("title")?,
+        })
+    }
+}
+
+/// Parsed field attribute
+#[derive(Default)]
+pub struct FieldAttribute {
+    /// Use a different custom ID than the field name
+    pub rename: Option<ParseSpanned<String>>,
+    /// Overwrite the field label
+    pub label: Option<ModalLabel>,
+    /// Style of the text input
+    pub style: TextInputStyle,
+    /// Placeholder shown when the text input is empty
+    pub placeholder: Option<ParseSpanned<String>>,
+    /// Maximum text length
+    pub max_length: Option<ParseSpanned<u16>>,
+    /// Minimum text length
+    pub min_length: Option<ParseSpanned<u16>>,
+}
+
+impl FieldAttribute {
+    const VALID_ATTRIBUTES: &'static [&'static str] = &["rename", "label", "style", "placeholder", "max_length", "min_length"];
+
+    /// Parse a single [`Attribute`]
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let mut parser = NamedAttrs::parse(attr, Self::VALID_ATTRIBUTES)?;
+
+        let attributes = Self {
+            rename: parser.optional("rename")?,
+            label: parser.optional("label")?,
+            style: parser.optional("style")?.unwrap_or_default(),
+            placeholder: parser.optional("placeholder")?,
+            max_length: parser.optional("max_length")?,
+            min_length: parser.optional("min_length")?,
+        };
+
+        attributes.validate()?;
+        Ok(attributes)
+    }
+
+    /// Validate attribute values against Discord limits
+    fn validate(&self) -> Result<()> {
+        if let Some(rename) = &self.rename {
+            if !(1..=100).contains(&rename.inner.chars().count()) {
+                return Err(rename.error("custom id must be between 1 and 100 characters"));
+            }
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            if !(1..=100).contains(&placeholder.inner.chars().count()) {
+                return Err(placeholder.error("placeholder must be between 1 and 100 characters"));
+            }
+        }
+
+        if let Some(max_length) = &self.max_length {
+            if !(1..=4000).contains(&max_length.inner) {
+                return Err(max_length.error("max_length must be between 1 and 4000"));
+            }
+        }
+
+        if let Some(min_length) = &self.min_length {
+            if min_length.inner > 4000 {
+                return Err(min_length.error("min_length must be between 0 and 4000"));
+            }
+
+            if let Some(max_length) = &self.max_length {
+                if min_length.inner > max_length.inner {
+                    return Err(min_length.error("min_length cannot be greater than max_length"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parsed text input style
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextInputStyle {
+    #[default]
+    Short,
+    Paragraph,
+}
+
+impl ParseAttribute for TextInputStyle {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+
+        match spanned.inner.as_str() {
+            "short" => Ok(Self::Short),
+            "paragraph" => Ok(Self::Paragraph),
+            invalid => Err(spanned.error(format!(
+                "`{invalid}` is not a valid text input style (expected one of short, paragraph)"
+            ))),
+        }
+    }
+}
+
+/// Convert a [`TextInputStyle`] into a [`TokenStream`]
+pub fn text_input_style(style: TextInputStyle) -> TokenStream {
+    match style {
+        TextInputStyle::Short => quote!(::twilight_model::channel::message::component::TextInputStyle::Short),
+        TextInputStyle::Paragraph => {
+            quote!(::twilight_model::channel::message::component::TextInputStyle::Paragraph)
+        }
+    }
+}
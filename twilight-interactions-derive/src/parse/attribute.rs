@@ -9,12 +9,13 @@
 use std::fmt::Display;
 
 use proc_macro2::{Ident, Span};
-use syn::{meta::ParseNestedMeta, spanned::Spanned, Attribute, Error, Lit, Result};
+use syn::{meta::ParseNestedMeta, spanned::Spanned, Attribute, Error, Lit, LitBool, Result, Token};
 
 /// Parse a list of named attributes like `#[command(rename = "name")]`.
 ///
-/// This only support `(ident) = (literal)` syntax for simplicity. Collected
-/// values can be parsed using the `optional` and `required` methods.
+/// This only support `(ident) = (literal)` syntax for simplicity, with a bare
+/// `(ident)` being shorthand for `(ident) = true`. Collected values can be
+/// parsed using the `optional` and `required` methods.
 pub struct NamedAttrs {
     attr_span: Span,
     values: Vec<(Ident, Lit)>,
@@ -46,7 +47,11 @@ impl NamedAttrs {
             ));
         };
 
-        let lit: Lit = meta.value()?.parse()?;
+        let lit = if meta.input.is_empty() || meta.input.peek(Token![,]) {
+            Lit::Bool(LitBool::new(true, ident.span()))
+        } else {
+            meta.value()?.parse()?
+        };
         self.values.push((ident.clone(), lit));
 
         Ok(())
@@ -112,6 +117,16 @@ impl ParseAttribute for u16 {
     }
 }
 
+impl ParseAttribute for u8 {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let Lit::Int(lit) = input else {
+            return Err(Error::new_spanned(input, "expected integer literal"));
+        };
+
+        lit.base10_parse()
+    }
+}
+
 /// Capture the [`Span`] of a parsed attribute.
 pub struct ParseSpanned<T> {
     pub span: Span,
@@ -1,5 +1,5 @@
-use proc_macro2::TokenStream;
-use quote::ToTokens;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 use syn::{Error, Lit, Path, Result};
 
 use super::attribute::{ParseAttribute, ParseSpanned};
@@ -73,6 +73,34 @@ impl From<CommandName> for String {
     }
 }
 
+/// Context menu command name.
+///
+/// Unlike slash commands, context menu names may contain spaces and uppercase
+/// letters. Only the length is validated, which must be between 1 and 32
+/// characters.
+#[derive(Clone, Debug)]
+pub struct ContextMenuName(String);
+
+impl ParseAttribute for ContextMenuName {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let value = spanned.inner.trim();
+
+        match value.chars().count() {
+            1..=32 => (),
+            _ => return Err(spanned.error("name must be between 1 and 32 characters")),
+        }
+
+        Ok(Self(value.to_owned()))
+    }
+}
+
+impl From<ContextMenuName> for String {
+    fn from(value: ContextMenuName) -> Self {
+        value.0
+    }
+}
+
 /// Slash command or command option description.
 ///
 /// This validate that the description is between 1 and 100 characters.
@@ -131,3 +159,180 @@ impl ToTokens for ChoiceName {
         self.0.to_tokens(tokens)
     }
 }
+
+/// Modal title or text input label.
+///
+/// This validate that the text is between 1 and 45 characters.
+/// https://discord.com/developers/docs/interactions/message-components#text-inputs
+#[derive(Clone, Debug)]
+pub struct ModalLabel(String);
+
+impl ParseAttribute for ModalLabel {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let value = spanned.inner.trim();
+
+        match value.chars().count() {
+            1..=45 => (),
+            _ => return Err(spanned.error("label must be between 1 and 45 characters")),
+        }
+
+        Ok(Self(value.to_owned()))
+    }
+}
+
+impl ToTokens for ModalLabel {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+/// Custom ID prefix.
+///
+/// The following requirements are validated:
+/// - Length between 1 and 32 characters
+/// - Must not contain the `:` separator
+#[derive(Clone, Debug)]
+pub struct CustomIdPrefix(String);
+
+impl ParseAttribute for CustomIdPrefix {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let value = spanned.inner.trim();
+
+        match value.chars().count() {
+            1..=32 => (),
+            _ => return Err(spanned.error("prefix must be between 1 and 32 characters")),
+        }
+
+        if value.contains(':') {
+            return Err(spanned.error("prefix must not contain the `:` separator"));
+        }
+
+        Ok(Self(value.to_owned()))
+    }
+}
+
+impl ToTokens for CustomIdPrefix {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+/// List of permission names.
+///
+/// Names are separated by spaces or `|` and must be uppercase, like the
+/// constants of `twilight_model::guild::Permissions`. Unknown names are
+/// reported by the compiler when the generated code is checked.
+#[derive(Clone, Debug)]
+pub struct PermissionNames(Vec<Ident>);
+
+impl ParseAttribute for PermissionNames {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let span = input.span();
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let mut names = Vec::new();
+
+        for name in spanned.inner.split(|char: char| char == '|' || char.is_whitespace()) {
+            if name.is_empty() {
+                continue;
+            }
+
+            if !name
+                .chars()
+                .all(|char| char.is_ascii_uppercase() || char.is_ascii_digit() || char == '_')
+            {
+                return Err(spanned.error(format!(
+                    "`{name}` is not a valid permission name (expected a name like BAN_MEMBERS)"
+                )));
+            }
+
+            names.push(Ident::new(name, span));
+        }
+
+        if names.is_empty() {
+            return Err(spanned.error("expected at least one permission"));
+        }
+
+        Ok(Self(names))
+    }
+}
+
+impl ToTokens for PermissionNames {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let names = &self.0;
+        tokens.extend(quote!(::twilight_model::guild::Permissions::empty()#(.union(::twilight_model::guild::Permissions::#names))*));
+    }
+}
+
+/// Command cooldown, like `5s per user`.
+///
+/// The duration is a number followed by `s`, `m`, `h` or `d`. It may be
+/// followed by `per user`, `per channel`, `per guild` or `globally`, and is
+/// per user by default.
+#[derive(Clone, Debug)]
+pub struct CommandCooldown {
+    seconds: u64,
+    bucket: Ident,
+}
+
+impl ParseAttribute for CommandCooldown {
+    fn parse_attribute(input: Lit) -> Result<Self> {
+        let span = input.span();
+        let spanned: ParseSpanned<String> = ParseAttribute::parse_attribute(input)?;
+        let mut words = spanned.inner.split_whitespace();
+
+        let Some(duration) = words.next() else {
+            return Err(spanned.error("expected a cooldown like `5s per user`"));
+        };
+
+        let unit_start = duration.find(|char: char| !char.is_ascii_digit()).unwrap_or(duration.len());
+        let (amount, unit) = duration.split_at(unit_start);
+        let multiplier = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 60 * 60 * 24,
+            _ => {
+                return Err(spanned.error(format!(
+                    "`{duration}` is not a valid duration (expected a number followed by s, m, h or d)"
+                )))
+            }
+        };
+        let seconds = match amount.parse::<u64>() {
+            Ok(amount) if amount > 0 => amount * multiplier,
+            _ => {
+                return Err(spanned.error(format!(
+                    "`{duration}` is not a valid duration (expected a number greater than zero)"
+                )))
+            }
+        };
+
+        let bucket = match (words.next(), words.next(), words.next()) {
+            (None, None, None) | (Some("per"), Some("user"), None) => "User",
+            (Some("per"), Some("channel"), None) => "Channel",
+            (Some("per"), Some("guild"), None) => "Guild",
+            (Some("globally"), None, None) => "Global",
+            _ => return Err(spanned.error("invalid cooldown bucket (expected one of per user, per channel, per guild, globally)")),
+        };
+
+        Ok(Self {
+            seconds,
+            bucket: Ident::new(bucket, span),
+        })
+    }
+}
+
+impl ToTokens for CommandCooldown {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let seconds = self.seconds;
+        let bucket = &self.bucket;
+
+        tokens.extend(quote! {
+            ::twilight_interactions::command::Cooldown {
+                duration: ::std::time::Duration::from_secs(#seconds),
+                bucket: ::twilight_interactions::command::CooldownBucket::#bucket,
+            }
+        });
+    }
+}
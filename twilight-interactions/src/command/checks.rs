@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use twilight_model::guild::Permissions;
+
+/// Checks to run before a command is handled.
+///
+/// The checks are declared with attributes on the command type and exposed
+/// with [`CreateCommand::CHECKS`]. This crate only describes them, evaluating
+/// them is left to the command handler.
+///
+/// Checks of a subcommand are merged with the checks of its parent, see
+/// [`CreateCommand::checks`].
+///
+/// [`CreateCommand::CHECKS`]: super::CreateCommand::CHECKS
+/// [`CreateCommand::checks`]: super::CreateCommand::checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandChecks {
+    /// The command can only be run in a guild.
+    pub guild_only: bool,
+    /// The command can only be run by the owners of the bot.
+    pub owner_only: bool,
+    /// Permissions the member running the command must have.
+    pub user_permissions: Permissions,
+    /// Permissions the bot must have to run the command.
+    pub bot_permissions: Permissions,
+    /// How often the command can be run.
+    pub cooldown: Option<Cooldown>,
+}
+
+impl CommandChecks {
+    /// A command without any checks.
+    pub const NONE: Self = Self {
+        guild_only: false,
+        owner_only: false,
+        user_permissions: Permissions::empty(),
+        bot_permissions: Permissions::empty(),
+        cooldown: None,
+    };
+
+    /// Whether there is nothing to check.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+
+    /// Combine the checks of a command with the checks of one of its subcommands.
+    ///
+    /// Flags and permissions are combined, while the cooldown of the
+    /// subcommand replaces the cooldown of the command.
+    pub fn merge(self, subcommand: Self) -> Self {
+        Self {
+            guild_only: self.guild_only || subcommand.guild_only,
+            owner_only: self.owner_only || subcommand.owner_only,
+            user_permissions: self.user_permissions | subcommand.user_permissions,
+            bot_permissions: self.bot_permissions | subcommand.bot_permissions,
+            cooldown: subcommand.cooldown.or(self.cooldown),
+        }
+    }
+}
+
+impl Default for CommandChecks {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// How long to wait between uses of a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cooldown {
+    /// Time to wait after the command was run.
+    pub duration: Duration,
+    /// Who the cooldown applies to.
+    pub bucket: CooldownBucket,
+}
+
+/// Who a [`Cooldown`] applies to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CooldownBucket {
+    /// Each user has their own cooldown.
+    #[default]
+    User,
+    /// Each channel has its own cooldown.
+    Channel,
+    /// Each guild has its own cooldown.
+    Guild,
+    /// A single cooldown shared by everyone.
+    Global,
+}
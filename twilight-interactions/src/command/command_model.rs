@@ -119,12 +119,30 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 /// ```
 ///
 ///
+/// ## Context menu commands
+/// User and message context menu commands are declared with the
+/// `#[command(kind = "user")]` or `#[command(kind = "message")]` attribute.
+/// They receive no options, so the model has a single field that holds the
+/// target the command was used on. Its type must implement [`CommandTarget`].
+///
+/// ```
+/// use twilight_interactions::command::CommandModel;
+/// use twilight_model::channel::Message;
+///
+/// #[derive(CommandModel)]
+/// #[command(kind = "message")]
+/// struct QuoteMessage {
+///     message: Message,
+/// }
+/// ```
+///
 /// ## Macro attributes
 /// The macro provides a `#[command]` attribute to configure generated code.
 ///
 /// | Attribute                  | Type           | Location             | Description                                                     |
 /// |----------------------------|----------------|----------------------|-----------------------------------------------------------------|
 /// | `name`                     | `str`          | Variant (subcommand) | Subcommand name (required).                                     |
+/// | `kind`                     | `str`          | Type                 | `user` or `message` for context menu commands.                  |
 /// | `rename`                   | `str`          | Field                | Use a different name for the field when parsing.                |
 /// | `channel_types`            | `str`          | Field                | Restricts the channel choice to specific types.[^channel_types] |
 /// | `max_value`, `min_value`   | `i64` or `f64` | Field                | Maximum and/or minimum value permitted.                         |
@@ -147,6 +165,7 @@ use crate::error::{ParseError, ParseOptionError, ParseOptionErrorType};
 ///                   like `guild_text private`.
 ///
 /// [`CreateCommand`]: super::CreateCommand
+/// [`CommandTarget`]: super::CommandTarget
 /// [`ChannelType`]: twilight_model::channel::ChannelType
 pub trait CommandModel: Sized {
     /// Construct this type from [`CommandInputData`].
@@ -228,6 +247,8 @@ pub trait CommandOption: Sized {
 pub struct CommandInputData<'a> {
     pub options: Vec<CommandDataOption>,
     pub resolved: Option<Cow<'a, InteractionDataResolved>>,
+    /// The user or message a context menu command was used on.
+    pub target_id: Option<Id<GenericMarker>>,
 }
 
 impl<'a> CommandInputData<'a> {
@@ -246,7 +267,7 @@ impl<'a> CommandInputData<'a> {
     /// # let options = vec![CommandDataOption { name: "message".into(), value: CommandOptionValue::String("Hello world".into()) }];
     ///
     /// // `options` is a Vec<CommandDataOption>
-    /// let data = CommandInputData { options, resolved: None };
+    /// let data = CommandInputData { options, resolved: None, target_id: None };
     /// let message = data.parse_field::<String>("message").unwrap();
     ///
     /// assert_eq!(message, Some("Hello world".to_string()));
@@ -287,7 +308,7 @@ impl<'a> CommandInputData<'a> {
     /// # let options = vec![CommandDataOption { name: "message".into(), value: CommandOptionValue::Focused("Hello world".into(), CommandOptionType::String) }];
     ///
     /// // `options` is a Vec<CommandDataOption>
-    /// let data = CommandInputData { options, resolved: None };
+    /// let data = CommandInputData { options, resolved: None, target_id: None };
     ///
     /// assert_eq!(data.focused(), Some("message"));
     /// ```
@@ -312,6 +333,7 @@ impl<'a> CommandInputData<'a> {
         Ok(CommandInputData {
             options,
             resolved: resolved.map(Cow::Borrowed),
+            target_id: None,
         })
     }
 }
@@ -321,6 +343,7 @@ impl From<CommandData> for CommandInputData<'_> {
         Self {
             options: data.options,
             resolved: data.resolved.map(Cow::Owned),
+            target_id: data.target_id,
         }
     }
 }
@@ -425,15 +448,18 @@ impl CommandOption for String {
             other => return Err(ParseOptionErrorType::InvalidType(other.kind())),
         };
 
+        // Discord counts the length in characters, not bytes
+        let length = value.chars().count();
+
         if let Some(min) = data.min_length {
-            if value.len() < min.into() {
-                todo!()
+            if length < min.into() {
+                return Err(ParseOptionErrorType::StringLengthOutOfRange(value));
             }
         }
 
         if let Some(max) = data.max_length {
-            if value.len() > max.into() {
-                todo!()
+            if length > max.into() {
+                return Err(ParseOptionErrorType::StringLengthOutOfRange(value));
             }
         }
 
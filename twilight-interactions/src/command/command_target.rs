@@ -0,0 +1,87 @@
+use twilight_model::{
+    application::interaction::InteractionDataResolved,
+    channel::Message,
+    id::{
+        marker::{GenericMarker, MessageMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+use super::ResolvedUser;
+use crate::error::ParseOptionErrorType;
+
+/// Parse the target of a context menu command into a concrete type.
+///
+/// This trait is used by the implementation of [`CommandModel`] generated by
+/// the derive macro for context menu commands, declared with the
+/// `#[command(kind = "user")]` or `#[command(kind = "message")]` attribute.
+/// The model must have a single field, which holds the user or message the
+/// command was used on.
+///
+/// | Command type | Provided implementations                       |
+/// |--------------|------------------------------------------------|
+/// | `USER`       | [`ResolvedUser`], [`User`], [`Id<UserMarker>`] |
+/// | `MESSAGE`    | [`Message`], [`Id<MessageMarker>`]             |
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::command::{CommandModel, CreateCommand, ResolvedUser};
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(kind = "user", name = "Say hello")]
+/// struct HelloUser {
+///     user: ResolvedUser,
+/// }
+/// ```
+///
+/// [`CommandModel`]: super::CommandModel
+/// [`Id<UserMarker>`]: twilight_model::id::Id
+/// [`Id<MessageMarker>`]: twilight_model::id::Id
+pub trait CommandTarget: Sized {
+    /// Convert the target ID and resolved data into this type.
+    fn from_target(target_id: Id<GenericMarker>, resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType>;
+}
+
+impl CommandTarget for Id<GenericMarker> {
+    fn from_target(target_id: Id<GenericMarker>, _resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType> {
+        Ok(target_id)
+    }
+}
+
+impl CommandTarget for Id<UserMarker> {
+    fn from_target(target_id: Id<GenericMarker>, _resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType> {
+        Ok(target_id.cast())
+    }
+}
+
+impl CommandTarget for User {
+    fn from_target(target_id: Id<GenericMarker>, resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType> {
+        resolved
+            .and_then(|resolved| resolved.users.get(&target_id.cast()).cloned())
+            .ok_or_else(|| ParseOptionErrorType::LookupFailed(target_id.get()))
+    }
+}
+
+impl CommandTarget for ResolvedUser {
+    fn from_target(target_id: Id<GenericMarker>, resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType> {
+        Ok(Self {
+            resolved: User::from_target(target_id, resolved)?,
+            member: resolved.and_then(|resolved| resolved.members.get(&target_id.cast()).cloned()),
+        })
+    }
+}
+
+impl CommandTarget for Id<MessageMarker> {
+    fn from_target(target_id: Id<GenericMarker>, _resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType> {
+        Ok(target_id.cast())
+    }
+}
+
+impl CommandTarget for Message {
+    fn from_target(target_id: Id<GenericMarker>, resolved: Option<&InteractionDataResolved>) -> Result<Self, ParseOptionErrorType> {
+        resolved
+            .and_then(|resolved| resolved.messages.get(&target_id.cast()).cloned())
+            .ok_or_else(|| ParseOptionErrorType::LookupFailed(target_id.get()))
+    }
+}
@@ -14,7 +14,7 @@ use twilight_model::{
     user::User,
 };
 
-use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
+use super::{internal::CreateOptionData, CommandChecks, ResolvedMentionable, ResolvedUser};
 
 /// Create a slash command from a type.
 ///
@@ -61,10 +61,16 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// | Attribute                  | Type                | Location               | Description                                                     |
 /// |----------------------------|---------------------|------------------------|-----------------------------------------------------------------|
 /// | `name`                     | `str`               | Type                   | Name of the command (required).                                 |
+/// | `kind`                     | `str`               | Type                   | Type of the command, `chat_input` (default), `user` or `message`.[^kind] |
 /// | `desc`                     | `str`               | Type / Field / Variant | Description of the command (required).                          |
 /// | `default_permissions`      | `fn`[^perms]        | Type                   | Default permissions required by members to run the command.     |
 /// | `dm_permission`            | `bool`              | Type                   | Whether the command can be run in DMs.                          |
 /// | `nsfw`                     | `bool`              | Type                   | Whether the command is age-restricted.
+/// | `guild_only`               | `bool`[^checks]     | Type                   | Only allow the command in guilds. Implies `dm_permission = false`. |
+/// | `owner_only`               | `bool`[^checks]     | Type                   | Only allow the owners of the bot to run the command.           |
+/// | `user_permissions`         | `str`[^checks]      | Type                   | Permissions required by the member running the command.[^permissions] |
+/// | `bot_permissions`          | `str`[^checks]      | Type                   | Permissions required by the bot to run the command.[^permissions] |
+/// | `cooldown`                 | `str`[^checks]      | Type                   | Time between uses of the command, like `5s per user`.[^cooldown] |
 /// | `rename`                   | `str`               | Field                  | Use a different option name than the field name.                |
 /// | `name_localizations`       | `fn`[^localization] | Type / Field / Variant | Localized name of the command (optional).                       |
 /// | `desc_localizations`       | `fn`[^localization] | Type / Field / Variant | Localized description of the command (optional).                |
@@ -73,8 +79,22 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// | `max_value`, `min_value`   | `i64` or `f64`      | Field                  | Set the maximum and/or minimum value permitted.                 |
 /// | `max_length`, `min_length` | `u16`               | Field                  |   Maximum and/or minimum string length permitted.               |
 ///
+/// [^kind]: `user` and `message` create context menu commands. They have no
+/// description or options, their name may contain spaces and uppercase
+/// letters, and the target is parsed with [`CommandTarget`].
+///
 /// [^perms]: Path to a function that returns [`Permissions`].
 ///
+/// [^checks]: Exposed with [`CreateCommand::CHECKS`] and evaluated by the
+/// command handler. Boolean checks can be written without a value, like
+/// `#[command(guild_only)]`.
+///
+/// [^permissions]: List of [`Permissions`] names separated by spaces or `|`
+/// like `BAN_MEMBERS | KICK_MEMBERS`.
+///
+/// [^cooldown]: A number followed by `s`, `m`, `h` or `d`, optionally followed
+/// by `per user` (default), `per channel`, `per guild` or `globally`.
+///
 /// [^localization]: Path to a function that returns a type that implements
 /// `IntoIterator<Item = (ToString, ToString)>`. See the module documentation to
 /// learn more.
@@ -83,21 +103,38 @@ use super::{internal::CreateOptionData, ResolvedMentionable, ResolvedUser};
 /// like `guild_text private`.
 ///
 /// [`CommandModel`]: super::CommandModel
+/// [`CommandTarget`]: super::CommandTarget
 /// [`ChannelType`]: twilight_model::channel::ChannelType
 pub trait CreateCommand: Sized {
     /// Name of the command.
     const NAME: &'static str;
 
+    /// Checks to run before the command is handled.
+    const CHECKS: CommandChecks = CommandChecks::NONE;
+
     /// Create an [`ApplicationCommandData`] for this type.
     fn create_command() -> ApplicationCommandData;
+
+    /// Checks to run before this command is handled.
+    ///
+    /// For commands with subcommands, this merges the checks of the command
+    /// with the checks of the subcommand that was run.
+    fn checks(&self) -> CommandChecks {
+        Self::CHECKS
+    }
 }
 
 impl<T: CreateCommand> CreateCommand for Box<T> {
     const NAME: &'static str = T::NAME;
+    const CHECKS: CommandChecks = T::CHECKS;
 
     fn create_command() -> ApplicationCommandData {
         T::create_command()
     }
+
+    fn checks(&self) -> CommandChecks {
+        T::checks(self)
+    }
 }
 
 /// Create a command option from a type.
@@ -170,6 +207,8 @@ pub struct ApplicationCommandData {
     pub group: bool,
     /// Whether the command is nsfw.
     pub nsfw: Option<bool>,
+    /// Type of the command, either a slash command or a context menu.
+    pub kind: CommandType,
 }
 
 impl From<ApplicationCommandData> for Command {
@@ -184,7 +223,7 @@ impl From<ApplicationCommandData> for Command {
             description: item.description,
             description_localizations: item.description_localizations,
             id: None,
-            kind: CommandType::ChatInput,
+            kind: item.kind,
             nsfw: item.nsfw,
             options: item.options,
             version: Id::new(1),
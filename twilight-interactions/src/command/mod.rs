@@ -11,6 +11,11 @@
 //! - Support for subcommands and subcommand groups.
 //! - Command option choices with the [`CommandOption`] and [`CreateOption`]
 //!   traits.
+//! - User and message context menu commands, with the target parsed by the
+//!   [`CommandTarget`] trait.
+//! - Declarative checks such as required permissions and cooldowns, exposed
+//!   as [`CommandChecks`].
+//! - Text command arguments parsed into the same models with [`parse_prefix`].
 //!
 //! Read the documentation of these traits for usage examples.
 //!
@@ -82,16 +87,22 @@
 //! [`Attachment`]: twilight_model::channel::Attachment
 //! [`Id<AttachmentMarker>`]: twilight_model::id::Id
 
+mod checks;
 mod command_model;
+mod command_target;
 mod create_command;
 mod localizations;
+mod prefix;
 
 #[doc(hidden)]
 pub mod internal;
 
+pub use checks::{CommandChecks, Cooldown, CooldownBucket};
 pub use command_model::{AutocompleteValue, CommandInputData, CommandModel, CommandOption, ResolvedMentionable, ResolvedUser};
+pub use command_target::CommandTarget;
 pub use create_command::{ApplicationCommandData, CreateCommand, CreateOption};
 pub use localizations::{DescriptionLocalizations, NameLocalizations};
+pub use prefix::{parse_prefix, parse_prefix_options};
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use twilight_interactions_derive::{CommandModel, CommandOption, CreateCommand, CreateOption};
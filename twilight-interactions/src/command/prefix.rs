@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use twilight_model::{
+    application::{
+        command::{CommandOption as OptionData, CommandOptionChoiceValue, CommandOptionType},
+        interaction::{
+            application_command::{CommandDataOption, CommandOptionValue},
+            InteractionDataResolved,
+        },
+    },
+    id::Id,
+};
+
+use super::{CommandInputData, CommandModel, CreateCommand};
+use crate::error::{PrefixArgumentError, PrefixArgumentErrorType, PrefixError};
+
+/// Parse the arguments of a text command into a command model.
+///
+/// This allows the same [`CommandModel`] to be used for slash commands and
+/// for text commands such as `!roll 2d20 --ephemeral`. The arguments are read
+/// using the options of [`CreateCommand::create_command`]:
+///
+/// - Subcommands are picked by name, such as `!dice roll 2d20`.
+/// - Options can be set by name with `--name value` or `--name=value`.
+///   Dashes and underscores in names are interchangeable.
+/// - Boolean options are flags, set to `true` with just `--name`.
+/// - Other arguments are given to the remaining options in order. The last
+///   one takes the rest of the arguments if it is a string option.
+/// - Quotes group words into a single argument, such as `"two words"`.
+/// - Users, channels and roles can be mentioned or given by ID. Resolved data
+///   for mentions, needed by types like [`ResolvedUser`], can be passed with
+///   `resolved`.
+///
+/// Attachments cannot be given as text.
+///
+/// ```
+/// use twilight_interactions::command::{parse_prefix, CommandModel, CreateCommand};
+///
+/// #[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+/// #[command(name = "roll", desc = "Roll some dice")]
+/// struct RollCommand {
+///     /// Dice to roll
+///     dice: String,
+///     /// Why you are rolling
+///     reason: Option<String>,
+///     /// Only show the result to you
+///     ephemeral: Option<bool>,
+/// }
+///
+/// let roll: RollCommand = parse_prefix("2d20 --ephemeral for initiative", None).unwrap();
+///
+/// assert_eq!(roll.dice, "2d20");
+/// assert_eq!(roll.reason.as_deref(), Some("for initiative"));
+/// assert_eq!(roll.ephemeral, Some(true));
+/// ```
+///
+/// [`ResolvedUser`]: super::ResolvedUser
+pub fn parse_prefix<T: CommandModel + CreateCommand>(input: &str, resolved: Option<InteractionDataResolved>) -> Result<T, PrefixError> {
+    let options = parse_prefix_options(&T::create_command().options, input)?;
+    let data = CommandInputData {
+        options,
+        resolved: resolved.map(Cow::Owned),
+        target_id: None,
+    };
+
+    T::from_interaction(data).map_err(PrefixError::Parse)
+}
+
+/// Parse the arguments of a text command into command options.
+///
+/// See [`parse_prefix`] for how the arguments are read.
+pub fn parse_prefix_options(options: &[OptionData], input: &str) -> Result<Vec<CommandDataOption>, PrefixError> {
+    let arguments = split_arguments(input)?;
+    parse_options(options, arguments).map_err(PrefixError::Argument)
+}
+
+/// Split arguments on whitespace, keeping quoted text together.
+fn split_arguments(input: &str) -> Result<Vec<Argument>, PrefixError> {
+    let mut arguments = Vec::new();
+    let mut current: Option<Argument> = None;
+    let mut quoted = false;
+    let mut chars = input.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' => {
+                quoted = !quoted;
+                current.get_or_insert_with(Argument::quoted);
+            }
+            '\\' if quoted => {
+                let escaped = chars.next().unwrap_or('\\');
+                current.get_or_insert_with(Argument::quoted).value.push(escaped);
+            }
+            char if char.is_whitespace() && !quoted => arguments.extend(current.take()),
+            char => current.get_or_insert_with(Argument::default).value.push(char),
+        }
+    }
+
+    if quoted {
+        return Err(PrefixError::Argument(PrefixArgumentError {
+            argument: current.map(|argument| argument.value).unwrap_or_default(),
+            kind: PrefixArgumentErrorType::UnclosedQuote,
+        }));
+    }
+
+    arguments.extend(current);
+    Ok(arguments)
+}
+
+/// A single argument of a text command.
+#[derive(Debug, Default)]
+struct Argument {
+    value: String,
+    /// Quoted arguments are never read as option names.
+    quoted: bool,
+}
+
+impl Argument {
+    fn quoted() -> Self {
+        Self {
+            value: String::new(),
+            quoted: true,
+        }
+    }
+
+    /// The option name and inline value of a `--name` or `--name=value` argument.
+    fn named(&self) -> Option<(&str, Option<&str>)> {
+        if self.quoted {
+            return None;
+        }
+
+        let name = self.value.strip_prefix("--").filter(|name| !name.is_empty())?;
+        match name.split_once('=') {
+            Some((name, value)) => Some((name, Some(value))),
+            None => Some((name, None)),
+        }
+    }
+}
+
+fn parse_options(options: &[OptionData], arguments: Vec<Argument>) -> Result<Vec<CommandDataOption>, PrefixArgumentError> {
+    let has_subcommands = options
+        .iter()
+        .any(|option| matches!(option.kind, CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup));
+
+    if has_subcommands {
+        return parse_subcommand(options, arguments);
+    }
+
+    let mut values: Vec<(&OptionData, String)> = Vec::new();
+    let mut positional = Vec::new();
+    let mut arguments = arguments.into_iter();
+
+    while let Some(argument) = arguments.next() {
+        let Some((name, inline)) = argument.named() else {
+            positional.push(argument.value);
+            continue;
+        };
+
+        let option = find_option(options, name).ok_or_else(|| error(&argument.value, PrefixArgumentErrorType::UnknownOption))?;
+        let value = match (inline, option.kind) {
+            (Some(value), _) => value.to_owned(),
+            (None, CommandOptionType::Boolean) => "true".to_owned(),
+            (None, _) => match arguments.next() {
+                Some(value) => value.value,
+                None => return Err(error(&option.name, PrefixArgumentErrorType::MissingValue)),
+            },
+        };
+
+        values.push((option, value));
+    }
+
+    // Flags are only set by name, everything else can be given in order
+    let remaining: Vec<_> = options
+        .iter()
+        .filter(|option| option.kind != CommandOptionType::Boolean)
+        .filter(|option| !values.iter().any(|(set, _)| set.name == option.name))
+        .collect();
+
+    let mut positional = positional.into_iter();
+    for (index, option) in remaining.iter().enumerate() {
+        let value = match index + 1 == remaining.len() && option.kind == CommandOptionType::String {
+            true => {
+                let rest: Vec<_> = positional.by_ref().collect();
+                match rest.is_empty() {
+                    true => None,
+                    false => Some(rest.join(" ")),
+                }
+            }
+            false => positional.next(),
+        };
+
+        if let Some(value) = value {
+            values.push((option, value));
+        }
+    }
+
+    if let Some(argument) = positional.next() {
+        return Err(error(&argument, PrefixArgumentErrorType::UnexpectedArgument));
+    }
+
+    values
+        .into_iter()
+        .map(|(option, value)| {
+            Ok(CommandDataOption {
+                name: option.name.clone(),
+                value: parse_value(option, &value)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_subcommand(options: &[OptionData], arguments: Vec<Argument>) -> Result<Vec<CommandDataOption>, PrefixArgumentError> {
+    let mut arguments = arguments.into_iter();
+    let Some(name) = arguments.next() else {
+        return Err(error("", PrefixArgumentErrorType::MissingSubcommand));
+    };
+
+    let Some(subcommand) = find_option(options, &name.value) else {
+        return Err(error(&name.value, PrefixArgumentErrorType::UnknownSubcommand));
+    };
+
+    let inner = parse_options(subcommand.options.as_deref().unwrap_or_default(), arguments.collect())?;
+    let value = match subcommand.kind {
+        CommandOptionType::SubCommandGroup => CommandOptionValue::SubCommandGroup(inner),
+        _ => CommandOptionValue::SubCommand(inner),
+    };
+
+    Ok(vec![CommandDataOption {
+        name: subcommand.name.clone(),
+        value,
+    }])
+}
+
+/// Find an option by name, ignoring case and treating dashes as underscores.
+fn find_option<'a>(options: &'a [OptionData], name: &str) -> Option<&'a OptionData> {
+    let name = name.to_lowercase().replace('-', "_");
+    options.iter().find(|option| option.name.replace('-', "_") == name)
+}
+
+fn parse_value(option: &OptionData, value: &str) -> Result<CommandOptionValue, PrefixArgumentError> {
+    let invalid = || error(value, PrefixArgumentErrorType::InvalidValue(option.name.clone()));
+
+    // Choices can be picked by their name or their value
+    if let Some(choices) = option.choices.as_ref().filter(|choices| !choices.is_empty()) {
+        let choice = choices
+            .iter()
+            .find(|choice| choice.name.eq_ignore_ascii_case(value) || choice_value(&choice.value) == value)
+            .ok_or_else(invalid)?;
+
+        return Ok(match &choice.value {
+            CommandOptionChoiceValue::String(value) => CommandOptionValue::String(value.clone()),
+            CommandOptionChoiceValue::Integer(value) => CommandOptionValue::Integer(*value),
+            CommandOptionChoiceValue::Number(value) => CommandOptionValue::Number(*value),
+        });
+    }
+
+    let value = match option.kind {
+        CommandOptionType::String => CommandOptionValue::String(value.to_owned()),
+        CommandOptionType::Integer => CommandOptionValue::Integer(value.parse().map_err(|_| invalid())?),
+        CommandOptionType::Number => CommandOptionValue::Number(value.parse().map_err(|_| invalid())?),
+        CommandOptionType::Boolean => match value.to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => CommandOptionValue::Boolean(true),
+            "false" | "no" | "off" | "0" => CommandOptionValue::Boolean(false),
+            _ => return Err(invalid()),
+        },
+        CommandOptionType::User => CommandOptionValue::User(mention(value, &["<@!", "<@"]).ok_or_else(invalid)?),
+        CommandOptionType::Channel => CommandOptionValue::Channel(mention(value, &["<#"]).ok_or_else(invalid)?),
+        CommandOptionType::Role => CommandOptionValue::Role(mention(value, &["<@&"]).ok_or_else(invalid)?),
+        CommandOptionType::Mentionable => CommandOptionValue::Mentionable(mention(value, &["<@&", "<@!", "<@"]).ok_or_else(invalid)?),
+        kind => return Err(error(&option.name, PrefixArgumentErrorType::UnsupportedType(kind))),
+    };
+
+    Ok(value)
+}
+
+/// Read an ID from a mention such as `<@123>`, or from the ID on its own.
+fn mention<T>(value: &str, prefixes: &[&str]) -> Option<Id<T>> {
+    let id = prefixes
+        .iter()
+        .find_map(|prefix| value.strip_prefix(prefix)?.strip_suffix('>'))
+        .unwrap_or(value);
+
+    Id::new_checked(id.parse().ok()?)
+}
+
+fn choice_value(value: &CommandOptionChoiceValue) -> String {
+    match value {
+        CommandOptionChoiceValue::String(value) => value.clone(),
+        CommandOptionChoiceValue::Integer(value) => value.to_string(),
+        CommandOptionChoiceValue::Number(value) => value.to_string(),
+    }
+}
+
+fn error(argument: &str, kind: PrefixArgumentErrorType) -> PrefixArgumentError {
+    PrefixArgumentError {
+        argument: argument.to_owned(),
+        kind,
+    }
+}
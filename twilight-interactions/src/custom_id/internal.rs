@@ -0,0 +1,100 @@
+//! Internal types used by the [`CustomId`] trait.
+//!
+//! This module contains types used by implementations generated by the
+//! derive macro.
+//!
+//! [`CustomId`]: super::CustomId
+
+use std::str::Split;
+
+use super::{CustomIdValue, MAX_LENGTH, SEPARATOR};
+use crate::error::{CustomIdError, CustomIdErrorType};
+
+/// Encode the fields of a custom ID.
+pub struct CustomIdWriter {
+    buffer: String,
+}
+
+impl CustomIdWriter {
+    /// Start a custom ID with its prefix and version.
+    pub fn new(prefix: &str, version: u8) -> Self {
+        let mut buffer = String::with_capacity(MAX_LENGTH);
+        buffer.push_str(prefix);
+        buffer.push(SEPARATOR);
+        version.encode(&mut buffer);
+
+        Self { buffer }
+    }
+
+    /// Append a field to the custom ID.
+    pub fn field<T: CustomIdValue>(&mut self, value: &T) {
+        self.buffer.push(SEPARATOR);
+        value.encode(&mut self.buffer);
+    }
+
+    /// Finish the custom ID, ensuring it is not over the length limit.
+    pub fn finish(self) -> Result<String, CustomIdError> {
+        match self.buffer.chars().count() {
+            length if length > MAX_LENGTH => Err(CustomIdError {
+                custom_id: self.buffer,
+                kind: CustomIdErrorType::TooLong(length),
+            }),
+            _ => Ok(self.buffer),
+        }
+    }
+}
+
+/// Decode the fields of a custom ID.
+pub struct CustomIdReader<'a> {
+    custom_id: &'a str,
+    parts: Split<'a, char>,
+}
+
+impl<'a> CustomIdReader<'a> {
+    /// Check the prefix and version of a custom ID.
+    pub fn new(custom_id: &'a str, prefix: &str, version: u8) -> Result<Self, CustomIdError> {
+        let mut reader = Self {
+            custom_id,
+            parts: custom_id.split(SEPARATOR),
+        };
+
+        if reader.parts.next() != Some(prefix) {
+            return Err(reader.error(CustomIdErrorType::InvalidPrefix));
+        }
+
+        match reader.parts.next() {
+            Some(found) if u8::decode(found) == Some(version) => Ok(reader),
+            found => {
+                let found = found.unwrap_or_default().to_owned();
+                Err(reader.error(CustomIdErrorType::InvalidVersion(found)))
+            }
+        }
+    }
+
+    /// Decode the next field of the custom ID.
+    pub fn field<T: CustomIdValue>(&mut self, name: &str) -> Result<T, CustomIdError> {
+        let Some(value) = self.parts.next() else {
+            return Err(self.error(CustomIdErrorType::MissingField(name.to_owned())));
+        };
+
+        match T::decode(value) {
+            Some(value) => Ok(value),
+            None => Err(self.error(CustomIdErrorType::InvalidField(name.to_owned()))),
+        }
+    }
+
+    /// Ensure all fields of the custom ID have been read.
+    pub fn finish(mut self) -> Result<(), CustomIdError> {
+        match self.parts.next() {
+            Some(_) => Err(self.error(CustomIdErrorType::UnexpectedData)),
+            None => Ok(()),
+        }
+    }
+
+    fn error(&self, kind: CustomIdErrorType) -> CustomIdError {
+        CustomIdError {
+            custom_id: self.custom_id.to_owned(),
+            kind,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Typed component and modal custom IDs.
+//!
+//! # Custom IDs
+//! Buttons, select menus and modals are identified by a custom ID of up to
+//! 100 characters, which is sent back when a user interacts with them. This
+//! module provides a way to encode a small state struct into that custom ID,
+//! so handlers get their state back without storing it elsewhere. Components
+//! keep working across restarts, since nothing is kept in memory.
+//!
+//! - State encoding and decoding with the [`CustomId`] trait.
+//! - Conversion of field values with the [`CustomIdValue`] trait.
+//! - Routing by prefix with the [`prefix`] function.
+//!
+//! ## Format
+//! Custom IDs are encoded as `prefix:version:field:field...`. The prefix is
+//! used to route the interaction to its handler, and the version is checked
+//! when decoding so components created by an older model are rejected instead
+//! of being misread. Increase the version whenever the fields change.
+//!
+//! Integers and IDs are encoded in base 36 to keep custom IDs short.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::custom_id::CustomId;
+//! use twilight_model::id::{marker::UserMarker, Id};
+//!
+//! #[derive(CustomId, Debug, PartialEq)]
+//! #[custom_id(prefix = "marry", version = 1)]
+//! struct ProposalAnswer {
+//!     requester: Id<UserMarker>,
+//!     accept: bool,
+//! }
+//!
+//! let answer = ProposalAnswer { requester: Id::new(1234), accept: true };
+//! let custom_id = answer.to_custom_id().unwrap();
+//!
+//! assert_eq!(custom_id, "marry:1:ya:1");
+//! assert_eq!(ProposalAnswer::from_custom_id(&custom_id), Ok(answer));
+//! ```
+//!
+//! ## Supported types
+//! The [`CustomIdValue`] trait is implemented for [`String`], [`bool`],
+//! unsigned and signed integers, [`Id<T>`] and [`Option<T>`].
+//!
+//! [`Id<T>`]: twilight_model::id::Id
+
+mod value;
+
+#[doc(hidden)]
+pub mod internal;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use twilight_interactions_derive::CustomId;
+pub use value::CustomIdValue;
+
+use crate::error::CustomIdError;
+
+/// Separator between the prefix, version and fields of a custom ID.
+pub const SEPARATOR: char = ':';
+
+/// Maximum length of a custom ID accepted by Discord.
+pub const MAX_LENGTH: usize = 100;
+
+/// Encode and decode state in a component or modal custom ID.
+///
+/// This trait is used to store a small state struct in a custom ID. A derive
+/// macro is provided to implement this trait automatically.
+///
+/// The trait can be derived on structs with named fields or unit structs. All
+/// type fields must implement the [`CustomIdValue`] trait. Fields are encoded
+/// in the order they are declared.
+///
+/// ## Macro attributes
+/// The macro provides a `#[custom_id]` attribute to provide additional
+/// information.
+///
+/// | Attribute | Type  | Location | Description                                          |
+/// |-----------|-------|----------|------------------------------------------------------|
+/// | `prefix`  | `str` | Type     | Prefix used to route the custom ID (required).       |
+/// | `version` | `u8`  | Type     | Version of the encoded fields, defaults to `1`.      |
+///
+/// The prefix must be between 1 and 32 characters and cannot contain the
+/// [`SEPARATOR`].
+pub trait CustomId: Sized {
+    /// Prefix used to route the custom ID to its handler.
+    const PREFIX: &'static str;
+    /// Version of the encoded fields.
+    const VERSION: u8;
+
+    /// Encode this type into a custom ID.
+    ///
+    /// An error is returned if the encoded custom ID is longer than
+    /// [`MAX_LENGTH`].
+    fn to_custom_id(&self) -> Result<String, CustomIdError>;
+
+    /// Decode a custom ID into this type.
+    fn from_custom_id(custom_id: &str) -> Result<Self, CustomIdError>;
+}
+
+/// Get the prefix of a custom ID.
+///
+/// Custom IDs not created by [`CustomId`] are returned unchanged, which allows
+/// routing typed and plain custom IDs the same way.
+///
+/// ```
+/// use twilight_interactions::custom_id::prefix;
+///
+/// assert_eq!(prefix("images:1:a"), "images");
+/// assert_eq!(prefix("music-pause"), "music-pause");
+/// ```
+pub fn prefix(custom_id: &str) -> &str {
+    match custom_id.split_once(SEPARATOR) {
+        Some((prefix, _)) => prefix,
+        None => custom_id,
+    }
+}
@@ -0,0 +1,148 @@
+use twilight_model::id::Id;
+
+/// Encode a value into a custom ID field.
+///
+/// This trait is used by the implementation of [`CustomId`] generated by the
+/// derive macro. See the [module documentation](crate::custom_id) for a list
+/// of supported types.
+///
+/// Encoded values must not contain the [`SEPARATOR`] character, and must not
+/// be equal to `~` which is used to encode [`None`].
+///
+/// [`CustomId`]: super::CustomId
+/// [`SEPARATOR`]: super::SEPARATOR
+pub trait CustomIdValue: Sized {
+    /// Append the encoded value to the buffer.
+    fn encode(&self, buffer: &mut String);
+
+    /// Decode a value, returning [`None`] if it is invalid.
+    fn decode(value: &str) -> Option<Self>;
+}
+
+/// Value used to encode [`None`].
+const NONE: &str = "~";
+
+impl CustomIdValue for String {
+    fn encode(&self, buffer: &mut String) {
+        for char in self.chars() {
+            match char {
+                ':' => buffer.push_str("%3A"),
+                '%' => buffer.push_str("%25"),
+                '~' => buffer.push_str("%7E"),
+                char => buffer.push(char),
+            }
+        }
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        let mut decoded = String::with_capacity(value.len());
+        let mut chars = value.chars();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '%' => {
+                    let code = chars.by_ref().take(2).collect::<String>();
+                    decoded.push(u8::from_str_radix(&code, 16).ok()?.into());
+                }
+                char => decoded.push(char),
+            }
+        }
+
+        Some(decoded)
+    }
+}
+
+impl CustomIdValue for bool {
+    fn encode(&self, buffer: &mut String) {
+        buffer.push(if *self { '1' } else { '0' });
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        match value {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Encode an unsigned integer in base 36.
+fn encode_base36(mut value: u64, buffer: &mut String) {
+    let mut digits = Vec::new();
+
+    loop {
+        digits.push(char::from_digit((value % 36) as u32, 36).unwrap_or('0'));
+        value /= 36;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    buffer.extend(digits.into_iter().rev());
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl CustomIdValue for $ty {
+                fn encode(&self, buffer: &mut String) {
+                    encode_base36(u64::from(*self), buffer);
+                }
+
+                fn decode(value: &str) -> Option<Self> {
+                    <$ty>::from_str_radix(value, 36).ok()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {
+        $(
+            impl CustomIdValue for $ty {
+                fn encode(&self, buffer: &mut String) {
+                    if self.is_negative() {
+                        buffer.push('-');
+                    }
+
+                    encode_base36(u64::from(self.unsigned_abs()), buffer);
+                }
+
+                fn decode(value: &str) -> Option<Self> {
+                    <$ty>::from_str_radix(value, 36).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64);
+impl_signed!(i8, i16, i32, i64);
+
+impl<T> CustomIdValue for Id<T> {
+    fn encode(&self, buffer: &mut String) {
+        encode_base36(self.get(), buffer);
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        u64::decode(value).and_then(Id::new_checked)
+    }
+}
+
+impl<T: CustomIdValue> CustomIdValue for Option<T> {
+    fn encode(&self, buffer: &mut String) {
+        match self {
+            Some(value) => value.encode(buffer),
+            None => buffer.push_str(NONE),
+        }
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        match value {
+            NONE => Some(None),
+            value => T::decode(value).map(Some),
+        }
+    }
+}
@@ -100,3 +100,177 @@ pub enum ParseOptionErrorType {
     /// Received an unknown subcommand.
     UnknownSubcommand,
 }
+
+/// Error when parsing the arguments of a text command.
+///
+/// This error type is returned by the [`parse_prefix`] function.
+///
+/// [`parse_prefix`]: crate::command::parse_prefix
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrefixError {
+    /// The arguments could not be matched to the command options.
+    Argument(PrefixArgumentError),
+    /// The matched options could not be parsed into the command model.
+    Parse(ParseError),
+}
+
+impl Error for PrefixError {}
+
+impl Display for PrefixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PrefixError::Argument(error) => error.fmt(f),
+            PrefixError::Parse(error) => error.fmt(f),
+        }
+    }
+}
+
+/// Error when matching the arguments of a text command to its options.
+///
+/// This type is used by [`PrefixError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixArgumentError {
+    /// The argument that caused the error.
+    pub argument: String,
+    /// The type of the error.
+    pub kind: PrefixArgumentErrorType,
+}
+
+impl Error for PrefixArgumentError {}
+
+impl Display for PrefixArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PrefixArgumentErrorType::UnclosedQuote => write!(f, "unclosed quote in `{}`", self.argument),
+            PrefixArgumentErrorType::MissingSubcommand => write!(f, "missing subcommand"),
+            PrefixArgumentErrorType::UnknownSubcommand => write!(f, "unknown subcommand `{}`", self.argument),
+            PrefixArgumentErrorType::UnknownOption => write!(f, "unknown option `{}`", self.argument),
+            PrefixArgumentErrorType::MissingValue => write!(f, "missing value for option `{}`", self.argument),
+            PrefixArgumentErrorType::InvalidValue(option) => {
+                write!(f, "invalid value for option `{option}`, received `{}`", self.argument)
+            }
+            PrefixArgumentErrorType::UnsupportedType(kind) => {
+                write!(f, "option `{}` of type {} cannot be given as text", self.argument, kind.kind())
+            }
+            PrefixArgumentErrorType::UnexpectedArgument => write!(f, "unexpected argument `{}`", self.argument),
+        }
+    }
+}
+
+/// Type of [`PrefixArgumentError`] that occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrefixArgumentErrorType {
+    /// A quoted argument was not closed.
+    UnclosedQuote,
+    /// The command has subcommands, but none was given.
+    MissingSubcommand,
+    /// Received an unknown subcommand.
+    UnknownSubcommand,
+    /// Received an unknown `--option`.
+    UnknownOption,
+    /// An `--option` was given without a value.
+    MissingValue,
+    /// Received a value that does not match the option type or choices.
+    ///
+    /// Contains the name of the option.
+    InvalidValue(String),
+    /// The option type cannot be given as text, such as attachments.
+    UnsupportedType(CommandOptionType),
+    /// Received more arguments than the command has options.
+    UnexpectedArgument,
+}
+
+/// Error when parsing a modal submission.
+///
+/// This error type is returned by the [`ModalModel::from_interaction`]
+/// method.
+///
+/// [`ModalModel::from_interaction`]: crate::modal::ModalModel::from_interaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalParseError {
+    /// The custom ID of the text input that caused the error.
+    pub field: String,
+    /// The type of the error.
+    pub kind: ModalParseErrorType,
+}
+
+impl Error for ModalParseError {}
+
+impl Display for ModalParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "failed to parse modal field `{}`: ", self.field)?;
+
+        match &self.kind {
+            ModalParseErrorType::InvalidValue(value) => write!(f, "invalid value, received `{value}`"),
+            ModalParseErrorType::RequiredField => write!(f, "missing required field"),
+            ModalParseErrorType::UnknownField => write!(f, "unknown field"),
+        }
+    }
+}
+
+/// Type of [`ModalParseError`] that occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModalParseErrorType {
+    /// Received a value that could not be converted into the field type.
+    InvalidValue(String),
+    /// A required field is missing or was left empty.
+    RequiredField,
+    /// Received an unknown text input.
+    UnknownField,
+}
+
+/// Error when encoding or decoding a custom ID.
+///
+/// This error type is returned by the [`CustomId::to_custom_id`] and
+/// [`CustomId::from_custom_id`] methods.
+///
+/// [`CustomId::to_custom_id`]: crate::custom_id::CustomId::to_custom_id
+/// [`CustomId::from_custom_id`]: crate::custom_id::CustomId::from_custom_id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomIdError {
+    /// The custom ID that caused the error.
+    pub custom_id: String,
+    /// The type of the error.
+    pub kind: CustomIdErrorType,
+}
+
+impl Error for CustomIdError {}
+
+impl Display for CustomIdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "invalid custom id `{}`: ", self.custom_id)?;
+
+        match &self.kind {
+            CustomIdErrorType::InvalidPrefix => write!(f, "unexpected prefix"),
+            CustomIdErrorType::InvalidVersion(version) => {
+                write!(f, "unsupported version, found `{version}`")
+            }
+            CustomIdErrorType::InvalidField(field) => write!(f, "invalid value for field `{field}`"),
+            CustomIdErrorType::MissingField(field) => write!(f, "missing field `{field}`"),
+            CustomIdErrorType::UnexpectedData => write!(f, "unexpected trailing data"),
+            CustomIdErrorType::TooLong(length) => {
+                write!(f, "encoded length of {length} is over the limit of 100 characters")
+            }
+        }
+    }
+}
+
+/// Type of [`CustomIdError`] that occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomIdErrorType {
+    /// The custom ID does not start with the expected prefix.
+    InvalidPrefix,
+    /// The custom ID was encoded by another version of the model.
+    ///
+    /// This usually means the component was created before the model changed,
+    /// and should be treated as expired.
+    InvalidVersion(String),
+    /// Failed to decode the value of a field.
+    InvalidField(String),
+    /// A field is missing from the custom ID.
+    MissingField(String),
+    /// The custom ID has more fields than the model.
+    UnexpectedData,
+    /// The encoded custom ID is longer than 100 characters.
+    TooLong(usize),
+}
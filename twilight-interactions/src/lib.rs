@@ -13,10 +13,22 @@
 //!
 //! See the [`command`] module for more information.
 //!
+//! ### Modals
+//! Modals can be created from typed structures, and their submissions parsed
+//! back into the same structure. See the [`modal`] module for more
+//! information.
+//!
+//! ### Custom IDs
+//! State can be encoded into the custom ID of buttons, select menus and
+//! modals with typed structures, and decoded back when they are used. See the
+//! [`custom_id`] module for more information.
+//!
 //! ## Versioning
 //! To facilitate dependencies management, this crate will always use the same
 //! major version as the official `twilight` crates.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod command;
+pub mod custom_id;
 pub mod error;
+pub mod modal;
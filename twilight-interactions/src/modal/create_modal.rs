@@ -0,0 +1,120 @@
+use twilight_model::{
+    channel::message::component::{ActionRow, Component, TextInput},
+    http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+};
+
+/// Create a modal from a type.
+///
+/// This trait is used to create modals from modal models. A derive macro is
+/// provided to automatically implement the traits.
+///
+/// ## Types and fields documentation
+/// The trait can be derived on structs with named fields, where each field
+/// becomes a text input. A modal must have between one and five fields.
+///
+/// Unlike the [`ModalModel`] trait, all fields of the type must have a label.
+/// The label corresponds either to the first line of the documentation
+/// comment or the value of the `label` attribute. The type must also have a
+/// title set with the `title` attribute.
+///
+/// ## Example
+/// ```
+/// use twilight_interactions::modal::CreateModal;
+///
+/// #[derive(CreateModal)]
+/// #[modal(title = "Send feedback")]
+/// struct FeedbackModal {
+///     /// Summary
+///     #[modal(placeholder = "What went wrong?", max_length = 100)]
+///     summary: String,
+///     /// Details
+///     #[modal(style = "paragraph", min_length = 20)]
+///     details: Option<String>,
+/// }
+///
+/// let modal = FeedbackModal::create_modal("feedback");
+/// assert_eq!(modal.text_inputs.len(), 2);
+/// ```
+///
+/// ## Macro attributes
+/// The macro provides a `#[modal]` attribute to provide additional
+/// information.
+///
+/// | Attribute                  | Type  | Location | Description                                                  |
+/// |----------------------------|-------|----------|--------------------------------------------------------------|
+/// | `title`                    | `str` | Type     | Title of the modal (required).                               |
+/// | `rename`                   | `str` | Field    | Use a different custom ID than the field name.               |
+/// | `label`                    | `str` | Field    | Label of the text input, instead of the documentation.       |
+/// | `style`                    | `str` | Field    | Style of the text input, `short` (default) or `paragraph`.   |
+/// | `placeholder`              | `str` | Field    | Text shown while the text input is empty.                    |
+/// | `max_length`, `min_length` | `u16` | Field    | Maximum and/or minimum text length permitted.                |
+///
+/// Titles and labels must be 45 characters or less, and placeholders 100
+/// characters or less.
+///
+/// [`ModalModel`]: super::ModalModel
+pub trait CreateModal: Sized {
+    /// Title of the modal.
+    const TITLE: &'static str;
+
+    /// Create a [`ModalData`] for this type, using the given custom ID.
+    fn create_modal(custom_id: impl Into<String>) -> ModalData;
+}
+
+/// Data sent to Discord to open a modal.
+///
+/// This type is used in the [`CreateModal`] trait. To convert it into an
+/// [`InteractionResponse`] or [`InteractionResponseData`], use the [From]
+/// (or [Into]) trait.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalData {
+    /// Custom ID of the modal, sent back with the submission.
+    pub custom_id: String,
+    /// Title of the modal. It must be 45 characters or less.
+    pub title: String,
+    /// Text inputs of the modal, each rendered on its own row.
+    pub text_inputs: Vec<TextInput>,
+}
+
+impl ModalData {
+    /// Pre-fill the text input with the given custom ID.
+    ///
+    /// Unknown custom IDs are ignored.
+    pub fn value(mut self, custom_id: &str, value: impl Into<String>) -> Self {
+        if let Some(text_input) = self.text_inputs.iter_mut().find(|input| input.custom_id == custom_id) {
+            text_input.value = Some(value.into());
+        }
+
+        self
+    }
+}
+
+impl From<ModalData> for InteractionResponseData {
+    fn from(item: ModalData) -> Self {
+        let components = item
+            .text_inputs
+            .into_iter()
+            .map(|text_input| {
+                Component::ActionRow(ActionRow {
+                    components: vec![Component::TextInput(text_input)],
+                })
+            })
+            .collect();
+
+        InteractionResponseData {
+            components: Some(components),
+            custom_id: Some(item.custom_id),
+            title: Some(item.title),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ModalData> for InteractionResponse {
+    fn from(item: ModalData) -> Self {
+        InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(item.into()),
+        }
+    }
+}
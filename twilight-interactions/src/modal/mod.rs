@@ -0,0 +1,44 @@
+//! Modal creation and parsing.
+//!
+//! # Modals
+//! Modals are pop-up forms made of text inputs. This module provides a way to
+//! create them from typed structs and to parse their submissions back into
+//! the same structs. Derive macros are provided to automatically implement
+//! related traits.
+//!
+//! - Modal parsing with the [`ModalModel`] trait.
+//! - Modal creation with the [`CreateModal`] trait.
+//! - Conversion of text input values with the [`ModalValue`] trait.
+//!
+//! Read the documentation of these traits for usage examples.
+//!
+//! ## Example
+//! ```
+//! use twilight_interactions::modal::{CreateModal, ModalModel};
+//!
+//! #[derive(ModalModel, CreateModal)]
+//! #[modal(title = "Send feedback")]
+//! struct FeedbackModal {
+//!     /// Summary
+//!     summary: String,
+//!     /// Details
+//!     #[modal(style = "paragraph", max_length = 2000)]
+//!     details: Option<String>,
+//! }
+//! ```
+//!
+//! ## Supported types
+//! The [`ModalValue`] trait is implemented for [`String`], [`i64`], [`u64`],
+//! [`f64`] and [`Id<T>`]. Wrap the type in an [`Option<T>`] to make the text
+//! input optional.
+//!
+//! [`Id<T>`]: twilight_model::id::Id
+
+mod create_modal;
+mod modal_model;
+
+pub use create_modal::{CreateModal, ModalData};
+pub use modal_model::{ModalModel, ModalValue};
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use twilight_interactions_derive::{CreateModal, ModalModel};
@@ -0,0 +1,86 @@
+use twilight_model::{application::interaction::modal::ModalInteractionData, id::Id};
+
+use crate::error::{ModalParseError, ModalParseErrorType};
+
+/// Parse modal submission data into a concrete type.
+///
+/// This trait is used to parse received modal data into a concrete modal
+/// model. A derive macro is provided to implement this trait automatically.
+///
+/// ## Modal models
+/// This trait can be implemented on structs with named fields, each field
+/// corresponding to a text input of the modal. All type fields must implement
+/// the [`ModalValue`] trait. Fields wrapped in an [`Option<T>`] are optional,
+/// and text inputs left empty are parsed as [`None`].
+///
+/// The custom ID of each text input is the name of the field, unless
+/// overwritten with the `rename` attribute.
+///
+/// ```
+/// use twilight_interactions::modal::ModalModel;
+///
+/// #[derive(ModalModel)]
+/// struct FeedbackModal {
+///     summary: String,
+///     #[modal(rename = "feedback-details")]
+///     details: Option<String>,
+/// }
+/// ```
+///
+/// ### Errors
+/// Parsing fails with a [`ModalParseError`] if a required text input is
+/// missing or empty, if a value cannot be converted into the field type, or
+/// if an unknown text input is received. As with the [`CommandModel`] trait,
+/// validation is limited to what is declared on the model.
+///
+/// [`CommandModel`]: crate::command::CommandModel
+pub trait ModalModel: Sized {
+    /// Construct this type from [`ModalInteractionData`].
+    fn from_interaction(data: ModalInteractionData) -> Result<Self, ModalParseError>;
+}
+
+/// Parse a text input value into a concrete type.
+///
+/// This trait is used by the implementation of [`ModalModel`] generated by
+/// the derive macro. See the [module documentation](crate::modal) for a list
+/// of supported types.
+pub trait ModalValue: Sized {
+    /// Convert the value of a text input into this type.
+    fn from_value(value: String) -> Result<Self, ModalParseErrorType>;
+}
+
+impl ModalValue for String {
+    fn from_value(value: String) -> Result<Self, ModalParseErrorType> {
+        Ok(value)
+    }
+}
+
+impl ModalValue for i64 {
+    fn from_value(value: String) -> Result<Self, ModalParseErrorType> {
+        value.trim().parse().map_err(|_| ModalParseErrorType::InvalidValue(value))
+    }
+}
+
+impl ModalValue for u64 {
+    fn from_value(value: String) -> Result<Self, ModalParseErrorType> {
+        value.trim().parse().map_err(|_| ModalParseErrorType::InvalidValue(value))
+    }
+}
+
+impl ModalValue for f64 {
+    fn from_value(value: String) -> Result<Self, ModalParseErrorType> {
+        match value.trim().parse::<f64>() {
+            Ok(number) if number.is_finite() => Ok(number),
+            _ => Err(ModalParseErrorType::InvalidValue(value)),
+        }
+    }
+}
+
+impl<T> ModalValue for Id<T> {
+    fn from_value(value: String) -> Result<Self, ModalParseErrorType> {
+        match value.trim().parse().ok().and_then(Id::new_checked) {
+            Some(id) => Ok(id),
+            None => Err(ModalParseErrorType::InvalidValue(value)),
+        }
+    }
+}
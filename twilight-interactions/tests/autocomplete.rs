@@ -25,7 +25,11 @@ fn test_autocomplete_model() {
         },
     ];
 
-    let data = CommandInputData { options, resolved: None };
+    let data = CommandInputData {
+        options,
+        resolved: None,
+        target_id: None,
+    };
 
     let result = DemoCommand::from_interaction(data).unwrap();
 
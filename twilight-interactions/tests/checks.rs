@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use twilight_interactions::command::{CommandChecks, CommandModel, Cooldown, CooldownBucket, CreateCommand};
+use twilight_model::guild::Permissions;
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "ban", desc = "Ban a member", cooldown = "5s per user")]
+struct BanCommand {
+    /// The reason of the ban
+    reason: String,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "reload", desc = "Reload the config", owner_only, cooldown = "1m globally")]
+struct ReloadCommand;
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(
+    name = "moderation",
+    desc = "Moderation commands",
+    guild_only,
+    user_permissions = "BAN_MEMBERS | KICK_MEMBERS",
+    bot_permissions = "BAN_MEMBERS",
+    cooldown = "2h per guild"
+)]
+enum ModerationCommand {
+    #[command(name = "ban")]
+    Ban(BanCommand),
+    #[command(name = "reload")]
+    Reload(ReloadCommand),
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(name = "hello", desc = "Say hello")]
+struct HelloCommand;
+
+#[test]
+fn test_checks() {
+    let expected = CommandChecks {
+        guild_only: true,
+        owner_only: false,
+        user_permissions: Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS,
+        bot_permissions: Permissions::BAN_MEMBERS,
+        cooldown: Some(Cooldown {
+            duration: Duration::from_secs(2 * 60 * 60),
+            bucket: CooldownBucket::Guild,
+        }),
+    };
+
+    assert_eq!(ModerationCommand::CHECKS, expected);
+    assert_eq!(ModerationCommand::create_command().dm_permission, Some(false));
+    assert_eq!(HelloCommand::CHECKS, CommandChecks::NONE);
+    assert!(HelloCommand.checks().is_empty());
+}
+
+#[test]
+fn test_subcommand_checks() {
+    let ban = ModerationCommand::Ban(BanCommand { reason: "Spam".into() });
+    let reload = ModerationCommand::Reload(ReloadCommand);
+
+    let ban_checks = ban.checks();
+    assert!(ban_checks.guild_only);
+    assert!(!ban_checks.owner_only);
+    assert_eq!(
+        ban_checks.cooldown,
+        Some(Cooldown {
+            duration: Duration::from_secs(5),
+            bucket: CooldownBucket::User,
+        })
+    );
+
+    let reload_checks = reload.checks();
+    assert!(reload_checks.owner_only);
+    assert_eq!(reload_checks.user_permissions, Permissions::BAN_MEMBERS | Permissions::KICK_MEMBERS);
+    assert_eq!(
+        reload_checks.cooldown,
+        Some(Cooldown {
+            duration: Duration::from_secs(60),
+            bucket: CooldownBucket::Global,
+        })
+    );
+}
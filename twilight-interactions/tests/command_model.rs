@@ -111,6 +111,7 @@ fn test_command_model() {
     let data = CommandInputData {
         options,
         resolved: Some(Cow::Owned(resolved)),
+        target_id: None,
     };
 
     let result = DemoCommand::from_interaction(data).unwrap();
@@ -133,6 +134,7 @@ fn test_unit_command_model() {
     let data = CommandInputData {
         options: vec![],
         resolved: None,
+        target_id: None,
     };
 
     let result = UnitCommand::from_interaction(data).unwrap();
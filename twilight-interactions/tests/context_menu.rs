@@ -0,0 +1,147 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use twilight_interactions::{
+    command::{ApplicationCommandData, CommandInputData, CommandModel, CreateCommand, ResolvedUser},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType},
+};
+use twilight_model::{
+    application::{command::CommandType, interaction::InteractionDataResolved},
+    guild::Permissions,
+    id::{
+        marker::{MessageMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(kind = "user", name = "User Info", dm_permission = false)]
+struct UserInfo {
+    user: ResolvedUser,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(kind = "message", name = "Report to mods", default_permissions = "report_permissions")]
+struct Report {
+    message: Id<MessageMarker>,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq, Eq)]
+#[command(kind = "user", name = "Wave")]
+struct Wave;
+
+fn report_permissions() -> Permissions {
+    Permissions::SEND_MESSAGES
+}
+
+fn context_menu(name: &str, kind: CommandType) -> ApplicationCommandData {
+    ApplicationCommandData {
+        name: name.into(),
+        name_localizations: None,
+        description: String::new(),
+        description_localizations: None,
+        options: vec![],
+        default_member_permissions: None,
+        dm_permission: None,
+        group: false,
+        nsfw: None,
+        kind,
+    }
+}
+
+#[test]
+fn test_create_context_menu() {
+    let mut user_info = context_menu("User Info", CommandType::User);
+    user_info.dm_permission = Some(false);
+    let mut report = context_menu("Report to mods", CommandType::Message);
+    report.default_member_permissions = Some(Permissions::SEND_MESSAGES);
+
+    assert_eq!(UserInfo::create_command(), user_info);
+    assert_eq!(UserInfo::NAME, "User Info");
+    assert_eq!(Report::create_command(), report);
+    assert_eq!(Wave::create_command(), context_menu("Wave", CommandType::User));
+}
+
+#[test]
+fn test_user_context_menu_model() {
+    let user_id: Id<UserMarker> = Id::new(123);
+    let user = User {
+        avatar: None,
+        bot: false,
+        discriminator: 1,
+        email: None,
+        flags: None,
+        id: user_id,
+        locale: None,
+        mfa_enabled: None,
+        name: "someone".into(),
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+        accent_color: None,
+        banner: None,
+        avatar_decoration: None,
+        global_name: None,
+    };
+
+    let resolved = InteractionDataResolved {
+        attachments: HashMap::new(),
+        channels: HashMap::new(),
+        members: HashMap::new(),
+        messages: HashMap::new(),
+        roles: HashMap::new(),
+        users: HashMap::from([(user_id, user.clone())]),
+    };
+
+    let data = CommandInputData {
+        options: vec![],
+        resolved: Some(Cow::Owned(resolved)),
+        target_id: Some(user_id.cast()),
+    };
+
+    let expected = UserInfo {
+        user: ResolvedUser {
+            resolved: user,
+            member: None,
+        },
+    };
+
+    assert_eq!(UserInfo::from_interaction(data).unwrap(), expected);
+}
+
+#[test]
+fn test_message_context_menu_model() {
+    let data = CommandInputData {
+        options: vec![],
+        resolved: None,
+        target_id: Some(Id::new(456)),
+    };
+
+    assert_eq!(Report::from_interaction(data).unwrap(), Report { message: Id::new(456) });
+}
+
+#[test]
+fn test_context_menu_missing_target() {
+    let data = CommandInputData {
+        options: vec![],
+        resolved: None,
+        target_id: Some(Id::new(123)),
+    };
+    let lookup_failed = ParseError::Option(ParseOptionError {
+        field: "user".into(),
+        kind: ParseOptionErrorType::LookupFailed(123),
+    });
+    assert_eq!(UserInfo::from_interaction(data), Err(lookup_failed));
+
+    let data = CommandInputData {
+        options: vec![],
+        resolved: None,
+        target_id: None,
+    };
+    let required = ParseError::Option(ParseOptionError {
+        field: "message".into(),
+        kind: ParseOptionErrorType::RequiredField,
+    });
+    assert_eq!(Report::from_interaction(data), Err(required));
+}
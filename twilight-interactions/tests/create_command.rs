@@ -3,7 +3,7 @@ use std::{borrow::Cow, collections::HashMap};
 use twilight_interactions::command::{ApplicationCommandData, CreateCommand, CreateOption, ResolvedUser};
 use twilight_model::{
     application::{
-        command::{CommandOption, CommandOptionType, CommandOptionValue},
+        command::{CommandOption, CommandOptionType, CommandOptionValue, CommandType},
         interaction::InteractionChannel,
     },
     channel::ChannelType,
@@ -168,6 +168,7 @@ fn test_create_command() {
         dm_permission: Some(false),
         group: false,
         nsfw: Some(true),
+        kind: CommandType::ChatInput,
     };
 
     assert_eq!(DemoCommand::<i64>::create_command(), expected);
@@ -186,6 +187,7 @@ fn test_unit_create_command() {
         dm_permission: None,
         group: false,
         nsfw: None,
+        kind: CommandType::ChatInput,
     };
 
     assert_eq!(UnitCommand::create_command(), expected);
@@ -0,0 +1,90 @@
+use twilight_interactions::{
+    custom_id::{prefix, CustomId},
+    error::{CustomIdError, CustomIdErrorType},
+};
+use twilight_model::id::{marker::UserMarker, Id};
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "search", version = 2)]
+struct SearchPage {
+    author: Id<UserMarker>,
+    page: u32,
+    offset: i64,
+    forwards: bool,
+    query: String,
+    tag: Option<String>,
+}
+
+#[derive(CustomId, Debug, PartialEq, Eq)]
+#[custom_id(prefix = "refresh")]
+struct Refresh;
+
+fn error(custom_id: &str, kind: CustomIdErrorType) -> CustomIdError {
+    CustomIdError {
+        custom_id: custom_id.into(),
+        kind,
+    }
+}
+
+#[test]
+fn test_custom_id_round_trip() {
+    let state = SearchPage {
+        author: Id::new(1234),
+        page: 36,
+        offset: -10,
+        forwards: true,
+        query: "cats: 100% ~fluffy~".into(),
+        tag: None,
+    };
+
+    let custom_id = state.to_custom_id().unwrap();
+
+    assert_eq!(SearchPage::PREFIX, "search");
+    assert_eq!(SearchPage::VERSION, 2);
+    assert_eq!(custom_id, "search:2:ya:10:-a:1:cats%3A 100%25 %7Efluffy%7E:~");
+    assert_eq!(prefix(&custom_id), "search");
+    assert_eq!(SearchPage::from_custom_id(&custom_id), Ok(state));
+}
+
+#[test]
+fn test_custom_id_unit() {
+    assert_eq!(Refresh.to_custom_id(), Ok("refresh:1".to_owned()));
+    assert_eq!(Refresh::from_custom_id("refresh:1"), Ok(Refresh));
+}
+
+#[test]
+fn test_custom_id_errors() {
+    let too_long = SearchPage {
+        author: Id::new(1),
+        page: 0,
+        offset: 0,
+        forwards: false,
+        query: "a".repeat(100),
+        tag: Some("b".into()),
+    };
+
+    assert_eq!(
+        too_long.to_custom_id().map_err(|error| error.kind),
+        Err(CustomIdErrorType::TooLong(119))
+    );
+    assert_eq!(
+        SearchPage::from_custom_id("refresh:1"),
+        Err(error("refresh:1", CustomIdErrorType::InvalidPrefix))
+    );
+    assert_eq!(
+        SearchPage::from_custom_id("search:1:ya"),
+        Err(error("search:1:ya", CustomIdErrorType::InvalidVersion("1".into())))
+    );
+    assert_eq!(
+        SearchPage::from_custom_id("search:2:ya:10"),
+        Err(error("search:2:ya:10", CustomIdErrorType::MissingField("offset".into())))
+    );
+    assert_eq!(
+        SearchPage::from_custom_id("search:2:0:10:-a:1:cats:~"),
+        Err(error("search:2:0:10:-a:1:cats:~", CustomIdErrorType::InvalidField("author".into())))
+    );
+    assert_eq!(
+        Refresh::from_custom_id("refresh:1:extra"),
+        Err(error("refresh:1:extra", CustomIdErrorType::UnexpectedData))
+    );
+}
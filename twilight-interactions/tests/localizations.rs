@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use twilight_interactions::command::{ApplicationCommandData, CommandModel, CreateCommand, DescriptionLocalizations};
-use twilight_model::application::command::{CommandOption, CommandOptionType};
+use twilight_model::application::command::{CommandOption, CommandOptionType, CommandType};
 
 fn localize() -> DescriptionLocalizations {
     DescriptionLocalizations::new("fallback", [("en", "english"), ("fr", "french")])
@@ -103,6 +103,7 @@ fn command(
         default_member_permissions: None,
         group,
         nsfw: None,
+        kind: CommandType::ChatInput,
     }
 }
 
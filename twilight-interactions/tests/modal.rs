@@ -0,0 +1,148 @@
+use twilight_interactions::{
+    error::{ModalParseError, ModalParseErrorType},
+    modal::{CreateModal, ModalData, ModalModel},
+};
+use twilight_model::{
+    application::interaction::modal::{ModalInteractionData, ModalInteractionDataActionRow, ModalInteractionDataComponent},
+    channel::message::component::{ComponentType, TextInput, TextInputStyle},
+    http::interaction::{InteractionResponse, InteractionResponseType},
+    id::{marker::UserMarker, Id},
+};
+
+#[derive(ModalModel, CreateModal, Debug, PartialEq, Eq)]
+#[modal(title = "Warn a user")]
+struct WarnModal {
+    /// Why they should be warned
+    #[modal(
+        rename = "warn-reason",
+        style = "paragraph",
+        min_length = 20,
+        max_length = 2048,
+        placeholder = "Describe what happened"
+    )]
+    reason: String,
+    /// User ID
+    user_id: Id<UserMarker>,
+    #[modal(label = "Warning count")]
+    count: Option<i64>,
+}
+
+fn component(custom_id: &str, value: &str) -> ModalInteractionDataActionRow {
+    ModalInteractionDataActionRow {
+        components: vec![ModalInteractionDataComponent {
+            custom_id: custom_id.into(),
+            kind: ComponentType::TextInput,
+            value: Some(value.into()),
+        }],
+    }
+}
+
+fn modal_data(components: Vec<ModalInteractionDataActionRow>) -> ModalInteractionData {
+    ModalInteractionData {
+        components,
+        custom_id: "warn".into(),
+    }
+}
+
+#[test]
+fn test_create_modal() {
+    let expected = ModalData {
+        custom_id: "warn".into(),
+        title: "Warn a user".into(),
+        text_inputs: vec![
+            TextInput {
+                custom_id: "warn-reason".into(),
+                label: "Why they should be warned".into(),
+                max_length: Some(2048),
+                min_length: Some(20),
+                placeholder: Some("Describe what happened".into()),
+                required: Some(true),
+                style: TextInputStyle::Paragraph,
+                value: None,
+            },
+            TextInput {
+                custom_id: "user_id".into(),
+                label: "User ID".into(),
+                max_length: None,
+                min_length: None,
+                placeholder: None,
+                required: Some(true),
+                style: TextInputStyle::Short,
+                value: None,
+            },
+            TextInput {
+                custom_id: "count".into(),
+                label: "Warning count".into(),
+                max_length: None,
+                min_length: None,
+                placeholder: None,
+                required: Some(false),
+                style: TextInputStyle::Short,
+                value: None,
+            },
+        ],
+    };
+
+    assert_eq!(WarnModal::TITLE, "Warn a user");
+    assert_eq!(WarnModal::create_modal("warn"), expected);
+}
+
+#[test]
+fn test_modal_response() {
+    let modal = WarnModal::create_modal("warn").value("user_id", "123");
+    assert_eq!(modal.text_inputs[1].value.as_deref(), Some("123"));
+
+    let response = InteractionResponse::from(modal);
+    let data = response.data.unwrap();
+
+    assert_eq!(response.kind, InteractionResponseType::Modal);
+    assert_eq!(data.custom_id.as_deref(), Some("warn"));
+    assert_eq!(data.title.as_deref(), Some("Warn a user"));
+    assert_eq!(data.components.unwrap().len(), 3);
+}
+
+#[test]
+fn test_modal_model() {
+    let data = modal_data(vec![
+        component("warn-reason", "They were being very rude"),
+        component("user_id", "123"),
+        component("count", ""),
+    ]);
+
+    let expected = WarnModal {
+        reason: "They were being very rude".into(),
+        user_id: Id::new(123),
+        count: None,
+    };
+
+    assert_eq!(WarnModal::from_interaction(data), Ok(expected));
+}
+
+#[test]
+fn test_modal_model_errors() {
+    let missing = modal_data(vec![component("warn-reason", "")]);
+    let invalid = modal_data(vec![component("warn-reason", "Spam"), component("user_id", "not an id")]);
+    let unknown = modal_data(vec![component("reason", "Spam")]);
+
+    assert_eq!(
+        WarnModal::from_interaction(missing),
+        Err(ModalParseError {
+            field: "warn-reason".into(),
+            kind: ModalParseErrorType::RequiredField,
+        })
+    );
+    assert_eq!(
+        WarnModal::from_interaction(invalid),
+        Err(ModalParseError {
+            field: "user_id".into(),
+            kind: ModalParseErrorType::InvalidValue("not an id".into()),
+        })
+    );
+    assert_eq!(
+        WarnModal::from_interaction(unknown),
+        Err(ModalParseError {
+            field: "reason".into(),
+            kind: ModalParseErrorType::UnknownField,
+        })
+    );
+}
@@ -0,0 +1,173 @@
+use twilight_interactions::{
+    command::{parse_prefix, CommandModel, CommandOption, CreateCommand, CreateOption},
+    error::{ParseError, ParseOptionError, ParseOptionErrorType, PrefixArgumentError, PrefixArgumentErrorType, PrefixError},
+};
+use twilight_model::id::{marker::UserMarker, Id};
+
+#[derive(CommandOption, CreateOption, Debug, PartialEq, Eq)]
+enum Direction {
+    #[option(name = "Ascending", value = "asc")]
+    Ascending,
+    #[option(name = "Descending", value = "desc")]
+    Descending,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "roll", desc = "Roll some dice")]
+struct RollCommand {
+    /// Dice to roll
+    dice: String,
+    /// Why you are rolling
+    reason: Option<String>,
+    /// Only show the result to you
+    ephemeral: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "stats", desc = "Roll stats")]
+struct StatsCommand {
+    /// How many stats to roll
+    #[command(min_value = 1, max_value = 10)]
+    count: i64,
+    /// Sort order
+    direction: Option<Direction>,
+    /// Who to roll for
+    user: Option<Id<UserMarker>>,
+    /// Show the rolls
+    show_rolls: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "nickname", desc = "Change your nickname")]
+struct NicknameCommand {
+    /// The new nickname
+    #[command(min_length = 2, max_length = 5)]
+    name: String,
+}
+
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "dice", desc = "Dice commands")]
+enum DiceCommand {
+    #[command(name = "roll")]
+    Roll(RollCommand),
+    #[command(name = "stats")]
+    Stats(StatsCommand),
+}
+
+#[test]
+fn test_prefix_positional() {
+    let roll: RollCommand = parse_prefix("2d20 for initiative", None).unwrap();
+
+    assert_eq!(
+        roll,
+        RollCommand {
+            dice: "2d20".to_owned(),
+            reason: Some("for initiative".to_owned()),
+            ephemeral: None,
+        }
+    );
+}
+
+#[test]
+fn test_prefix_named() {
+    let roll: RollCommand = parse_prefix(r#"--reason "a \"good\" reason" --ephemeral 1d6"#, None).unwrap();
+
+    assert_eq!(
+        roll,
+        RollCommand {
+            dice: "1d6".to_owned(),
+            reason: Some("a \"good\" reason".to_owned()),
+            ephemeral: Some(true),
+        }
+    );
+
+    let roll: RollCommand = parse_prefix("--dice=4d6 --ephemeral=no", None).unwrap();
+    assert_eq!(roll.dice, "4d6");
+    assert_eq!(roll.ephemeral, Some(false));
+}
+
+#[test]
+fn test_prefix_subcommand() {
+    let dice: DiceCommand = parse_prefix("stats 6 desc <@!123> --show-rolls", None).unwrap();
+
+    assert_eq!(
+        dice,
+        DiceCommand::Stats(StatsCommand {
+            count: 6,
+            direction: Some(Direction::Descending),
+            user: Some(Id::new(123)),
+            show_rolls: Some(true),
+        })
+    );
+
+    let dice: DiceCommand = parse_prefix("STATS 2 ascending", None).unwrap();
+    assert_eq!(
+        dice,
+        DiceCommand::Stats(StatsCommand {
+            count: 2,
+            direction: Some(Direction::Ascending),
+            user: None,
+            show_rolls: None,
+        })
+    );
+}
+
+#[test]
+fn test_prefix_errors() {
+    let error = |argument: &str, kind| {
+        PrefixError::Argument(PrefixArgumentError {
+            argument: argument.to_owned(),
+            kind,
+        })
+    };
+
+    assert_eq!(
+        parse_prefix::<DiceCommand>("", None).unwrap_err(),
+        error("", PrefixArgumentErrorType::MissingSubcommand)
+    );
+    assert_eq!(
+        parse_prefix::<DiceCommand>("flip", None).unwrap_err(),
+        error("flip", PrefixArgumentErrorType::UnknownSubcommand)
+    );
+    assert_eq!(
+        parse_prefix::<DiceCommand>("stats many", None).unwrap_err(),
+        error("many", PrefixArgumentErrorType::InvalidValue("count".to_owned()))
+    );
+    assert_eq!(
+        parse_prefix::<DiceCommand>("stats 1 asc 123 456", None).unwrap_err(),
+        error("456", PrefixArgumentErrorType::UnexpectedArgument)
+    );
+    assert_eq!(
+        parse_prefix::<RollCommand>("1d6 --loud", None).unwrap_err(),
+        error("--loud", PrefixArgumentErrorType::UnknownOption)
+    );
+    assert_eq!(
+        parse_prefix::<RollCommand>("--reason", None).unwrap_err(),
+        error("reason", PrefixArgumentErrorType::MissingValue)
+    );
+    assert_eq!(
+        parse_prefix::<RollCommand>("\"1d6", None).unwrap_err(),
+        error("1d6", PrefixArgumentErrorType::UnclosedQuote)
+    );
+
+    // Limits are still checked by the command model
+    assert!(matches!(parse_prefix::<DiceCommand>("stats 20", None), Err(PrefixError::Parse(_))));
+    assert!(matches!(parse_prefix::<RollCommand>("", None), Err(PrefixError::Parse(_))));
+}
+
+#[test]
+fn test_prefix_string_length() {
+    let error = |value: &str| {
+        PrefixError::Parse(ParseError::Option(ParseOptionError {
+            field: "name".to_owned(),
+            kind: ParseOptionErrorType::StringLengthOutOfRange(value.to_owned()),
+        }))
+    };
+
+    assert_eq!(parse_prefix::<NicknameCommand>("a", None).unwrap_err(), error("a"));
+    assert_eq!(parse_prefix::<NicknameCommand>("abcdef", None).unwrap_err(), error("abcdef"));
+
+    // The length is counted in characters
+    let nickname: NicknameCommand = parse_prefix("héllo", None).unwrap();
+    assert_eq!(nickname.name, "héllo");
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use twilight_interactions::command::{ApplicationCommandData, CommandInputData, CommandModel, CreateCommand, DescriptionLocalizations};
 use twilight_model::{
     application::{
-        command::{CommandOption, CommandOptionType},
+        command::{CommandOption, CommandOptionType, CommandType},
         interaction::application_command::{CommandDataOption, CommandOptionValue},
     },
     guild::Permissions,
@@ -75,6 +75,7 @@ fn test_subcommand_model() {
     let data = CommandInputData {
         options: command_options,
         resolved: None,
+        target_id: None,
     };
 
     let result = SubCommand::from_interaction(data).unwrap();
@@ -102,6 +103,7 @@ fn test_subcommand_group_model() {
     let data = CommandInputData {
         options: command_options,
         resolved: None,
+        target_id: None,
     };
 
     let result = SubCommand::from_interaction(data).unwrap();
@@ -211,6 +213,7 @@ fn test_create_subcommand() {
         dm_permission: None,
         group: true,
         nsfw: None,
+        kind: CommandType::ChatInput,
     };
 
     assert_eq!(SubCommand::create_command(), expected);